@@ -6,3 +6,23 @@ pub struct ProgramRegistryUpdate {
     pub program_ids: Vec<Pubkey>,
     pub remove: bool,
 }
+
+/// Event emitted when the folio action registrar is updated.
+#[event]
+pub struct FolioActionRegistryUpdate {
+    pub program_ids: Vec<Pubkey>,
+    pub remove: bool,
+}
+
+/// Event emitted when the transfer hook registrar is updated.
+#[event]
+pub struct TransferHookRegistryUpdate {
+    pub program_ids: Vec<Pubkey>,
+    pub remove: bool,
+}
+
+/// Event emitted when the unkill config is set.
+#[event]
+pub struct UnkillConfigSet {
+    pub unkill_timelock: u64,
+}
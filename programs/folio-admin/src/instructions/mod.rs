@@ -1,9 +1,15 @@
 pub mod init_program_registrar;
 pub mod set_dao_fee_config;
 pub mod set_folio_fee_config;
+pub mod set_unkill_config;
+pub mod update_folio_action_registrar;
 pub mod update_program_registrar;
+pub mod update_transfer_hook_registrar;
 
 pub use init_program_registrar::*;
 pub use set_dao_fee_config::*;
 pub use set_folio_fee_config::*;
+pub use set_unkill_config::*;
+pub use update_folio_action_registrar::*;
 pub use update_program_registrar::*;
+pub use update_transfer_hook_registrar::*;
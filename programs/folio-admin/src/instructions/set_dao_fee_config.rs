@@ -1,4 +1,5 @@
 use crate::state::DAOFeeConfig;
+use crate::utils::ProtocolFeeRecipient;
 use anchor_lang::prelude::*;
 use shared::check_condition;
 use shared::constants::common::ADMIN;
@@ -11,7 +12,7 @@ use shared::errors::ErrorCode;
 /// * `system_program` - The system program.
 /// * `rent` - The rent sysvar.
 /// * `admin` - The admin account (mut, signer).
-/// * `dao_fee_config` - The DAO fee config account (PDA) (init_if_needed, not signer).
+/// * `dao_fee_config` - The DAO fee config account (PDA) (mut, not signer).
 #[derive(Accounts)]
 pub struct SetDAOFeeConfig<'info> {
     pub system_program: Program<'info, System>,
@@ -20,14 +21,12 @@ pub struct SetDAOFeeConfig<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
 
-    #[account(
-        init_if_needed,
-        payer = admin,
-        space = DAOFeeConfig::SIZE,
+    /// CHECK: Could be empty, legacy or set, initialized or resized in the handler
+    #[account(mut,
         seeds = [DAO_FEE_CONFIG_SEEDS],
         bump
     )]
-    pub dao_fee_config: Account<'info, DAOFeeConfig>,
+    pub dao_fee_config: UncheckedAccount<'info>,
 }
 
 impl SetDAOFeeConfig<'_> {
@@ -66,23 +65,27 @@ impl SetDAOFeeConfig<'_> {
 /// * `fee_recipient` - The fee recipient of the DAO.
 /// * `scaled_default_fee_numerator` - The default fee numerator of the DAO, scaled in D18.
 /// * `scaled_default_fee_floor` - The default fee floor of the DAO, scaled in D18.
+/// * `protocol_fee_recipients` - The protocol fee recipients to split the DAO fee between, replaces the current ones.
+///                               An empty list means the fee recipient gets the whole DAO fee.
 pub fn handler(
     ctx: Context<SetDAOFeeConfig>,
     fee_recipient: Option<Pubkey>,
     scaled_default_fee_numerator: Option<u128>,
     scaled_default_fee_floor: Option<u128>,
+    protocol_fee_recipients: Option<Vec<ProtocolFeeRecipient>>,
 ) -> Result<()> {
     ctx.accounts
         .validate(&scaled_default_fee_numerator, &scaled_default_fee_floor)?;
 
-    let dao_fee_config = &mut ctx.accounts.dao_fee_config;
-
     DAOFeeConfig::init_or_update_dao_fee_config(
-        dao_fee_config,
+        &ctx.accounts.dao_fee_config,
+        &ctx.accounts.admin,
+        &ctx.accounts.system_program,
         ctx.bumps.dao_fee_config,
         fee_recipient,
         scaled_default_fee_numerator,
         scaled_default_fee_floor,
+        protocol_fee_recipients,
     )?;
 
     Ok(())
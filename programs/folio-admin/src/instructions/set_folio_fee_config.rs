@@ -20,11 +20,13 @@ use shared::errors::ErrorCode;
 /// * `dao_fee_config` - The DAO fee config account (PDA) (not mut, not signer).
 /// * `folio_token_mint` - The folio token mint account (mut, not signer).
 /// * `folio` - The folio account (PDA) (mut, not signer).
-/// * `folio_fee_config` - The folio fee config account (PDA) (init_if_needed, not signer).
+/// * `folio_fee_config` - The folio fee config account (PDA) (mut, not signer).
 /// * `folio_program` - The folio program account (CHECK: executable).
 /// * `fee_recipients` - The folio fee recipients account (mut).
 /// * `fee_distribution` - The folio fee distribution account (mut).
 /// * `dao_fee_recipient` - The DAO fee recipient account's token account (mut).
+///
+/// * `remaining_accounts` - The token accounts of the other protocol fee recipients (mut), in the same order as in the DAO fee config.
 #[derive(Accounts)]
 pub struct SetFolioFeeConfig<'info> {
     pub system_program: Program<'info, System>,
@@ -34,11 +36,12 @@ pub struct SetFolioFeeConfig<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
 
+    /// CHECK: Could be a legacy account, loaded with the legacy layout support in the folio program
     #[account(
         seeds = [DAO_FEE_CONFIG_SEEDS],
-        bump = dao_fee_config.bump
+        bump
     )]
-    pub dao_fee_config: Account<'info, DAOFeeConfig>,
+    pub dao_fee_config: UncheckedAccount<'info>,
 
     /// CHECK: Folio token mint
     #[account(mut)]
@@ -52,14 +55,12 @@ pub struct SetFolioFeeConfig<'info> {
     )]
     pub folio: UncheckedAccount<'info>,
 
-    #[account(
-        init_if_needed,
-        payer = admin,
-        space = FolioFeeConfig::SIZE,
+    /// CHECK: Could be empty, legacy or set, initialized or resized in the handler
+    #[account(mut,
         seeds = [FOLIO_FEE_CONFIG_SEEDS, folio.key().as_ref()],
         bump
     )]
-    pub folio_fee_config: Account<'info, FolioFeeConfig>,
+    pub folio_fee_config: UncheckedAccount<'info>,
 
     /*
     Specific accounts for the distribute fees instruction
@@ -113,10 +114,12 @@ impl SetFolioFeeConfig<'_> {
 /// * `ctx` - The context of the instruction.
 /// * `scaled_fee_numerator` - The fee numerator of the Folio, scaled in D18.
 /// * `scaled_fee_floor` - The fee floor of the Folio, scaled in D18.
-pub fn handler(
-    ctx: Context<SetFolioFeeConfig>,
+/// * `fee_recipient` - The DAO fee recipient of the Folio, default pubkey to go back to the DAO fee recipient.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SetFolioFeeConfig<'info>>,
     scaled_fee_numerator: Option<u128>,
     scaled_fee_floor: Option<u128>,
+    fee_recipient: Option<Pubkey>,
 ) -> Result<()> {
     ctx.accounts
         .validate(&scaled_fee_numerator, &scaled_fee_floor)?;
@@ -135,16 +138,19 @@ pub fn handler(
         &ctx.accounts.fee_recipients.to_account_info(),
         &ctx.accounts.fee_distribution.to_account_info(),
         &ctx.accounts.dao_fee_recipient.to_account_info(),
+        ctx.remaining_accounts,
     )?;
 
-    let folio_fee_config = &mut ctx.accounts.folio_fee_config;
-
     FolioFeeConfig::init_or_update_folio_fee_config(
-        folio_fee_config,
-        &ctx.accounts.dao_fee_config,
+        &ctx.accounts.folio_fee_config,
+        &DAOFeeConfig::try_from_account_info_with_legacy_support(&ctx.accounts.dao_fee_config)?,
+        &ctx.accounts.admin,
+        &ctx.accounts.system_program,
+        &ctx.accounts.folio.key(),
         ctx.bumps.folio_fee_config,
         scaled_fee_numerator,
         scaled_fee_floor,
+        fee_recipient,
     )?;
 
     Ok(())
@@ -0,0 +1,64 @@
+use crate::events::UnkillConfigSet;
+use crate::state::UnkillConfig;
+use anchor_lang::prelude::*;
+use shared::check_condition;
+use shared::constants::common::ADMIN;
+use shared::constants::UNKILL_CONFIG_SEEDS;
+use shared::errors::ErrorCode;
+
+/// Set the unkill config.
+///
+/// # Arguments
+/// * `system_program` - The system program.
+/// * `admin` - The admin account (mut, signer).
+/// * `unkill_config` - The unkill config account (PDA) (init if needed, not signer).
+#[derive(Accounts)]
+pub struct SetUnkillConfig<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = UnkillConfig::SIZE,
+        seeds = [UNKILL_CONFIG_SEEDS],
+        bump
+    )]
+    pub unkill_config: Account<'info, UnkillConfig>,
+}
+
+impl SetUnkillConfig<'_> {
+    /// Validate the instruction.
+    ///
+    /// # Checks
+    /// * Admin account is the authorized admin.
+    /// * Unkill timelock is less than or equal to the max unkill timelock.
+    pub fn validate(&self, unkill_timelock: u64) -> Result<()> {
+        check_condition!(self.admin.key() == ADMIN, Unauthorized);
+
+        UnkillConfig::validate_unkill_timelock(unkill_timelock)?;
+
+        Ok(())
+    }
+}
+
+/// Set the time that needs to pass after a folio is killed before its owner can unkill it.
+/// Initializes the unkill config if it doesn't exist yet. Folios that are already killed keep the timelock that was
+/// set when they were killed.
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+/// * `unkill_timelock` - The unkill timelock, scaled in seconds.
+pub fn handler(ctx: Context<SetUnkillConfig>, unkill_timelock: u64) -> Result<()> {
+    ctx.accounts.validate(unkill_timelock)?;
+
+    let unkill_config = &mut ctx.accounts.unkill_config;
+    unkill_config.bump = ctx.bumps.unkill_config;
+    unkill_config.unkill_timelock = unkill_timelock;
+
+    emit!(UnkillConfigSet { unkill_timelock });
+
+    Ok(())
+}
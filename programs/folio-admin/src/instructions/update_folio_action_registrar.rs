@@ -0,0 +1,73 @@
+use crate::events::FolioActionRegistryUpdate;
+use crate::state::FolioActionRegistrar;
+use anchor_lang::prelude::*;
+use shared::check_condition;
+use shared::constants::common::ADMIN;
+use shared::constants::FOLIO_ACTION_REGISTRAR_SEEDS;
+use shared::errors::ErrorCode;
+
+/// Update the folio action registrar.
+///
+/// # Arguments
+/// * `system_program` - The system program.
+/// * `admin` - The admin account (mut, signer).
+/// * `folio_action_registrar` - The folio action registrar account (PDA) (init if needed, not signer).
+#[derive(Accounts)]
+pub struct UpdateFolioActionRegistrar<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = FolioActionRegistrar::SIZE,
+        seeds = [FOLIO_ACTION_REGISTRAR_SEEDS],
+        bump
+    )]
+    pub folio_action_registrar: Account<'info, FolioActionRegistrar>,
+}
+
+impl UpdateFolioActionRegistrar<'_> {
+    /// Validate the instruction.
+    ///
+    /// # Checks
+    /// * Admin account is the authorized admin.
+    pub fn validate(&self) -> Result<()> {
+        check_condition!(self.admin.key() == ADMIN, Unauthorized);
+
+        Ok(())
+    }
+}
+
+/// Update the folio action registrar, which holds the programs a folio can call into with `execute_folio_action`.
+/// Initializes the registrar if it doesn't exist yet.
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+/// * `program_ids` - The program ids to add or remove from the registrar.
+/// * `remove` - Whether to remove the program ids from the registrar or to add them.
+pub fn handler(
+    ctx: Context<UpdateFolioActionRegistrar>,
+    program_ids: Vec<Pubkey>,
+    remove: bool,
+) -> Result<()> {
+    ctx.accounts.validate()?;
+
+    let folio_action_registrar = &mut ctx.accounts.folio_action_registrar;
+    folio_action_registrar.bump = ctx.bumps.folio_action_registrar;
+
+    if remove {
+        folio_action_registrar.remove_from_registrar(&program_ids)?;
+    } else {
+        folio_action_registrar.add_to_registrar(&program_ids)?;
+    }
+
+    emit!(FolioActionRegistryUpdate {
+        program_ids,
+        remove,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use crate::events::TransferHookRegistryUpdate;
+use crate::state::TransferHookRegistrar;
+use anchor_lang::prelude::*;
+use shared::check_condition;
+use shared::constants::common::ADMIN;
+use shared::constants::TRANSFER_HOOK_REGISTRAR_SEEDS;
+use shared::errors::ErrorCode;
+
+/// Update the transfer hook registrar.
+///
+/// # Arguments
+/// * `system_program` - The system program.
+/// * `admin` - The admin account (mut, signer).
+/// * `transfer_hook_registrar` - The transfer hook registrar account (PDA) (init if needed, not signer).
+#[derive(Accounts)]
+pub struct UpdateTransferHookRegistrar<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = TransferHookRegistrar::SIZE,
+        seeds = [TRANSFER_HOOK_REGISTRAR_SEEDS],
+        bump
+    )]
+    pub transfer_hook_registrar: Account<'info, TransferHookRegistrar>,
+}
+
+impl UpdateTransferHookRegistrar<'_> {
+    /// Validate the instruction.
+    ///
+    /// # Checks
+    /// * Admin account is the authorized admin.
+    pub fn validate(&self) -> Result<()> {
+        check_condition!(self.admin.key() == ADMIN, Unauthorized);
+
+        Ok(())
+    }
+}
+
+/// Update the transfer hook registrar, which holds the transfer hook programs allowed on the basket tokens.
+/// Initializes the registrar if it doesn't exist yet.
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+/// * `program_ids` - The program ids to add or remove from the registrar.
+/// * `remove` - Whether to remove the program ids from the registrar or to add them.
+pub fn handler(
+    ctx: Context<UpdateTransferHookRegistrar>,
+    program_ids: Vec<Pubkey>,
+    remove: bool,
+) -> Result<()> {
+    ctx.accounts.validate()?;
+
+    let transfer_hook_registrar = &mut ctx.accounts.transfer_hook_registrar;
+    transfer_hook_registrar.bump = ctx.bumps.transfer_hook_registrar;
+
+    if remove {
+        transfer_hook_registrar.remove_from_registrar(&program_ids)?;
+    } else {
+        transfer_hook_registrar.add_to_registrar(&program_ids)?;
+    }
+
+    emit!(TransferHookRegistryUpdate {
+        program_ids,
+        remove,
+    });
+
+    Ok(())
+}
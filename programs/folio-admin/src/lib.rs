@@ -6,12 +6,18 @@
 //!
 //! * `init_program_registrar` - Initialize the program registrar.
 //! * `update_program_registrar` - Update the program registrar.
-//! * `set_dao_fee_config` - Set the DAO fee config.
-//! * `set_folio_fee_config` - Set the Folio fee config.
+//! * `set_dao_fee_config` - Set the DAO fee config, optionally splitting the DAO fee between multiple protocol recipients.
+//! * `set_folio_fee_config` - Set the Folio fee config, optionally with a Folio specific DAO fee recipient.
+//! * `update_folio_action_registrar` - Update the programs a folio can call into with `execute_folio_action`.
+//! * `set_unkill_config` - Set the time that needs to pass after a folio is killed before it can be unkilled.
+//! * `update_transfer_hook_registrar` - Update the transfer hook programs allowed on the basket tokens.
 //!
 //! # Events
 //!
 //! * `ProgramRegistryUpdate` - Emitted when the program registrar is updated.
+//! * `FolioActionRegistryUpdate` - Emitted when the folio action registrar is updated.
+//! * `UnkillConfigSet` - Emitted when the unkill config is set.
+//! * `TransferHookRegistryUpdate` - Emitted when the transfer hook registrar is updated.
 //!
 #![allow(unexpected_cfgs)]
 #![allow(clippy::doc_overindented_list_items)]
@@ -22,6 +28,7 @@
 use anchor_lang::prelude::*;
 
 use instructions::*;
+use utils::*;
 pub mod events;
 pub mod instructions;
 pub mod state;
@@ -61,20 +68,43 @@ pub mod folio_admin {
         fee_recipient: Option<Pubkey>,
         scaled_default_fee_numerator: Option<u128>,
         scaled_default_fee_floor: Option<u128>,
+        protocol_fee_recipients: Option<Vec<ProtocolFeeRecipient>>,
     ) -> Result<()> {
         set_dao_fee_config::handler(
             ctx,
             fee_recipient,
             scaled_default_fee_numerator,
             scaled_default_fee_floor,
+            protocol_fee_recipients,
         )
     }
 
-    pub fn set_folio_fee_config(
-        ctx: Context<SetFolioFeeConfig>,
+    pub fn set_folio_fee_config<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SetFolioFeeConfig<'info>>,
         scaled_fee_numerator: Option<u128>,
         scaled_fee_floor: Option<u128>,
+        fee_recipient: Option<Pubkey>,
+    ) -> Result<()> {
+        set_folio_fee_config::handler(ctx, scaled_fee_numerator, scaled_fee_floor, fee_recipient)
+    }
+
+    pub fn update_folio_action_registrar(
+        ctx: Context<UpdateFolioActionRegistrar>,
+        program_ids: Vec<Pubkey>,
+        remove: bool,
+    ) -> Result<()> {
+        update_folio_action_registrar::handler(ctx, program_ids, remove)
+    }
+
+    pub fn set_unkill_config(ctx: Context<SetUnkillConfig>, unkill_timelock: u64) -> Result<()> {
+        set_unkill_config::handler(ctx, unkill_timelock)
+    }
+
+    pub fn update_transfer_hook_registrar(
+        ctx: Context<UpdateTransferHookRegistrar>,
+        program_ids: Vec<Pubkey>,
+        remove: bool,
     ) -> Result<()> {
-        set_folio_fee_config::handler(ctx, scaled_fee_numerator, scaled_fee_floor)
+        update_transfer_hook_registrar::handler(ctx, program_ids, remove)
     }
 }
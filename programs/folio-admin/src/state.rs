@@ -1,4 +1,6 @@
+use crate::utils::ProtocolFeeRecipient;
 use anchor_lang::prelude::*;
+use shared::constants::MAX_PROTOCOL_FEE_RECIPIENTS;
 
 /// DAO Fee config tracks the DAO fees that should be applied to each Folio.
 /// The DAO fee is the % of the Folio fees that should go to the DAO.
@@ -21,6 +23,10 @@ pub struct DAOFeeConfig {
     /// The fee floor is a lower-bound on what can be charged to Folio users, in case (Scaled in D18)
     /// the Folio has set its own top-level fees too low.
     pub default_fee_floor: u128,
+
+    /// The protocol level recipients the DAO fee is split between, portions sum to 1e18.
+    /// If empty (all default pubkeys), the fee recipient gets the whole DAO fee.
+    pub protocol_fee_recipients: [ProtocolFeeRecipient; MAX_PROTOCOL_FEE_RECIPIENTS],
 }
 
 impl DAOFeeConfig {
@@ -43,6 +49,10 @@ pub struct FolioFeeConfig {
     /// The fee floor is a lower-bound on what can be charged to Folio users, in case (Scaled in D18)
     /// the Folio has set its own top-level fees too low.
     pub fee_floor: u128,
+
+    /// The recipient of the DAO fee for this Folio (this is the owner of the token account and not the token account itself).
+    /// Default pubkey means the fee recipient of the DAOFeeConfig is used.
+    pub fee_recipient: Pubkey,
 }
 
 impl FolioFeeConfig {
@@ -68,3 +78,62 @@ impl ProgramRegistrar {
 
     pub const MAX_ACCEPTED_PROGRAMS: usize = 10;
 }
+
+/// Tracks the programs a folio can call into with `execute_folio_action`, signed by the folio, for example to claim
+/// airdrops or rewards of its basket tokens.
+/// Is controlled by the Admin of the protocol.
+/// Maximum number of programs that can be registered is 10.
+///
+/// PDA Seeds ["folio_action_registrar"]
+#[account]
+#[derive(Default, InitSpace)]
+pub struct FolioActionRegistrar {
+    pub bump: u8,
+
+    pub allowed_programs: [Pubkey; FolioActionRegistrar::MAX_ALLOWED_PROGRAMS],
+}
+
+impl FolioActionRegistrar {
+    pub const SIZE: usize = 8 + FolioActionRegistrar::INIT_SPACE;
+
+    pub const MAX_ALLOWED_PROGRAMS: usize = 10;
+}
+
+/// Tracks the time that needs to pass after a folio is killed before its owner can unkill it.
+/// If not set, the default unkill timelock of 1 week is used.
+/// Is controlled by the Admin of the protocol.
+///
+/// PDA Seeds ["unkill_config"]
+#[account]
+#[derive(Default, InitSpace)]
+pub struct UnkillConfig {
+    pub bump: u8,
+
+    /// Scaled in seconds
+    pub unkill_timelock: u64,
+}
+
+impl UnkillConfig {
+    pub const SIZE: usize = 8 + UnkillConfig::INIT_SPACE;
+}
+
+/// Tracks the transfer hook programs allowed on the basket tokens of the folios. A basket token with an active
+/// transfer hook can only be added to a basket, or transferred into a folio, if its transfer hook program is
+/// registered here, as the hook is invoked on every transfer of the token.
+/// Is controlled by the Admin of the protocol.
+/// Maximum number of programs that can be registered is 10.
+///
+/// PDA Seeds ["transfer_hook_registrar"]
+#[account]
+#[derive(Default, InitSpace)]
+pub struct TransferHookRegistrar {
+    pub bump: u8,
+
+    pub allowed_programs: [Pubkey; TransferHookRegistrar::MAX_ALLOWED_PROGRAMS],
+}
+
+impl TransferHookRegistrar {
+    pub const SIZE: usize = 8 + TransferHookRegistrar::INIT_SPACE;
+
+    pub const MAX_ALLOWED_PROGRAMS: usize = 10;
+}
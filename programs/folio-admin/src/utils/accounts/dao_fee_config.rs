@@ -1,15 +1,27 @@
+use std::collections::BTreeSet;
+
 use anchor_lang::prelude::*;
 use shared::check_condition;
-use shared::constants::{FEE_DENOMINATOR, MAX_DAO_FEE, MAX_FEE_FLOOR};
+use shared::constants::{
+    DAO_FEE_CONFIG_SEEDS, FEE_DENOMINATOR, MAX_DAO_FEE, MAX_FEE_FLOOR, MAX_FEE_RECIPIENTS_PORTION,
+    MAX_PROTOCOL_FEE_RECIPIENTS,
+};
 use shared::errors::ErrorCode;
+use shared::utils::account_util::{init_pda_account_rent, resize_account_rent};
+use shared::utils::{Decimal, Rounding};
 
 use crate::state::{DAOFeeConfig, FolioFeeConfig};
+use crate::utils::ProtocolFeeRecipient;
 
 /// Fee details for a given Folio.
 pub struct FeeDetails {
     /// The fee recipient of the DAO, is the owner and not the token account itself.
+    /// This is the first of the protocol fee recipients, and receives the rounding dust of the split.
     pub fee_recipient: Pubkey,
 
+    /// The protocol fee recipients the DAO fee is split between (at least one, portions sum to 1e18).
+    pub protocol_fee_recipients: Vec<ProtocolFeeRecipient>,
+
     /// The denominator of the fee, in D18.
     pub scaled_fee_denominator: u128,
 
@@ -20,63 +32,249 @@ pub struct FeeDetails {
     pub scaled_fee_floor: u128,
 }
 
+impl FeeDetails {
+    /// Split an amount of DAO fee shares between the protocol fee recipients, based on their portions.
+    /// The first recipient receives the rounding dust, so that the sum of the amounts is the amount to split.
+    ///
+    /// # Arguments
+    /// * `raw_amount` - The amount of DAO fee shares to split (D9).
+    ///
+    /// Returns the amount for each protocol fee recipient, in the same order as the protocol fee recipients.
+    pub fn split_protocol_fee_amount(&self, raw_amount: u64) -> Result<Vec<u64>> {
+        let mut raw_amounts = vec![0u64; self.protocol_fee_recipients.len()];
+        let mut raw_amount_left = raw_amount;
+
+        for (index, protocol_fee_recipient) in
+            self.protocol_fee_recipients.iter().enumerate().skip(1)
+        {
+            let raw_recipient_amount = Decimal::from_token_amount(raw_amount)?
+                .mul(&Decimal::from_scaled(protocol_fee_recipient.portion))?
+                .div(&Decimal::from_scaled(MAX_FEE_RECIPIENTS_PORTION))?
+                .to_token_amount(Rounding::Floor)?
+                .0;
+
+            raw_amounts[index] = raw_recipient_amount;
+            raw_amount_left = raw_amount_left
+                .checked_sub(raw_recipient_amount)
+                .ok_or(ErrorCode::MathOverflow)?;
+        }
+
+        if let Some(first_raw_amount) = raw_amounts.first_mut() {
+            *first_raw_amount = raw_amount_left;
+        }
+
+        Ok(raw_amounts)
+    }
+}
+
 impl DAOFeeConfig {
     /// Initialize or update the DAO fee config.
     /// If the DAO fee config is not initialized, it will be initialized with the given fee recipient, default fee numerator, and default fee floor.
     /// If the DAO fee config is already initialized, it will update the fee recipient, default fee numerator, and default fee floor.
+    /// DAO fee configs created before the protocol fee recipients were added are resized to the current size.
     ///
     /// # Arguments
     /// * `dao_fee_config` - The account info of the DAOFeeConfig account.
+    /// * `payer` - The payer of the rent for the DAOFeeConfig account.
+    /// * `system_program` - The system program.
     /// * `context_bump` - The bump of the dao fee config account in the context.
     /// * `fee_recipient` - The fee recipient of the DAO, is the owner and not the token account itself.
     /// * `scaled_default_fee_numerator` - The default fee numerator of the DAO, scaled in D18.
     /// * `scaled_default_fee_floor` - The default fee floor of the DAO, scaled in D18.
+    /// * `protocol_fee_recipients` - The protocol fee recipients to split the DAO fee between, replaces the current ones.
     #[cfg(not(tarpaulin_include))]
-    pub fn init_or_update_dao_fee_config(
-        dao_fee_config: &mut Account<DAOFeeConfig>,
+    #[allow(clippy::too_many_arguments)]
+    pub fn init_or_update_dao_fee_config<'info>(
+        dao_fee_config: &AccountInfo<'info>,
+        payer: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
         context_bump: u8,
         fee_recipient: Option<Pubkey>,
         scaled_default_fee_numerator: Option<u128>,
         scaled_default_fee_floor: Option<u128>,
+        protocol_fee_recipients: Option<Vec<ProtocolFeeRecipient>>,
     ) -> Result<()> {
-        let account_info_dao_fee_config = dao_fee_config.to_account_info();
+        let mut updated_dao_fee_config = if dao_fee_config.data_is_empty() {
+            // Not initialized yet
+            init_pda_account_rent(
+                dao_fee_config,
+                DAOFeeConfig::SIZE,
+                payer,
+                &crate::ID,
+                system_program,
+                &[&[DAO_FEE_CONFIG_SEEDS, &[context_bump]]],
+            )?;
 
-        let data = account_info_dao_fee_config.try_borrow_mut_data()?;
-        let mut disc_bytes = [0u8; 8];
-        disc_bytes.copy_from_slice(&data[..8]);
+            DAOFeeConfig {
+                bump: context_bump,
+                fee_recipient: fee_recipient.ok_or(ErrorCode::InvalidFeeRecipient)?,
+                default_fee_numerator: scaled_default_fee_numerator.unwrap_or(MAX_DAO_FEE),
+                default_fee_floor: scaled_default_fee_floor.unwrap_or(MAX_FEE_FLOOR),
+                protocol_fee_recipients: [ProtocolFeeRecipient::default();
+                    MAX_PROTOCOL_FEE_RECIPIENTS],
+            }
+        } else {
+            check_condition!(*dao_fee_config.owner == crate::ID, InvalidAccountOwner);
 
-        let discriminator = u64::from_le_bytes(disc_bytes);
+            if dao_fee_config.data_len() < DAOFeeConfig::SIZE {
+                resize_account_rent(dao_fee_config, DAOFeeConfig::SIZE, payer, system_program)?;
+            }
 
-        drop(data);
+            let mut existing_dao_fee_config = DAOFeeConfig::try_deserialize_with_legacy_support(
+                &dao_fee_config.try_borrow_data()?,
+            )?;
 
-        if discriminator == 0 {
-            // Not initialized yet
-            dao_fee_config.bump = context_bump;
-            dao_fee_config.fee_recipient = fee_recipient.ok_or(ErrorCode::InvalidFeeRecipient)?;
-            dao_fee_config.default_fee_numerator =
-                scaled_default_fee_numerator.unwrap_or(MAX_DAO_FEE);
-            dao_fee_config.default_fee_floor = scaled_default_fee_floor.unwrap_or(MAX_FEE_FLOOR);
-        } else {
-            check_condition!(dao_fee_config.bump == context_bump, InvalidBump);
+            check_condition!(existing_dao_fee_config.bump == context_bump, InvalidBump);
 
             if let Some(fee_recipient) = fee_recipient {
-                dao_fee_config.fee_recipient = fee_recipient;
+                existing_dao_fee_config.fee_recipient = fee_recipient;
             }
 
             if let Some(scaled_default_fee_numerator) = scaled_default_fee_numerator {
-                dao_fee_config.default_fee_numerator = scaled_default_fee_numerator;
+                existing_dao_fee_config.default_fee_numerator = scaled_default_fee_numerator;
             }
 
             if let Some(scaled_default_fee_floor) = scaled_default_fee_floor {
-                dao_fee_config.default_fee_floor = scaled_default_fee_floor;
+                existing_dao_fee_config.default_fee_floor = scaled_default_fee_floor;
             }
+
+            existing_dao_fee_config
+        };
+
+        if let Some(protocol_fee_recipients) = protocol_fee_recipients {
+            updated_dao_fee_config.set_protocol_fee_recipients(protocol_fee_recipients)?;
+        }
+
+        updated_dao_fee_config
+            .try_serialize(&mut &mut dao_fee_config.try_borrow_mut_data()?[..])?;
+
+        Ok(())
+    }
+
+    /// Deserialize a DAOFeeConfig account, supporting the legacy layout (before the protocol fee recipients were added).
+    /// Legacy accounts are zero-padded, meaning the fee recipient gets the whole DAO fee.
+    ///
+    /// # Arguments
+    /// * `data` - The data of the DAOFeeConfig account.
+    ///
+    /// Returns the deserialized DAOFeeConfig.
+    pub fn try_deserialize_with_legacy_support(data: &[u8]) -> Result<DAOFeeConfig> {
+        let mut padded_data = data.to_vec();
+
+        if padded_data.len() < DAOFeeConfig::SIZE {
+            padded_data.resize(DAOFeeConfig::SIZE, 0);
+        }
+
+        DAOFeeConfig::try_deserialize(&mut &padded_data[..])
+    }
+
+    /// Load a DAOFeeConfig account, supporting the legacy layout. Programs reading the DAO fee config use this instead
+    /// of `Account<DAOFeeConfig>`, as they can't resize the legacy accounts and those would fail to deserialize.
+    ///
+    /// # Arguments
+    /// * `dao_fee_config` - The account info of the DAOFeeConfig account.
+    ///
+    /// Returns the deserialized DAOFeeConfig.
+    #[cfg(not(tarpaulin_include))]
+    pub fn try_from_account_info_with_legacy_support(
+        dao_fee_config: &AccountInfo,
+    ) -> Result<DAOFeeConfig> {
+        check_condition!(*dao_fee_config.owner == crate::ID, InvalidAccountOwner);
+
+        DAOFeeConfig::try_deserialize_with_legacy_support(&dao_fee_config.try_borrow_data()?)
+    }
+
+    /// Set the protocol fee recipients, replacing the current ones.
+    /// An empty list means the fee recipient gets the whole DAO fee.
+    ///
+    /// # Arguments
+    /// * `protocol_fee_recipients` - The protocol fee recipients to split the DAO fee between.
+    pub fn set_protocol_fee_recipients(
+        &mut self,
+        protocol_fee_recipients: Vec<ProtocolFeeRecipient>,
+    ) -> Result<()> {
+        check_condition!(
+            protocol_fee_recipients.len() <= MAX_PROTOCOL_FEE_RECIPIENTS,
+            InvalidFeeRecipientCount
+        );
+
+        let mut new_protocol_fee_recipients =
+            [ProtocolFeeRecipient::default(); MAX_PROTOCOL_FEE_RECIPIENTS];
+
+        for (index, protocol_fee_recipient) in protocol_fee_recipients.iter().enumerate() {
+            check_condition!(
+                protocol_fee_recipient.recipient != Pubkey::default(),
+                InvalidFeeRecipient
+            );
+
+            new_protocol_fee_recipients[index] = *protocol_fee_recipient;
+        }
+
+        self.protocol_fee_recipients = new_protocol_fee_recipients;
+
+        if protocol_fee_recipients.is_empty() {
+            return Ok(());
+        }
+
+        self.validate_protocol_fee_recipient_total_portions_and_check_for_duplicates()
+    }
+
+    /// Validate the protocol fee recipient total portions.
+    /// Total portions must be 100% (in D18).
+    /// And validates there are no duplicate recipients.
+    pub fn validate_protocol_fee_recipient_total_portions_and_check_for_duplicates(
+        &self,
+    ) -> Result<()> {
+        let total_portion = self
+            .protocol_fee_recipients
+            .iter()
+            .try_fold(0u128, |total, r| total.checked_add(r.portion))
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        check_condition!(
+            total_portion == MAX_FEE_RECIPIENTS_PORTION,
+            InvalidFeeRecipientPortion
+        );
+
+        let mut seen = BTreeSet::new();
+        if !self
+            .protocol_fee_recipients
+            .iter()
+            .filter(|r| r.recipient != Pubkey::default())
+            .map(|r| r.recipient)
+            .all(|pubkey| seen.insert(pubkey))
+        {
+            return err!(ErrorCode::InvalidFeeRecipientContainsDuplicates);
         }
 
         Ok(())
     }
 
+    /// Get the protocol fee recipients the DAO fee is split between.
+    /// If no protocol fee recipients are set, the fee recipient gets the whole DAO fee.
+    ///
+    /// Returns the protocol fee recipients (at least one).
+    pub fn get_protocol_fee_recipients(&self) -> Vec<ProtocolFeeRecipient> {
+        let protocol_fee_recipients: Vec<ProtocolFeeRecipient> = self
+            .protocol_fee_recipients
+            .iter()
+            .filter(|r| r.recipient != Pubkey::default())
+            .copied()
+            .collect();
+
+        if protocol_fee_recipients.is_empty() {
+            return vec![ProtocolFeeRecipient {
+                recipient: self.fee_recipient,
+                portion: MAX_FEE_RECIPIENTS_PORTION,
+            }];
+        }
+
+        protocol_fee_recipients
+    }
+
     /// Get the fee details for a given Folio.
     /// If the Folio has its own fee config set, it will use that one, otherwise it will use the default one in DAOFeeConfig.
+    /// The fee recipient is only overridden if the Folio fee config has one set, in which case it gets the whole DAO fee.
     ///
     /// # Arguments
     /// * `folio_fee_config` - The account info of the FolioFeeConfig account.
@@ -84,8 +282,11 @@ impl DAOFeeConfig {
     /// Returns the fee details for the Folio.
     #[cfg(not(tarpaulin_include))]
     pub fn get_fee_details(&self, folio_fee_config: &AccountInfo) -> Result<FeeDetails> {
+        let protocol_fee_recipients = self.get_protocol_fee_recipients();
+
         let mut fee_details = FeeDetails {
-            fee_recipient: self.fee_recipient,
+            fee_recipient: protocol_fee_recipients[0].recipient,
+            protocol_fee_recipients,
             scaled_fee_denominator: FEE_DENOMINATOR,
             scaled_fee_numerator: self.default_fee_numerator,
             scaled_fee_floor: self.default_fee_floor,
@@ -104,10 +305,18 @@ impl DAOFeeConfig {
             }
 
             let folio_fee_config =
-                FolioFeeConfig::try_deserialize(&mut &folio_fee_config_data[..])?;
+                FolioFeeConfig::try_deserialize_with_legacy_support(&folio_fee_config_data)?;
 
             fee_details.scaled_fee_numerator = folio_fee_config.fee_numerator;
             fee_details.scaled_fee_floor = folio_fee_config.fee_floor;
+
+            if folio_fee_config.fee_recipient != Pubkey::default() {
+                fee_details.fee_recipient = folio_fee_config.fee_recipient;
+                fee_details.protocol_fee_recipients = vec![ProtocolFeeRecipient {
+                    recipient: folio_fee_config.fee_recipient,
+                    portion: MAX_FEE_RECIPIENTS_PORTION,
+                }];
+            }
         }
 
         Ok(fee_details)
@@ -1,56 +1,111 @@
 use anchor_lang::prelude::*;
 use shared::check_condition;
+use shared::constants::FOLIO_FEE_CONFIG_SEEDS;
 use shared::errors::ErrorCode;
+use shared::utils::account_util::{init_pda_account_rent, resize_account_rent};
 
 use crate::state::{DAOFeeConfig, FolioFeeConfig};
 
 impl FolioFeeConfig {
     /// Initialize or update the Folio fee config.
-    /// If the Folio fee config is not initialized, it will be initialized with the given fee numerator and fee floor.
-    /// If the Folio fee config is already initialized, it will update the fee numerator and fee floor.
+    /// If the Folio fee config is not initialized, it will be initialized with the given fee numerator, fee floor and fee recipient.
+    /// If the Folio fee config is already initialized, it will update the fee numerator, fee floor and fee recipient.
+    /// Folio fee configs created before the fee recipient was added are resized to the current size.
     ///
     /// # Arguments
     /// * `folio_fee_config` - The account info of the FolioFeeConfig account.
     /// * `dao_fee_config` - The account info of the DAOFeeConfig account.
+    /// * `payer` - The payer of the rent for the FolioFeeConfig account.
+    /// * `system_program` - The system program.
+    /// * `folio` - The folio the FolioFeeConfig account belongs to.
     /// * `context_bump` - The bump of the folio fee config account in the context.
     /// * `fee_numerator` - The fee numerator of the Folio, scaled in D18.
     /// * `fee_floor` - The fee floor of the Folio, scaled in D18.
+    /// * `fee_recipient` - The DAO fee recipient of the Folio, default pubkey to use the one of the DAOFeeConfig.
     #[cfg(not(tarpaulin_include))]
-    pub fn init_or_update_folio_fee_config(
-        folio_fee_config: &mut Account<FolioFeeConfig>,
+    #[allow(clippy::too_many_arguments)]
+    pub fn init_or_update_folio_fee_config<'info>(
+        folio_fee_config: &AccountInfo<'info>,
         dao_fee_config: &DAOFeeConfig,
+        payer: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+        folio: &Pubkey,
         context_bump: u8,
         fee_numerator: Option<u128>,
         fee_floor: Option<u128>,
+        fee_recipient: Option<Pubkey>,
     ) -> Result<()> {
-        let account_info_folio_fee_config = folio_fee_config.to_account_info();
+        let updated_folio_fee_config = if folio_fee_config.data_is_empty() {
+            // Not initialized yet
+            init_pda_account_rent(
+                folio_fee_config,
+                FolioFeeConfig::SIZE,
+                payer,
+                &crate::ID,
+                system_program,
+                &[&[FOLIO_FEE_CONFIG_SEEDS, folio.as_ref(), &[context_bump]]],
+            )?;
 
-        let data = account_info_folio_fee_config.try_borrow_mut_data()?;
-        let mut disc_bytes = [0u8; 8];
-        disc_bytes.copy_from_slice(&data[..8]);
+            FolioFeeConfig {
+                bump: context_bump,
+                fee_numerator: fee_numerator.unwrap_or(dao_fee_config.default_fee_numerator),
+                fee_floor: fee_floor.unwrap_or(dao_fee_config.default_fee_floor),
+                fee_recipient: fee_recipient.unwrap_or_default(),
+            }
+        } else {
+            check_condition!(*folio_fee_config.owner == crate::ID, InvalidAccountOwner);
 
-        let discriminator = u64::from_le_bytes(disc_bytes);
+            if folio_fee_config.data_len() < FolioFeeConfig::SIZE {
+                resize_account_rent(
+                    folio_fee_config,
+                    FolioFeeConfig::SIZE,
+                    payer,
+                    system_program,
+                )?;
+            }
 
-        drop(data);
+            let mut existing_folio_fee_config =
+                FolioFeeConfig::try_deserialize_with_legacy_support(
+                    &folio_fee_config.try_borrow_data()?,
+                )?;
 
-        if discriminator == 0 {
-            // Not initialized yet
-            folio_fee_config.bump = context_bump;
-            folio_fee_config.fee_numerator =
-                fee_numerator.unwrap_or(dao_fee_config.default_fee_numerator);
-            folio_fee_config.fee_floor = fee_floor.unwrap_or(dao_fee_config.default_fee_floor);
-        } else {
-            check_condition!(folio_fee_config.bump == context_bump, InvalidBump);
+            check_condition!(existing_folio_fee_config.bump == context_bump, InvalidBump);
 
             if let Some(fee_numerator) = fee_numerator {
-                folio_fee_config.fee_numerator = fee_numerator;
+                existing_folio_fee_config.fee_numerator = fee_numerator;
             }
 
             if let Some(fee_floor) = fee_floor {
-                folio_fee_config.fee_floor = fee_floor;
+                existing_folio_fee_config.fee_floor = fee_floor;
             }
-        }
+
+            if let Some(fee_recipient) = fee_recipient {
+                existing_folio_fee_config.fee_recipient = fee_recipient;
+            }
+
+            existing_folio_fee_config
+        };
+
+        updated_folio_fee_config
+            .try_serialize(&mut &mut folio_fee_config.try_borrow_mut_data()?[..])?;
 
         Ok(())
     }
+
+    /// Deserialize a FolioFeeConfig account, supporting the legacy layout (before the fee recipient was added).
+    /// Legacy accounts are zero-padded, meaning the fee recipient of the DAOFeeConfig is used.
+    ///
+    /// # Arguments
+    /// * `data` - The data of the FolioFeeConfig account.
+    ///
+    /// Returns the deserialized FolioFeeConfig.
+    pub fn try_deserialize_with_legacy_support(data: &[u8]) -> Result<FolioFeeConfig> {
+        let mut padded_data = data.to_vec();
+
+        if padded_data.len() < FolioFeeConfig::SIZE {
+            padded_data.resize(FolioFeeConfig::SIZE, 0);
+        }
+
+        FolioFeeConfig::try_deserialize(&mut &padded_data[..])
+    }
 }
@@ -1,3 +1,6 @@
 pub mod dao_fee_config;
+pub mod folio_action_registrar;
 pub mod folio_fee_config;
 pub mod program_registrar;
+pub mod transfer_hook_registrar;
+pub mod unkill_config;
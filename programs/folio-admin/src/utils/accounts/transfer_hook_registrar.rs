@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use shared::{check_condition, errors::ErrorCode};
+
+use crate::state::TransferHookRegistrar;
+use anchor_lang::prelude::Pubkey;
+
+impl TransferHookRegistrar {
+    /// Add one or multiple programs to the registrar. Programs that are already registered are skipped.
+    ///
+    /// # Arguments
+    /// * `program_ids` - The program ids to add to the registrar.
+    ///
+    /// Returns an error if there is not enough empty slots in the registrar.
+    pub fn add_to_registrar(&mut self, program_ids: &[Pubkey]) -> Result<()> {
+        let mut new_program_ids: Vec<Pubkey> = vec![];
+
+        for program_id in program_ids {
+            check_condition!(*program_id != Pubkey::default(), InvalidProgram);
+
+            if !self.is_in_registrar(*program_id) && !new_program_ids.contains(program_id) {
+                new_program_ids.push(*program_id);
+            }
+        }
+
+        let empty_slots = self
+            .allowed_programs
+            .iter()
+            .filter(|&&pubkey| pubkey == Pubkey::default())
+            .count();
+
+        check_condition!(empty_slots >= new_program_ids.len(), InvalidProgramCount);
+
+        let mut new_program_ids_iter = new_program_ids.into_iter();
+
+        for pubkey in self.allowed_programs.iter_mut() {
+            if *pubkey == Pubkey::default() {
+                if let Some(new_key) = new_program_ids_iter.next() {
+                    *pubkey = new_key;
+                } else {
+                    break;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Remove one or multiple programs from the registrar.
+    ///
+    /// # Arguments
+    /// * `program_ids` - The program ids to remove from the registrar.
+    ///
+    /// Returns an error if the program ids are not in the registrar.
+    pub fn remove_from_registrar(&mut self, program_ids: &[Pubkey]) -> Result<()> {
+        for program_id in program_ids {
+            let slot = self
+                .allowed_programs
+                .iter_mut()
+                .find(|pubkey| *pubkey == program_id && *program_id != Pubkey::default());
+
+            match slot {
+                Some(slot) => *slot = Pubkey::default(),
+                None => return Err(error!(ErrorCode::ProgramNotInRegistrar)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check if a program is in the registrar.
+    ///
+    /// # Arguments
+    /// * `program_id` - The program id to check.
+    ///
+    /// Returns true if the program is in the registrar, false otherwise.
+    pub fn is_in_registrar(&self, program_id: Pubkey) -> bool {
+        program_id != Pubkey::default() && self.allowed_programs.contains(&program_id)
+    }
+
+    /// Get the transfer hook programs allowed on the basket tokens, from the transfer hook registrar account.
+    /// No transfer hook program is allowed if the admin hasn't set up the registrar yet.
+    ///
+    /// # Arguments
+    /// * `transfer_hook_registrar` - The account info of the TransferHookRegistrar account.
+    ///
+    /// Returns the allowed transfer hook programs.
+    #[cfg(not(tarpaulin_include))]
+    pub fn get_allowed_programs(transfer_hook_registrar: &AccountInfo) -> Result<Vec<Pubkey>> {
+        if transfer_hook_registrar.data_is_empty() {
+            return Ok(vec![]);
+        }
+
+        check_condition!(
+            *transfer_hook_registrar.owner == crate::ID,
+            InvalidAccountOwner
+        );
+
+        let transfer_hook_registrar = TransferHookRegistrar::try_deserialize(
+            &mut &transfer_hook_registrar.try_borrow_data()?[..],
+        )?;
+
+        Ok(transfer_hook_registrar
+            .allowed_programs
+            .into_iter()
+            .filter(|program_id| *program_id != Pubkey::default())
+            .collect())
+    }
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use shared::check_condition;
+use shared::constants::{MAX_UNKILL_FOLIO_TIMELOCK, UNKILL_FOLIO_TIMELOCK};
+use shared::errors::ErrorCode;
+
+use crate::state::UnkillConfig;
+
+impl UnkillConfig {
+    /// Validate the unkill timelock to set in the unkill config.
+    ///
+    /// # Arguments
+    /// * `unkill_timelock` - The unkill timelock, scaled in seconds.
+    pub fn validate_unkill_timelock(unkill_timelock: u64) -> Result<()> {
+        check_condition!(
+            unkill_timelock <= MAX_UNKILL_FOLIO_TIMELOCK,
+            InvalidUnkillTimelock
+        );
+
+        Ok(())
+    }
+
+    /// Get the unkill timelock from the unkill config account, or the default one if the admin hasn't set it yet.
+    ///
+    /// # Arguments
+    /// * `unkill_config` - The account info of the UnkillConfig account.
+    ///
+    /// Returns the unkill timelock, scaled in seconds.
+    #[cfg(not(tarpaulin_include))]
+    pub fn get_unkill_timelock(unkill_config: &AccountInfo) -> Result<u64> {
+        if unkill_config.data_is_empty() {
+            return Ok(UNKILL_FOLIO_TIMELOCK);
+        }
+
+        check_condition!(*unkill_config.owner == crate::ID, InvalidAccountOwner);
+
+        let unkill_config =
+            UnkillConfig::try_deserialize(&mut &unkill_config.try_borrow_data()?[..])?;
+
+        Ok(unkill_config.unkill_timelock)
+    }
+}
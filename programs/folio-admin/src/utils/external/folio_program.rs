@@ -73,6 +73,7 @@ impl FolioProgram {
     /// * `fee_recipients` - The fee recipients account.
     /// * `fee_distribution` - The fee distribution account.
     /// * `dao_fee_recipient` - The DAO fee recipient account.
+    /// * `additional_dao_fee_recipients` - The token accounts of the other protocol fee recipients.
     #[allow(clippy::too_many_arguments)]
     pub fn distribute_fees_cpi<'a>(
         folio_program: &AccountInfo<'a>,
@@ -87,13 +88,14 @@ impl FolioProgram {
         fee_recipients: &AccountInfo<'a>,
         fee_distribution: &AccountInfo<'a>,
         dao_fee_recipient: &AccountInfo<'a>,
+        additional_dao_fee_recipients: &[AccountInfo<'a>],
     ) -> Result<()> {
         // Won't distribute the fees if the fee recipients account is not initialized (since it's initialized on update_folio instruction)
         if fee_recipients.data_is_empty() {
             return Ok(());
         }
 
-        let accounts = vec![
+        let mut accounts = vec![
             rent.clone(),
             system_program.clone(),
             token_program.clone(),
@@ -107,7 +109,7 @@ impl FolioProgram {
             dao_fee_recipient.clone(),
         ];
 
-        let account_metas = vec![
+        let mut account_metas = vec![
             AccountMeta::new_readonly(rent.key(), false),
             AccountMeta::new_readonly(system_program.key(), false),
             AccountMeta::new_readonly(token_program.key(), false),
@@ -121,6 +123,11 @@ impl FolioProgram {
             AccountMeta::new(dao_fee_recipient.key(), false),
         ];
 
+        for additional_dao_fee_recipient in additional_dao_fee_recipients {
+            accounts.push(additional_dao_fee_recipient.clone());
+            account_metas.push(AccountMeta::new(additional_dao_fee_recipient.key(), false));
+        }
+
         let mut data =
             FolioProgram::get_instruction_discriminator(Self::DISTRIBUTE_FEES_FUNCTION_NAME)
                 .to_vec();
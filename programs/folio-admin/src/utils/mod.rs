@@ -1,5 +1,7 @@
 pub mod accounts;
 pub mod external;
+pub mod structs;
 
 pub use accounts::*;
 pub use external::*;
+pub use structs::*;
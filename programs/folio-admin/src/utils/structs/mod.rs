@@ -0,0 +1,4 @@
+//! Structs for the Folio Admin program. Often used within an account.
+pub mod protocol_fee_recipient;
+
+pub use protocol_fee_recipient::*;
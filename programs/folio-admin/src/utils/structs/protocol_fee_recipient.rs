@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+/// A protocol level recipient of the DAO fees (treasury, insurance fund, buyback, etc.).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace, PartialEq, Debug)]
+pub struct ProtocolFeeRecipient {
+    /// The recipient of the fee (this is the owner of the token account and not the token account itself).
+    pub recipient: Pubkey,
+
+    /// The portion of the DAO fee to be sent to the recipient, scaled in D18.
+    pub portion: u128,
+}
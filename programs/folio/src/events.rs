@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::utils::RebalanceDetails;
+use crate::utils::{FeeDistributionFallback, RebalanceDetails};
 
 /// Event emitted when a folio is created.
 #[event]
@@ -12,6 +12,24 @@ pub struct FolioCreated {
 #[event]
 pub struct FolioKilled {}
 
+/// Event emitted when a folio is unkilled.
+#[event]
+pub struct FolioUnkilled {}
+
+/// Event emitted when the kill record of a folio killed before kill records existed is created, starting its unkill
+/// timelock.
+///
+/// # Arguments
+/// * `unkillable_at` - The time from which the folio can be unkilled, scaled in seconds.
+#[event]
+pub struct FolioUnkillTimelockStarted {
+    pub unkillable_at: u64,
+}
+
+/// Event emitted when a folio migration is aborted.
+#[event]
+pub struct FolioMigrationAborted {}
+
 /// Event emitted when a basket token is added.
 ///
 /// # Arguments
@@ -50,6 +68,19 @@ pub struct MintFeeSet {
     pub new_fee: u128,
 }
 
+/// Event emitted when a fee discount is set for a minter.
+///
+/// # Arguments
+/// * `minter` - The minter that benefits from the discount.
+/// * `discount` - The discount applied on the mint fee, scaled in D18.
+#[event]
+pub struct FeeDiscountSet {
+    pub minter: Pubkey,
+
+    /// Scaled in D18
+    pub discount: u128,
+}
+
 /// Event emitted when a fee recipient is set.
 ///
 /// # Arguments
@@ -87,6 +118,22 @@ pub struct ProtocolFeePaid {
     pub amount: u64,
 }
 
+/// Event emitted when a fee distribution is closed before being fully cranked.
+///
+/// # Arguments
+/// * `index` - The index of the fee distribution.
+/// * `recipient` - The recipient of the undistributed shares, the folio if they were added back to the pending fee shares
+///                 of the fee recipients.
+/// * `amount` - The amount of undistributed shares.
+#[event]
+pub struct FeeDistributionClosed {
+    pub index: u64,
+
+    pub recipient: Pubkey,
+
+    pub amount: u64,
+}
+
 /// Event emitted when an auction is opened.
 ///
 /// # Arguments
@@ -125,6 +172,7 @@ pub struct AuctionOpened {
 /// * `restricted_until` - The time only Auction Launcher can create auctions
 /// * `available_until` - The rebalance TTL
 /// * `details` - The details rebalance.
+/// * `use_ui_amounts` - Whether the limits and prices are in UI amounts.
 #[event]
 pub struct RebalanceStarted {
     pub nonce: u64,
@@ -133,6 +181,7 @@ pub struct RebalanceStarted {
     pub restricted_until: u64,
     pub available_until: u64,
     pub details: RebalanceDetails,
+    pub use_ui_amounts: bool,
 }
 
 /// Event emitted when an auction is closed.
@@ -177,3 +226,160 @@ pub struct AuctionLengthSet {
 pub struct MandateSet {
     pub new_mandate: Pubkey,
 }
+
+/// Event emitted when the branding of a folio token is updated.
+///
+/// # Arguments
+/// * `name` - The new name, if updated.
+/// * `symbol` - The new symbol, if updated.
+/// * `uri` - The new URI, if updated.
+#[event]
+pub struct FolioBrandingUpdated {
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub uri: Option<String>,
+}
+
+/// Event emitted when actions are paused on a folio.
+///
+/// # Arguments
+/// * `pause_flags` - The bitmask of the paused actions after the update.
+#[event]
+pub struct FolioPaused {
+    pub pause_flags: u8,
+}
+
+/// Event emitted when actions are unpaused on a folio.
+///
+/// # Arguments
+/// * `pause_flags` - The bitmask of the paused actions after the update.
+#[event]
+pub struct FolioUnpaused {
+    pub pause_flags: u8,
+}
+
+/// Event emitted when an ownership transfer is proposed.
+///
+/// # Arguments
+/// * `proposed_by` - The owner that proposed the ownership transfer.
+/// * `new_owner` - The proposed new owner.
+#[event]
+pub struct OwnerProposed {
+    pub proposed_by: Pubkey,
+    pub new_owner: Pubkey,
+}
+
+/// Event emitted when an ownership transfer is accepted.
+///
+/// # Arguments
+/// * `previous_owner` - The owner that lost the owner role.
+/// * `new_owner` - The new owner.
+#[event]
+pub struct OwnerAccepted {
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+}
+
+/// Event emitted when a pending ownership transfer is cancelled.
+///
+/// # Arguments
+/// * `cancelled_by` - The owner that cancelled the ownership transfer.
+/// * `new_owner` - The new owner that was proposed.
+#[event]
+pub struct OwnerProposalCancelled {
+    pub cancelled_by: Pubkey,
+    pub new_owner: Pubkey,
+}
+
+/// Event emitted when the mint allowlist is enabled or disabled on a folio.
+///
+/// # Arguments
+/// * `enabled` - Whether minting is restricted to the users on the mint allowlist.
+#[event]
+pub struct MintAllowlistEnabledSet {
+    pub enabled: bool,
+}
+
+/// Event emitted when the fallback for the undelivered shares of closed fee distributions is set on a folio.
+///
+/// # Arguments
+/// * `fee_distribution_fallback` - The fallback for the undelivered shares.
+#[event]
+pub struct FeeDistributionFallbackSet {
+    pub fee_distribution_fallback: FeeDistributionFallback,
+}
+
+/// Event emitted when a user is added to the mint allowlist of a folio.
+///
+/// # Arguments
+/// * `user` - The user added to the mint allowlist.
+#[event]
+pub struct MintAllowlistEntryAdded {
+    pub user: Pubkey,
+}
+
+/// Event emitted when a user is removed from the mint allowlist of a folio.
+///
+/// # Arguments
+/// * `user` - The user removed from the mint allowlist.
+#[event]
+pub struct MintAllowlistEntryRemoved {
+    pub user: Pubkey,
+}
+
+/// Event emitted when the mint limits of a folio are set.
+///
+/// # Arguments
+/// * `max_supply` - The maximum total supply of the folio, 0 means no cap.
+/// * `max_mint_per_tx` - The maximum amount of shares that can be minted in a single transaction, 0 means no limit.
+#[event]
+pub struct MintLimitsSet {
+    pub max_supply: u64,
+    pub max_mint_per_tx: u64,
+}
+
+/// Event emitted when the surplus balance of a basket token is credited to the basket.
+///
+/// # Arguments
+/// * `token` - The token mint of the basket token.
+/// * `amount` - The surplus amount credited to the basket.
+#[event]
+pub struct BasketBalanceSynced {
+    pub token: Pubkey,
+    pub amount: u64,
+}
+
+/// Event emitted when tokens that aren't part of the basket are swept out of the folio.
+///
+/// # Arguments
+/// * `token` - The token mint of the swept tokens.
+/// * `recipient` - The token account that received the swept tokens.
+/// * `amount` - The amount of tokens swept.
+#[event]
+pub struct NonBasketTokensSwept {
+    pub token: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+/// Event emitted when an action is executed on behalf of a folio.
+///
+/// # Arguments
+/// * `program_id` - The program called on behalf of the folio.
+#[event]
+pub struct FolioActionExecuted {
+    pub program_id: Pubkey,
+}
+
+/// Event emitted when a user pending basket created before the folio pending basket existed is tracked.
+///
+/// # Arguments
+/// * `owner` - The owner of the user pending basket.
+#[event]
+pub struct UserPendingBasketTracked {
+    pub owner: Pubkey,
+}
+
+/// Event emitted when the admin marks every user pending basket of a folio as tracked.
+#[event]
+pub struct PendingBasketsTrackedSet {}
@@ -0,0 +1,3 @@
+pub mod set_pending_baskets_tracked;
+
+pub use set_pending_baskets_tracked::*;
@@ -0,0 +1,61 @@
+use crate::events::PendingBasketsTrackedSet;
+use crate::state::Folio;
+use anchor_lang::prelude::*;
+use shared::check_condition;
+use shared::constants::common::ADMIN;
+use shared::errors::ErrorCode;
+
+/// Set Pending Baskets Tracked
+///
+/// # Arguments
+/// * `admin` - The admin account (not mut, signer).
+/// * `folio` - The folio account (PDA) (mut, not signer).
+#[derive(Accounts)]
+pub struct SetPendingBasketsTracked<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub folio: AccountLoader<'info, Folio>,
+}
+
+impl SetPendingBasketsTracked<'_> {
+    /// Validate the instruction.
+    ///
+    /// # Checks
+    /// * Admin account is the authorized admin.
+    /// * Folio is valid PDA.
+    /// * Pending baskets aren't marked as tracked yet.
+    pub fn validate(&self, folio: &Folio) -> Result<()> {
+        check_condition!(self.admin.key() == ADMIN, Unauthorized);
+
+        folio.validate_folio(&self.folio.key(), None, None, None)?;
+
+        check_condition!(
+            folio.pending_baskets_tracked == 0,
+            PendingBasketsAlreadyTracked
+        );
+
+        Ok(())
+    }
+}
+
+/// Mark every user pending basket of a folio created before the folio pending basket existed as tracked, which
+/// allows the folio owner to sync the basket balances.
+///
+/// This can't be verified on chain, so the admin first checks off chain that no user pending basket of the folio is
+/// left untracked (`is_tracked` = 0), backfilling them with `track_user_pending_basket` if needed. Otherwise the
+/// pending amounts of those users would be counted as surplus by `sync_basket_balance`.
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+pub fn handler(ctx: Context<SetPendingBasketsTracked>) -> Result<()> {
+    let folio = &mut ctx.accounts.folio.load_mut()?;
+
+    ctx.accounts.validate(folio)?;
+
+    folio.pending_baskets_tracked = 1;
+
+    emit!(PendingBasketsTrackedSet {});
+
+    Ok(())
+}
@@ -4,7 +4,9 @@ use crate::state::{Actor, Folio};
 use crate::utils::structs::{FolioStatus, Role};
 use crate::utils::RebalancePriceAndLimits;
 use anchor_lang::prelude::*;
-use shared::constants::REBALANCE_SEEDS;
+use folio_admin::state::TransferHookRegistrar;
+use folio_admin::ID as FOLIO_ADMIN_PROGRAM_ID;
+use shared::constants::{REBALANCE_SEEDS, TRANSFER_HOOK_REGISTRAR_SEEDS};
 use shared::utils::TokenUtil;
 use shared::{check_condition, constants::ACTOR_SEEDS, errors::ErrorCode};
 
@@ -17,6 +19,7 @@ use shared::{check_condition, constants::ACTOR_SEEDS, errors::ErrorCode};
 /// * `actor` - The actor account (PDA) (not mut, not signer).
 /// * `folio` - The folio account (PDA) (mut, not signer).
 /// * `rebalance` - The rebalance account (PDA) (init, not signer).
+/// * `transfer_hook_registrar` - The transfer hook registrar account (PDA) of the folio admin program (not mut, not signer).
 /// * remaining account tokens:
 ///  - token mints for rebalance
 #[derive(Accounts)]
@@ -42,6 +45,14 @@ pub struct AddRebalanceDetails<'info> {
         bump = rebalance.load()?.bump,
     )]
     pub rebalance: AccountLoader<'info, Rebalance>,
+
+    /// CHECK: Could be empty or could be set, if set we use the transfer hook programs allowed in it, else none are allowed
+    #[account(
+        seeds = [TRANSFER_HOOK_REGISTRAR_SEEDS],
+        bump,
+        seeds::program = FOLIO_ADMIN_PROGRAM_ID,
+    )]
+    pub transfer_hook_registrar: UncheckedAccount<'info>,
     // remaining accounts:
     // - token mints for rebalance
 }
@@ -60,10 +71,17 @@ impl AddRebalanceDetails<'_> {
             Some(vec![FolioStatus::Initialized]),
         )?;
 
+        let allowed_transfer_hook_programs =
+            TransferHookRegistrar::get_allowed_programs(&self.transfer_hook_registrar)?;
+
         for mint in mints {
             // Validate that the buy mint is a supported SPL token (can only check mint here, will check token account in the bid)
             check_condition!(
-                TokenUtil::is_supported_spl_token(Some(mint), None)?,
+                TokenUtil::is_supported_basket_spl_token(
+                    Some(mint),
+                    None,
+                    &allowed_transfer_hook_programs
+                )?,
                 UnsupportedSPLToken
             );
         }
@@ -97,7 +115,8 @@ pub fn handler(
             started_at: rebalance.started_at,
             restricted_until: rebalance.restricted_until,
             available_until: rebalance.available_until,
-            details: rebalance.details
+            details: rebalance.details,
+            use_ui_amounts: rebalance.use_ui_amounts == 1,
         });
     }
 
@@ -1,5 +1,5 @@
 use crate::state::{AuctionEnds, Rebalance};
-use crate::utils::structs::FolioStatus;
+use crate::utils::structs::{FolioStatus, PauseFlag};
 use crate::utils::{AuctionStatus, FolioTokenAmount};
 use crate::{
     cpi_call,
@@ -9,11 +9,11 @@ use crate::{
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked},
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
-use folio_admin::state::DAOFeeConfig;
+use folio_admin::state::{DAOFeeConfig, TransferHookRegistrar};
 use folio_admin::ID as FOLIO_ADMIN_PROGRAM_ID;
-use shared::constants::REBALANCE_SEEDS;
+use shared::constants::{REBALANCE_SEEDS, TRANSFER_HOOK_REGISTRAR_SEEDS};
 use shared::utils::TokenUtil;
 use shared::{
     check_condition,
@@ -41,8 +41,22 @@ use shared::{
 /// * `bidder_sell_token_account` - The bidder sell token account (PDA) (mut, not signer).
 /// * `bidder_buy_token_account` - The bidder buy token account (PDA) (mut, not signer).
 /// * `folio_sell_token_metadata` - The folio sell token metadata account (PDA) (mut, not signer).
+/// * `transfer_hook_registrar` - The transfer hook registrar account (PDA) of the folio admin program (not mut, not signer).
 ///
-/// * `remaining_accounts` - The remaining accounts will be the accounts required for the "custom" CPI provided by the bidder.
+/// * `remaining_accounts` - The remaining accounts will be the accounts required by the transfer hooks of the mints, followed by
+///     the accounts required for the "custom" CPI provided by the bidder.
+///
+/// Order is
+///
+/// - Only if the sell mint has an active transfer hook:
+///     - Transfer Hook Program (read)
+///     - Extra Account Meta List (read)
+///     - Extra accounts of the transfer hook
+/// - Only if the buy mint has an active transfer hook and there is no callback:
+///     - Transfer Hook Program (read)
+///     - Extra Account Meta List (read)
+///     - Extra accounts of the transfer hook
+/// - Accounts required for the "custom" CPI provided by the bidder
 #[derive(Accounts)]
 pub struct Bid<'info> {
     pub system_program: Program<'info, System>,
@@ -116,7 +130,8 @@ pub struct Bid<'info> {
         bump,
         seeds::program = FOLIO_ADMIN_PROGRAM_ID,
     )]
-    pub dao_fee_config: Account<'info, DAOFeeConfig>,
+    /// CHECK: Could be a legacy account, loaded with the legacy layout support in the handler
+    pub dao_fee_config: UncheckedAccount<'info>,
 
     /// CHECK: Could be empty or could be set, if set we use that one, else we use dao fee config
     #[account(
@@ -125,8 +140,25 @@ pub struct Bid<'info> {
         seeds::program = FOLIO_ADMIN_PROGRAM_ID,
     )]
     pub folio_fee_config: UncheckedAccount<'info>,
+
+    /// CHECK: Could be empty or could be set, if set we use the transfer hook programs allowed in it, else none are allowed
+    #[account(
+        seeds = [TRANSFER_HOOK_REGISTRAR_SEEDS],
+        bump,
+        seeds::program = FOLIO_ADMIN_PROGRAM_ID,
+    )]
+    pub transfer_hook_registrar: UncheckedAccount<'info>,
     /*
-    Remaining accounts will be the accounts required for the "custom" CPI provided by the bidder.
+    Remaining accounts will be (always in the same order):
+        - Only if the sell mint has an active transfer hook:
+            - Transfer Hook Program (read)
+            - Extra Account Meta List (read)
+            - Extra accounts of the transfer hook
+        - Only if the buy mint has an active transfer hook and there is no callback:
+            - Transfer Hook Program (read)
+            - Extra Account Meta List (read)
+            - Extra accounts of the transfer hook
+        - Accounts required for the "custom" CPI provided by the bidder.
      */
 }
 
@@ -139,8 +171,10 @@ impl Bid<'_> {
     /// * Auction sell token mint provided is the same as the sell mint on the auction account.
     /// * Auction buy token mint provided is the same as the buy mint on the auction account.
     /// * Buy token is a supported SPL token (mean it doesn't have any forbidden extensions).
+    /// * Transfer hook program of the buy token, if any, is allowed by the admin.
     /// * Validate auction ends account.
     /// * Validate rebalance nonce.
+    /// * Auctions aren't paused.
     pub fn validate(
         &self,
         folio: &Folio,
@@ -155,6 +189,8 @@ impl Bid<'_> {
             Some(vec![FolioStatus::Initialized]),
         )?;
 
+        folio.validate_not_paused(PauseFlag::Auctions)?;
+
         check_condition!(
             self.folio_token_mint.key() == folio.folio_token_mint,
             InvalidFolioTokenMint
@@ -172,13 +208,23 @@ impl Bid<'_> {
 
         // Validate that the buy token is a supported SPL token (only need to check the token account here)
         check_condition!(
-            TokenUtil::is_supported_spl_token(
+            TokenUtil::is_supported_basket_spl_token(
                 None,
-                Some(&self.bidder_buy_token_account.to_account_info())
+                Some(&self.bidder_buy_token_account.to_account_info()),
+                &[]
             )?,
             UnsupportedSPLToken
         );
 
+        // The transfer hook program of the buy mint could have been changed since the rebalance was started
+        check_condition!(
+            TokenUtil::is_transfer_hook_program_allowed(
+                &self.auction_buy_token_mint.to_account_info(),
+                &TransferHookRegistrar::get_allowed_programs(&self.transfer_hook_registrar)?
+            )?,
+            TransferHookProgramNotAllowed
+        );
+
         self.auction_ends.validate_auction_ends(
             &self.auction_ends.key(),
             auction,
@@ -215,11 +261,12 @@ impl Bid<'_> {
 /// # Arguments
 /// * `ctx` - The context of the instruction.
 /// * `raw_sell_amount` - The amount of sell tokens to sell (how much the bidder wants to buy from the folio).
-/// * `raw_max_buy_amount` - The maximum amount of buy tokens to buy (how much the bidder is willing to pay for the sell tokens from the folio).
+/// * `raw_max_buy_amount` - The maximum amount of buy tokens to buy (how much the bidder is willing to pay for the sell tokens from the folio),
+///                          including the transfer fee of the buy token (if any).
 /// * `with_callback` - Whether there is a provided callback that needs to be called before finishing the transfer.
 /// * `callback_data` - The data to pass to the callback.
-pub fn handler(
-    ctx: Context<Bid>,
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, Bid<'info>>,
     raw_sell_amount: u64,
     raw_max_buy_amount: u64,
     with_callback: bool,
@@ -234,6 +281,16 @@ pub fn handler(
 
     let folio_bump: u8;
 
+    // Limits and prices are in UI amounts for mints with a UI amount multiplier, if the rebalance uses them
+    let (scaled_sell_ui_multiplier, scaled_buy_ui_multiplier) = rebalance
+        .get_scaled_ui_multipliers(
+            &ctx.accounts.auction_sell_token_mint.to_account_info(),
+            &ctx.accounts.auction_buy_token_mint.to_account_info(),
+            current_time,
+        )?;
+    let (raw_sell_limit, raw_buy_limit) =
+        auction.get_raw_limits(scaled_sell_ui_multiplier, scaled_buy_ui_multiplier)?;
+
     let (_, raw_bought_amount, _price, scaled_folio_token_total_supply) = {
         let folio = &mut ctx.accounts.folio.load_mut()?;
         // checks auction is ongoing
@@ -241,10 +298,9 @@ pub fn handler(
             .validate(folio, current_time as u64, auction, rebalance)?;
 
         // Poke folio
-        let fee_details = ctx
-            .accounts
-            .dao_fee_config
-            .get_fee_details(&ctx.accounts.folio_fee_config)?;
+        let fee_details =
+            DAOFeeConfig::try_from_account_info_with_legacy_support(&ctx.accounts.dao_fee_config)?
+                .get_fee_details(&ctx.accounts.folio_fee_config)?;
 
         folio.poke(
             ctx.accounts.folio_token_mint.supply,
@@ -262,6 +318,8 @@ pub fn handler(
             current_time as u64,
             raw_sell_amount,
             raw_max_buy_amount,
+            scaled_sell_ui_multiplier,
+            scaled_buy_ui_multiplier,
         )?
     };
 
@@ -283,28 +341,29 @@ pub fn handler(
             &scaled_folio_token_total_supply,
         )?;
 
-        check_condition!(
-            sell_basket_presence >= auction.sell_limit,
-            BidInvariantViolated
-        );
+        check_condition!(sell_basket_presence >= raw_sell_limit, BidInvariantViolated);
     }
 
     // pay bidder
     let signer_seeds = &[FOLIO_SEEDS, folio_token_mint_key.as_ref(), &[folio_bump]];
 
-    token_interface::transfer_checked(
-        CpiContext::new_with_signer(
-            ctx.accounts.sell_token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.folio_sell_token_account.to_account_info(),
-                to: ctx.accounts.bidder_sell_token_account.to_account_info(),
-                authority: ctx.accounts.folio.to_account_info(),
-                mint: ctx.accounts.auction_sell_token_mint.to_account_info(),
-            },
-            &[signer_seeds],
-        ),
+    let mut remaining_accounts_iter = ctx.remaining_accounts.iter();
+
+    let sell_transfer_hook_accounts = TokenUtil::next_transfer_hook_accounts(
+        &mut remaining_accounts_iter,
+        &ctx.accounts.auction_sell_token_mint.to_account_info(),
+    )?;
+
+    TokenUtil::transfer_checked(
+        &ctx.accounts.sell_token_program.to_account_info(),
+        &ctx.accounts.folio_sell_token_account.to_account_info(),
+        &ctx.accounts.auction_sell_token_mint.to_account_info(),
+        &ctx.accounts.bidder_sell_token_account.to_account_info(),
+        &ctx.accounts.folio.to_account_info(),
+        &sell_transfer_hook_accounts,
         raw_sell_amount,
         ctx.accounts.auction_sell_token_mint.decimals,
+        &[signer_seeds],
     )?;
 
     emit!(AuctionBid {
@@ -319,7 +378,7 @@ pub fn handler(
 
         let raw_folio_buy_balance_before = ctx.accounts.folio_buy_token_account.amount;
 
-        cpi_call(ctx.remaining_accounts, callback_data)?;
+        cpi_call(remaining_accounts_iter.as_slice(), callback_data)?;
 
         // Validate we received the proper funds
         ctx.accounts.folio_buy_token_account.reload()?;
@@ -334,18 +393,39 @@ pub fn handler(
             InsufficientBid
         );
     } else {
-        token_interface::transfer_checked(
-            CpiContext::new(
-                ctx.accounts.buy_token_program.to_account_info(),
-                TransferChecked {
-                    from: ctx.accounts.bidder_buy_token_account.to_account_info(),
-                    to: ctx.accounts.folio_buy_token_account.to_account_info(),
-                    authority: ctx.accounts.bidder.to_account_info(),
-                    mint: ctx.accounts.auction_buy_token_mint.to_account_info(),
-                },
-            ),
-            raw_bought_amount,
+        let auction_buy_token_mint = ctx.accounts.auction_buy_token_mint.to_account_info();
+
+        let buy_transfer_hook_accounts = TokenUtil::next_transfer_hook_accounts(
+            &mut remaining_accounts_iter,
+            &auction_buy_token_mint,
+        )?;
+
+        // The bidder pays the transfer fee (if any), so the folio receives the full bought amount
+        let raw_bought_amount_with_fee =
+            TokenUtil::get_amount_before_transfer_fee(&auction_buy_token_mint, raw_bought_amount)?;
+
+        check_condition!(
+            raw_bought_amount_with_fee <= raw_max_buy_amount,
+            SlippageExceeded
+        );
+        check_condition!(
+            TokenUtil::get_amount_after_transfer_fee(
+                &auction_buy_token_mint,
+                raw_bought_amount_with_fee
+            )? >= raw_bought_amount,
+            InsufficientBid
+        );
+
+        TokenUtil::transfer_checked(
+            &ctx.accounts.buy_token_program.to_account_info(),
+            &ctx.accounts.bidder_buy_token_account.to_account_info(),
+            &auction_buy_token_mint,
+            &ctx.accounts.folio_buy_token_account.to_account_info(),
+            &ctx.accounts.bidder.to_account_info(),
+            &buy_transfer_hook_accounts,
+            raw_bought_amount_with_fee,
             ctx.accounts.auction_buy_token_mint.decimals,
+            &[],
         )?;
     }
 
@@ -365,7 +445,7 @@ pub fn handler(
     // end auction at limits
     // can still be griefed
     // limits may not be reacheable due to limited precision + defensive roundings
-    if sell_basket_presence == auction.sell_limit || buy_basket_presence >= auction.buy_limit {
+    if sell_basket_presence == raw_sell_limit || buy_basket_presence >= raw_buy_limit {
         auction.end = current_time - 1;
         ctx.accounts.auction_ends.end_time = current_time - 1;
     }
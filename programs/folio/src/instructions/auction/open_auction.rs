@@ -1,5 +1,5 @@
 use crate::state::{AuctionEnds, FolioBasket, Rebalance};
-use crate::utils::structs::{FolioStatus, Role};
+use crate::utils::structs::{FolioStatus, PauseFlag, Role};
 use crate::utils::{OpenAuctionConfig, PricesInAuction};
 use crate::{
     events::AuctionOpened,
@@ -11,8 +11,8 @@ use folio_admin::state::DAOFeeConfig;
 use folio_admin::ID as FOLIO_ADMIN_PROGRAM_ID;
 use shared::check_condition;
 use shared::constants::{
-    ACTOR_SEEDS, AUCTION_ENDS_SEEDS, AUCTION_SEEDS, DAO_FEE_CONFIG_SEEDS, FOLIO_BASKET_SEEDS,
-    FOLIO_FEE_CONFIG_SEEDS, REBALANCE_SEEDS,
+    ACTOR_SEEDS, AUCTION_ENDS_SEEDS, AUCTION_SEEDS, AUCTION_VERSION, DAO_FEE_CONFIG_SEEDS,
+    FOLIO_BASKET_SEEDS, FOLIO_FEE_CONFIG_SEEDS, REBALANCE_SEEDS,
 };
 use shared::errors::ErrorCode;
 
@@ -91,7 +91,8 @@ pub struct OpenAuction<'info> {
         bump,
         seeds::program = FOLIO_ADMIN_PROGRAM_ID,
     )]
-    pub dao_fee_config: Account<'info, DAOFeeConfig>,
+    /// CHECK: Could be a legacy account, loaded with the legacy layout support in the handler
+    pub dao_fee_config: UncheckedAccount<'info>,
 
     /// CHECK: Could be empty or could be set, if set we use that one, else we use dao fee config
     #[account(
@@ -107,6 +108,7 @@ impl OpenAuction<'_> {
     ///
     /// # Checks
     /// * Folio has the correct status and actor has the correct role.
+    /// * Auctions aren't paused.
     pub fn validate(
         &self,
         folio: &Folio,
@@ -121,6 +123,8 @@ impl OpenAuction<'_> {
             Some(vec![FolioStatus::Initialized]),
         )?;
 
+        folio.validate_not_paused(PauseFlag::Auctions)?;
+
         check_condition!(
             self.folio_token_mint.key() == folio.folio_token_mint,
             InvalidFolioTokenMint
@@ -172,6 +176,7 @@ pub fn handler(
     let folio = &mut ctx.accounts.folio.load_mut()?;
     let auction = &mut ctx.accounts.auction.load_init()?;
     auction.bump = ctx.bumps.auction;
+    auction.version = AUCTION_VERSION;
     let rebalance = &mut ctx.accounts.rebalance.load_mut()?;
     let folio_basket = &ctx.accounts.folio_basket.load()?;
 
@@ -189,10 +194,9 @@ pub fn handler(
     let current_time = Clock::get()?.unix_timestamp;
     {
         // Poke folio
-        let fee_details = ctx
-            .accounts
-            .dao_fee_config
-            .get_fee_details(&ctx.accounts.folio_fee_config)?;
+        let fee_details =
+            DAOFeeConfig::try_from_account_info_with_legacy_support(&ctx.accounts.dao_fee_config)?
+                .get_fee_details(&ctx.accounts.folio_fee_config)?;
 
         folio.poke(
             ctx.accounts.folio_token_mint.supply,
@@ -215,6 +219,13 @@ pub fn handler(
 
     let current_time = current_time as u64;
 
+    let (scaled_sell_ui_multiplier, scaled_buy_ui_multiplier) = rebalance
+        .get_scaled_ui_multipliers(
+            &ctx.accounts.sell_mint.to_account_info(),
+            &ctx.accounts.buy_mint.to_account_info(),
+            current_time as i64,
+        )?;
+
     // Input is also validate in open_auction.
     auction.open_auction(
         folio,
@@ -229,6 +240,8 @@ pub fn handler(
         0,
         config,
         false,
+        scaled_sell_ui_multiplier,
+        scaled_buy_ui_multiplier,
     )?;
 
     emit!(AuctionOpened {
@@ -1,5 +1,5 @@
 use crate::state::{AuctionEnds, FolioBasket, Rebalance};
-use crate::utils::structs::FolioStatus;
+use crate::utils::structs::{FolioStatus, PauseFlag};
 use crate::{
     events::AuctionOpened,
     state::{Auction, Folio},
@@ -11,7 +11,7 @@ use folio_admin::state::DAOFeeConfig;
 use folio_admin::ID as FOLIO_ADMIN_PROGRAM_ID;
 use shared::check_condition;
 use shared::constants::{
-    AUCTION_ENDS_SEEDS, AUCTION_SEEDS, DAO_FEE_CONFIG_SEEDS, FOLIO_BASKET_SEEDS,
+    AUCTION_ENDS_SEEDS, AUCTION_SEEDS, AUCTION_VERSION, DAO_FEE_CONFIG_SEEDS, FOLIO_BASKET_SEEDS,
     FOLIO_FEE_CONFIG_SEEDS, REBALANCE_SEEDS, RESTRICTED_AUCTION_BUFFER,
 };
 use shared::errors::ErrorCode;
@@ -90,7 +90,8 @@ pub struct OpenAuctionPermissionless<'info> {
         bump,
         seeds::program = FOLIO_ADMIN_PROGRAM_ID,
     )]
-    pub dao_fee_config: Account<'info, DAOFeeConfig>,
+    /// CHECK: Could be a legacy account, loaded with the legacy layout support in the handler
+    pub dao_fee_config: UncheckedAccount<'info>,
 
     /// CHECK: Could be empty or could be set, if set we use that one, else we use dao fee config
     #[account(
@@ -106,6 +107,7 @@ impl OpenAuctionPermissionless<'_> {
     ///
     /// # Checks
     /// * Folio has the correct status.
+    /// * Auctions aren't paused.
     pub fn validate(
         &self,
         folio: &Folio,
@@ -120,6 +122,8 @@ impl OpenAuctionPermissionless<'_> {
             Some(vec![FolioStatus::Initialized]),
         )?;
 
+        folio.validate_not_paused(PauseFlag::Auctions)?;
+
         check_condition!(
             self.folio_token_mint.key() == folio.folio_token_mint,
             InvalidFolioTokenMint
@@ -154,6 +158,7 @@ pub fn handler(
     let folio = &mut ctx.accounts.folio.load_mut()?;
     let auction = &mut ctx.accounts.auction.load_init()?;
     auction.bump = ctx.bumps.auction;
+    auction.version = AUCTION_VERSION;
     let rebalance = &mut ctx.accounts.rebalance.load_mut()?;
     let folio_basket = &ctx.accounts.folio_basket.load()?;
 
@@ -162,10 +167,9 @@ pub fn handler(
     let current_time = Clock::get()?.unix_timestamp;
     {
         // Poke folio
-        let fee_details = ctx
-            .accounts
-            .dao_fee_config
-            .get_fee_details(&ctx.accounts.folio_fee_config)?;
+        let fee_details =
+            DAOFeeConfig::try_from_account_info_with_legacy_support(&ctx.accounts.dao_fee_config)?
+                .get_fee_details(&ctx.accounts.folio_fee_config)?;
 
         folio.poke(
             ctx.accounts.folio_token_mint.supply,
@@ -187,6 +191,13 @@ pub fn handler(
     )?;
     let current_time = current_time as u64;
 
+    let (scaled_sell_ui_multiplier, scaled_buy_ui_multiplier) = rebalance
+        .get_scaled_ui_multipliers(
+            &ctx.accounts.sell_mint.to_account_info(),
+            &ctx.accounts.buy_mint.to_account_info(),
+            current_time as i64,
+        )?;
+
     auction.open_auction(
         folio,
         &ctx.accounts.folio.key(),
@@ -200,6 +211,8 @@ pub fn handler(
         RESTRICTED_AUCTION_BUFFER,
         None,
         true,
+        scaled_sell_ui_multiplier,
+        scaled_buy_ui_multiplier,
     )?;
 
     emit!(AuctionOpened {
@@ -5,9 +5,11 @@ use crate::utils::structs::{FolioStatus, Role};
 use crate::utils::RebalancePriceAndLimits;
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
-use folio_admin::state::DAOFeeConfig;
+use folio_admin::state::{DAOFeeConfig, TransferHookRegistrar};
 use folio_admin::ID as FOLIO_ADMIN_PROGRAM_ID;
-use shared::constants::{DAO_FEE_CONFIG_SEEDS, FOLIO_FEE_CONFIG_SEEDS, REBALANCE_SEEDS};
+use shared::constants::{
+    DAO_FEE_CONFIG_SEEDS, FOLIO_FEE_CONFIG_SEEDS, REBALANCE_SEEDS, TRANSFER_HOOK_REGISTRAR_SEEDS,
+};
 use shared::utils::TokenUtil;
 use shared::{check_condition, constants::ACTOR_SEEDS, errors::ErrorCode};
 
@@ -21,6 +23,7 @@ use shared::{check_condition, constants::ACTOR_SEEDS, errors::ErrorCode};
 /// * `actor` - The actor account (PDA) (not mut, not signer).
 /// * `folio` - The folio account (PDA) (mut, not signer).
 /// * `rebalance` - The rebalance account (PDA) (init, not signer).
+/// * `transfer_hook_registrar` - The transfer hook registrar account (PDA) of the folio admin program (not mut, not signer).
 /// * remaining account tokens:
 ///  - token mints for rebalance
 #[derive(Accounts)]
@@ -54,7 +57,8 @@ pub struct StartRebalance<'info> {
         bump,
         seeds::program = FOLIO_ADMIN_PROGRAM_ID,
     )]
-    pub dao_fee_config: Account<'info, DAOFeeConfig>,
+    /// CHECK: Could be a legacy account, loaded with the legacy layout support in the handler
+    pub dao_fee_config: UncheckedAccount<'info>,
 
     /// CHECK: Could be empty or could be set, if set we use that one, else we use dao fee config
     #[account(
@@ -66,6 +70,14 @@ pub struct StartRebalance<'info> {
 
     #[account(mut)]
     pub folio_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Could be empty or could be set, if set we use the transfer hook programs allowed in it, else none are allowed
+    #[account(
+        seeds = [TRANSFER_HOOK_REGISTRAR_SEEDS],
+        bump,
+        seeds::program = FOLIO_ADMIN_PROGRAM_ID,
+    )]
+    pub transfer_hook_registrar: UncheckedAccount<'info>,
     // remaining accounts:
     // - token mints for rebalance
 }
@@ -84,10 +96,17 @@ impl StartRebalance<'_> {
             Some(vec![FolioStatus::Initialized]),
         )?;
 
+        let allowed_transfer_hook_programs =
+            TransferHookRegistrar::get_allowed_programs(&self.transfer_hook_registrar)?;
+
         for mint in mints {
             // Validate that the buy mint is a supported SPL token (can only check mint here, will check token account in the bid)
             check_condition!(
-                TokenUtil::is_supported_spl_token(Some(mint), None)?,
+                TokenUtil::is_supported_basket_spl_token(
+                    Some(mint),
+                    None,
+                    &allowed_transfer_hook_programs
+                )?,
                 UnsupportedSPLToken
             );
         }
@@ -105,12 +124,14 @@ impl StartRebalance<'_> {
 ///
 /// # Arguments
 /// * `ctx` - The context of the instruction.
+/// * `use_ui_amounts` - Whether the limits and prices are in UI amounts, fixed for the whole rebalance.
 pub fn handler(
     ctx: Context<StartRebalance>,
     auction_launcher_window: u64,
     ttl: u64,
     prices_and_limits: Vec<RebalancePriceAndLimits>,
     all_rebalance_details_added: bool,
+    use_ui_amounts: bool,
 ) -> Result<()> {
     let folio_key = ctx.accounts.folio.key();
     let folio = &mut ctx.accounts.folio.load_mut()?;
@@ -121,10 +142,9 @@ pub fn handler(
     let current_time = Clock::get()?.unix_timestamp;
     {
         // Poke folio
-        let fee_details = ctx
-            .accounts
-            .dao_fee_config
-            .get_fee_details(&ctx.accounts.folio_fee_config)?;
+        let fee_details =
+            DAOFeeConfig::try_from_account_info_with_legacy_support(&ctx.accounts.dao_fee_config)?
+                .get_fee_details(&ctx.accounts.folio_fee_config)?;
 
         folio.poke(
             ctx.accounts.folio_token_mint.supply,
@@ -156,6 +176,7 @@ pub fn handler(
         mints,
         prices_and_limits,
         all_rebalance_details_added,
+        use_ui_amounts,
     )?;
 
     if all_rebalance_details_added {
@@ -165,7 +186,8 @@ pub fn handler(
             started_at: rebalance.started_at,
             restricted_until: rebalance.restricted_until,
             available_until: rebalance.available_until,
-            details: rebalance.details
+            details: rebalance.details,
+            use_ui_amounts: rebalance.use_ui_amounts == 1,
         });
     }
 
@@ -0,0 +1,253 @@
+use crate::events::FeeDistributionClosed;
+use crate::state::{FeeDistribution, Folio};
+use crate::utils::structs::{FeeDistributionFallback, FolioStatus, PauseFlag};
+use crate::utils::versioned_account::VersionedAccount;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_2022;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
+use folio_admin::state::DAOFeeConfig;
+use folio_admin::ID as FOLIO_ADMIN_PROGRAM_ID;
+use shared::check_condition;
+use shared::constants::{
+    DAO_FEE_CONFIG_SEEDS, FEE_DISTRIBUTION_SEEDS, FOLIO_FEE_CONFIG_SEEDS, FOLIO_SEEDS,
+};
+use shared::errors::ErrorCode;
+use shared::utils::{Decimal, Rounding};
+
+/// Close Fee Distribution
+/// Permissionless.
+///
+/// # Arguments
+/// * `system_program` - The system program.
+/// * `token_program` - The token program.
+/// * `user` - The user account (mut, signer).
+/// * `cranker` - The cranker account (mut, not signer). Receives the rent of the fee distribution account.
+/// * `dao_fee_config` - The DAO fee config account (not mut, not signer).
+/// * `folio_fee_config` - The folio fee config account (not mut, not signer).
+/// * `folio` - The folio account (PDA) (mut, not signer).
+/// * `folio_token_mint` - The folio token mint account (mut, not signer).
+/// * `fee_distribution` - The fee distribution account (PDA) (mut, not signer).
+/// * `dao_fee_recipient` - The DAO fee recipient token account (mut, not signer), only required when the fallback of the
+///                         folio is the DAO.
+#[derive(Accounts)]
+pub struct CloseFeeDistribution<'info> {
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: Cranker account
+    #[account(mut)]
+    pub cranker: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [DAO_FEE_CONFIG_SEEDS],
+        bump,
+        seeds::program = FOLIO_ADMIN_PROGRAM_ID,
+    )]
+    /// CHECK: Could be a legacy account, loaded with the legacy layout support in the handler
+    pub dao_fee_config: UncheckedAccount<'info>,
+
+    /// CHECK: Could be empty or could be set, if set we use that one, else we use dao fee config
+    #[account(
+        seeds = [FOLIO_FEE_CONFIG_SEEDS, folio.key().as_ref()],
+        bump,
+        seeds::program = FOLIO_ADMIN_PROGRAM_ID,
+    )]
+    pub folio_fee_config: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub folio: AccountLoader<'info, Folio>,
+
+    #[account(mut)]
+    pub folio_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub fee_distribution: AccountLoader<'info, FeeDistribution>,
+
+    #[account(mut)]
+    pub dao_fee_recipient: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+impl CloseFeeDistribution<'_> {
+    /// Validate the instruction.
+    ///
+    /// # Checks
+    /// * Folio has the correct status.
+    /// * Fee distribution is valid PDA.
+    /// * Provided folio token mint account is the same as the one on the folio account.
+    /// * Cranker account is the same as the one on the fee distribution account.
+    /// * DAO fee recipient token account is the one of the fee recipient from the fee config, if the fallback is the DAO.
+    /// * Fee distribution is fully distributed or the grace period has passed.
+    /// * Fee distribution isn't paused.
+    pub fn validate(
+        &self,
+        folio: &Folio,
+        fee_distribution: &FeeDistribution,
+        current_time: u64,
+    ) -> Result<()> {
+        folio.validate_folio(
+            &self.folio.key(),
+            None,
+            None,
+            Some(vec![FolioStatus::Initialized, FolioStatus::Killed]),
+        )?;
+
+        folio.validate_not_paused(PauseFlag::FeeDistribution)?;
+
+        check_condition!(
+            self.fee_distribution.key()
+                == Pubkey::find_program_address(
+                    &[
+                        FEE_DISTRIBUTION_SEEDS,
+                        self.folio.key().as_ref(),
+                        fee_distribution.index.to_le_bytes().as_slice()
+                    ],
+                    &crate::id()
+                )
+                .0,
+            InvalidFeeDistribution
+        );
+
+        check_condition!(
+            self.folio_token_mint.key() == folio.folio_token_mint,
+            InvalidFolioTokenMint
+        );
+
+        check_condition!(
+            self.cranker.key() == fee_distribution.cranker,
+            InvalidCranker
+        );
+
+        if folio.get_fee_distribution_fallback()? == FeeDistributionFallback::Dao {
+            let fee_details =
+                DAOFeeConfig::try_from_account_info_with_legacy_support(&self.dao_fee_config)?
+                    .get_fee_details(&self.folio_fee_config)?;
+
+            check_condition!(
+                self.dao_fee_recipient
+                    .as_ref()
+                    .is_some_and(|dao_fee_recipient| {
+                        dao_fee_recipient.key()
+                            == get_associated_token_address_with_program_id(
+                                &fee_details.fee_recipient,
+                                &self.folio_token_mint.key(),
+                                &self.token_program.key(),
+                            )
+                    }),
+                InvalidDaoFeeRecipient
+            );
+        }
+
+        check_condition!(
+            fee_distribution.is_closable(current_time),
+            FeeDistributionNotClosable
+        );
+
+        Ok(())
+    }
+}
+
+/// Close Fee Distribution.
+/// If a fee recipient can't receive its shares (i.e. its token account can't be created or is frozen), the fee distribution
+/// account would never be fully cranked. After a grace period, anyone can close it: the undistributed shares go to the
+/// fallback set by the folio owner and the rent is reimbursed to the cranker that created the fee distribution account.
+///
+/// The fallback is either the DAO fee recipient, where the shares are minted, or the fee recipients of the folio, where
+/// the shares are added back to the pending fee shares and distributed with the next fees. Fee distributions created
+/// before `created_at` was added are considered created when they are upgraded (with `upgrade_account` or a crank), so
+/// the grace period starts then.
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+pub fn handler(ctx: Context<CloseFeeDistribution>) -> Result<()> {
+    let folio_bump: u8;
+    let raw_undistributed_amount: u64;
+    let fee_distribution_index: u64;
+    let fee_distribution_fallback: FeeDistributionFallback;
+
+    let token_mint_key = ctx.accounts.folio_token_mint.key();
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    // Fee distributions created before `created_at` was added are upgraded first, so they can be loaded
+    FeeDistribution::upgrade_if_needed(
+        &ctx.accounts.fee_distribution.to_account_info(),
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    {
+        let folio = &ctx.accounts.folio.load()?;
+        let fee_distribution = &ctx.accounts.fee_distribution.load()?;
+
+        ctx.accounts
+            .validate(folio, fee_distribution, current_time)?;
+
+        folio_bump = folio.bump;
+        fee_distribution_fallback = folio.get_fee_distribution_fallback()?;
+        fee_distribution_index = fee_distribution.index;
+        raw_undistributed_amount = fee_distribution.get_raw_undistributed_amount()?;
+    }
+
+    let recipient = match fee_distribution_fallback {
+        FeeDistributionFallback::Dao => {
+            let dao_fee_recipient = ctx
+                .accounts
+                .dao_fee_recipient
+                .as_ref()
+                .ok_or(ErrorCode::InvalidDaoFeeRecipient)?;
+
+            if raw_undistributed_amount > 0 {
+                let signer_seeds = &[FOLIO_SEEDS, token_mint_key.as_ref(), &[folio_bump]];
+
+                let cpi_accounts = token_2022::MintTo {
+                    mint: ctx.accounts.folio_token_mint.to_account_info(),
+                    to: dao_fee_recipient.to_account_info(),
+                    authority: ctx.accounts.folio.to_account_info(),
+                };
+
+                token_interface::mint_to(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        cpi_accounts,
+                        &[signer_seeds],
+                    ),
+                    raw_undistributed_amount,
+                )?;
+            }
+
+            dao_fee_recipient.key()
+        }
+        FeeDistributionFallback::FeeRecipients => ctx.accounts.folio.key(),
+    };
+
+    ctx.accounts
+        .fee_distribution
+        .close(ctx.accounts.cranker.to_account_info())?;
+
+    let scaled_undistributed_amount =
+        Decimal::from_token_amount(raw_undistributed_amount)?.to_scaled(Rounding::Floor)?;
+
+    let folio = &mut ctx.accounts.folio.load_mut()?;
+    folio.fee_recipients_pending_fee_shares_to_be_minted = folio
+        .fee_recipients_pending_fee_shares_to_be_minted
+        .checked_sub(scaled_undistributed_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    if fee_distribution_fallback == FeeDistributionFallback::FeeRecipients {
+        folio.fee_recipients_pending_fee_shares = folio
+            .fee_recipients_pending_fee_shares
+            .checked_add(scaled_undistributed_amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+    }
+
+    emit!(FeeDistributionClosed {
+        index: fee_distribution_index,
+        recipient,
+        amount: raw_undistributed_amount,
+    });
+
+    Ok(())
+}
@@ -1,11 +1,14 @@
 use crate::events::TVLFeePaid;
 use crate::state::{FeeDistribution, Folio};
-use crate::utils::structs::FolioStatus;
+use crate::utils::structs::{FolioStatus, PauseFlag};
+use crate::utils::versioned_account::VersionedAccount;
+use crate::utils::RewardsProgram;
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::get_associated_token_address_with_program_id;
 use anchor_spl::token_2022;
 use anchor_spl::token_interface::{self, Mint, TokenInterface};
 use shared::check_condition;
+use shared::constants::REWARDS_PROGRAM_ID;
 use shared::constants::{FEE_DISTRIBUTION_SEEDS, FOLIO_SEEDS, MAX_FEE_RECIPIENTS_PORTION};
 use shared::errors::ErrorCode;
 use shared::utils::account_util::next_account;
@@ -25,6 +28,17 @@ use shared::utils::{Decimal, Rounding};
 ///
 /// * `remaining_accounts` - The remaining accounts will be the token accounts of the fee recipients, needs to follow the
 ///                          order of the indices passed as parameters.
+///
+/// If a fee recipient is a reward tokens account of the Rewards program, its token account can be followed by the
+/// accounts to accrue the rewards of the realm (permissionless, no token owner record needed). They aren't needed for
+/// the other fee recipients, nor for the ones that were already distributed to:
+///
+/// - Reward tokens (the fee recipient itself)
+/// - Rewards program
+/// - Realm
+/// - Governance token mint
+/// - Governance staked token account
+/// - Reward info for the folio token mint (mut)
 #[derive(Accounts)]
 pub struct CrankFeeDistribution<'info> {
     pub rent: Sysvar<'info, Rent>,
@@ -48,7 +62,8 @@ pub struct CrankFeeDistribution<'info> {
     pub fee_distribution: AccountLoader<'info, FeeDistribution>,
     /*
     Remaining accounts will be the token accounts of the fee recipients, needs to follow the
-    order of the indices passed as parameters.
+    order of the indices passed as parameters. Reward tokens recipients can be followed by the accounts
+    to accrue the rewards.
      */
 }
 
@@ -60,6 +75,7 @@ impl CrankFeeDistribution<'_> {
     /// * Fee distribution is valid PDA.
     /// * Provided folio token mint account is the same as the one on the folio account.
     /// * Cranker account is the same as the one on the fee distribution account.
+    /// * Fee distribution isn't paused.
     pub fn validate(&self, folio: &Folio, fee_distribution: &FeeDistribution) -> Result<()> {
         folio.validate_folio(
             &self.folio.key(),
@@ -68,6 +84,8 @@ impl CrankFeeDistribution<'_> {
             Some(vec![FolioStatus::Initialized, FolioStatus::Killed]),
         )?;
 
+        folio.validate_not_paused(PauseFlag::FeeDistribution)?;
+
         // Validate fee distribution
         check_condition!(
             self.fee_distribution.key()
@@ -102,6 +120,10 @@ impl CrankFeeDistribution<'_> {
 /// this couldn't fit in only one transaction (size and CUs limits). Therefore, this permissionless instruction is used to distribute the fees in multiple transactions.
 /// When all fees are distributed, the fee distribution account is closed and the cranker is reimbursed for the rent, so that people are inclined to
 /// call the distribute fees instruction even if there is a rent cost.
+/// Fee recipients that are reward tokens accounts of the Rewards program get their rewards accrued right after the mint,
+/// so that the fees are turned into staking rewards of the realm. The accrual is skipped if the accounts to accrue the
+/// rewards aren't provided, or if the folio token isn't a reward token of the realm (no reward info), so that the fees
+/// can always be distributed; the new balance is then picked up by the next accrual of the realm.
 ///
 /// # Arguments
 /// * `ctx` - The context of the instruction.
@@ -115,6 +137,13 @@ pub fn handler<'info>(
 
     let token_mint_key = ctx.accounts.folio_token_mint.key();
 
+    // Fee distributions created before `created_at` was added are upgraded first, so they can be loaded
+    FeeDistribution::upgrade_if_needed(
+        &ctx.accounts.fee_distribution.to_account_info(),
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
     {
         let folio = &ctx.accounts.folio.load()?;
 
@@ -161,6 +190,8 @@ pub fn handler<'info>(
                 InvalidFeeRecipient
             );
 
+            let fee_recipient_key = related_fee_distribution.recipient;
+
             // Set as distributed
             related_fee_distribution.recipient = Pubkey::default();
 
@@ -193,6 +224,57 @@ pub fn handler<'info>(
                 recipient: related_fee_distribution.recipient.key(),
                 amount: raw_amount_to_distribute,
             });
+
+            // Accrue the rewards if the fee recipient is a reward tokens account of the Rewards program
+            let is_reward_tokens_recipient = remaining_accounts_iter
+                .as_slice()
+                .first()
+                .is_some_and(|account| {
+                    RewardsProgram::is_reward_tokens_recipient(account, &fee_recipient_key)
+                });
+
+            if is_reward_tokens_recipient {
+                let reward_tokens = next_account(
+                    &mut remaining_accounts_iter,
+                    false,
+                    false,
+                    &REWARDS_PROGRAM_ID,
+                )?;
+                let rewards_program = remaining_accounts_iter
+                    .next()
+                    .ok_or(ErrorCode::MissingRemainingAccount)?;
+                let realm = remaining_accounts_iter
+                    .next()
+                    .ok_or(ErrorCode::MissingRemainingAccount)?;
+                let governance_token_mint = remaining_accounts_iter
+                    .next()
+                    .ok_or(ErrorCode::MissingRemainingAccount)?;
+                let governance_staked_token_account = remaining_accounts_iter
+                    .next()
+                    .ok_or(ErrorCode::MissingRemainingAccount)?;
+                let reward_info = remaining_accounts_iter
+                    .next()
+                    .ok_or(ErrorCode::MissingRemainingAccount)?;
+
+                // The folio token isn't a reward token of the realm, nothing to accrue
+                if reward_info.data_is_empty() {
+                    continue;
+                }
+
+                check_condition!(reward_info.is_writable, InvalidRewardInfo);
+
+                RewardsProgram::accrue_reward_info_cpi(
+                    rewards_program,
+                    &ctx.accounts.token_program,
+                    realm,
+                    reward_tokens,
+                    governance_token_mint,
+                    governance_staked_token_account,
+                    &ctx.accounts.folio_token_mint.to_account_info(),
+                    reward_info,
+                    fee_recipient,
+                )?;
+            }
         }
     }
 
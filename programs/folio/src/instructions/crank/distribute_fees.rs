@@ -1,4 +1,4 @@
-use crate::utils::structs::FolioStatus;
+use crate::utils::structs::{FolioStatus, PauseFlag};
 use crate::ID;
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::get_associated_token_address_with_program_id;
@@ -10,8 +10,8 @@ use folio_admin::state::DAOFeeConfig;
 use folio_admin::ID as FOLIO_ADMIN_PROGRAM_ID;
 use shared::check_condition;
 use shared::constants::{
-    D9_U128, DAO_FEE_CONFIG_SEEDS, FEE_DISTRIBUTION_SEEDS, FEE_RECIPIENTS_SEEDS,
-    FOLIO_FEE_CONFIG_SEEDS, FOLIO_SEEDS,
+    D9_U128, DAO_FEE_CONFIG_SEEDS, FEE_DISTRIBUTION_SEEDS, FEE_DISTRIBUTION_VERSION,
+    FEE_RECIPIENTS_SEEDS, FOLIO_FEE_CONFIG_SEEDS, FOLIO_SEEDS,
 };
 use shared::errors::ErrorCode;
 use shared::utils::{Decimal, Rounding};
@@ -34,6 +34,8 @@ use crate::state::{FeeDistribution, FeeRecipients, Folio};
 /// * `fee_recipients` - The fee recipients account (PDA) (mut, not signer).
 /// * `fee_distribution` - The fee distribution account (PDA) (init, not signer).
 /// * `dao_fee_recipient` - The DAO fee recipient token account (mut, not signer).
+///
+/// * `remaining_accounts` - The token accounts of the other protocol fee recipients (mut, not signer), in the same order as in the DAO fee config.
 #[derive(Accounts)]
 #[instruction(index: u64)]
 pub struct DistributeFees<'info> {
@@ -44,9 +46,9 @@ pub struct DistributeFees<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
-    /// CHECK: seeds validated in validate function
+    /// CHECK: seeds validated in validate function, could be a legacy account, loaded with the legacy layout support
     #[account()]
-    pub dao_fee_config: Account<'info, DAOFeeConfig>,
+    pub dao_fee_config: UncheckedAccount<'info>,
 
     /// CHECK: Could be empty or could be set, if set we use that one, else we use dao fee config, seeds validated in validate function
     #[account()]
@@ -81,11 +83,12 @@ pub struct DistributeFees<'info> {
 /// * Folio has the correct status.
 /// * Fee recipients distribution index is the next index to distribute to.
 /// * Provided folio token mint account is the same as the one on the folio account.
+/// * Fee distribution isn't paused.
 pub fn validate<'info>(
     folio: &AccountLoader<'info, Folio>,
     fee_recipients: &FeeRecipients,
     folio_token_mint: &InterfaceAccount<'info, Mint>,
-    dao_fee_config: &Account<'info, DAOFeeConfig>,
+    dao_fee_config: &AccountInfo<'info>,
     folio_fee_config: &AccountInfo<'info>,
     fee_recipients_account: &AccountInfo<'info>,
     fee_distribution_account: &AccountInfo<'info>,
@@ -101,6 +104,8 @@ pub fn validate<'info>(
         Some(vec![FolioStatus::Initialized, FolioStatus::Killed]),
     )?;
 
+    loaded_folio.validate_not_paused(PauseFlag::FeeDistribution)?;
+
     check_condition!(
         fee_recipients.distribution_index + 1 == index,
         InvalidDistributionIndex
@@ -176,20 +181,40 @@ pub fn validate<'info>(
 /// * `folio_token_mint` - The folio token mint account.
 /// * `fee_recipients` - The fee recipients account.
 /// * `fee_distribution` - The fee distribution account.
-/// * `dao_fee_recipient` - The DAO fee recipient token account.
+/// * `dao_fee_recipient` - The DAO fee recipient token account, the first protocol fee recipient.
+/// * `additional_dao_fee_recipients` - The token accounts of the other protocol fee recipients.
 /// * `index` - The index of the next fee distribution account to create.
+#[allow(clippy::too_many_arguments)]
 pub fn distribute_fees<'info>(
     token_program: &AccountInfo<'info>,
     user: &AccountInfo<'info>,
-    dao_fee_config: &Account<'info, DAOFeeConfig>,
+    dao_fee_config: &AccountInfo<'info>,
     folio_fee_config: &AccountInfo<'info>,
     folio: &AccountLoader<'info, Folio>,
     folio_token_mint: &InterfaceAccount<'info, Mint>,
     fee_recipients: &AccountLoader<'info, FeeRecipients>,
     fee_distribution: &AccountLoader<'info, FeeDistribution>,
     dao_fee_recipient: &AccountInfo<'info>,
+    additional_dao_fee_recipients: &[AccountInfo<'info>],
     index: u64,
 ) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let fee_details = DAOFeeConfig::try_from_account_info_with_legacy_support(dao_fee_config)?
+        .get_fee_details(folio_fee_config)?;
+
+    // The DAO fee recipient is the first protocol fee recipient, the others are in the additional accounts
+    let protocol_fee_recipient_accounts: Vec<&AccountInfo<'info>> =
+        std::iter::once(dao_fee_recipient)
+            .chain(additional_dao_fee_recipients.iter())
+            .take(fee_details.protocol_fee_recipients.len())
+            .collect();
+
+    check_condition!(
+        protocol_fee_recipient_accounts.len() == fee_details.protocol_fee_recipients.len(),
+        MissingRemainingAccount
+    );
+
     {
         let fee_recipients_data = fee_recipients.load()?;
 
@@ -207,21 +232,24 @@ pub fn distribute_fees<'info>(
 
         let folio = &mut folio.load_mut()?;
 
-        let fee_details = dao_fee_config.get_fee_details(folio_fee_config)?;
-
-        // Validate token account for the DAO fee recipient
-        check_condition!(
-            dao_fee_recipient.key()
-                == get_associated_token_address_with_program_id(
-                    &fee_details.fee_recipient,
-                    &folio_token_mint.key(),
-                    &token_program.key(),
-                ),
-            InvalidDaoFeeRecipient
-        );
+        // Validate token accounts for the protocol fee recipients (folio specific one if set)
+        for (protocol_fee_recipient_account, protocol_fee_recipient) in
+            protocol_fee_recipient_accounts
+                .iter()
+                .zip(fee_details.protocol_fee_recipients.iter())
+        {
+            check_condition!(
+                protocol_fee_recipient_account.key()
+                    == get_associated_token_address_with_program_id(
+                        &protocol_fee_recipient.recipient,
+                        &folio_token_mint.key(),
+                        &token_program.key(),
+                    ),
+                InvalidDaoFeeRecipient
+            );
+        }
 
         // Update pending fees by poking to get latest fees
-        let current_time = Clock::get()?.unix_timestamp;
         folio.poke(
             folio_token_mint.supply,
             current_time,
@@ -265,12 +293,6 @@ pub fn distribute_fees<'info>(
         let bump = loaded_folio.bump;
         let signer_seeds = &[FOLIO_SEEDS, token_mint_key.as_ref(), &[bump]];
 
-        let cpi_accounts = token_2022::MintTo {
-            mint: folio_token_mint.to_account_info(),
-            to: dao_fee_recipient.to_account_info(),
-            authority: folio.to_account_info(),
-        };
-
         if !has_fee_recipients {
             // If there are no fee recipients, the DAO gets all the fees
             raw_dao_pending_fee_shares = raw_dao_pending_fee_shares
@@ -278,14 +300,35 @@ pub fn distribute_fees<'info>(
                 .ok_or(ErrorCode::MathOverflow)?;
         }
 
-        token_interface::mint_to(
-            CpiContext::new_with_signer(
-                token_program.to_account_info(),
-                cpi_accounts,
-                &[signer_seeds],
-            ),
-            raw_dao_pending_fee_shares,
-        )?;
+        // Split the DAO fees between the protocol fee recipients
+        let raw_protocol_fee_amounts =
+            fee_details.split_protocol_fee_amount(raw_dao_pending_fee_shares)?;
+
+        for (protocol_fee_recipient_account, raw_protocol_fee_amount) in
+            protocol_fee_recipient_accounts
+                .iter()
+                .zip(raw_protocol_fee_amounts.into_iter())
+        {
+            let cpi_accounts = token_2022::MintTo {
+                mint: folio_token_mint.to_account_info(),
+                to: protocol_fee_recipient_account.to_account_info(),
+                authority: folio.to_account_info(),
+            };
+
+            token_interface::mint_to(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    cpi_accounts,
+                    &[signer_seeds],
+                ),
+                raw_protocol_fee_amount,
+            )?;
+
+            emit!(ProtocolFeePaid {
+                recipient: protocol_fee_recipient_account.key(),
+                amount: raw_protocol_fee_amount,
+            });
+        }
 
         // Create new fee distribution for other recipients if there are any
         if has_fee_recipients {
@@ -307,21 +350,18 @@ pub fn distribute_fees<'info>(
             );
 
             fee_distribution_loaded.bump = bump;
+            fee_distribution_loaded.version = FEE_DISTRIBUTION_VERSION;
             fee_distribution_loaded.index = index;
             fee_distribution_loaded.folio = folio_key;
             fee_distribution_loaded.cranker = user.key();
             fee_distribution_loaded.amount_to_distribute =
                 scaled_fee_recipients_pending_fee_shares_minus_dust;
             fee_distribution_loaded.fee_recipients_state = fee_recipients.fee_recipients;
+            fee_distribution_loaded.created_at = current_time as u64;
         } else {
             // We close it if there are no fee recipients
             fee_distribution.close(user.to_account_info())?;
         }
-
-        emit!(ProtocolFeePaid {
-            recipient: dao_fee_recipient.key(),
-            amount: raw_dao_pending_fee_shares,
-        });
     }
 
     // Update folio pending fees based on what was distributed
@@ -390,6 +430,7 @@ pub fn handler<'info>(
         &ctx.accounts.fee_recipients,
         &ctx.accounts.fee_distribution,
         &ctx.accounts.dao_fee_recipient.to_account_info(),
+        ctx.remaining_accounts,
         index,
     )?;
 
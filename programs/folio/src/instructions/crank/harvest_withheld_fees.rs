@@ -0,0 +1,84 @@
+use crate::state::Folio;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::Token2022,
+    token_2022_extensions::transfer_fee::{
+        harvest_withheld_tokens_to_mint, HarvestWithheldTokensToMint,
+    },
+    token_interface::{Mint, TokenAccount},
+};
+
+/// Harvest Withheld Fees
+/// Permissionless.
+///
+/// # Arguments
+/// * `token_program` - The token program (Token2022).
+/// * `user` - The user account (mut, signer).
+/// * `folio` - The folio account (PDA) (not mut, not signer).
+/// * `mint` - The mint of the basket token with a transfer fee (mut, not signer).
+/// * `folio_token_account` - The folio's token account of the mint (mut, not signer).
+#[derive(Accounts)]
+pub struct HarvestWithheldFees<'info> {
+    pub token_program: Program<'info, Token2022>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account()]
+    pub folio: AccountLoader<'info, Folio>,
+
+    #[account(mut,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut,
+        associated_token::mint = mint,
+        associated_token::authority = folio,
+        associated_token::token_program = token_program,
+    )]
+    pub folio_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+}
+
+impl HarvestWithheldFees<'_> {
+    /// Validate the instruction.
+    ///
+    /// # Checks
+    /// * Folio is valid PDA.
+    pub fn validate(&self, folio: &Folio) -> Result<()> {
+        folio.validate_folio(
+            &self.folio.key(),
+            None,
+            None,
+            // Withheld fees can always be harvested
+            None,
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Harvest the transfer fees withheld in the folio's token account of a basket token to the mint, where they can be
+/// withdrawn by the withdraw withheld authority of the mint. The withheld fees are never part of the folio's basket.
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+pub fn handler(ctx: Context<HarvestWithheldFees>) -> Result<()> {
+    {
+        let folio = ctx.accounts.folio.load()?;
+        ctx.accounts.validate(&folio)?;
+    }
+
+    harvest_withheld_tokens_to_mint(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            HarvestWithheldTokensToMint {
+                token_program_id: ctx.accounts.token_program.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+        ),
+        vec![ctx.accounts.folio_token_account.to_account_info()],
+    )?;
+
+    Ok(())
+}
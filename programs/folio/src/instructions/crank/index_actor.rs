@@ -0,0 +1,56 @@
+use crate::state::{Actor, FolioActors};
+use anchor_lang::prelude::*;
+use shared::constants::{ACTOR_SEEDS, FOLIO_ACTORS_SEEDS};
+
+/// Index Actor
+///
+/// # Arguments
+/// * `system_program` - The system program.
+/// * `rent` - The rent sysvar.
+/// * `payer` - The payer of the folio actors index rent, if it doesn't exist yet (mut, signer).
+/// * `actor` - The actor account (PDA) to index (not mut, not signer).
+/// * `folio_actors` - The folio actors index account (PDA) of the folio of the actor (init if needed, not signer).
+#[derive(Accounts)]
+pub struct IndexActor<'info> {
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [ACTOR_SEEDS, actor.authority.as_ref(), actor.folio.as_ref()],
+        bump = actor.bump,
+    )]
+    pub actor: Box<Account<'info, Actor>>,
+
+    #[account(init_if_needed,
+        payer = payer,
+        space = FolioActors::SIZE,
+        seeds = [FOLIO_ACTORS_SEEDS, actor.folio.as_ref()],
+        bump
+    )]
+    pub folio_actors: Box<Account<'info, FolioActors>>,
+}
+
+/// Index an actor in the folio actors index of its folio, for the actors created before the index existed.
+/// Permissionless, as the index only mirrors the roles stored on the actor. Actors created before the role
+/// expirations were added need to be migrated first (see migrate_actor).
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+pub fn handler(ctx: Context<IndexActor>) -> Result<()> {
+    let folio_actors = &mut ctx.accounts.folio_actors;
+
+    let folio_actors_bump = folio_actors.bump;
+
+    folio_actors.process_init_if_needed(
+        folio_actors_bump,
+        ctx.bumps.folio_actors,
+        &ctx.accounts.actor.folio,
+    )?;
+
+    folio_actors.set_actor_roles(&ctx.accounts.actor.authority, ctx.accounts.actor.roles);
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use crate::state::Actor;
+use anchor_lang::prelude::*;
+use shared::check_condition;
+use shared::constants::{ACTOR_SEEDS, ACTOR_VERSION};
+use shared::errors::ErrorCode;
+use shared::utils::account_util::resize_account_rent;
+
+/// Migrate Actor
+///
+/// # Arguments
+/// * `system_program` - The system program.
+/// * `payer` - The payer of the additional rent (mut, signer).
+/// * `actor` - The actor account (PDA) to migrate (mut, not signer).
+#[derive(Accounts)]
+pub struct MigrateActor<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Can't be deserialized as an Actor before being resized, seeds are checked in the validate function
+    #[account(mut)]
+    pub actor: UncheckedAccount<'info>,
+}
+
+impl MigrateActor<'_> {
+    /// Validate the instruction.
+    ///
+    /// # Checks
+    /// * Actor is owned by the folio program.
+    /// * Actor still has the legacy layout.
+    /// * Actor PDA is valid for the authority and folio stored on it.
+    pub fn validate(&self, actor: &Actor) -> Result<()> {
+        check_condition!(*self.actor.owner == crate::ID, InvalidAccountOwner);
+
+        check_condition!(self.actor.data_len() < Actor::SIZE, ActorAlreadyMigrated);
+
+        check_condition!(
+            (self.actor.key(), actor.bump)
+                == Pubkey::find_program_address(
+                    &[ACTOR_SEEDS, actor.authority.as_ref(), actor.folio.as_ref()],
+                    &crate::ID
+                ),
+            InvalidPda
+        );
+
+        Ok(())
+    }
+}
+
+/// Migrate an actor created before the role expirations were added to the current version.
+/// Permissionless, as the migration doesn't change any of the roles, none of which expire.
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+pub fn handler(ctx: Context<MigrateActor>) -> Result<()> {
+    let actor_info = ctx.accounts.actor.to_account_info();
+
+    let mut actor = Actor::try_deserialize_with_legacy_support(&actor_info.try_borrow_data()?)?;
+
+    ctx.accounts.validate(&actor)?;
+
+    resize_account_rent(
+        &actor_info,
+        Actor::SIZE,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    actor.version = ACTOR_VERSION;
+
+    actor.try_serialize(&mut &mut actor_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
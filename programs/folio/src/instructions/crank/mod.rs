@@ -1,7 +1,19 @@
+pub mod close_fee_distribution;
 pub mod crank_fee_distribution;
 pub mod distribute_fees;
+pub mod harvest_withheld_fees;
+pub mod index_actor;
+pub mod migrate_actor;
 pub mod poke_folio;
+pub mod track_user_pending_basket;
+pub mod upgrade_account;
 
+pub use close_fee_distribution::*;
 pub use crank_fee_distribution::*;
 pub use distribute_fees::*;
+pub use harvest_withheld_fees::*;
+pub use index_actor::*;
+pub use migrate_actor::*;
 pub use poke_folio::*;
+pub use track_user_pending_basket::*;
+pub use upgrade_account::*;
@@ -30,7 +30,8 @@ pub struct PokeFolio<'info> {
         bump,
         seeds::program = FOLIO_ADMIN_PROGRAM_ID,
     )]
-    pub dao_fee_config: Account<'info, DAOFeeConfig>,
+    /// CHECK: Could be a legacy account, loaded with the legacy layout support in the handler
+    pub dao_fee_config: UncheckedAccount<'info>,
 
     /// CHECK: Could be empty or could be set, if set we use that one, else we use dao fee config
     #[account(
@@ -81,10 +82,9 @@ pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, PokeFolio<'info>>) -> R
 
     let current_time = Clock::get()?.unix_timestamp;
 
-    let fee_details = ctx
-        .accounts
-        .dao_fee_config
-        .get_fee_details(&ctx.accounts.folio_fee_config)?;
+    let fee_details =
+        DAOFeeConfig::try_from_account_info_with_legacy_support(&ctx.accounts.dao_fee_config)?
+            .get_fee_details(&ctx.accounts.folio_fee_config)?;
 
     folio.poke(
         ctx.accounts.folio_token_mint.supply,
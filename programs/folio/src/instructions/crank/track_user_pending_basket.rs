@@ -0,0 +1,96 @@
+use crate::events::UserPendingBasketTracked;
+use crate::state::{Folio, FolioPendingBasket, UserPendingBasket};
+use anchor_lang::prelude::*;
+use shared::check_condition;
+use shared::constants::{FOLIO_PENDING_BASKET_SEEDS, USER_PENDING_BASKET_SEEDS};
+use shared::errors::ErrorCode;
+
+/// Track User Pending Basket
+///
+/// # Arguments
+/// * `system_program` - The system program.
+/// * `payer` - The payer of the folio pending basket rent, if it doesn't exist yet (mut, signer).
+/// * `folio` - The folio account (PDA) (not mut, not signer).
+/// * `owner` - The owner of the user pending basket (not mut, not signer).
+/// * `user_pending_basket` - The user pending basket account (PDA) to track (mut, not signer).
+/// * `folio_pending_basket` - The folio pending basket account (PDA) (init if needed, not signer).
+#[derive(Accounts)]
+pub struct TrackUserPendingBasket<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account()]
+    pub folio: AccountLoader<'info, Folio>,
+
+    /// CHECK: Only used for the seeds of the user pending basket
+    #[account()]
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(mut,
+        seeds = [USER_PENDING_BASKET_SEEDS, folio.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub user_pending_basket: AccountLoader<'info, UserPendingBasket>,
+
+    #[account(init_if_needed,
+        payer = payer,
+        space = FolioPendingBasket::SIZE,
+        seeds = [FOLIO_PENDING_BASKET_SEEDS, folio.key().as_ref()],
+        bump
+    )]
+    pub folio_pending_basket: AccountLoader<'info, FolioPendingBasket>,
+}
+
+impl TrackUserPendingBasket<'_> {
+    /// Validate the instruction.
+    ///
+    /// # Checks
+    /// * Folio is valid PDA.
+    /// * User pending basket isn't tracked yet.
+    pub fn validate(&self, folio: &Folio, user_pending_basket: &UserPendingBasket) -> Result<()> {
+        folio.validate_folio(&self.folio.key(), None, None, None)?;
+
+        check_condition!(
+            user_pending_basket.is_tracked == 0,
+            UserPendingBasketAlreadyTracked
+        );
+
+        Ok(())
+    }
+}
+
+/// Account for the amounts of a user pending basket created before the folio pending basket existed in the folio
+/// pending basket, so the folio's token balances can be reconciled with the basket (see sync_basket_balance).
+/// Permissionless, as it only mirrors the amounts stored on the user pending basket.
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+pub fn handler(ctx: Context<TrackUserPendingBasket>) -> Result<()> {
+    let folio_key = ctx.accounts.folio.key();
+
+    {
+        let folio = ctx.accounts.folio.load()?;
+        let user_pending_basket = ctx.accounts.user_pending_basket.load()?;
+
+        ctx.accounts.validate(&folio, &user_pending_basket)?;
+    }
+
+    let user_pending_basket = &mut ctx.accounts.user_pending_basket.load_mut()?;
+
+    // Nothing of this pending basket is accounted for yet
+    FolioPendingBasket::process_user_pending_basket_change(
+        &ctx.accounts.folio_pending_basket,
+        ctx.bumps.folio_pending_basket,
+        &folio_key,
+        &[],
+        user_pending_basket,
+    )?;
+
+    emit!(UserPendingBasketTracked {
+        owner: ctx.accounts.owner.key(),
+    });
+
+    Ok(())
+}
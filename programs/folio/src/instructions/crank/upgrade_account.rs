@@ -0,0 +1,74 @@
+use crate::state::{Auction, FeeDistribution, Folio, FolioBasket, Rebalance};
+use crate::utils::versioned_account::VersionedAccount;
+use anchor_lang::{prelude::*, Discriminator};
+use shared::check_condition;
+use shared::errors::ErrorCode;
+
+/// Upgrade Account
+///
+/// # Arguments
+/// * `system_program` - The system program.
+/// * `payer` - The payer of the additional rent (mut, signer).
+/// * `account` - The versioned account to upgrade, a folio, folio basket, rebalance, auction or fee distribution
+///               (mut, not signer).
+#[derive(Accounts)]
+pub struct UpgradeAccount<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Can't be loaded before being resized, the owner and discriminator are checked in the handler
+    #[account(mut)]
+    pub account: UncheckedAccount<'info>,
+}
+
+impl UpgradeAccount<'_> {
+    /// Validate the instruction.
+    ///
+    /// # Checks
+    /// * Account is owned by the folio program.
+    pub fn validate(&self) -> Result<()> {
+        check_condition!(*self.account.owner == crate::ID, InvalidAccountOwner);
+
+        Ok(())
+    }
+}
+
+/// Upgrade a folio, folio basket, rebalance, auction or fee distribution account with an older layout to the current version, in place.
+/// Permissionless, as the upgrade only resizes the account and sets the defaults of the fields that were added.
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+pub fn handler(ctx: Context<UpgradeAccount>) -> Result<()> {
+    ctx.accounts.validate()?;
+
+    let account_info = ctx.accounts.account.to_account_info();
+    let payer = ctx.accounts.payer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+
+    let discriminator = {
+        let data = account_info.try_borrow_data()?;
+
+        check_condition!(data.len() >= 8, AccountNotUpgradable);
+
+        data[..8].to_vec()
+    };
+
+    match discriminator.as_slice() {
+        d if d == Folio::DISCRIMINATOR => Folio::upgrade(&account_info, &payer, &system_program),
+        d if d == FolioBasket::DISCRIMINATOR => {
+            FolioBasket::upgrade(&account_info, &payer, &system_program)
+        }
+        d if d == Rebalance::DISCRIMINATOR => {
+            Rebalance::upgrade(&account_info, &payer, &system_program)
+        }
+        d if d == Auction::DISCRIMINATOR => {
+            Auction::upgrade(&account_info, &payer, &system_program)
+        }
+        d if d == FeeDistribution::DISCRIMINATOR => {
+            FeeDistribution::upgrade(&account_info, &payer, &system_program)
+        }
+        _ => err!(ErrorCode::AccountNotUpgradable),
+    }
+}
@@ -1,8 +1,10 @@
+pub mod admin;
 pub mod auction;
 pub mod crank;
 pub mod owner;
 pub mod user;
 
+pub use admin::*;
 pub use auction::*;
 pub use crank::*;
 pub use owner::*;
@@ -0,0 +1,139 @@
+use crate::events::OwnerAccepted;
+use crate::state::{Actor, Folio, FolioActors, PendingOwner};
+use crate::utils::structs::Role;
+use anchor_lang::prelude::*;
+use shared::constants::{ACTOR_SEEDS, FOLIO_ACTORS_SEEDS, PENDING_OWNER_SEEDS};
+
+/// Accept Owner
+///
+/// # Arguments
+/// * `system_program` - The system program.
+/// * `rent` - The rent sysvar.
+/// * `new_owner` - The new owner account (mut, signer).
+/// * `new_owner_actor` - The actor account (PDA) of the new owner (init, not signer).
+/// * `previous_owner` - The owner that proposed the ownership transfer, receives the pending owner rent back (mut, not signer).
+/// * `previous_owner_actor` - The actor account (PDA) of the previous owner (mut, not signer).
+/// * `pending_owner` - The pending owner account (PDA) (mut, not signer).
+/// * `folio` - The folio account (PDA) (not mut, not signer).
+/// * `folio_actors` - The folio actors index account (PDA) (init if needed, not signer).
+#[derive(Accounts)]
+pub struct AcceptOwner<'info> {
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+
+    #[account(mut)]
+    pub new_owner: Signer<'info>,
+
+    /*
+    Init if needed because the new owner could already have other roles
+     */
+    #[account(init_if_needed,
+        payer = new_owner,
+        space = Actor::SIZE,
+        seeds = [ACTOR_SEEDS, new_owner.key().as_ref(), folio.key().as_ref()],
+        bump
+    )]
+    pub new_owner_actor: Box<Account<'info, Actor>>,
+
+    /// CHECK: Checked to be the proposer of the pending owner in the validate function
+    #[account(mut)]
+    pub previous_owner: UncheckedAccount<'info>,
+
+    #[account(mut,
+        seeds = [ACTOR_SEEDS, previous_owner.key().as_ref(), folio.key().as_ref()],
+        bump = previous_owner_actor.bump,
+    )]
+    pub previous_owner_actor: Box<Account<'info, Actor>>,
+
+    #[account(mut,
+        close = previous_owner,
+        seeds = [PENDING_OWNER_SEEDS, folio.key().as_ref()],
+        bump = pending_owner.bump,
+    )]
+    pub pending_owner: Box<Account<'info, PendingOwner>>,
+
+    #[account()]
+    pub folio: AccountLoader<'info, Folio>,
+
+    #[account(init_if_needed,
+        payer = new_owner,
+        space = FolioActors::SIZE,
+        seeds = [FOLIO_ACTORS_SEEDS, folio.key().as_ref()],
+        bump
+    )]
+    pub folio_actors: Box<Account<'info, FolioActors>>,
+}
+
+impl AcceptOwner<'_> {
+    /// Validate the instruction.
+    ///
+    /// # Checks
+    /// * Previous owner actor is still an owner of the folio.
+    /// * New owner is the proposed one and previous owner is the proposer.
+    pub fn validate(&self) -> Result<()> {
+        let folio = &self.folio.load()?;
+
+        folio.validate_folio(
+            &self.folio.key(),
+            Some(&self.previous_owner_actor),
+            Some(vec![Role::Owner]),
+            None, // Can transfer the ownership no matter the status
+        )?;
+
+        self.pending_owner
+            .validate_acceptance(&self.new_owner.key(), &self.previous_owner.key())?;
+
+        Ok(())
+    }
+}
+
+/// Accept the ownership transfer of the folio. Gives the owner role to the new owner and removes it
+/// from the previous owner in the same instruction.
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+pub fn handler(ctx: Context<AcceptOwner>) -> Result<()> {
+    ctx.accounts.validate()?;
+
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    let new_owner_actor = &mut ctx.accounts.new_owner_actor;
+
+    let new_owner_actor_bump = new_owner_actor.bump;
+
+    new_owner_actor.process_init_if_needed(
+        new_owner_actor_bump,
+        ctx.bumps.new_owner_actor,
+        &ctx.accounts.new_owner.key(),
+        &ctx.accounts.folio.key(),
+    )?;
+
+    new_owner_actor.add_role(Role::Owner, None, current_time)?;
+
+    let new_owner_roles = new_owner_actor.roles;
+
+    ctx.accounts.previous_owner_actor.remove_role(Role::Owner);
+
+    let folio_actors = &mut ctx.accounts.folio_actors;
+
+    let folio_actors_bump = folio_actors.bump;
+
+    folio_actors.process_init_if_needed(
+        folio_actors_bump,
+        ctx.bumps.folio_actors,
+        &ctx.accounts.folio.key(),
+    )?;
+
+    folio_actors.set_actor_roles(
+        &ctx.accounts.previous_owner.key(),
+        ctx.accounts.previous_owner_actor.roles,
+    );
+    folio_actors.set_actor_roles(&ctx.accounts.new_owner.key(), new_owner_roles);
+
+    emit!(OwnerAccepted {
+        previous_owner: ctx.accounts.previous_owner.key(),
+        new_owner: ctx.accounts.new_owner.key(),
+    });
+
+    Ok(())
+}
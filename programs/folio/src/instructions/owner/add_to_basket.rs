@@ -5,15 +5,18 @@ use crate::utils::FolioTokenAmount;
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::{get_associated_token_address_with_program_id, AssociatedToken};
 use anchor_spl::token_2022;
-use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
+use folio_admin::state::TransferHookRegistrar;
+use folio_admin::ID as FOLIO_ADMIN_PROGRAM_ID;
 use shared::check_condition;
-use shared::constants::{ACTOR_SEEDS, FOLIO_BASKET_SEEDS, FOLIO_SEEDS, USER_PENDING_BASKET_SEEDS};
+use shared::constants::{
+    ACTOR_SEEDS, FOLIO_BASKET_SEEDS, FOLIO_SEEDS, TRANSFER_HOOK_REGISTRAR_SEEDS,
+    USER_PENDING_BASKET_SEEDS,
+};
 use shared::errors::ErrorCode;
 use shared::utils::account_util::next_account;
 use shared::utils::{next_token_program, TokenUtil};
 
-const EXPECTED_REMAINING_ACCOUNTS_LENGTH: usize = 4;
-
 /// Add a token to the folio's basket.
 ///
 /// # Arguments
@@ -26,12 +29,17 @@ const EXPECTED_REMAINING_ACCOUNTS_LENGTH: usize = 4;
 /// * `folio_basket` - The folio basket account (PDA) (init, not signer).
 /// * `folio_token_mint` - The folio token mint account (mut, not signer).
 /// * `owner_folio_token_account` - The owner's folio token account (mut, not signer).
+/// * `transfer_hook_registrar` - The transfer hook registrar account (PDA) of the folio admin program (not mut, not signer).
 ///
 /// * `remaining_accounts` - The remaining accounts will be the token accounts of the tokens to add to the basket.
 ///         - token program
 ///         - Token Mint
 ///         - Sender Token Account (needs to be owned by owner/UserPendingBasket) (mut)
 ///         - Recipient Token Account (needs to be owned by folio) (this is expected to be the ATA and already exist, to save on compute) (mut)
+///         - Only if the mint has an active transfer hook:
+///             - Transfer Hook Program (read)
+///             - Extra Account Meta List (read)
+///             - Extra accounts of the transfer hook
 #[derive(Accounts)]
 pub struct AddToBasket<'info> {
     pub system_program: Program<'info, System>,
@@ -74,6 +82,14 @@ pub struct AddToBasket<'info> {
         bump
     )]
     pub user_pending_basket: AccountInfo<'info>,
+
+    /// CHECK: Could be empty or could be set, if set we use the transfer hook programs allowed in it, else none are allowed
+    #[account(
+        seeds = [TRANSFER_HOOK_REGISTRAR_SEEDS],
+        bump,
+        seeds::program = FOLIO_ADMIN_PROGRAM_ID,
+    )]
+    pub transfer_hook_registrar: UncheckedAccount<'info>,
     /*
     The remaining accounts need to match the order of amounts as parameter
     Remaining accounts will have as many as possible of the following (always in the same order):
@@ -81,6 +97,10 @@ pub struct AddToBasket<'info> {
         - Token Mint (read)
         - Sender Token Account (needs to be owned by owner or UserPendingBasket) (mut)
         - Recipient Token Account (needs to be owned by folio) (this is expected to be the ATA and already exist, to save on compute) (mut)
+        - Only if the mint has an active transfer hook:
+            - Transfer Hook Program (read)
+            - Extra Account Meta List (read)
+            - Extra accounts of the transfer hook
      */
 }
 
@@ -178,15 +198,8 @@ pub fn handler<'info>(
 
     let mut folio_token_amounts: Vec<FolioTokenAmount> = vec![];
 
-    check_condition!(
-        remaining_accounts.len() % EXPECTED_REMAINING_ACCOUNTS_LENGTH == 0,
-        InvalidNumberOfRemainingAccounts
-    );
-
-    check_condition!(
-        remaining_accounts.len() / EXPECTED_REMAINING_ACCOUNTS_LENGTH == raw_amounts.len(),
-        InvalidNumberOfRemainingAccounts
-    );
+    let allowed_transfer_hook_programs =
+        TransferHookRegistrar::get_allowed_programs(&ctx.accounts.transfer_hook_registrar)?;
 
     for raw_amount in raw_amounts {
         let token_program = next_token_program(&mut remaining_accounts_iter)?;
@@ -223,14 +236,21 @@ pub fn handler<'info>(
 
         // Validate that the token mint is a supported SPL token
         check_condition!(
-            TokenUtil::is_supported_spl_token(
+            TokenUtil::is_supported_basket_spl_token(
                 Some(&token_mint.to_account_info()),
-                Some(&sender_token_account.to_account_info())
+                Some(&sender_token_account.to_account_info()),
+                &allowed_transfer_hook_programs
             )?,
             UnsupportedSPLToken
         );
-        let data = token_mint.try_borrow_data()?;
-        let mint = Mint::try_deserialize(&mut &data[..])?;
+
+        let transfer_hook_accounts =
+            TokenUtil::next_transfer_hook_accounts(&mut remaining_accounts_iter, token_mint)?;
+
+        let decimals = {
+            let data = token_mint.try_borrow_data()?;
+            Mint::try_deserialize(&mut &data[..])?.decimals
+        };
 
         let is_sender_ata_of_user_pending_basket = get_associated_token_address_with_program_id(
             &ctx.accounts.user_pending_basket.key(),
@@ -251,44 +271,42 @@ pub fn handler<'info>(
             //
             // This ensures all dust ends up in user_pending_basket, making
             // transfer_from_user_pending_basket_ata rarely needed (though it exists as fallback).
-            let cpi_accounts = TransferChecked {
-                from: sender_token_account.to_account_info(),
-                to: recipient_token_account.to_account_info(),
-                authority: ctx.accounts.user_pending_basket.to_account_info(),
-                mint: token_mint.to_account_info(),
-            };
-
-            token_interface::transfer_checked(
-                CpiContext::new_with_signer(
-                    token_program.to_account_info(),
-                    cpi_accounts,
-                    &[&[
-                        USER_PENDING_BASKET_SEEDS,
-                        ctx.accounts.folio.key().as_ref(),
-                        ctx.accounts.folio_owner.key().as_ref(),
-                        &[ctx.bumps.user_pending_basket],
-                    ]],
-                ),
+            TokenUtil::transfer_checked(
+                token_program,
+                sender_token_account,
+                token_mint,
+                recipient_token_account,
+                &ctx.accounts.user_pending_basket.to_account_info(),
+                &transfer_hook_accounts,
                 raw_amount,
-                mint.decimals,
+                decimals,
+                &[&[
+                    USER_PENDING_BASKET_SEEDS,
+                    ctx.accounts.folio.key().as_ref(),
+                    ctx.accounts.folio_owner.key().as_ref(),
+                    &[ctx.bumps.user_pending_basket],
+                ]],
             )?;
         } else {
-            let cpi_accounts = TransferChecked {
-                from: sender_token_account.to_account_info(),
-                to: recipient_token_account.to_account_info(),
-                authority: ctx.accounts.folio_owner.to_account_info(),
-                mint: token_mint.to_account_info(),
-            };
-
-            token_interface::transfer_checked(
-                CpiContext::new(token_program.to_account_info(), cpi_accounts),
+            TokenUtil::transfer_checked(
+                token_program,
+                sender_token_account,
+                token_mint,
+                recipient_token_account,
+                &ctx.accounts.folio_owner.to_account_info(),
+                &transfer_hook_accounts,
                 raw_amount,
-                mint.decimals,
+                decimals,
+                &[],
             )?;
         }
+
+        // Only account for what the folio received, net of the transfer fee (if any)
+        let raw_received_amount = TokenUtil::get_amount_after_transfer_fee(token_mint, raw_amount)?;
+
         folio_token_amounts.push(FolioTokenAmount {
             mint: token_mint.key(),
-            amount: raw_amount,
+            amount: raw_received_amount,
         });
 
         emit!(BasketTokenAdded {
@@ -296,6 +314,11 @@ pub fn handler<'info>(
         });
     }
 
+    check_condition!(
+        remaining_accounts_iter.next().is_none(),
+        InvalidNumberOfRemainingAccounts
+    );
+
     FolioBasket::process_init_if_needed(
         &mut ctx.accounts.folio_basket,
         ctx.bumps.folio_basket,
@@ -0,0 +1,85 @@
+use crate::events::MintAllowlistEntryAdded;
+use crate::state::{Actor, Folio, MintAllowlistEntry};
+use crate::utils::structs::Role;
+use anchor_lang::prelude::*;
+use shared::constants::{ACTOR_SEEDS, MINT_ALLOWLIST_ENTRY_SEEDS};
+
+/// Add a user to the mint allowlist of a folio.
+/// Allowlist manager or owner only.
+///
+/// # Arguments
+/// * `system_program` - The system program.
+/// * `rent` - The rent sysvar.
+/// * `allowlist_manager` - The allowlist manager account (mut, signer).
+/// * `actor` - The actor account of the allowlist manager (PDA) (not mut, not signer).
+/// * `folio` - The folio account (PDA) (not mut, not signer).
+/// * `user` - The user account that will be allowed to mint (not mut, not signer).
+/// * `mint_allowlist_entry` - The mint allowlist entry account (PDA) for the user (init, not signer).
+#[derive(Accounts)]
+pub struct AddToMintAllowlist<'info> {
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+
+    #[account(mut)]
+    pub allowlist_manager: Signer<'info>,
+
+    #[account(
+        seeds = [ACTOR_SEEDS, allowlist_manager.key().as_ref(), folio.key().as_ref()],
+        bump = actor.bump,
+    )]
+    pub actor: Box<Account<'info, Actor>>,
+
+    #[account()]
+    pub folio: AccountLoader<'info, Folio>,
+
+    /// CHECK: Wallet, program, multisig that will be allowed to mint
+    #[account()]
+    pub user: UncheckedAccount<'info>,
+
+    #[account(init,
+        payer = allowlist_manager,
+        space = MintAllowlistEntry::SIZE,
+        seeds = [MINT_ALLOWLIST_ENTRY_SEEDS, folio.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub mint_allowlist_entry: Box<Account<'info, MintAllowlistEntry>>,
+}
+
+impl AddToMintAllowlist<'_> {
+    /// Validate the instruction.
+    ///
+    /// # Checks
+    /// * Actor is an allowlist manager or an owner of the folio.
+    pub fn validate(&self) -> Result<()> {
+        let folio = &self.folio.load()?;
+
+        folio.validate_folio(
+            &self.folio.key(),
+            Some(&self.actor),
+            Some(vec![Role::AllowlistManager, Role::Owner]),
+            None, // Can CRUD the mint allowlist no matter the status
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Add a user to the mint allowlist of a folio. Only has an effect on minting once the mint allowlist is enabled on the folio.
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+pub fn handler(ctx: Context<AddToMintAllowlist>) -> Result<()> {
+    ctx.accounts.validate()?;
+
+    let mint_allowlist_entry = &mut ctx.accounts.mint_allowlist_entry;
+
+    mint_allowlist_entry.bump = ctx.bumps.mint_allowlist_entry;
+    mint_allowlist_entry.folio = ctx.accounts.folio.key();
+    mint_allowlist_entry.user = ctx.accounts.user.key();
+
+    emit!(MintAllowlistEntryAdded {
+        user: mint_allowlist_entry.user,
+    });
+
+    Ok(())
+}
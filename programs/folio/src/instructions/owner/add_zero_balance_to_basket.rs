@@ -0,0 +1,129 @@
+use crate::events::BasketTokenAdded;
+use crate::state::{Actor, Folio, FolioBasket};
+use crate::utils::structs::{FolioStatus, Role};
+use crate::utils::FolioTokenAmount;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use folio_admin::state::TransferHookRegistrar;
+use folio_admin::ID as FOLIO_ADMIN_PROGRAM_ID;
+use shared::check_condition;
+use shared::constants::{ACTOR_SEEDS, FOLIO_BASKET_SEEDS, TRANSFER_HOOK_REGISTRAR_SEEDS};
+use shared::errors::ErrorCode;
+use shared::utils::TokenUtil;
+
+/// Add a token to the folio's basket with a zero balance.
+///
+/// # Arguments
+/// * `folio_owner` - The folio owner account (signer).
+/// * `actor` - The actor account (PDA) of the Folio owner (not mut, not signer).
+/// * `folio` - The folio account (PDA) (not mut, not signer).
+/// * `folio_basket` - The folio basket account (PDA) (mut, not signer).
+/// * `token_mint` - The mint of the token to add (not mut, not signer).
+/// * `transfer_hook_registrar` - The transfer hook registrar account (PDA) of the folio admin program (not mut, not signer).
+#[derive(Accounts)]
+pub struct AddZeroBalanceToBasket<'info> {
+    pub folio_owner: Signer<'info>,
+
+    #[account(
+        seeds = [ACTOR_SEEDS, folio_owner.key().as_ref(), folio.key().as_ref()],
+        bump = actor.bump,
+    )]
+    pub actor: Account<'info, Actor>,
+
+    #[account()]
+    pub folio: AccountLoader<'info, Folio>,
+
+    #[account(mut,
+        seeds = [FOLIO_BASKET_SEEDS, folio.key().as_ref()],
+        bump
+    )]
+    pub folio_basket: AccountLoader<'info, FolioBasket>,
+
+    #[account()]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Could be empty or could be set, if set we use the transfer hook programs allowed in it, else none are allowed
+    #[account(
+        seeds = [TRANSFER_HOOK_REGISTRAR_SEEDS],
+        bump,
+        seeds::program = FOLIO_ADMIN_PROGRAM_ID,
+    )]
+    pub transfer_hook_registrar: UncheckedAccount<'info>,
+}
+
+impl AddZeroBalanceToBasket<'_> {
+    /// Validate the instruction.
+    ///
+    /// # Checks
+    /// * Folio has the correct status.
+    /// * Actor is the owner of the folio.
+    /// * Token mint is not the folio token mint.
+    /// * Token mint is a supported SPL token.
+    /// * Token mint is not already in the basket.
+    pub fn validate(&self, folio: &Folio, folio_basket: &FolioBasket) -> Result<()> {
+        folio.validate_folio(
+            &self.folio.key(),
+            Some(&self.actor),
+            Some(vec![Role::Owner]),
+            Some(vec![FolioStatus::Initialized]),
+        )?;
+
+        let token_mint_key = self.token_mint.key();
+
+        check_condition!(
+            token_mint_key != folio.folio_token_mint,
+            InvalidFolioTokenMint
+        );
+
+        let allowed_transfer_hook_programs =
+            TransferHookRegistrar::get_allowed_programs(&self.transfer_hook_registrar)?;
+
+        check_condition!(
+            TokenUtil::is_supported_basket_spl_token(
+                Some(&self.token_mint.to_account_info()),
+                None,
+                &allowed_transfer_hook_programs
+            )?,
+            UnsupportedSPLToken
+        );
+
+        check_condition!(
+            !folio_basket
+                .basket
+                .token_amounts
+                .iter()
+                .any(|ta| ta.mint == token_mint_key),
+            InvalidAddedTokenMints
+        );
+
+        Ok(())
+    }
+}
+
+/// Add a token to the folio's basket with a zero balance, so it can be targeted by a rebalance and bought through
+/// auctions without the owner having to source an initial amount of it.
+///
+/// While its balance is zero, the token isn't required when minting and nothing is given for it when redeeming.
+/// The folio's token account of the mint needs to exist before an auction buying it can be bid on.
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+pub fn handler(ctx: Context<AddZeroBalanceToBasket>) -> Result<()> {
+    let folio = ctx.accounts.folio.load()?;
+    let folio_basket = &mut ctx.accounts.folio_basket.load_mut()?;
+
+    ctx.accounts.validate(&folio, folio_basket)?;
+
+    let token_mint_key = ctx.accounts.token_mint.key();
+
+    folio_basket.add_tokens_to_basket(&vec![FolioTokenAmount {
+        mint: token_mint_key,
+        amount: 0,
+    }])?;
+
+    emit!(BasketTokenAdded {
+        token: token_mint_key,
+    });
+
+    Ok(())
+}
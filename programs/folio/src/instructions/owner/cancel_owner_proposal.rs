@@ -0,0 +1,76 @@
+use crate::events::OwnerProposalCancelled;
+use crate::state::{Actor, Folio, PendingOwner};
+use crate::utils::structs::Role;
+use anchor_lang::prelude::*;
+use shared::constants::{ACTOR_SEEDS, PENDING_OWNER_SEEDS};
+
+/// Cancel Owner Proposal
+/// Owner only.
+///
+/// # Arguments
+/// * `folio_owner` - The folio owner account (signer).
+/// * `folio_owner_actor` - The folio owner actor account (PDA) (not mut, not signer).
+/// * `proposed_by` - The owner that proposed the ownership transfer, receives the pending owner rent back (mut, not signer).
+/// * `pending_owner` - The pending owner account (PDA) (mut, not signer).
+/// * `folio` - The folio account (PDA) (not mut, not signer).
+#[derive(Accounts)]
+pub struct CancelOwnerProposal<'info> {
+    pub folio_owner: Signer<'info>,
+
+    #[account(
+        seeds = [ACTOR_SEEDS, folio_owner.key().as_ref(), folio.key().as_ref()],
+        bump = folio_owner_actor.bump,
+    )]
+    pub folio_owner_actor: Box<Account<'info, Actor>>,
+
+    /// CHECK: Checked to be the proposer of the pending owner
+    #[account(mut,
+        address = pending_owner.proposed_by,
+    )]
+    pub proposed_by: UncheckedAccount<'info>,
+
+    #[account(mut,
+        close = proposed_by,
+        seeds = [PENDING_OWNER_SEEDS, folio.key().as_ref()],
+        bump = pending_owner.bump,
+    )]
+    pub pending_owner: Box<Account<'info, PendingOwner>>,
+
+    #[account()]
+    pub folio: AccountLoader<'info, Folio>,
+}
+
+impl CancelOwnerProposal<'_> {
+    /// Validate the instruction.
+    ///
+    /// # Checks
+    /// * Folio owner actor is an owner of the folio.
+    pub fn validate(&self) -> Result<()> {
+        let folio = &self.folio.load()?;
+
+        folio.validate_folio(
+            &self.folio.key(),
+            Some(&self.folio_owner_actor),
+            Some(vec![Role::Owner]),
+            None, // Can cancel the ownership transfer no matter the status
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Cancel a pending ownership transfer, so that the proposed new owner can't accept it anymore.
+/// To propose another owner instead, `propose_owner` can be called again, which overrides the pending proposal.
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+pub fn handler(ctx: Context<CancelOwnerProposal>) -> Result<()> {
+    ctx.accounts.validate()?;
+
+    emit!(OwnerProposalCancelled {
+        cancelled_by: ctx.accounts.folio_owner.key(),
+        new_owner: ctx.accounts.pending_owner.new_owner,
+    });
+
+    Ok(())
+}
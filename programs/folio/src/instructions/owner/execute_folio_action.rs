@@ -0,0 +1,195 @@
+use crate::events::FolioActionExecuted;
+use crate::state::{Actor, Folio};
+use crate::utils::structs::{FolioStatus, Role};
+use crate::utils::FolioTokenAccountSnapshot;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::system_program::ID as SYSTEM_PROGRAM_ID;
+use anchor_spl::associated_token::ID as ASSOCIATED_TOKEN_PROGRAM_ID;
+use anchor_spl::token::ID as TOKEN_PROGRAM_ID;
+use anchor_spl::token_2022::ID as TOKEN_2022_PROGRAM_ID;
+use anchor_spl::token_interface::TokenAccount;
+use folio_admin::state::FolioActionRegistrar;
+use folio_admin::ID as FOLIO_ADMIN_PROGRAM_ID;
+use shared::errors::ErrorCode;
+use shared::{
+    check_condition,
+    constants::{ACTOR_SEEDS, FOLIO_ACTION_REGISTRAR_SEEDS, FOLIO_SEEDS},
+};
+
+/// Programs that can't be called by a folio action. The folio is the mint and freeze authority of the folio token and
+/// owns the basket token accounts, so calling them directly would let the owner act with those authorities.
+const FORBIDDEN_TARGET_PROGRAMS: [Pubkey; 5] = [
+    crate::ID,
+    TOKEN_PROGRAM_ID,
+    TOKEN_2022_PROGRAM_ID,
+    ASSOCIATED_TOKEN_PROGRAM_ID,
+    SYSTEM_PROGRAM_ID,
+];
+
+/// Execute an action on behalf of the folio.
+///
+/// # Arguments
+/// * `folio_owner` - The folio owner account (mut, signer).
+/// * `actor` - The actor account (PDA) of the Folio owner (not mut, not signer).
+/// * `folio` - The folio account (PDA) (not mut, not signer).
+/// * `folio_action_registrar` - The folio action registrar account (PDA) of the folio admin program (not mut, not signer).
+/// * `target_program` - The program to call (executable), can't be the folio, token, associated token or system program.
+///
+/// * `remaining_accounts` - The accounts of the instruction to execute, in the order expected by the target program.
+///   The folio is marked as signer.
+#[derive(Accounts)]
+pub struct ExecuteFolioAction<'info> {
+    #[account(mut)]
+    pub folio_owner: Signer<'info>,
+
+    #[account(
+        seeds = [ACTOR_SEEDS, folio_owner.key().as_ref(), folio.key().as_ref()],
+        bump = actor.bump,
+    )]
+    pub actor: Account<'info, Actor>,
+
+    #[account()]
+    pub folio: AccountLoader<'info, Folio>,
+
+    #[account(
+        seeds = [FOLIO_ACTION_REGISTRAR_SEEDS],
+        bump = folio_action_registrar.bump,
+        seeds::program = FOLIO_ADMIN_PROGRAM_ID,
+    )]
+    pub folio_action_registrar: Box<Account<'info, FolioActionRegistrar>>,
+
+    /// CHECK: Program to call, must be in the folio action registrar
+    #[account(executable)]
+    pub target_program: UncheckedAccount<'info>,
+}
+
+impl<'info> ExecuteFolioAction<'info> {
+    /// Validate the instruction.
+    ///
+    /// # Checks
+    /// * Folio has the correct status.
+    /// * Actor is the owner of the folio.
+    /// * Target program is in the folio action registrar and isn't a program the folio has authority in (folio,
+    ///   token, associated token or system program).
+    /// * Neither the folio token mint nor a token account of the folio token mint is passed, as the folio is the
+    ///   mint and freeze authority of the folio token.
+    pub fn validate(&self, folio: &Folio, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        folio.validate_folio(
+            &self.folio.key(),
+            Some(&self.actor),
+            Some(vec![Role::Owner]),
+            Some(vec![FolioStatus::Initialized, FolioStatus::Killed]),
+        )?;
+
+        check_condition!(
+            self.folio_action_registrar
+                .is_in_registrar(self.target_program.key()),
+            ProgramNotInRegistrar
+        );
+
+        check_condition!(
+            !FORBIDDEN_TARGET_PROGRAMS.contains(&self.target_program.key()),
+            InvalidProgram
+        );
+
+        check_condition!(
+            !remaining_accounts.iter().any(|account| {
+                account.key() == folio.folio_token_mint
+                    || Self::is_folio_token_account(account, &folio.folio_token_mint)
+            }),
+            InvalidFolioTokenMint
+        );
+
+        Ok(())
+    }
+
+    /// Check if the account is a token account of the folio token mint, no matter who holds it.
+    ///
+    /// # Arguments
+    /// * `account` - The account to check.
+    /// * `folio_token_mint` - The folio token mint.
+    #[cfg(not(tarpaulin_include))]
+    fn is_folio_token_account(account: &AccountInfo, folio_token_mint: &Pubkey) -> bool {
+        if *account.owner != TOKEN_PROGRAM_ID && *account.owner != TOKEN_2022_PROGRAM_ID {
+            return false;
+        }
+
+        let Ok(data) = account.try_borrow_data() else {
+            return false;
+        };
+
+        TokenAccount::try_deserialize(&mut &data[..])
+            .is_ok_and(|token_account| token_account.mint == *folio_token_mint)
+    }
+}
+
+/// Execute an action on behalf of the folio, meaning a CPI to a program of the folio action registrar (folio admin)
+/// signed by the folio. This is used to claim the airdrops and rewards the folio is entitled to as holder of its
+/// basket tokens.
+///
+/// The action can't take tokens away from the folio: every token account owned by the folio that is passed to the
+/// action must keep its owner, delegate and close authority, and its balance can't decrease. Since the pending amounts
+/// of the users are held in the same token accounts as the basket, this applies to all the folio's token accounts.
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+/// * `data` - The data of the instruction to execute.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteFolioAction<'info>>,
+    data: Vec<u8>,
+) -> Result<()> {
+    let folio_key = ctx.accounts.folio.key();
+
+    let (folio_bump, folio_token_mint) = {
+        let folio = ctx.accounts.folio.load()?;
+        ctx.accounts.validate(&folio, ctx.remaining_accounts)?;
+        (folio.bump, folio.folio_token_mint)
+    };
+
+    let snapshots: Vec<FolioTokenAccountSnapshot> = ctx
+        .remaining_accounts
+        .iter()
+        .filter_map(|account| FolioTokenAccountSnapshot::try_from_account_info(account, &folio_key))
+        .collect();
+
+    let instruction = Instruction {
+        program_id: ctx.accounts.target_program.key(),
+        accounts: ctx
+            .remaining_accounts
+            .iter()
+            .map(|account| AccountMeta {
+                pubkey: account.key(),
+                is_signer: account.is_signer || account.key() == folio_key,
+                is_writable: account.is_writable,
+            })
+            .collect(),
+        data,
+    };
+
+    let mut account_infos = ctx.remaining_accounts.to_vec();
+    account_infos.push(ctx.accounts.target_program.to_account_info());
+
+    let signer_seeds = &[FOLIO_SEEDS, folio_token_mint.as_ref(), &[folio_bump]];
+
+    invoke_signed(&instruction, &account_infos, &[signer_seeds])?;
+
+    for snapshot in snapshots.iter() {
+        let after = ctx
+            .remaining_accounts
+            .iter()
+            .find(|account| account.key() == snapshot.key)
+            .and_then(|account| {
+                FolioTokenAccountSnapshot::try_from_account_info(account, &folio_key)
+            });
+
+        snapshot.validate_after_folio_action(after.as_ref())?;
+    }
+
+    emit!(FolioActionExecuted {
+        program_id: ctx.accounts.target_program.key(),
+    });
+
+    Ok(())
+}
@@ -20,7 +20,8 @@ use anchor_spl::{
 use shared::{
     check_condition,
     constants::{
-        ACTOR_SEEDS, FOLIO_SEEDS, MAX_AUCTION_LENGTH, MAX_MINT_FEE, MAX_TVL_FEE, MIN_AUCTION_LENGTH,
+        ACTOR_SEEDS, ACTOR_VERSION, FOLIO_SEEDS, FOLIO_VERSION, MAX_AUCTION_LENGTH, MAX_MINT_FEE,
+        MAX_TVL_FEE, MIN_AUCTION_LENGTH,
     },
     errors::ErrorCode,
 };
@@ -154,6 +155,8 @@ pub fn handler(
         let folio = &mut ctx.accounts.folio.load_init()?;
 
         folio.bump = bump;
+        folio.version = FOLIO_VERSION;
+        folio.pending_baskets_tracked = 1;
         folio.folio_token_mint = folio_token_mint_key;
         folio.set_tvl_fee(scaled_tvl_fee)?;
         folio.mint_fee = scaled_mint_fee;
@@ -170,6 +173,7 @@ pub fn handler(
     actor.bump = ctx.bumps.actor;
     actor.authority = ctx.accounts.folio_owner.key();
     actor.folio = ctx.accounts.folio.key();
+    actor.version = ACTOR_VERSION;
     Role::add_role(&mut actor.roles, Role::Owner);
 
     // Create the metadata via spl 2022
@@ -1,7 +1,9 @@
-use crate::state::{Actor, Folio};
+use crate::state::{Actor, Folio, FolioActors};
 use crate::utils::structs::Role;
 use anchor_lang::prelude::*;
-use shared::constants::ACTOR_SEEDS;
+use shared::check_condition;
+use shared::constants::{ACTOR_SEEDS, FOLIO_ACTORS_SEEDS};
+use shared::errors::ErrorCode;
 
 /// Initialize or Update Actor
 ///
@@ -13,6 +15,7 @@ use shared::constants::ACTOR_SEEDS;
 /// * `new_actor_authority` - The new actor authority account (not mut, not signer).
 /// * `new_actor` - The new actor account (PDA) for the new actor authority (init, not signer).
 /// * `folio` - The folio account (PDA) (not mut, not signer).
+/// * `folio_actors` - The folio actors index account (PDA) (init if needed, not signer).
 #[derive(Accounts)]
 pub struct InitOrUpdateActor<'info> {
     pub system_program: Program<'info, System>,
@@ -32,7 +35,8 @@ pub struct InitOrUpdateActor<'info> {
     pub new_actor_authority: UncheckedAccount<'info>,
 
     /*
-    Init if needed because we use the same functionality to add roles to the actor
+    Init if needed because we use the same functionality to add roles to the actor.
+    Existing actors created before the role expirations were added need to be migrated first (see migrate_actor).
      */
     #[account(init_if_needed,
         payer = folio_owner,
@@ -44,6 +48,14 @@ pub struct InitOrUpdateActor<'info> {
 
     #[account()]
     pub folio: AccountLoader<'info, Folio>,
+
+    #[account(init_if_needed,
+        payer = folio_owner,
+        space = FolioActors::SIZE,
+        seeds = [FOLIO_ACTORS_SEEDS, folio_owner_actor.folio.key().as_ref()],
+        bump
+    )]
+    pub folio_actors: Box<Account<'info, FolioActors>>,
 }
 
 impl InitOrUpdateActor<'_> {
@@ -51,7 +63,8 @@ impl InitOrUpdateActor<'_> {
     ///
     /// # Checks
     /// * Folio owner actor is an owner of the folio.
-    pub fn validate(&self) -> Result<()> {
+    /// * Role to give is not the owner role, which can only be given via `propose_owner` and `accept_owner`.
+    pub fn validate(&self, role: Role) -> Result<()> {
         let folio = &self.folio.load()?;
 
         folio.validate_folio(
@@ -61,17 +74,24 @@ impl InitOrUpdateActor<'_> {
             None, // Can CRUD actors no matter the status
         )?;
 
+        check_condition!(
+            !matches!(role, Role::Owner),
+            OwnerRoleRequiresOwnershipTransfer
+        );
+
         Ok(())
     }
 }
 
 /// Initialize or Update Actor
+/// The owner role can't be given here, so that the ownership always goes through the two-step transfer.
 ///
 /// # Arguments
 /// * `ctx` - The context of the instruction.
 /// * `role` - The role to add or give to the actor.
-pub fn handler(ctx: Context<InitOrUpdateActor>, role: Role) -> Result<()> {
-    ctx.accounts.validate()?;
+/// * `expires_at` - The timestamp at which the role expires, None if the role doesn't expire.
+pub fn handler(ctx: Context<InitOrUpdateActor>, role: Role, expires_at: Option<u64>) -> Result<()> {
+    ctx.accounts.validate(role)?;
 
     let new_actor = &mut ctx.accounts.new_actor;
 
@@ -84,7 +104,27 @@ pub fn handler(ctx: Context<InitOrUpdateActor>, role: Role) -> Result<()> {
         &ctx.accounts.folio_owner_actor.folio,
     )?;
 
-    Role::add_role(&mut new_actor.roles, role);
+    new_actor.add_role(role, expires_at, Clock::get()?.unix_timestamp as u64)?;
+
+    let new_actor_authority = new_actor.authority;
+    let new_actor_roles = new_actor.roles;
+
+    let folio_actors = &mut ctx.accounts.folio_actors;
+
+    let folio_actors_bump = folio_actors.bump;
+
+    folio_actors.process_init_if_needed(
+        folio_actors_bump,
+        ctx.bumps.folio_actors,
+        &ctx.accounts.folio_owner_actor.folio,
+    )?;
+
+    // Also index the owner, as folios created before the index existed won't have it yet
+    folio_actors.set_actor_roles(
+        &ctx.accounts.folio_owner_actor.authority,
+        ctx.accounts.folio_owner_actor.roles,
+    );
+    folio_actors.set_actor_roles(&new_actor_authority, new_actor_roles);
 
     Ok(())
 }
@@ -0,0 +1,97 @@
+use crate::events::FeeDiscountSet;
+use crate::state::{Actor, FeeDiscount, Folio};
+use crate::utils::structs::Role;
+use anchor_lang::prelude::*;
+use shared::constants::{ACTOR_SEEDS, FEE_DISCOUNT_SEEDS};
+
+/// Initialize or Update Fee Discount
+/// Owner only.
+///
+/// # Arguments
+/// * `system_program` - The system program.
+/// * `rent` - The rent sysvar.
+/// * `folio_owner` - The folio owner account (mut, signer).
+/// * `actor` - The actor account of the folio owner (PDA) (not mut, not signer).
+/// * `folio` - The folio account (PDA) (not mut, not signer).
+/// * `minter` - The minter account that benefits from the discount (not mut, not signer).
+/// * `fee_discount` - The fee discount account (PDA) for the minter (init, not signer).
+#[derive(Accounts)]
+pub struct InitOrUpdateFeeDiscount<'info> {
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+
+    #[account(mut)]
+    pub folio_owner: Signer<'info>,
+
+    #[account(
+        seeds = [ACTOR_SEEDS, folio_owner.key().as_ref(), folio.key().as_ref()],
+        bump = actor.bump,
+    )]
+    pub actor: Box<Account<'info, Actor>>,
+
+    #[account()]
+    pub folio: AccountLoader<'info, Folio>,
+
+    /// CHECK: Wallet, program, multisig that will benefit from the discount
+    #[account()]
+    pub minter: UncheckedAccount<'info>,
+
+    /*
+    Init if needed because we use the same functionality to update the discount
+     */
+    #[account(init_if_needed,
+        payer = folio_owner,
+        space = FeeDiscount::SIZE,
+        seeds = [FEE_DISCOUNT_SEEDS, folio.key().as_ref(), minter.key().as_ref()],
+        bump
+    )]
+    pub fee_discount: Box<Account<'info, FeeDiscount>>,
+}
+
+impl InitOrUpdateFeeDiscount<'_> {
+    /// Validate the instruction.
+    ///
+    /// # Checks
+    /// * Actor is an owner of the folio.
+    pub fn validate(&self) -> Result<()> {
+        let folio = &self.folio.load()?;
+
+        folio.validate_folio(
+            &self.folio.key(),
+            Some(&self.actor),
+            Some(vec![Role::Owner]),
+            None, // Can CRUD fee discounts no matter the status
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Initialize or Update Fee Discount
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+/// * `scaled_discount` - The discount applied on the mint fee of the minter, scaled in D18 (1e18 = 100% discount).
+pub fn handler(ctx: Context<InitOrUpdateFeeDiscount>, scaled_discount: u128) -> Result<()> {
+    ctx.accounts.validate()?;
+
+    let fee_discount = &mut ctx.accounts.fee_discount;
+
+    let fee_discount_bump = fee_discount.bump;
+
+    fee_discount.process_init_if_needed(
+        fee_discount_bump,
+        ctx.bumps.fee_discount,
+        &ctx.accounts.folio.key(),
+        &ctx.accounts.minter.key(),
+    )?;
+
+    fee_discount.set_discount(scaled_discount)?;
+
+    emit!(FeeDiscountSet {
+        minter: fee_discount.minter,
+        discount: fee_discount.scaled_discount,
+    });
+
+    Ok(())
+}
@@ -1,8 +1,10 @@
 use crate::events::FolioKilled;
-use crate::state::{Actor, Folio};
+use crate::state::{Actor, Folio, KillRecord};
 use crate::utils::structs::{FolioStatus, Role};
 use anchor_lang::prelude::*;
-use shared::constants::ACTOR_SEEDS;
+use folio_admin::state::UnkillConfig;
+use folio_admin::ID as FOLIO_ADMIN_PROGRAM_ID;
+use shared::constants::{ACTOR_SEEDS, KILL_RECORD_SEEDS, UNKILL_CONFIG_SEEDS};
 
 /// Kill Folio
 ///
@@ -11,6 +13,8 @@ use shared::constants::ACTOR_SEEDS;
 /// * `folio_owner` - The folio owner account (mut, signer).
 /// * `actor` - The actor account (PDA) of the Folio owner (not mut, not signer).
 /// * `folio` - The folio account (PDA) (mut, not signer).
+/// * `kill_record` - The kill record account (PDA) (init, not signer).
+/// * `unkill_config` - The unkill config account (PDA) (not mut, not signer).
 #[derive(Accounts)]
 pub struct KillFolio<'info> {
     pub system_program: Program<'info, System>,
@@ -26,6 +30,23 @@ pub struct KillFolio<'info> {
 
     #[account(mut)]
     pub folio: AccountLoader<'info, Folio>,
+
+    #[account(
+        init,
+        payer = folio_owner,
+        space = KillRecord::SIZE,
+        seeds = [KILL_RECORD_SEEDS, folio.key().as_ref()],
+        bump
+    )]
+    pub kill_record: Account<'info, KillRecord>,
+
+    /// CHECK: Could be empty or could be set, if set we use that one, else we use the default unkill timelock
+    #[account(
+        seeds = [UNKILL_CONFIG_SEEDS],
+        bump,
+        seeds::program = FOLIO_ADMIN_PROGRAM_ID,
+    )]
+    pub unkill_config: UncheckedAccount<'info>,
 }
 
 impl KillFolio<'_> {
@@ -47,6 +68,8 @@ impl KillFolio<'_> {
 }
 
 /// Kill Folio
+/// The time of the kill is recorded, so that the folio can be unkilled after a timelock. The timelock of the unkill
+/// config is recorded too, so that a later change of the config doesn't apply to the folios already killed.
 ///
 /// # Arguments
 /// * `ctx` - The context of the instruction.
@@ -57,6 +80,15 @@ pub fn handler(ctx: Context<KillFolio>) -> Result<()> {
 
     folio.status = FolioStatus::Killed as u8;
 
+    let unkill_timelock = UnkillConfig::get_unkill_timelock(&ctx.accounts.unkill_config)?;
+
+    ctx.accounts.kill_record.init(
+        ctx.bumps.kill_record,
+        ctx.accounts.folio.key(),
+        Clock::get()?.unix_timestamp as u64,
+        unkill_timelock,
+    );
+
     emit!(FolioKilled {});
 
     Ok(())
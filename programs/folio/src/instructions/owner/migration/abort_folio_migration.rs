@@ -0,0 +1,241 @@
+use crate::events::FolioMigrationAborted;
+use crate::utils::NewFolioProgram;
+use crate::ID as FOLIO_PROGRAM_ID;
+use crate::{
+    state::{Actor, Folio, MigrationRecord},
+    utils::{FolioStatus, Role},
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions;
+use anchor_spl::metadata::mpl_token_metadata;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+use folio_admin::{state::ProgramRegistrar, ID as FOLIO_ADMIN_PROGRAM_ID};
+use shared::constants::METADATA_SEEDS;
+use shared::errors::ErrorCode;
+use shared::{
+    check_condition,
+    constants::{ACTOR_SEEDS, FOLIO_SEEDS, MIGRATION_RECORD_SEEDS, PROGRAM_REGISTRAR_SEEDS},
+};
+
+/// Abort Folio Migration
+/// Folio owner
+///
+/// # Arguments
+/// * `system_program` - The system program.
+/// * `rent` - The rent sysvar.
+/// * `instructions_sysvar` - The instructions sysvar.
+/// * `token_program` - The token program.
+/// * `folio_owner` - The folio owner account (mut, signer).
+/// * `actor` - The actor account (PDA) of the Folio owner (not mut, not signer).
+/// * `program_registrar` - The program registrar account (not mut, not signer).
+/// * `new_folio_program` - The new folio program (executable).
+/// * `old_folio` - The old folio account (PDA) (mut, not signer).
+/// * `new_folio` - The new folio account (mut, not signer).
+/// * `folio_token_mint` - The folio token mint account (mut, not signer).
+/// * `new_actor` - The actor account in the new folio program (mut, not signer).
+/// * `new_folio_basket` - The folio basket account in the new folio program (mut, not signer).
+/// * `new_fee_recipients` - The fee recipients account in the new folio program (mut, not signer).
+/// * `migration_record` - The migration record account (PDA) (mut, not signer), closed to the folio owner.
+/// * `token_metadata_program` - The token metadata program.
+/// * `metadata` - The metadata account of the folio token mint (mut, not signer).
+#[derive(Accounts)]
+pub struct AbortFolioMigration<'info> {
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    /// CHECK: Instructions sysvar
+    #[account(address = instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+
+    #[account(mut)]
+    pub folio_owner: Signer<'info>,
+
+    #[account(
+        seeds = [ACTOR_SEEDS, folio_owner.key().as_ref(), old_folio.key().as_ref()],
+        bump = actor.bump,
+    )]
+    pub actor: Account<'info, Actor>,
+
+    #[account(
+        seeds = [PROGRAM_REGISTRAR_SEEDS],
+        bump = program_registrar.bump,
+        seeds::program = FOLIO_ADMIN_PROGRAM_ID,
+    )]
+    pub program_registrar: Box<Account<'info, ProgramRegistrar>>,
+
+    /// CHECK: Folio program used for new folio
+    #[account(executable)]
+    pub new_folio_program: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub old_folio: AccountLoader<'info, Folio>,
+
+    /// CHECK: The new folio
+    #[account(mut)]
+    pub new_folio: UncheckedAccount<'info>,
+
+    // Validate mint is still owned by the new folio, it's returned in the cpi to the new folio program
+    #[account(mut,
+        mint::authority = new_folio,
+        mint::freeze_authority = new_folio,
+    )]
+    pub folio_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: it is checked in the cpi to the new folio program
+    #[account(mut)]
+    pub new_actor: UncheckedAccount<'info>,
+
+    /// CHECK: it is checked in the cpi to the new folio program
+    #[account(mut)]
+    pub new_folio_basket: UncheckedAccount<'info>,
+
+    /// CHECK: it is checked in the cpi to the new folio program
+    #[account(mut)]
+    pub new_fee_recipients: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = folio_owner,
+        seeds = [MIGRATION_RECORD_SEEDS, old_folio.key().as_ref()],
+        bump = migration_record.bump,
+    )]
+    pub migration_record: Box<Account<'info, MigrationRecord>>,
+
+    /*
+    Metaplex accounts for metadata
+     */
+    /// CHECK: Token metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    /// CHECK: Metadata account
+    #[account(
+        mut,
+        seeds = [
+            METADATA_SEEDS,
+            mpl_token_metadata::ID.as_ref(),
+            folio_token_mint.key().as_ref()
+        ],
+        seeds::program = mpl_token_metadata::ID,
+        bump
+    )]
+    pub metadata: UncheckedAccount<'info>,
+}
+
+impl AbortFolioMigration<'_> {
+    /// Validate the instruction.
+    ///
+    /// # Checks
+    /// * Old folio is migrating and is owned by the folio owner.
+    /// * Token mint is the same as the one on the old folio.
+    /// * New folio is the one the folio is migrating to.
+    /// * Abort grace period of the migration hasn't passed.
+    /// * New folio program is in the registrar.
+    /// * New folio is owned by the new folio program.
+    /// * New folio program is not the same as the old folio program.
+    pub fn validate(&self, old_folio: &Folio, current_time: u64) -> Result<()> {
+        old_folio.validate_folio(
+            &self.old_folio.key(),
+            Some(&self.actor),
+            Some(vec![Role::Owner]),
+            Some(vec![FolioStatus::Migrating]),
+        )?;
+
+        check_condition!(
+            old_folio.folio_token_mint == self.folio_token_mint.key(),
+            InvalidFolioTokenMint
+        );
+
+        check_condition!(
+            self.migration_record.new_folio == self.new_folio.key(),
+            InvalidNewFolio
+        );
+
+        self.migration_record
+            .validate_abort_grace_period(current_time)?;
+
+        /*
+        New Folio Validation
+         */
+        // Make sure the new folio program is in the registrar
+        check_condition!(
+            self.program_registrar
+                .is_in_registrar(self.new_folio_program.key()),
+            ProgramNotInRegistrar
+        );
+
+        // Make sure the new folio is owned by the new folio program
+        check_condition!(
+            *self.new_folio.owner == self.new_folio_program.key(),
+            NewFolioNotOwnedByNewFolioProgram
+        );
+
+        check_condition!(
+            self.new_folio_program.key() != FOLIO_PROGRAM_ID,
+            CantMigrateToSameProgram
+        );
+
+        Ok(())
+    }
+}
+
+/// Abort Folio Migration. Only possible during the grace period after the start of the migration, before any of the
+/// assets of the folio were migrated.
+/// The new folio program returns the mint, freeze and metadata authorities to the old folio, and the old folio is
+/// restored to the status it had before the migration.
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+pub fn handler(ctx: Context<AbortFolioMigration>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    let old_folio_bump: u8;
+    {
+        let old_folio = &ctx.accounts.old_folio.load()?;
+
+        old_folio_bump = old_folio.bump;
+
+        ctx.accounts.validate(old_folio, current_time)?;
+    }
+
+    let token_mint_key = ctx.accounts.folio_token_mint.key();
+
+    let folio_signer_seeds = &[FOLIO_SEEDS, token_mint_key.as_ref(), &[old_folio_bump]];
+
+    NewFolioProgram::abort_folio_migration_from_old_program(
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.rent.to_account_info(),
+        &ctx.accounts.instructions_sysvar.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.folio_owner.to_account_info(),
+        &ctx.accounts.old_folio.to_account_info(),
+        &ctx.accounts.new_folio.to_account_info(),
+        &ctx.accounts.new_actor.to_account_info(),
+        &ctx.accounts.new_folio_basket.to_account_info(),
+        &ctx.accounts.new_fee_recipients.to_account_info(),
+        &ctx.accounts.folio_token_mint.to_account_info(),
+        &ctx.accounts.token_metadata_program.to_account_info(),
+        &ctx.accounts.metadata.to_account_info(),
+        &ctx.accounts.program_registrar.to_account_info(),
+        &ctx.accounts.new_folio_program.to_account_info(),
+        &[&folio_signer_seeds[..]],
+    )?;
+
+    // Don't rely on the new folio program, the old folio needs to be able to mint again.
+    ctx.accounts.folio_token_mint.reload()?;
+
+    let old_folio_key = ctx.accounts.old_folio.key();
+
+    check_condition!(
+        ctx.accounts.folio_token_mint.mint_authority == Some(old_folio_key).into()
+            && ctx.accounts.folio_token_mint.freeze_authority == Some(old_folio_key).into(),
+        MigrationAuthoritiesNotReturned
+    );
+
+    let old_folio = &mut ctx.accounts.old_folio.load_mut()?;
+    old_folio.status = ctx.accounts.migration_record.previous_status;
+
+    emit!(FolioMigrationAborted {});
+
+    Ok(())
+}
@@ -0,0 +1,231 @@
+use crate::utils::{Metaplex, UpdateAuthority};
+use crate::{
+    state::{Actor, FeeRecipients, Folio, FolioBasket},
+    utils::FolioStatus,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions;
+use anchor_spl::metadata::mpl_token_metadata;
+use anchor_spl::token::Token;
+use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
+use anchor_spl::token_2022_extensions::token_metadata::token_metadata_update_authority;
+use anchor_spl::token_interface::spl_pod::optional_keys::OptionalNonZeroPubkey;
+use anchor_spl::token_interface::{self, Mint, TokenInterface, TokenMetadataUpdateAuthority};
+use folio_admin::{state::ProgramRegistrar, ID as FOLIO_ADMIN_PROGRAM_ID};
+use shared::{
+    check_condition,
+    constants::{
+        FEE_RECIPIENTS_SEEDS, FOLIO_BASKET_SEEDS, FOLIO_SEEDS, METADATA_SEEDS,
+        PROGRAM_REGISTRAR_SEEDS,
+    },
+    errors::ErrorCode,
+};
+
+/// Abort the migration of a folio to this new folio program, called by the old folio program.
+/// Returns the authorities of the folio token mint to the old folio and closes the accounts of the new folio.
+///
+/// THIS IS ONLY TO SHOW AN EXAMPLE OF WHAT SHOULD BE IMPLEMENTED IN FUTURE VERSIONS
+/// OF THE FOLIO PROGRAM. IT WON'T BE INCLUDED IN THE MAINNET BUILD FOR THIS VERSION
+/// OF THE FOLIO PROGRAM.
+///
+/// # Arguments
+/// * `system_program` - The system program to use
+/// * `rent` - The rent sysvar
+/// * `instructions_sysvar` - The instructions sysvar
+/// * `token_program` - The token program of the folio token mint
+/// * `owner` - The folio owner, receives the rent of the closed accounts
+/// * `old_folio` - The old folio to use
+/// * `new_folio` - The new folio to use
+/// * `new_actor` - The actor of the new folio
+/// * `new_folio_basket` - The folio basket of the new folio
+/// * `new_fee_recipients` - The fee recipients of the new folio
+/// * `folio_token_mint` - The folio token mint to use
+/// * `token_metadata_program` - The token metadata program
+/// * `metadata` - The metaplex metadata account of the folio token mint
+/// * `program_registrar` - The program registrar
+#[derive(Accounts)]
+pub struct AbortFolioMigrationFromOldProgram<'info> {
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    /// CHECK: Instructions sysvar
+    #[account(address = instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: Validate is from the old folio program using the seeds
+    /// For now it validates with hardcoded program ids, but this is just because it's for testing only
+    /// in this version of the folio program
+    #[account()]
+    pub old_folio: Signer<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [FOLIO_SEEDS, folio_token_mint.key().as_ref()],
+        bump,
+    )]
+    pub new_folio: AccountLoader<'info, Folio>,
+
+    #[account(
+        mut,
+        close = owner,
+        constraint = new_actor.folio == new_folio.key() @ ErrorCode::InvalidActorPda,
+    )]
+    pub new_actor: Box<Account<'info, Actor>>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [FOLIO_BASKET_SEEDS, new_folio.key().as_ref()],
+        bump,
+    )]
+    pub new_folio_basket: AccountLoader<'info, FolioBasket>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [FEE_RECIPIENTS_SEEDS, new_folio.key().as_ref()],
+        bump,
+    )]
+    pub new_fee_recipients: AccountLoader<'info, FeeRecipients>,
+
+    #[account(mut,
+        mint::authority = new_folio,
+        mint::freeze_authority = new_folio,
+    )]
+    pub folio_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Token metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    /// CHECK: Metadata account
+    #[account(
+        mut,
+        seeds = [
+            METADATA_SEEDS,
+            mpl_token_metadata::ID.as_ref(),
+            folio_token_mint.key().as_ref()
+        ],
+        seeds::program = mpl_token_metadata::ID,
+        bump
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PROGRAM_REGISTRAR_SEEDS],
+        bump = program_registrar.bump,
+        seeds::program = FOLIO_ADMIN_PROGRAM_ID,
+    )]
+    pub program_registrar: Box<Account<'info, ProgramRegistrar>>,
+}
+
+impl AbortFolioMigrationFromOldProgram<'_> {
+    /// Validate the instruction.
+    pub fn validate(&self, old_folio: &Folio, new_folio: &Folio) -> Result<()> {
+        check_condition!(
+            old_folio.status == FolioStatus::Migrating as u8,
+            InvalidFolioStatus
+        );
+        check_condition!(
+            new_folio.status == FolioStatus::Migrating as u8,
+            InvalidFolioStatus
+        );
+        check_condition!(
+            new_folio.folio_token_mint == old_folio.folio_token_mint,
+            InvalidFolioTokenMint
+        );
+
+        check_condition!(
+            self.program_registrar
+                .is_in_registrar(*self.old_folio.owner),
+            ProgramNotInRegistrar
+        );
+
+        Ok(())
+    }
+}
+
+/// This is used to abort the migration of a folio to this new folio program.
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+#[allow(unused_variables)]
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, AbortFolioMigrationFromOldProgram<'info>>,
+) -> Result<()> {
+    // If by mistake it's included in the program, if we don't see dev flag, we return ok
+    #[cfg(not(feature = "test"))]
+    return Ok(());
+
+    #[allow(unreachable_code)]
+    let new_folio_bump: u8;
+
+    {
+        let folio_data = &ctx.accounts.old_folio.data.borrow();
+        let old_folio: &Folio = bytemuck::from_bytes(&folio_data[8..]);
+        let new_folio = &ctx.accounts.new_folio.load()?;
+
+        ctx.accounts.validate(old_folio, new_folio)?;
+
+        new_folio_bump = new_folio.bump;
+    }
+
+    let token_mint_key = ctx.accounts.folio_token_mint.key();
+    let old_folio_key = ctx.accounts.old_folio.key();
+
+    let folio_signer_seeds = &[FOLIO_SEEDS, token_mint_key.as_ref(), &[new_folio_bump]];
+    let folio_signer = &[&folio_signer_seeds[..]];
+
+    if ctx.accounts.token_program.key() == Token::id() {
+        Metaplex::update_metadata_authority(
+            &UpdateAuthority {
+                metadata: ctx.accounts.metadata.to_account_info(),
+                mint: ctx.accounts.folio_token_mint.to_account_info(),
+                mint_authority: ctx.accounts.new_folio.to_account_info(),
+                payer: ctx.accounts.owner.to_account_info(),
+                update_authority: ctx.accounts.new_folio.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+                token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+                sysvar_instructions: ctx.accounts.instructions_sysvar.to_account_info(),
+            },
+            old_folio_key,
+            folio_signer,
+        )?;
+    } else {
+        token_metadata_update_authority(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TokenMetadataUpdateAuthority {
+                    program_id: ctx.accounts.token_program.to_account_info(),
+                    metadata: ctx.accounts.folio_token_mint.to_account_info(),
+                    current_authority: ctx.accounts.new_folio.to_account_info(),
+                    new_authority: ctx.accounts.old_folio.to_account_info(),
+                },
+                folio_signer,
+            ),
+            OptionalNonZeroPubkey(old_folio_key),
+        )?;
+    }
+
+    for authority_type in [AuthorityType::MintTokens, AuthorityType::FreezeAccount] {
+        token_interface::set_authority(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_interface::SetAuthority {
+                    current_authority: ctx.accounts.new_folio.to_account_info(),
+                    account_or_mint: ctx.accounts.folio_token_mint.to_account_info(),
+                },
+                folio_signer,
+            ),
+            authority_type,
+            Some(old_folio_key),
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,132 @@
+use crate::{
+    state::{FeeDistribution, Folio},
+    utils::FolioStatus,
+};
+use anchor_lang::prelude::*;
+use folio_admin::{state::ProgramRegistrar, ID as FOLIO_ADMIN_PROGRAM_ID};
+use shared::{
+    check_condition,
+    constants::{FEE_DISTRIBUTION_SEEDS, FEE_DISTRIBUTION_VERSION, PROGRAM_REGISTRAR_SEEDS},
+    errors::ErrorCode,
+};
+
+/// Create a fee distribution in this new folio program from a fee distribution of the old folio program, called by
+/// the old folio program.
+///
+/// THIS IS ONLY TO SHOW AN EXAMPLE OF WHAT SHOULD BE IMPLEMENTED IN FUTURE VERSIONS
+/// OF THE FOLIO PROGRAM. IT WON'T BE INCLUDED IN THE MAINNET BUILD FOR THIS VERSION
+/// OF THE FOLIO PROGRAM.
+///
+/// # Arguments
+/// * `system_program` - The system program to use
+/// * `user` - The payer of the rent of the new fee distribution, becomes its cranker
+/// * `old_folio` - The old folio to use
+/// * `new_folio` - The new folio to use
+/// * `old_fee_distribution` - The fee distribution in the old folio program
+/// * `new_fee_distribution` - The fee distribution in the new folio program
+#[derive(Accounts)]
+#[instruction(index: u64)]
+pub struct CreateFeeDistributionFromOldProgram<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: Validate is from the old folio program using the seeds
+    /// For now it validates with hardcoded program ids, but this is just because it's for testing only
+    /// in this version of the folio program
+    #[account()]
+    pub old_folio: Signer<'info>,
+
+    #[account()]
+    pub new_folio: AccountLoader<'info, Folio>,
+
+    /// CHECK: Seeds are checked and the account data is read in the handler
+    #[account(
+        seeds = [FEE_DISTRIBUTION_SEEDS, old_folio.key().as_ref(), index.to_le_bytes().as_slice()],
+        bump,
+        seeds::program = old_folio.owner,
+        owner = *old_folio.owner,
+    )]
+    pub old_fee_distribution: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = user,
+        space = FeeDistribution::SIZE,
+        seeds = [FEE_DISTRIBUTION_SEEDS, new_folio.key().as_ref(), index.to_le_bytes().as_slice()],
+        bump,
+    )]
+    pub new_fee_distribution: AccountLoader<'info, FeeDistribution>,
+
+    #[account(
+        seeds = [PROGRAM_REGISTRAR_SEEDS],
+        bump = program_registrar.bump,
+        seeds::program = FOLIO_ADMIN_PROGRAM_ID,
+    )]
+    pub program_registrar: Box<Account<'info, ProgramRegistrar>>,
+}
+
+impl CreateFeeDistributionFromOldProgram<'_> {
+    /// Validate the instruction.
+    pub fn validate(&self, old_folio: &Folio, new_folio: &Folio) -> Result<()> {
+        check_condition!(
+            old_folio.status == FolioStatus::Migrating as u8,
+            InvalidFolioStatus
+        );
+        check_condition!(
+            new_folio.folio_token_mint == old_folio.folio_token_mint,
+            InvalidFolioTokenMint
+        );
+
+        check_condition!(
+            self.program_registrar
+                .is_in_registrar(*self.old_folio.owner),
+            ProgramNotInRegistrar
+        );
+
+        Ok(())
+    }
+}
+
+/// This is used to create a fee distribution in the new folio program, with the fee recipients that haven't been
+/// cranked yet in the old folio program.
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+/// * `index` - The index of the fee distribution.
+#[allow(unused_variables)]
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateFeeDistributionFromOldProgram<'info>>,
+    index: u64,
+) -> Result<()> {
+    // If by mistake it's included in the program, if we don't see dev flag, we return ok
+    #[cfg(not(feature = "test"))]
+    return Ok(());
+
+    #[allow(unreachable_code)]
+    let folio_data = &ctx.accounts.old_folio.data.borrow();
+    let old_folio: &Folio = bytemuck::from_bytes(&folio_data[8..]);
+    let new_folio = &ctx.accounts.new_folio.load()?;
+
+    {
+        ctx.accounts.validate(old_folio, new_folio)?;
+    }
+
+    {
+        let old_fee_distribution_data = &ctx.accounts.old_fee_distribution.data.borrow();
+        let old_fee_distribution: &FeeDistribution =
+            bytemuck::from_bytes(&old_fee_distribution_data[8..]);
+
+        let new_fee_distribution = &mut ctx.accounts.new_fee_distribution.load_init()?;
+
+        new_fee_distribution.bump = ctx.bumps.new_fee_distribution;
+        new_fee_distribution.version = FEE_DISTRIBUTION_VERSION;
+        new_fee_distribution.index = index;
+        new_fee_distribution.folio = ctx.accounts.new_folio.key();
+        new_fee_distribution.cranker = ctx.accounts.user.key();
+        new_fee_distribution.init_from_old_fee_distribution(old_fee_distribution);
+    }
+
+    Ok(())
+}
@@ -1,16 +1,19 @@
 use crate::{
-    state::{Actor, Folio, FolioBasket},
+    state::{Actor, FeeRecipients, Folio, FolioBasket, FolioMintLimits},
     utils::FolioStatus,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
 use shared::{
     check_condition,
-    constants::{ACTOR_SEEDS, FOLIO_BASKET_SEEDS, FOLIO_SEEDS},
+    constants::{
+        ACTOR_SEEDS, FEE_RECIPIENTS_SEEDS, FOLIO_BASKET_SEEDS, FOLIO_MINT_LIMITS_SEEDS, FOLIO_SEEDS,
+    },
     errors::ErrorCode,
 };
 
-/// Create a new folio from the old folio program. And initializes the FolioBasket account.
+/// Create a new folio from the old folio program. And initializes the FolioBasket and FeeRecipients accounts, as well
+/// as the FolioMintLimits account if the old folio has mint limits.
 ///
 /// THIS IS ONLY TO SHOW AN EXAMPLE OF WHAT SHOULD BE IMPLEMENTED IN FUTURE VERSIONS
 /// OF THE FOLIO PROGRAM. IT WON'T BE INCLUDED IN THE MAINNET BUILD FOR THIS VERSION
@@ -24,6 +27,10 @@ use shared::{
 /// * `actor` - The actor to use
 /// * `new_folio_basket` - The new folio basket to use
 /// * `folio_token_mint` - The folio token mint to use
+/// * `old_fee_recipients` - The fee recipients of the old folio
+/// * `new_fee_recipients` - The fee recipients of the new folio
+/// * `old_folio_mint_limits` - The mint limits of the old folio
+/// * `new_folio_mint_limits` - The mint limits of the new folio
 #[derive(Accounts)]
 pub struct CreateFolioFromOldProgram<'info> {
     pub system_program: Program<'info, System>,
@@ -62,6 +69,39 @@ pub struct CreateFolioFromOldProgram<'info> {
     pub new_folio_basket: AccountLoader<'info, FolioBasket>,
 
     pub folio_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Seeds are checked, might not be initialized if the old folio never set fee recipients
+    #[account(
+        seeds = [FEE_RECIPIENTS_SEEDS, old_folio.key().as_ref()],
+        bump,
+        seeds::program = old_folio.owner,
+    )]
+    pub old_fee_recipients: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = FeeRecipients::SIZE,
+        seeds = [FEE_RECIPIENTS_SEEDS, new_folio.key().as_ref()],
+        bump,
+    )]
+    pub new_fee_recipients: AccountLoader<'info, FeeRecipients>,
+
+    /// CHECK: Seeds are checked, might not be initialized if the old folio never set mint limits
+    #[account(
+        seeds = [FOLIO_MINT_LIMITS_SEEDS, old_folio.key().as_ref()],
+        bump,
+        seeds::program = old_folio.owner,
+    )]
+    pub old_folio_mint_limits: UncheckedAccount<'info>,
+
+    /// CHECK: Seeds are checked, only initialized if the old folio has mint limits
+    #[account(
+        mut,
+        seeds = [FOLIO_MINT_LIMITS_SEEDS, new_folio.key().as_ref()],
+        bump,
+    )]
+    pub new_folio_mint_limits: UncheckedAccount<'info>,
 }
 
 impl CreateFolioFromOldProgram<'_> {
@@ -104,22 +144,7 @@ pub fn handler<'info>(
     {
         let folio = &mut ctx.accounts.new_folio.load_init()?;
 
-        folio.bump = ctx.bumps.new_folio;
-        folio.folio_token_mint = ctx.accounts.folio_token_mint.key();
-        folio.set_tvl_fee(old_folio.tvl_fee)?;
-        folio.mint_fee = old_folio.mint_fee;
-        folio.last_poke = old_folio.last_poke;
-        folio.auction_length = old_folio.auction_length;
-        folio.mandate = old_folio.mandate;
-        folio.initialized_at = old_folio.initialized_at;
-
-        // We can set these to 0, as the old_program, before calling this function confirms us that the
-        // values are less then D9, or max the folio owner is willing to loss as fees.
-        folio.dao_pending_fee_shares = 0;
-        folio.fee_recipients_pending_fee_shares = 0;
-        folio.fee_recipients_pending_fee_shares_to_be_minted = 0;
-
-        folio.status = FolioStatus::Migrating as u8;
+        folio.init_from_old_folio(old_folio, ctx.bumps.new_folio);
     }
 
     FolioBasket::process_init_if_needed(
@@ -129,5 +154,51 @@ pub fn handler<'info>(
         &vec![],
     )?;
 
+    {
+        let new_fee_recipients = &mut ctx.accounts.new_fee_recipients.load_init()?;
+
+        new_fee_recipients.bump = ctx.bumps.new_fee_recipients;
+        new_fee_recipients.folio = ctx.accounts.new_folio.key();
+
+        let old_fee_recipients_info = &ctx.accounts.old_fee_recipients;
+
+        if !old_fee_recipients_info.data_is_empty() {
+            check_condition!(
+                old_fee_recipients_info.owner == ctx.accounts.old_folio.owner,
+                InvalidFeeRecipient
+            );
+
+            let old_fee_recipients_data = old_fee_recipients_info.data.borrow();
+            let old_fee_recipients: &FeeRecipients =
+                bytemuck::from_bytes(&old_fee_recipients_data[8..]);
+
+            new_fee_recipients.init_from_old_fee_recipients(old_fee_recipients);
+        }
+    }
+
+    // The flag is copied over with the folio, so the limits need to follow for the new folio to enforce them
+    if old_folio.is_mint_limits_enabled() {
+        let old_folio_mint_limits_info = &ctx.accounts.old_folio_mint_limits;
+
+        check_condition!(
+            old_folio_mint_limits_info.owner == ctx.accounts.old_folio.owner,
+            InvalidAccountOwner
+        );
+
+        let old_folio_mint_limits = FolioMintLimits::try_deserialize(
+            &mut &old_folio_mint_limits_info.try_borrow_data()?[..],
+        )?;
+
+        FolioMintLimits::init_or_update_folio_mint_limits(
+            &ctx.accounts.new_folio_mint_limits,
+            &ctx.accounts.owner,
+            &ctx.accounts.system_program,
+            &ctx.accounts.new_folio.key(),
+            ctx.bumps.new_folio_mint_limits,
+            Some(old_folio_mint_limits.max_supply),
+            Some(old_folio_mint_limits.max_mint_per_tx),
+        )?;
+    }
+
     Ok(())
 }
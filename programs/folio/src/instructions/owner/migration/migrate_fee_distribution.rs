@@ -0,0 +1,224 @@
+use crate::utils::versioned_account::VersionedAccount;
+use crate::utils::NewFolioProgram;
+use crate::ID as FOLIO_PROGRAM_ID;
+use crate::{
+    state::{FeeDistribution, Folio, MigrationRecord},
+    utils::FolioStatus,
+};
+use anchor_lang::{prelude::*, Discriminator};
+use anchor_spl::token_interface::Mint;
+use folio_admin::{state::ProgramRegistrar, ID as FOLIO_ADMIN_PROGRAM_ID};
+use shared::errors::ErrorCode;
+use shared::{
+    check_condition,
+    constants::{
+        FEE_DISTRIBUTION_SEEDS, FOLIO_SEEDS, MIGRATION_RECORD_SEEDS, PROGRAM_REGISTRAR_SEEDS,
+    },
+};
+
+/// Migrate Fee Distribution
+/// Permissionless
+///
+/// # Arguments
+/// * `system_program` - The system program.
+/// * `user` - The user account (mut, signer).
+/// * `cranker` - The cranker account (mut, not signer). Receives the rent of the old fee distribution account.
+/// * `program_registrar` - The program registrar account (not mut, not signer).
+/// * `new_folio_program` - The new folio program (executable).
+/// * `old_folio` - The old folio account (PDA) (not mut, not signer).
+/// * `new_folio` - The new folio account (not mut, not signer).
+/// * `folio_token_mint` - The folio token mint account (not mut, not signer).
+/// * `old_fee_distribution` - The fee distribution account (PDA) in the old folio program (mut, not signer).
+/// * `new_fee_distribution` - The fee distribution account (PDA) in the new folio program (mut, not signer).
+/// * `migration_record` - The migration record account (PDA) (not mut, not signer).
+#[derive(Accounts)]
+pub struct MigrateFeeDistribution<'info> {
+    pub system_program: Program<'info, System>,
+
+    // Is permissionless, so fee recipients aren't blocked by folio owner.
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: Cranker account
+    #[account(mut)]
+    pub cranker: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PROGRAM_REGISTRAR_SEEDS],
+        bump = program_registrar.bump,
+        seeds::program = FOLIO_ADMIN_PROGRAM_ID,
+    )]
+    pub program_registrar: Box<Account<'info, ProgramRegistrar>>,
+
+    /// CHECK: Folio program used for new folio
+    #[account(executable)]
+    pub new_folio_program: UncheckedAccount<'info>,
+
+    #[account()]
+    pub old_folio: AccountLoader<'info, Folio>,
+
+    /// CHECK: The new folio
+    #[account()]
+    pub new_folio: UncheckedAccount<'info>,
+
+    // Validate mint is now owned by the new folio
+    #[account(
+        mint::authority = new_folio,
+        mint::freeze_authority = new_folio,
+    )]
+    pub folio_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub old_fee_distribution: AccountLoader<'info, FeeDistribution>,
+
+    /// CHECK: Seeds are checked and the account data is checked in cpi to new folio program
+    #[account(mut)]
+    pub new_fee_distribution: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [MIGRATION_RECORD_SEEDS, old_folio.key().as_ref()],
+        bump = migration_record.bump,
+    )]
+    pub migration_record: Box<Account<'info, MigrationRecord>>,
+}
+
+impl MigrateFeeDistribution<'_> {
+    /// Validate the instruction.
+    ///
+    /// # Checks
+    /// * Old folio has the correct status.
+    /// * Token mint is the same as the one on the old folio.
+    /// * Abort grace period of the migration has passed.
+    /// * Old fee distribution is valid PDA.
+    /// * Cranker account is the same as the one on the old fee distribution account.
+    /// * New folio program is in the registrar.
+    /// * New folio is owned by the new folio program.
+    /// * New folio program is not the same as the old folio program.
+    pub fn validate(
+        &self,
+        old_folio: &Folio,
+        old_fee_distribution: &FeeDistribution,
+    ) -> Result<()> {
+        // Validate old folio
+        old_folio.validate_folio(
+            &self.old_folio.key(),
+            None,
+            None,
+            Some(vec![FolioStatus::Migrating]),
+        )?;
+
+        check_condition!(
+            old_folio.folio_token_mint == self.folio_token_mint.key(),
+            InvalidFolioTokenMint
+        );
+
+        self.migration_record
+            .validate_abort_grace_period_passed(Clock::get()?.unix_timestamp as u64)?;
+
+        // Validate old fee distribution
+        check_condition!(
+            self.old_fee_distribution.key()
+                == Pubkey::find_program_address(
+                    &[
+                        FEE_DISTRIBUTION_SEEDS,
+                        self.old_folio.key().as_ref(),
+                        old_fee_distribution.index.to_le_bytes().as_slice()
+                    ],
+                    &crate::id()
+                )
+                .0,
+            InvalidFeeDistribution
+        );
+
+        check_condition!(
+            self.cranker.key() == old_fee_distribution.cranker,
+            InvalidCranker
+        );
+
+        /*
+        New Folio Validation
+         */
+        // Make sure the new folio program is in the registrar
+        check_condition!(
+            self.program_registrar
+                .is_in_registrar(self.new_folio_program.key()),
+            ProgramNotInRegistrar
+        );
+
+        // Make sure the new folio is owned by the new folio program
+        check_condition!(
+            *self.new_folio.owner == self.new_folio_program.key(),
+            NewFolioNotOwnedByNewFolioProgram
+        );
+
+        check_condition!(
+            self.new_folio_program.key() != FOLIO_PROGRAM_ID,
+            CantMigrateToSameProgram
+        );
+
+        // Make sure the discriminator of the new folio is correct
+        let data = self.new_folio.try_borrow_data()?;
+        check_condition!(
+            data.len() >= 8 && data[0..8] == Folio::DISCRIMINATOR[..],
+            InvalidNewFolio
+        );
+
+        Ok(())
+    }
+}
+
+/// Migrate Fee Distribution. Once the folio is migrating, the old folio can't mint anymore, so the fee distributions
+/// that haven't been fully cranked are moved to the new folio program, where the remaining fee recipients can be
+/// cranked. The rent of the old fee distribution account is reimbursed to its cranker.
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+pub fn handler(ctx: Context<MigrateFeeDistribution>) -> Result<()> {
+    let old_folio_token_mint: Pubkey;
+    let old_folio_bump: u8;
+    let fee_distribution_index: u64;
+
+    // Fee distributions created before `created_at` was added are upgraded first, so they can be loaded
+    FeeDistribution::upgrade_if_needed(
+        &ctx.accounts.old_fee_distribution.to_account_info(),
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    {
+        let old_folio = &ctx.accounts.old_folio.load()?;
+        let old_fee_distribution = &ctx.accounts.old_fee_distribution.load()?;
+
+        ctx.accounts.validate(old_folio, old_fee_distribution)?;
+
+        old_folio_token_mint = old_folio.folio_token_mint;
+        old_folio_bump = old_folio.bump;
+        fee_distribution_index = old_fee_distribution.index;
+    }
+
+    let folio_signer_seeds = &[
+        FOLIO_SEEDS,
+        old_folio_token_mint.as_ref(),
+        &[old_folio_bump],
+    ];
+
+    NewFolioProgram::create_fee_distribution_from_old_program(
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.old_folio.to_account_info(),
+        &ctx.accounts.new_folio.to_account_info(),
+        &ctx.accounts.old_fee_distribution.to_account_info(),
+        &ctx.accounts.new_fee_distribution.to_account_info(),
+        &ctx.accounts.program_registrar.to_account_info(),
+        &ctx.accounts.new_folio_program.to_account_info(),
+        fee_distribution_index,
+        &[&folio_signer_seeds[..]],
+    )?;
+
+    // The shares still to be minted were already carried over to the new folio when the migration started.
+    ctx.accounts
+        .old_fee_distribution
+        .close(ctx.accounts.cranker.to_account_info())?;
+
+    Ok(())
+}
@@ -1,23 +1,21 @@
 use crate::utils::NewFolioProgram;
 use crate::ID as FOLIO_PROGRAM_ID;
 use crate::{
-    state::{Folio, FolioBasket},
+    state::{Folio, FolioBasket, MigrationRecord},
     utils::FolioStatus,
 };
 use anchor_lang::{prelude::*, Discriminator};
 use anchor_spl::associated_token::get_associated_token_address_with_program_id;
-use anchor_spl::token_interface;
-use anchor_spl::token_interface::{Mint, TokenInterface, TransferChecked};
+use anchor_spl::token_interface::{Mint, TokenInterface};
 use folio_admin::{state::ProgramRegistrar, ID as FOLIO_ADMIN_PROGRAM_ID};
 use shared::errors::ErrorCode;
 use shared::utils::account_util::next_account;
+use shared::utils::TokenUtil;
 use shared::{
     check_condition,
-    constants::{FOLIO_BASKET_SEEDS, FOLIO_SEEDS, PROGRAM_REGISTRAR_SEEDS},
+    constants::{FOLIO_BASKET_SEEDS, FOLIO_SEEDS, MIGRATION_RECORD_SEEDS, PROGRAM_REGISTRAR_SEEDS},
 };
 
-const REMAINING_ACCOUNTS_DIVIDER: usize = 3;
-
 /// Migrate Folio Tokens
 /// Permissionless
 /// Token2022 mints and Token mints can't be mixed in the same instruction.
@@ -32,11 +30,13 @@ const REMAINING_ACCOUNTS_DIVIDER: usize = 3;
 /// * `new_folio_basket` - The new folio basket account (PDA) (mut, not signer).
 /// * `new_folio` - The new folio account (mut, not signer).
 /// * `folio_token_mint` - The folio token mint account (mut, not signer).
+/// * `migration_record` - The migration record account (PDA) (not mut, not signer).
 ///
 /// * `remaining_accounts` - The remaining accounts will be the token accounts of the folio that are being transferred from the old folio to the new one.
 ///         - Token Mint
 ///         - Sender Token Account (needs to be owned by old folio) (mut)
 ///         - Recipient Token Account (needs to be owned by new folio) (mut)
+///         - Transfer Hook Accounts (only if the mint has a transfer hook, see `TokenUtil::next_transfer_hook_accounts`)
 #[derive(Accounts)]
 pub struct MigrateFolioTokens<'info> {
     pub token_program: Interface<'info, TokenInterface>,
@@ -85,12 +85,19 @@ pub struct MigrateFolioTokens<'info> {
         mint::freeze_authority = new_folio,
     )]
     pub folio_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [MIGRATION_RECORD_SEEDS, old_folio.key().as_ref()],
+        bump = migration_record.bump,
+    )]
+    pub migration_record: Box<Account<'info, MigrationRecord>>,
     /*
     The remaining accounts will represent the folio tokens (in the folio basket)
     Remaining accounts will have as many as possible of the following (always in the same order):
         - Token Mint (read)
         - Sender Token Account (needs to be owned by old folio) (mut)
         - Recipient Token Account (needs to be owned by new folio) (this is expected to be the ATA and already exist, to save on compute) (mut)
+        - Transfer Hook Accounts (only if the mint has a transfer hook, see `TokenUtil::next_transfer_hook_accounts`)
      */
 }
 
@@ -100,6 +107,7 @@ impl MigrateFolioTokens<'_> {
     /// # Checks
     /// * Old folio has the correct status.
     /// * Token mint is the same as the one on the old folio.
+    /// * Abort grace period of the migration has passed.
     /// * New folio program is in the registrar.
     /// * New folio is owned by the new folio program.
     /// * New folio program is not the same as the old folio program.
@@ -117,6 +125,9 @@ impl MigrateFolioTokens<'_> {
             InvalidFolioTokenMint
         );
 
+        self.migration_record
+            .validate_abort_grace_period_passed(Clock::get()?.unix_timestamp as u64)?;
+
         /*
         New Folio Validation
          */
@@ -181,14 +192,9 @@ pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, MigrateFolioTokens<'inf
     Transfer the folio tokens (from the folio basket), won't transfer the pending amounts, as those users
     will be able to take them back, on the old folio program, rather than the new one for simplicity and security.
     */
-    check_condition!(
-        ctx.remaining_accounts.len() % REMAINING_ACCOUNTS_DIVIDER == 0,
-        InvalidNumberOfRemainingAccounts
-    );
-
     let mut remaining_accounts_iter = ctx.remaining_accounts.iter();
 
-    for _ in 0..ctx.remaining_accounts.len() / REMAINING_ACCOUNTS_DIVIDER {
+    while !remaining_accounts_iter.as_slice().is_empty() {
         let token_mint = next_account(
             &mut remaining_accounts_iter,
             false,
@@ -222,6 +228,9 @@ pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, MigrateFolioTokens<'inf
             InvalidRecipientTokenAccount
         );
 
+        let transfer_hook_accounts =
+            TokenUtil::next_transfer_hook_accounts(&mut remaining_accounts_iter, token_mint)?;
+
         let raw_migrate_balance: u64;
 
         {
@@ -236,19 +245,16 @@ pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, MigrateFolioTokens<'inf
             Mint::try_deserialize(&mut &data[..])?.decimals
         };
 
-        let cpi_accounts = TransferChecked {
-            from: sender_token_account.to_account_info(),
-            to: recipient_token_account.to_account_info(),
-            authority: ctx.accounts.old_folio.to_account_info(),
-            mint: token_mint.to_account_info(),
-        };
-
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-
-        token_interface::transfer_checked(
-            CpiContext::new_with_signer(cpi_program, cpi_accounts, folio_signer),
+        TokenUtil::transfer_checked(
+            &ctx.accounts.token_program.to_account_info(),
+            sender_token_account,
+            token_mint,
+            recipient_token_account,
+            &ctx.accounts.old_folio.to_account_info(),
+            &transfer_hook_accounts,
             raw_migrate_balance,
             mint_decimals,
+            folio_signer,
         )?;
 
         NewFolioProgram::update_folio_basket_in_new_folio_program(
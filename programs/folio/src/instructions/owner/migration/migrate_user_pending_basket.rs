@@ -0,0 +1,334 @@
+use crate::utils::NewFolioProgram;
+use crate::ID as FOLIO_PROGRAM_ID;
+use crate::{
+    state::{Folio, FolioPendingBasket, MigrationRecord, UserPendingBasket},
+    utils::FolioStatus,
+};
+use anchor_lang::{prelude::*, Discriminator};
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+use folio_admin::{state::ProgramRegistrar, ID as FOLIO_ADMIN_PROGRAM_ID};
+use shared::errors::ErrorCode;
+use shared::utils::account_util::next_account;
+use shared::utils::TokenUtil;
+use shared::{
+    check_condition,
+    constants::{
+        FOLIO_PENDING_BASKET_SEEDS, FOLIO_SEEDS, MIGRATION_RECORD_SEEDS, PROGRAM_REGISTRAR_SEEDS,
+        USER_PENDING_BASKET_SEEDS,
+    },
+};
+
+/// Migrate User Pending Basket
+/// Permissionless
+/// Token2022 mints and Token mints can't be mixed in the same instruction.
+///
+/// # Arguments
+/// * `system_program` - The system program.
+/// * `token_program` - The token program.
+/// * `user` - The user account (mut, signer).
+/// * `program_registrar` - The program registrar account (not mut, not signer).
+/// * `new_folio_program` - The new folio program (executable).
+/// * `old_folio` - The old folio account (PDA) (not mut, not signer).
+/// * `new_folio` - The new folio account (not mut, not signer).
+/// * `folio_token_mint` - The folio token mint account (not mut, not signer).
+/// * `pending_basket_owner` - The owner of the user pending basket (not mut, not signer).
+/// * `old_user_pending_basket` - The user pending basket account (PDA) in the old folio program (mut, not signer).
+/// * `old_folio_pending_basket` - The folio pending basket account (PDA) in the old folio program (init if needed, not signer).
+/// * `new_user_pending_basket` - The user pending basket account (PDA) in the new folio program (mut, not signer).
+/// * `new_folio_pending_basket` - The folio pending basket account (PDA) in the new folio program (mut, not signer).
+/// * `migration_record` - The migration record account (PDA) (not mut, not signer).
+///
+/// * `remaining_accounts` - The remaining accounts will be the token accounts of the folio that are being transferred from the old folio to the new one.
+///         - Token Mint
+///         - Sender Token Account (needs to be owned by old folio) (mut)
+///         - Recipient Token Account (needs to be owned by new folio) (mut)
+///         - Transfer Hook Accounts (only if the mint has a transfer hook, see `TokenUtil::next_transfer_hook_accounts`)
+#[derive(Accounts)]
+pub struct MigrateUserPendingBasket<'info> {
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+
+    // Is permissionless, so users aren't blocked by the owner of the pending basket.
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [PROGRAM_REGISTRAR_SEEDS],
+        bump = program_registrar.bump,
+        seeds::program = FOLIO_ADMIN_PROGRAM_ID,
+    )]
+    pub program_registrar: Box<Account<'info, ProgramRegistrar>>,
+
+    /// CHECK: Folio program used for new folio
+    #[account(executable)]
+    pub new_folio_program: UncheckedAccount<'info>,
+
+    #[account()]
+    pub old_folio: AccountLoader<'info, Folio>,
+
+    /// CHECK: The new folio
+    #[account()]
+    pub new_folio: UncheckedAccount<'info>,
+
+    // Validate mint is now owned by the new folio
+    #[account(
+        mint::authority = new_folio,
+        mint::freeze_authority = new_folio,
+    )]
+    pub folio_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Owner of the user pending basket, only used for the seeds
+    #[account()]
+    pub pending_basket_owner: UncheckedAccount<'info>,
+
+    #[account(mut,
+        seeds = [USER_PENDING_BASKET_SEEDS, old_folio.key().as_ref(), pending_basket_owner.key().as_ref()],
+        bump
+    )]
+    pub old_user_pending_basket: AccountLoader<'info, UserPendingBasket>,
+
+    #[account(init_if_needed,
+        payer = user,
+        space = FolioPendingBasket::SIZE,
+        seeds = [FOLIO_PENDING_BASKET_SEEDS, old_folio.key().as_ref()],
+        bump
+    )]
+    pub old_folio_pending_basket: AccountLoader<'info, FolioPendingBasket>,
+
+    /// CHECK: Seeds are checked and the account data is checked in cpi to new folio program
+    #[account(
+        mut,
+        seeds = [USER_PENDING_BASKET_SEEDS, new_folio.key().as_ref(), pending_basket_owner.key().as_ref()],
+        bump,
+        seeds::program = new_folio_program.key(),
+    )]
+    pub new_user_pending_basket: UncheckedAccount<'info>,
+
+    /// CHECK: Seeds are checked and the account data is checked in cpi to new folio program
+    #[account(
+        mut,
+        seeds = [FOLIO_PENDING_BASKET_SEEDS, new_folio.key().as_ref()],
+        bump,
+        seeds::program = new_folio_program.key(),
+    )]
+    pub new_folio_pending_basket: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [MIGRATION_RECORD_SEEDS, old_folio.key().as_ref()],
+        bump = migration_record.bump,
+    )]
+    pub migration_record: Box<Account<'info, MigrationRecord>>,
+    /*
+    The remaining accounts will represent the tokens in the user pending basket
+    Remaining accounts will have as many as possible of the following (always in the same order):
+        - Token Mint (read)
+        - Sender Token Account (needs to be owned by old folio) (mut)
+        - Recipient Token Account (needs to be owned by new folio) (this is expected to be the ATA and already exist, to save on compute) (mut)
+        - Transfer Hook Accounts (only if the mint has a transfer hook, see `TokenUtil::next_transfer_hook_accounts`)
+     */
+}
+
+impl MigrateUserPendingBasket<'_> {
+    /// Validate the instruction.
+    ///
+    /// # Checks
+    /// * Old folio has the correct status.
+    /// * Token mint is the same as the one on the old folio.
+    /// * Abort grace period of the migration has passed.
+    /// * New folio program is in the registrar.
+    /// * New folio is owned by the new folio program.
+    /// * New folio program is not the same as the old folio program.
+    pub fn validate(&self, old_folio: &Folio) -> Result<()> {
+        // Validate old folio
+        old_folio.validate_folio(
+            &self.old_folio.key(),
+            None,
+            None,
+            Some(vec![FolioStatus::Migrating]),
+        )?;
+
+        check_condition!(
+            old_folio.folio_token_mint == self.folio_token_mint.key(),
+            InvalidFolioTokenMint
+        );
+
+        self.migration_record
+            .validate_abort_grace_period_passed(Clock::get()?.unix_timestamp as u64)?;
+
+        /*
+        New Folio Validation
+         */
+        // Make sure the new folio program is in the registrar
+        check_condition!(
+            self.program_registrar
+                .is_in_registrar(self.new_folio_program.key()),
+            ProgramNotInRegistrar
+        );
+
+        // Make sure the new folio is owned by the new folio program
+        check_condition!(
+            *self.new_folio.owner == self.new_folio_program.key(),
+            NewFolioNotOwnedByNewFolioProgram
+        );
+
+        check_condition!(
+            self.new_folio_program.key() != FOLIO_PROGRAM_ID,
+            CantMigrateToSameProgram
+        );
+
+        // Make sure the discriminator of the new folio is correct
+        let data = self.new_folio.try_borrow_data()?;
+        check_condition!(
+            data.len() >= 8 && data[0..8] == Folio::DISCRIMINATOR[..],
+            InvalidNewFolio
+        );
+
+        Ok(())
+    }
+}
+
+/// Migrate User Pending Basket. Moves the pending amounts (for minting and for redeeming) of a user, for the given
+/// token mints, from the old folio program to the new one, so that users don't lose their pending balances once
+/// the folio is migrated. The tokens are transferred from the old folio to the new folio.
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, MigrateUserPendingBasket<'info>>,
+) -> Result<()> {
+    let old_folio_key = ctx.accounts.old_folio.key();
+    let new_folio_key = ctx.accounts.new_folio.key();
+    let token_program_id = ctx.accounts.token_program.key();
+
+    let old_folio_token_mint: Pubkey;
+    let old_folio_bump: u8;
+
+    {
+        let old_folio = &ctx.accounts.old_folio.load()?;
+
+        old_folio_token_mint = old_folio.folio_token_mint;
+        old_folio_bump = old_folio.bump;
+
+        ctx.accounts.validate(old_folio)?;
+    }
+
+    let folio_signer_seeds = &[
+        FOLIO_SEEDS,
+        old_folio_token_mint.as_ref(),
+        &[old_folio_bump],
+    ];
+
+    let tracked_token_amounts_before = UserPendingBasket::get_tracked_token_amounts_of_account(
+        &ctx.accounts.old_user_pending_basket,
+    )?;
+
+    let mut remaining_accounts_iter = ctx.remaining_accounts.iter();
+
+    while !remaining_accounts_iter.as_slice().is_empty() {
+        let token_mint = next_account(
+            &mut remaining_accounts_iter,
+            false,
+            false,
+            &token_program_id,
+        )?;
+        let sender_token_account =
+            next_account(&mut remaining_accounts_iter, false, true, &token_program_id)?;
+        let recipient_token_account =
+            next_account(&mut remaining_accounts_iter, false, true, &token_program_id)?;
+
+        // Validate the sender token account is the ATA of the old folio
+        check_condition!(
+            sender_token_account.key()
+                == get_associated_token_address_with_program_id(
+                    &old_folio_key,
+                    token_mint.key,
+                    &token_program_id,
+                ),
+            InvalidSenderTokenAccount
+        );
+
+        // Validate the recipient token account is the ATA of the new folio
+        check_condition!(
+            recipient_token_account.key()
+                == get_associated_token_address_with_program_id(
+                    &new_folio_key,
+                    token_mint.key,
+                    &token_program_id,
+                ),
+            InvalidRecipientTokenAccount
+        );
+
+        let transfer_hook_accounts =
+            TokenUtil::next_transfer_hook_accounts(&mut remaining_accounts_iter, token_mint)?;
+
+        let raw_migrate_balance: u64 = {
+            let old_user_pending_basket = &ctx.accounts.old_user_pending_basket.load()?;
+
+            let token_amount = old_user_pending_basket
+                .basket
+                .token_amounts
+                .iter()
+                .find(|ta| ta.mint == token_mint.key())
+                .ok_or(ErrorCode::InvalidRemovedTokenMints)?;
+
+            token_amount
+                .amount_for_minting
+                .checked_add(token_amount.amount_for_redeeming)
+                .ok_or(ErrorCode::MathOverflow)?
+        };
+
+        let mint_decimals = {
+            let data = token_mint.try_borrow_data()?;
+            Mint::try_deserialize(&mut &data[..])?.decimals
+        };
+
+        TokenUtil::transfer_checked(
+            &ctx.accounts.token_program.to_account_info(),
+            sender_token_account,
+            token_mint,
+            recipient_token_account,
+            &ctx.accounts.old_folio.to_account_info(),
+            &transfer_hook_accounts,
+            raw_migrate_balance,
+            mint_decimals,
+            &[&folio_signer_seeds[..]],
+        )?;
+
+        // The new folio program reads the pending amounts from the old user pending basket, so they are only
+        // removed after the cpi.
+        NewFolioProgram::update_user_pending_basket_in_new_folio_program(
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.user.to_account_info(),
+            &ctx.accounts.old_folio.to_account_info(),
+            &ctx.accounts.new_folio.to_account_info(),
+            &ctx.accounts.pending_basket_owner.to_account_info(),
+            &ctx.accounts.old_user_pending_basket.to_account_info(),
+            &ctx.accounts.new_user_pending_basket.to_account_info(),
+            &ctx.accounts.new_folio_pending_basket.to_account_info(),
+            &token_mint.to_account_info(),
+            &recipient_token_account.to_account_info(),
+            &ctx.accounts.program_registrar.to_account_info(),
+            &ctx.accounts.new_folio_program.to_account_info(),
+            &[&folio_signer_seeds[..]],
+        )?;
+
+        {
+            let old_user_pending_basket = &mut ctx.accounts.old_user_pending_basket.load_mut()?;
+
+            old_user_pending_basket.take_token_amount(token_mint.key)?;
+        }
+    }
+
+    let old_user_pending_basket = &mut ctx.accounts.old_user_pending_basket.load_mut()?;
+
+    FolioPendingBasket::process_user_pending_basket_change(
+        &ctx.accounts.old_folio_pending_basket,
+        ctx.bumps.old_folio_pending_basket,
+        &old_folio_key,
+        &tracked_token_amounts_before,
+        old_user_pending_basket,
+    )?;
+
+    Ok(())
+}
@@ -1,9 +1,21 @@
+pub mod abort_folio_migration;
+pub mod abort_folio_migration_from_old_program;
+pub mod create_fee_distribution_from_old_program;
 pub mod create_folio_from_old_program;
+pub mod migrate_fee_distribution;
 pub mod migrate_folio_tokens;
+pub mod migrate_user_pending_basket;
 pub mod start_folio_migration;
 pub mod update_basket_in_new_folio_program;
+pub mod update_user_pending_basket_in_new_folio_program;
 
+pub use abort_folio_migration::*;
+pub use abort_folio_migration_from_old_program::*;
+pub use create_fee_distribution_from_old_program::*;
 pub use create_folio_from_old_program::*;
+pub use migrate_fee_distribution::*;
 pub use migrate_folio_tokens::*;
+pub use migrate_user_pending_basket::*;
 pub use start_folio_migration::*;
 pub use update_basket_in_new_folio_program::*;
+pub use update_user_pending_basket_in_new_folio_program::*;
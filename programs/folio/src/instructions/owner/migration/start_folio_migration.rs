@@ -1,7 +1,7 @@
 use crate::utils::{Metaplex, NewFolioProgram, UpdateAuthority};
 use crate::ID as FOLIO_PROGRAM_ID;
 use crate::{
-    state::{Actor, Folio},
+    state::{Actor, Folio, FolioLegacyBasket, MigrationRecord},
     utils::{FolioStatus, Role},
 };
 use anchor_lang::prelude::*;
@@ -20,7 +20,10 @@ use shared::constants::METADATA_SEEDS;
 use shared::errors::ErrorCode;
 use shared::{
     check_condition,
-    constants::{ACTOR_SEEDS, FOLIO_SEEDS, PROGRAM_REGISTRAR_SEEDS},
+    constants::{
+        ACTOR_SEEDS, FEE_RECIPIENTS_SEEDS, FOLIO_LEGACY_BASKET_SEEDS, FOLIO_MINT_LIMITS_SEEDS,
+        FOLIO_SEEDS, MIGRATION_RECORD_SEEDS, PROGRAM_REGISTRAR_SEEDS,
+    },
 };
 
 /// Start Folio Migration
@@ -35,6 +38,14 @@ use shared::{
 /// * `old_folio` - The old folio account (PDA) (mut, not signer).
 /// * `new_folio` - The new folio account (mut, not signer).
 /// * `folio_token_mint` - The folio token mint account (mut, not signer).
+/// * `new_folio_basket` - The folio basket account in the new folio program (mut, not signer).
+/// * `new_actor` - The actor account in the new folio program (mut, not signer).
+/// * `old_fee_recipients` - The fee recipients account (PDA) of the old folio (not mut, not signer).
+/// * `new_fee_recipients` - The fee recipients account in the new folio program (mut, not signer).
+/// * `old_folio_mint_limits` - The folio mint limits account (PDA) of the old folio (not mut, not signer).
+/// * `new_folio_mint_limits` - The folio mint limits account in the new folio program (mut, not signer).
+/// * `old_folio_legacy_basket` - The folio legacy basket account (PDA) of the old folio (not mut, not signer).
+/// * `migration_record` - The migration record account (PDA) (init, not signer).
 #[derive(Accounts)]
 pub struct StartFolioMigration<'info> {
     pub system_program: Program<'info, System>,
@@ -84,6 +95,44 @@ pub struct StartFolioMigration<'info> {
     /// CHECK: it is checked in the cpi to the new folio program
     #[account(mut)]
     pub new_actor: UncheckedAccount<'info>,
+
+    /// CHECK: Might not be initialized if the fee recipients were never set, read in the cpi to the new folio program
+    #[account(
+        seeds = [FEE_RECIPIENTS_SEEDS, old_folio.key().as_ref()],
+        bump,
+    )]
+    pub old_fee_recipients: UncheckedAccount<'info>,
+
+    /// CHECK: it is checked in the cpi to the new folio program
+    #[account(mut)]
+    pub new_fee_recipients: UncheckedAccount<'info>,
+
+    /// CHECK: Might not be initialized if the mint limits were never set, read in the cpi to the new folio program
+    #[account(
+        seeds = [FOLIO_MINT_LIMITS_SEEDS, old_folio.key().as_ref()],
+        bump,
+    )]
+    pub old_folio_mint_limits: UncheckedAccount<'info>,
+
+    /// CHECK: it is checked in the cpi to the new folio program
+    #[account(mut)]
+    pub new_folio_mint_limits: UncheckedAccount<'info>,
+
+    /// CHECK: Could be empty or could be set, if set it can't hold any legacy token
+    #[account(
+        seeds = [FOLIO_LEGACY_BASKET_SEEDS, old_folio.key().as_ref()],
+        bump,
+    )]
+    pub old_folio_legacy_basket: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = folio_owner,
+        space = MigrationRecord::SIZE,
+        seeds = [MIGRATION_RECORD_SEEDS, old_folio.key().as_ref()],
+        bump
+    )]
+    pub migration_record: Box<Account<'info, MigrationRecord>>,
     // Any remaining accounts that are required in the new folio program
     // When calling `create_folio_from_old_program`
 
@@ -117,8 +166,8 @@ impl StartFolioMigration<'_> {
     /// * New folio program is in the registrar.
     /// * New folio is owned by the new folio program.
     /// * New folio program is not the same as the old folio program.
-    /// * If `max_allowed_pending_fees` is set to true, the old folio can have pending fees.
-    pub fn validate(&self, old_folio: &Folio, max_allowed_pending_fees: u128) -> Result<()> {
+    /// * Legacy basket of the old folio is empty.
+    pub fn validate(&self, old_folio: &Folio) -> Result<()> {
         // Validate old folio, make sure the owner is the one calling the instruction
         old_folio.validate_folio(
             &self.old_folio.key(),
@@ -144,29 +193,6 @@ impl StartFolioMigration<'_> {
             MigrationFailedFolioNotPoked
         );
 
-        // Folio owners can decide, up-to what pending amount they want to loss.
-        // As these amounts become unmintable and non-distributable in the new program.
-        check_condition!(
-            old_folio
-                .dao_pending_fee_shares
-                .lt(&max_allowed_pending_fees),
-            MigrationFailedDaoPendingFeeSharesTooHigh
-        );
-
-        check_condition!(
-            old_folio
-                .fee_recipients_pending_fee_shares
-                .lt(&max_allowed_pending_fees),
-            MigrationFailedFeeRecipientsPendingFeeSharesTooHigh
-        );
-
-        check_condition!(
-            old_folio
-                .fee_recipients_pending_fee_shares_to_be_minted
-                .lt(&max_allowed_pending_fees),
-            MigrationFailedFeeRecipientsPendingFeeShareToBeMintedTooHigh
-        );
-
         /*
         New Folio Validation
          */
@@ -187,27 +213,40 @@ impl StartFolioMigration<'_> {
             CantMigrateToSameProgram
         );
 
+        // Legacy tokens aren't carried over to the new folio, so they need to be redeemed first
+        check_condition!(
+            FolioLegacyBasket::get_folio_legacy_basket(&self.old_folio_legacy_basket)?
+                .is_none_or(|folio_legacy_basket| folio_legacy_basket.is_empty()),
+            LegacyBasketNotEmpty
+        );
+
         Ok(())
     }
 }
 
 /// Start Folio Migration. This will be called to initiate the migration process.
 /// The start of the migration process will transfer the mint and freeze authority to the new folio.
+/// The pending fee shares and the fee recipients are carried over to the new folio, so no fees are lost, as well as
+/// the mint limits.
+/// The migration can be aborted by the folio owner during a grace period, after which the assets of the folio can
+/// be migrated.
+/// The tokens removed from the basket that are still owed to the holders (legacy basket) aren't carried over, so the
+/// migration can only start once they have all been redeemed.
 ///
 /// # Arguments
 /// * `ctx` - The context of the instruction.
-/// * `max_allowed_pending_fees` - The max allowed pending fees, the folio owner is willing to loss for the migration. Scaled by D18.
 pub fn handler<'info>(
     ctx: Context<'_, '_, 'info, 'info, StartFolioMigration<'info>>,
-    max_allowed_pending_fees: u128,
 ) -> Result<()> {
     let old_folio_bump: u8;
+    let old_folio_status: u8;
     {
         let old_folio = &mut ctx.accounts.old_folio.load_mut()?;
 
         old_folio_bump = old_folio.bump;
+        old_folio_status = old_folio.status;
 
-        ctx.accounts.validate(old_folio, max_allowed_pending_fees)?;
+        ctx.accounts.validate(old_folio)?;
     }
 
     // Transfer the mint and freeze authority to the new folio
@@ -293,10 +332,23 @@ pub fn handler<'info>(
         &ctx.accounts.new_actor,
         &ctx.accounts.new_folio_basket,
         &ctx.accounts.folio_token_mint.to_account_info(),
+        &ctx.accounts.old_fee_recipients,
+        &ctx.accounts.new_fee_recipients,
+        &ctx.accounts.old_folio_mint_limits,
+        &ctx.accounts.new_folio_mint_limits,
         ctx.remaining_accounts,
         folio_signer,
     )?;
 
+    // The old folio state is left as is, it can't be used while migrating, and is restored if the migration is
+    // aborted.
+    let migration_record = &mut ctx.accounts.migration_record;
+    migration_record.bump = ctx.bumps.migration_record;
+    migration_record.folio = ctx.accounts.old_folio.key();
+    migration_record.new_folio = ctx.accounts.new_folio.key();
+    migration_record.previous_status = old_folio_status;
+    migration_record.started_at = Clock::get()?.unix_timestamp as u64;
+
     // No need to transfer tokens of the folio token mint, as the folio is minting / burning, never holding them.
 
     Ok(())
@@ -1,6 +1,6 @@
 use crate::{
     state::{Folio, FolioBasket},
-    utils::{FolioStatus, FolioTokenAmount},
+    utils::FolioStatus,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount};
@@ -9,6 +9,7 @@ use shared::{
     check_condition,
     constants::{FOLIO_BASKET_SEEDS, PROGRAM_REGISTRAR_SEEDS},
     errors::ErrorCode,
+    utils::TokenUtil,
 };
 
 /// Mint from this new folio program, called by the old folio program.
@@ -119,38 +120,33 @@ pub fn handler<'info>(
 
     let mint_pk = ctx.accounts.token_mint.key();
 
-    let token_balance_in_old_folio_basket: u64;
-    let token_left_in_old_folio_basket_after_removal_of_mint_pk: usize;
-
-    {
+    let (raw_received_amount, has_tokens_left_in_old_folio_basket) = {
         let old_folio_basket_data = &ctx.accounts.old_folio_basket.data.borrow();
         let old_folio_basket: &FolioBasket = bytemuck::from_bytes(&old_folio_basket_data[8..]);
 
-        token_balance_in_old_folio_basket =
-            old_folio_basket.get_token_amount_in_folio_basket(&mint_pk)?;
-
-        token_left_in_old_folio_basket_after_removal_of_mint_pk = old_folio_basket
-            .basket
-            .token_amounts
-            .iter()
-            .filter(|token_amount| {
-                // We already know that the removal from folio-basket happens only after the cpi to new folio program, is made.
-                token_amount.mint != Pubkey::default() && token_amount.mint != mint_pk
-            })
-            .count();
-    }
+        // The old folio program sent its whole balance, only the amount received (net of the transfer fee, if any)
+        // is credited to the new basket.
+        let raw_received_amount = TokenUtil::get_amount_after_transfer_fee(
+            &ctx.accounts.token_mint.to_account_info(),
+            old_folio_basket.get_token_amount_in_folio_basket(&mint_pk)?,
+        )?;
+
+        // We already know that the removal from folio-basket happens only after the cpi to new folio program, is made.
+        let has_tokens_left = new_folio_basket.migrate_token_from_old_folio_basket(
+            old_folio_basket,
+            &mint_pk,
+            raw_received_amount,
+        )?;
 
-    new_folio_basket.add_tokens_to_basket(&vec![FolioTokenAmount {
-        mint: mint_pk,
-        amount: token_balance_in_old_folio_basket,
-    }])?;
+        (raw_received_amount, has_tokens_left)
+    };
 
     check_condition!(
-        ctx.accounts.folio_token_account.amount >= token_balance_in_old_folio_basket,
+        ctx.accounts.folio_token_account.amount >= raw_received_amount,
         InvalidTokenBalance
     );
 
-    if token_left_in_old_folio_basket_after_removal_of_mint_pk > 0 {
+    if has_tokens_left_in_old_folio_basket {
         // We set the status of new Folio to migrating to prevent any minting or redeeming
         new_folio.status = FolioStatus::Migrating as u8;
     } else {
@@ -0,0 +1,198 @@
+use crate::{
+    state::{Folio, FolioPendingBasket, UserPendingBasket},
+    utils::FolioStatus,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use folio_admin::{state::ProgramRegistrar, ID as FOLIO_ADMIN_PROGRAM_ID};
+use shared::{
+    check_condition,
+    constants::{FOLIO_PENDING_BASKET_SEEDS, PROGRAM_REGISTRAR_SEEDS, USER_PENDING_BASKET_SEEDS},
+    errors::ErrorCode,
+    utils::TokenUtil,
+};
+
+/// Update the pending basket of a user in this new folio program, called by the old folio program.
+///
+/// THIS IS ONLY TO SHOW AN EXAMPLE OF WHAT SHOULD BE IMPLEMENTED IN FUTURE VERSIONS
+/// OF THE FOLIO PROGRAM. IT WON'T BE INCLUDED IN THE MAINNET BUILD FOR THIS VERSION
+/// OF THE FOLIO PROGRAM.
+///
+/// # Arguments
+/// * `system_program` - The system program to use
+/// * `payer` - The payer of the rent of the new accounts
+/// * `old_folio` - The old folio to use
+/// * `new_folio` - The new folio to use
+/// * `pending_basket_owner` - The owner of the user pending basket
+/// * `old_user_pending_basket` - The user pending basket in the old folio program
+/// * `new_user_pending_basket` - The user pending basket in the new folio program
+/// * `new_folio_pending_basket` - The folio pending basket in the new folio program
+/// * `token_mint` - The token mint to use
+/// * `folio_token_account` - The folio token account to use
+#[derive(Accounts)]
+pub struct UpdateUserPendingBasketInNewFolioProgram<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Validate is from the old folio program using the seeds
+    /// For now it validates with hardcoded program ids, but this is just because it's for testing only
+    /// in this version of the folio program
+    #[account()]
+    pub old_folio: Signer<'info>,
+
+    #[account()]
+    pub new_folio: AccountLoader<'info, Folio>,
+
+    /// CHECK: Owner of the user pending basket, only used for the seeds
+    #[account()]
+    pub pending_basket_owner: UncheckedAccount<'info>,
+
+    /// CHECK: Seeds are checked and the account data is read in the handler
+    #[account(
+        seeds = [USER_PENDING_BASKET_SEEDS, old_folio.key().as_ref(), pending_basket_owner.key().as_ref()],
+        bump,
+        seeds::program = old_folio.owner,
+        owner = *old_folio.owner,
+    )]
+    pub old_user_pending_basket: UncheckedAccount<'info>,
+
+    #[account(init_if_needed,
+        payer = payer,
+        space = UserPendingBasket::SIZE,
+        seeds = [USER_PENDING_BASKET_SEEDS, new_folio.key().as_ref(), pending_basket_owner.key().as_ref()],
+        bump
+    )]
+    pub new_user_pending_basket: AccountLoader<'info, UserPendingBasket>,
+
+    #[account(init_if_needed,
+        payer = payer,
+        space = FolioPendingBasket::SIZE,
+        seeds = [FOLIO_PENDING_BASKET_SEEDS, new_folio.key().as_ref()],
+        bump
+    )]
+    pub new_folio_pending_basket: AccountLoader<'info, FolioPendingBasket>,
+
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Expected to be the ATA of the new folio with the token mint that is being migrated.
+    #[account(
+        associated_token::authority = new_folio,
+        associated_token::mint = token_mint,
+    )]
+    pub folio_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [PROGRAM_REGISTRAR_SEEDS],
+        bump = program_registrar.bump,
+        seeds::program = FOLIO_ADMIN_PROGRAM_ID,
+    )]
+    pub program_registrar: Box<Account<'info, ProgramRegistrar>>,
+}
+
+impl UpdateUserPendingBasketInNewFolioProgram<'_> {
+    /// Validate the instruction.
+    pub fn validate(&self, old_folio: &Folio, new_folio: &Folio) -> Result<()> {
+        check_condition!(
+            old_folio.status == FolioStatus::Migrating as u8,
+            InvalidFolioStatus
+        );
+        check_condition!(
+            new_folio.folio_token_mint == old_folio.folio_token_mint,
+            InvalidFolioTokenMint
+        );
+
+        check_condition!(
+            self.program_registrar
+                .is_in_registrar(*self.old_folio.owner),
+            ProgramNotInRegistrar
+        );
+
+        Ok(())
+    }
+}
+
+/// This is used to update the pending basket of a user in the new folio program.
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+#[allow(unused_variables)]
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UpdateUserPendingBasketInNewFolioProgram<'info>>,
+) -> Result<()> {
+    // If by mistake it's included in the program, if we don't see dev flag, we return ok
+    #[cfg(not(feature = "test"))]
+    return Ok(());
+
+    #[allow(unreachable_code)]
+    let folio_data = &ctx.accounts.old_folio.data.borrow();
+    let old_folio: &Folio = bytemuck::from_bytes(&folio_data[8..]);
+
+    {
+        let new_folio = &ctx.accounts.new_folio.load()?;
+
+        ctx.accounts.validate(old_folio, new_folio)?;
+    }
+
+    let mint_pk = ctx.accounts.token_mint.key();
+    let new_folio_key = ctx.accounts.new_folio.key();
+
+    let migrated_token_amount = {
+        let old_user_pending_basket_data = &ctx.accounts.old_user_pending_basket.data.borrow();
+        let old_user_pending_basket: &UserPendingBasket =
+            bytemuck::from_bytes(&old_user_pending_basket_data[8..]);
+
+        // We already know that the removal from the old user pending basket happens only after the cpi to new folio program, is made.
+        *old_user_pending_basket
+            .basket
+            .token_amounts
+            .iter()
+            .find(|ta| ta.mint == mint_pk)
+            .ok_or(ErrorCode::InvalidRemovedTokenMints)?
+    };
+
+    let tracked_token_amounts_before = UserPendingBasket::get_tracked_token_amounts_of_account(
+        &ctx.accounts.new_user_pending_basket,
+    )?;
+
+    UserPendingBasket::process_init_if_needed(
+        &mut ctx.accounts.new_user_pending_basket,
+        ctx.bumps.new_user_pending_basket,
+        &ctx.accounts.pending_basket_owner.key(),
+        &new_folio_key,
+        &vec![],
+        true,
+    )?;
+
+    let new_user_pending_basket = &mut ctx.accounts.new_user_pending_basket.load_mut()?;
+
+    let raw_sent_amount = migrated_token_amount
+        .amount_for_minting
+        .checked_add(migrated_token_amount.amount_for_redeeming)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    // Only the amount received (net of the transfer fee, if any) is credited to the new pending basket.
+    let raw_received_amount = TokenUtil::get_amount_after_transfer_fee(
+        &ctx.accounts.token_mint.to_account_info(),
+        raw_sent_amount,
+    )?;
+
+    new_user_pending_basket
+        .add_migrated_token_amount(&migrated_token_amount, raw_received_amount)?;
+
+    FolioPendingBasket::process_user_pending_basket_change(
+        &ctx.accounts.new_folio_pending_basket,
+        ctx.bumps.new_folio_pending_basket,
+        &new_folio_key,
+        &tracked_token_amounts_before,
+        new_user_pending_basket,
+    )?;
+
+    check_condition!(
+        ctx.accounts.folio_token_account.amount >= raw_received_amount,
+        InvalidTokenBalance
+    );
+
+    Ok(())
+}
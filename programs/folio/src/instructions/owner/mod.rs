@@ -1,19 +1,47 @@
+pub mod accept_owner;
 pub mod add_to_basket;
+pub mod add_to_mint_allowlist;
+pub mod add_zero_balance_to_basket;
+pub mod cancel_owner_proposal;
+pub mod execute_folio_action;
 pub mod init_folio;
 pub mod init_folio_2022;
 pub mod init_or_update_actor;
+pub mod init_or_update_fee_discount;
 pub mod kill_folio;
 pub mod migration;
+pub mod pause_folio;
+pub mod propose_owner;
 pub mod remove_actor;
+pub mod remove_fee_discount;
 pub mod remove_from_basket;
+pub mod remove_from_mint_allowlist;
+pub mod sync_basket_balance;
+pub mod unkill_folio;
+pub mod unpause_folio;
 pub mod update_folio;
+pub mod update_folio_branding;
 
+pub use accept_owner::*;
 pub use add_to_basket::*;
+pub use add_to_mint_allowlist::*;
+pub use add_zero_balance_to_basket::*;
+pub use cancel_owner_proposal::*;
+pub use execute_folio_action::*;
 pub use init_folio::*;
 pub use init_folio_2022::*;
 pub use init_or_update_actor::*;
+pub use init_or_update_fee_discount::*;
 pub use kill_folio::*;
 pub use migration::*;
+pub use pause_folio::*;
+pub use propose_owner::*;
 pub use remove_actor::*;
+pub use remove_fee_discount::*;
 pub use remove_from_basket::*;
+pub use remove_from_mint_allowlist::*;
+pub use sync_basket_balance::*;
+pub use unkill_folio::*;
+pub use unpause_folio::*;
 pub use update_folio::*;
+pub use update_folio_branding::*;
@@ -0,0 +1,101 @@
+use crate::events::OwnerProposed;
+use crate::state::{Actor, Folio, PendingOwner};
+use crate::utils::structs::Role;
+use anchor_lang::prelude::*;
+use shared::constants::{ACTOR_SEEDS, PENDING_OWNER_SEEDS};
+
+/// Propose Owner
+/// Owner only.
+///
+/// # Arguments
+/// * `system_program` - The system program.
+/// * `rent` - The rent sysvar.
+/// * `folio_owner` - The folio owner account (mut, signer).
+/// * `folio_owner_actor` - The folio owner actor account (PDA) (not mut, not signer).
+/// * `new_owner` - The proposed new owner account (not mut, not signer).
+/// * `pending_owner` - The pending owner account (PDA) (init, not signer).
+/// * `folio` - The folio account (PDA) (not mut, not signer).
+#[derive(Accounts)]
+pub struct ProposeOwner<'info> {
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+
+    #[account(mut)]
+    pub folio_owner: Signer<'info>,
+
+    #[account(
+        seeds = [ACTOR_SEEDS, folio_owner.key().as_ref(), folio.key().as_ref()],
+        bump = folio_owner_actor.bump,
+    )]
+    pub folio_owner_actor: Box<Account<'info, Actor>>,
+
+    /// CHECK: Wallet, DAO, multisig that will be the new owner
+    #[account()]
+    pub new_owner: UncheckedAccount<'info>,
+
+    /*
+    Init if needed because a new proposal overrides the previous one
+     */
+    #[account(init_if_needed,
+        payer = folio_owner,
+        space = PendingOwner::SIZE,
+        seeds = [PENDING_OWNER_SEEDS, folio.key().as_ref()],
+        bump
+    )]
+    pub pending_owner: Box<Account<'info, PendingOwner>>,
+
+    #[account()]
+    pub folio: AccountLoader<'info, Folio>,
+}
+
+impl ProposeOwner<'_> {
+    /// Validate the instruction.
+    ///
+    /// # Checks
+    /// * Folio owner actor is an owner of the folio.
+    pub fn validate(&self) -> Result<()> {
+        let folio = &self.folio.load()?;
+
+        folio.validate_folio(
+            &self.folio.key(),
+            Some(&self.folio_owner_actor),
+            Some(vec![Role::Owner]),
+            None, // Can transfer the ownership no matter the status
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Propose a new owner for the folio. The new owner needs to accept the ownership transfer
+/// via `accept_owner`, at which point the owner role is removed from the proposer.
+/// Overrides any pending proposal, which can also be cancelled via `cancel_owner_proposal`.
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+pub fn handler(ctx: Context<ProposeOwner>) -> Result<()> {
+    ctx.accounts.validate()?;
+
+    let pending_owner = &mut ctx.accounts.pending_owner;
+
+    let pending_owner_bump = pending_owner.bump;
+
+    pending_owner.process_init_if_needed(
+        pending_owner_bump,
+        ctx.bumps.pending_owner,
+        &ctx.accounts.folio.key(),
+    )?;
+
+    pending_owner.propose(
+        &ctx.accounts.folio_owner.key(),
+        &ctx.accounts.new_owner.key(),
+        Clock::get()?.unix_timestamp as u64,
+    )?;
+
+    emit!(OwnerProposed {
+        proposed_by: pending_owner.proposed_by,
+        new_owner: pending_owner.new_owner,
+    });
+
+    Ok(())
+}
@@ -1,7 +1,7 @@
-use crate::state::{Actor, Folio};
+use crate::state::{Actor, Folio, FolioActors};
 use crate::utils::structs::Role;
 use anchor_lang::prelude::*;
-use shared::constants::ACTOR_SEEDS;
+use shared::constants::{ACTOR_SEEDS, FOLIO_ACTORS_SEEDS};
 
 /// Remove a role from an actor or closes the actor accouunt completely.
 ///
@@ -13,6 +13,7 @@ use shared::constants::ACTOR_SEEDS;
 /// * `folio_owner_actor` - The folio owner actor account (PDA) (not mut, not signer).
 /// * `actor_to_remove` - The actor to remove the role from or close (mut, not signer).
 /// * `folio` - The folio account (PDA) (not mut, not signer).
+/// * `folio_actors` - The folio actors index account (PDA) (init if needed, not signer).
 #[derive(Accounts)]
 pub struct RemoveActor<'info> {
     pub system_program: Program<'info, System>,
@@ -39,6 +40,14 @@ pub struct RemoveActor<'info> {
 
     #[account()]
     pub folio: AccountLoader<'info, Folio>,
+
+    #[account(init_if_needed,
+        payer = folio_owner,
+        space = FolioActors::SIZE,
+        seeds = [FOLIO_ACTORS_SEEDS, folio_owner_actor.folio.key().as_ref()],
+        bump
+    )]
+    pub folio_actors: Box<Account<'info, FolioActors>>,
 }
 
 impl RemoveActor<'_> {
@@ -69,11 +78,25 @@ impl RemoveActor<'_> {
 pub fn handler(ctx: Context<RemoveActor>, role: Role, close_actor: bool) -> Result<()> {
     ctx.accounts.validate()?;
 
+    let folio_actors = &mut ctx.accounts.folio_actors;
+
+    let folio_actors_bump = folio_actors.bump;
+
+    folio_actors.process_init_if_needed(
+        folio_actors_bump,
+        ctx.bumps.folio_actors,
+        &ctx.accounts.folio_owner_actor.folio,
+    )?;
+
     let actor_to_remove = &mut ctx.accounts.actor_to_remove;
 
     if !close_actor {
-        Role::remove_role(&mut actor_to_remove.roles, role);
+        actor_to_remove.remove_role(role);
+
+        folio_actors.set_actor_roles(&actor_to_remove.authority, actor_to_remove.roles);
     } else {
+        folio_actors.remove_actor(&actor_to_remove.authority);
+
         // To prevent re-init attacks, we reset the actor with default values
         actor_to_remove.reset();
 
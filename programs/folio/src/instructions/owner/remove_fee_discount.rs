@@ -0,0 +1,77 @@
+use crate::events::FeeDiscountSet;
+use crate::state::{Actor, FeeDiscount, Folio};
+use crate::utils::structs::Role;
+use anchor_lang::prelude::*;
+use shared::constants::{ACTOR_SEEDS, FEE_DISCOUNT_SEEDS};
+
+/// Remove a fee discount, closing the fee discount account.
+/// Owner only.
+///
+/// # Arguments
+/// * `system_program` - The system program.
+/// * `folio_owner` - The folio owner account (mut, signer).
+/// * `actor` - The actor account of the folio owner (PDA) (not mut, not signer).
+/// * `folio` - The folio account (PDA) (not mut, not signer).
+/// * `minter` - The minter account that benefits from the discount (not mut, not signer).
+/// * `fee_discount` - The fee discount account (PDA) to close (mut, not signer).
+#[derive(Accounts)]
+pub struct RemoveFeeDiscount<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub folio_owner: Signer<'info>,
+
+    #[account(
+        seeds = [ACTOR_SEEDS, folio_owner.key().as_ref(), folio.key().as_ref()],
+        bump = actor.bump,
+    )]
+    pub actor: Box<Account<'info, Actor>>,
+
+    #[account()]
+    pub folio: AccountLoader<'info, Folio>,
+
+    /// CHECK: Wallet, program, multisig that benefits from the discount
+    #[account()]
+    pub minter: UncheckedAccount<'info>,
+
+    #[account(mut,
+        close = folio_owner,
+        seeds = [FEE_DISCOUNT_SEEDS, folio.key().as_ref(), minter.key().as_ref()],
+        bump = fee_discount.bump,
+    )]
+    pub fee_discount: Box<Account<'info, FeeDiscount>>,
+}
+
+impl RemoveFeeDiscount<'_> {
+    /// Validate the instruction.
+    ///
+    /// # Checks
+    /// * Actor is an owner of the folio.
+    pub fn validate(&self) -> Result<()> {
+        let folio = &self.folio.load()?;
+
+        folio.validate_folio(
+            &self.folio.key(),
+            Some(&self.actor),
+            Some(vec![Role::Owner]),
+            None, // Can CRUD fee discounts no matter the status
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Remove a fee discount, closing the fee discount account and reimbursing the rent to the folio owner.
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+pub fn handler(ctx: Context<RemoveFeeDiscount>) -> Result<()> {
+    ctx.accounts.validate()?;
+
+    emit!(FeeDiscountSet {
+        minter: ctx.accounts.minter.key(),
+        discount: 0,
+    });
+
+    Ok(())
+}
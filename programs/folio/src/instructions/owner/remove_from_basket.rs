@@ -1,10 +1,10 @@
-use crate::state::{Actor, Folio, FolioBasket};
+use crate::state::{Actor, Folio, FolioBasket, FolioLegacyBasket};
 use crate::utils::structs::{FolioStatus, Role};
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
 use shared::{
     check_condition,
-    constants::{ACTOR_SEEDS, FOLIO_BASKET_SEEDS},
+    constants::{ACTOR_SEEDS, FOLIO_BASKET_SEEDS, FOLIO_LEGACY_BASKET_SEEDS},
     errors::ErrorCode,
 };
 /// Remove tokens from the folio's basket.
@@ -15,6 +15,9 @@ use shared::{
 /// * `actor` - The actor account (PDA) of the Folio owner (not mut, not signer).
 /// * `folio` - The folio account (PDA) (not mut, not signer).
 /// * `folio_basket` - The folio basket account (PDA) (mut, not signer).
+/// * `folio_legacy_basket` - The folio legacy basket account (PDA) (mut, not signer).
+/// * `folio_token_mint` - The folio token mint account (not mut, not signer).
+/// * `token_mint` - The mint of the token to remove (not mut, not signer).
 #[derive(Accounts)]
 pub struct RemoveFromBasket<'info> {
     pub system_program: Program<'info, System>,
@@ -37,6 +40,13 @@ pub struct RemoveFromBasket<'info> {
     )]
     pub folio_basket: AccountLoader<'info, FolioBasket>,
 
+    /// CHECK: Could be empty or could be set, if empty it's initialized in the handler if needed
+    #[account(mut,
+        seeds = [FOLIO_LEGACY_BASKET_SEEDS, folio.key().as_ref()],
+        bump
+    )]
+    pub folio_legacy_basket: UncheckedAccount<'info>,
+
     #[account()]
     pub folio_token_mint: Box<InterfaceAccount<'info, Mint>>,
 
@@ -69,6 +79,9 @@ impl RemoveFromBasket<'_> {
 
 /// Remove tokens from the folio's basket.
 ///
+/// The residual amount of the removed token is still owed to the holders, so it's moved to the folio's legacy basket,
+/// where it can only be distributed on redemption.
+///
 /// # Arguments
 /// * `ctx` - The context of the instruction.
 pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, RemoveFromBasket<'info>>) -> Result<()> {
@@ -77,11 +90,32 @@ pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, RemoveFromBasket<'info>
 
     let folio_basket = &mut ctx.accounts.folio_basket.load_mut()?;
 
-    let _scaled_folio_token_total_supply =
-        folio.get_total_supply(ctx.accounts.folio_token_mint.supply)?;
+    let raw_folio_token_supply = ctx.accounts.folio_token_mint.supply;
+    let scaled_folio_token_total_supply = folio.get_total_supply(raw_folio_token_supply)?;
 
     let mint_to_remove = ctx.accounts.token_mint.key();
+    let raw_residual_amount =
+        folio_basket.get_token_amount_in_folio_basket_or_zero(&mint_to_remove);
+
     folio_basket.remove_token_mint_from_basket(mint_to_remove)?;
 
+    if raw_residual_amount > 0 {
+        let mut folio_legacy_basket = FolioLegacyBasket::get_or_init_folio_legacy_basket(
+            &ctx.accounts.folio_legacy_basket.to_account_info(),
+            &ctx.accounts.folio_owner.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.folio.key(),
+            ctx.bumps.folio_legacy_basket,
+        )?;
+
+        folio_legacy_basket.add_token_amount(
+            &mint_to_remove,
+            raw_residual_amount,
+            raw_folio_token_supply,
+            &scaled_folio_token_total_supply,
+        )?;
+        folio_legacy_basket.save(&ctx.accounts.folio_legacy_basket.to_account_info())?;
+    }
+
     Ok(())
 }
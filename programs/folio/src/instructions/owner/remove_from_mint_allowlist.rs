@@ -0,0 +1,77 @@
+use crate::events::MintAllowlistEntryRemoved;
+use crate::state::{Actor, Folio, MintAllowlistEntry};
+use crate::utils::structs::Role;
+use anchor_lang::prelude::*;
+use shared::constants::{ACTOR_SEEDS, MINT_ALLOWLIST_ENTRY_SEEDS};
+
+/// Remove a user from the mint allowlist of a folio, closing the mint allowlist entry account.
+/// Allowlist manager or owner only.
+///
+/// # Arguments
+/// * `system_program` - The system program.
+/// * `allowlist_manager` - The allowlist manager account (mut, signer).
+/// * `actor` - The actor account of the allowlist manager (PDA) (not mut, not signer).
+/// * `folio` - The folio account (PDA) (not mut, not signer).
+/// * `user` - The user account that will no longer be allowed to mint (not mut, not signer).
+/// * `mint_allowlist_entry` - The mint allowlist entry account (PDA) to close (mut, not signer).
+#[derive(Accounts)]
+pub struct RemoveFromMintAllowlist<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub allowlist_manager: Signer<'info>,
+
+    #[account(
+        seeds = [ACTOR_SEEDS, allowlist_manager.key().as_ref(), folio.key().as_ref()],
+        bump = actor.bump,
+    )]
+    pub actor: Box<Account<'info, Actor>>,
+
+    #[account()]
+    pub folio: AccountLoader<'info, Folio>,
+
+    /// CHECK: Wallet, program, multisig that will no longer be allowed to mint
+    #[account()]
+    pub user: UncheckedAccount<'info>,
+
+    #[account(mut,
+        close = allowlist_manager,
+        seeds = [MINT_ALLOWLIST_ENTRY_SEEDS, folio.key().as_ref(), user.key().as_ref()],
+        bump = mint_allowlist_entry.bump,
+    )]
+    pub mint_allowlist_entry: Box<Account<'info, MintAllowlistEntry>>,
+}
+
+impl RemoveFromMintAllowlist<'_> {
+    /// Validate the instruction.
+    ///
+    /// # Checks
+    /// * Actor is an allowlist manager or an owner of the folio.
+    pub fn validate(&self) -> Result<()> {
+        let folio = &self.folio.load()?;
+
+        folio.validate_folio(
+            &self.folio.key(),
+            Some(&self.actor),
+            Some(vec![Role::AllowlistManager, Role::Owner]),
+            None, // Can CRUD the mint allowlist no matter the status
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Remove a user from the mint allowlist of a folio, closing the mint allowlist entry account and reimbursing
+/// the rent to the allowlist manager.
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+pub fn handler(ctx: Context<RemoveFromMintAllowlist>) -> Result<()> {
+    ctx.accounts.validate()?;
+
+    emit!(MintAllowlistEntryRemoved {
+        user: ctx.accounts.user.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,240 @@
+use crate::events::{BasketBalanceSynced, NonBasketTokensSwept};
+use crate::state::{Actor, Folio, FolioBasket, FolioLegacyBasket, FolioPendingBasket};
+use crate::utils::structs::{FolioStatus, Role};
+use crate::utils::FolioTokenAmount;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use shared::errors::ErrorCode;
+use shared::utils::TokenUtil;
+use shared::{
+    check_condition,
+    constants::{
+        ACTOR_SEEDS, FOLIO_BASKET_SEEDS, FOLIO_LEGACY_BASKET_SEEDS, FOLIO_PENDING_BASKET_SEEDS,
+        FOLIO_SEEDS,
+    },
+};
+
+/// Sync the balance of the folio's token account of a mint with the basket.
+///
+/// # Arguments
+/// * `system_program` - The system program.
+/// * `token_program` - The token program.
+/// * `folio_owner` - The folio owner account (mut, signer).
+/// * `actor` - The actor account (PDA) of the Folio owner (not mut, not signer).
+/// * `folio` - The folio account (PDA) (not mut, not signer).
+/// * `folio_basket` - The folio basket account (PDA) (mut, not signer).
+/// * `folio_pending_basket` - The folio pending basket account (PDA) (init if needed, not signer).
+/// * `folio_legacy_basket` - The folio legacy basket account (PDA) (not mut, not signer).
+/// * `mint` - The mint of the token to sync (not mut, not signer).
+/// * `folio_token_account` - The folio's token account of the mint (mut, not signer).
+/// * `recipient_token_account` - The token account receiving the tokens, only used if the mint isn't in the basket (mut, not signer).
+///
+/// * `remaining_accounts` - Only if the mint isn't in the basket and has an active transfer hook:
+///     - Transfer Hook Program (read)
+///     - Extra Account Meta List (read)
+///     - Extra accounts of the transfer hook
+#[derive(Accounts)]
+pub struct SyncBasketBalance<'info> {
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+
+    #[account(mut)]
+    pub folio_owner: Signer<'info>,
+
+    #[account(
+        seeds = [ACTOR_SEEDS, folio_owner.key().as_ref(), folio.key().as_ref()],
+        bump = actor.bump,
+    )]
+    pub actor: Account<'info, Actor>,
+
+    #[account()]
+    pub folio: AccountLoader<'info, Folio>,
+
+    #[account(mut,
+        seeds = [FOLIO_BASKET_SEEDS, folio.key().as_ref()],
+        bump
+    )]
+    pub folio_basket: AccountLoader<'info, FolioBasket>,
+
+    #[account(init_if_needed,
+        payer = folio_owner,
+        space = FolioPendingBasket::SIZE,
+        seeds = [FOLIO_PENDING_BASKET_SEEDS, folio.key().as_ref()],
+        bump
+    )]
+    pub folio_pending_basket: AccountLoader<'info, FolioPendingBasket>,
+
+    /// CHECK: Could be empty or could be set, if set the legacy amount is accounted for
+    #[account(
+        seeds = [FOLIO_LEGACY_BASKET_SEEDS, folio.key().as_ref()],
+        bump
+    )]
+    pub folio_legacy_basket: UncheckedAccount<'info>,
+
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut,
+        associated_token::mint = mint,
+        associated_token::authority = folio,
+        associated_token::token_program = token_program,
+    )]
+    pub folio_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub recipient_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+}
+
+impl SyncBasketBalance<'_> {
+    /// Validate the instruction.
+    ///
+    /// # Checks
+    /// * Folio has the correct status.
+    /// * Actor is the owner of the folio.
+    /// * Every user pending basket of the folio is tracked.
+    /// * Mint is not the folio token mint.
+    /// * Recipient token account is not the folio's token account.
+    pub fn validate(&self, folio: &Folio) -> Result<()> {
+        folio.validate_folio(
+            &self.folio.key(),
+            Some(&self.actor),
+            Some(vec![Role::Owner]),
+            Some(vec![FolioStatus::Initialized, FolioStatus::Killed]),
+        )?;
+
+        check_condition!(
+            folio.pending_baskets_tracked == 1,
+            PendingBasketsNotTracked
+        );
+
+        check_condition!(
+            self.mint.key() != folio.folio_token_mint,
+            InvalidFolioTokenMint
+        );
+
+        check_condition!(
+            self.recipient_token_account.key() != self.folio_token_account.key(),
+            InvalidRecipientTokenAccount
+        );
+
+        Ok(())
+    }
+}
+
+/// Sync the balance of the folio's token account of a mint with the basket. Tokens can be sent directly to the folio's
+/// token account (airdrops, donations, yield), those aren't part of the basket and would otherwise be stranded.
+///
+/// The surplus is the balance of the folio's token account minus the amount in the basket, minus the amount still
+/// owed to the holders for removed tokens (legacy basket) and minus the pending amounts of all the users (tracked in
+/// the folio pending basket).
+///     - If the mint is in the basket, the surplus is credited to the basket.
+///     - If the mint isn't in the basket, the surplus is swept to the recipient token account.
+///
+/// Pending baskets of users created before the folio pending basket existed aren't accounted for until they are
+/// tracked, so syncing is only allowed once the folio is marked with `pending_baskets_tracked` (set at creation for
+/// new folios, see `track_user_pending_basket` and `set_pending_baskets_tracked` for older ones).
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, SyncBasketBalance<'info>>) -> Result<()> {
+    let folio_key = ctx.accounts.folio.key();
+
+    let (folio_bump, folio_token_mint) = {
+        let folio = ctx.accounts.folio.load()?;
+        ctx.accounts.validate(&folio)?;
+        (folio.bump, folio.folio_token_mint)
+    };
+
+    FolioPendingBasket::process_init_if_needed(
+        &ctx.accounts.folio_pending_basket,
+        ctx.bumps.folio_pending_basket,
+        &folio_key,
+    )?;
+
+    let mint_key = ctx.accounts.mint.key();
+    let raw_pending_amount = ctx
+        .accounts
+        .folio_pending_basket
+        .load()?
+        .get_total_pending_amount(&mint_key)?;
+
+    let raw_legacy_amount =
+        FolioLegacyBasket::get_folio_legacy_basket(&ctx.accounts.folio_legacy_basket)?
+            .map(|folio_legacy_basket| folio_legacy_basket.get_token_amount_or_zero(&mint_key))
+            .unwrap_or(0);
+
+    let folio_basket = &mut ctx.accounts.folio_basket.load_mut()?;
+
+    let is_in_basket = folio_basket
+        .basket
+        .token_amounts
+        .iter()
+        .any(|ta| ta.mint == mint_key);
+
+    // The amount held for the basket and the legacy basket, plus what is held for the users
+    let raw_accounted_amount = folio_basket
+        .get_token_amount_in_folio_basket_or_zero(&mint_key)
+        .checked_add(raw_legacy_amount)
+        .and_then(|amount| amount.checked_add(raw_pending_amount))
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let raw_surplus_amount = ctx
+        .accounts
+        .folio_token_account
+        .amount
+        .saturating_sub(raw_accounted_amount);
+
+    check_condition!(raw_surplus_amount > 0, NoSurplusBalanceToSync);
+
+    if is_in_basket {
+        folio_basket.add_tokens_to_basket(&vec![FolioTokenAmount {
+            mint: mint_key,
+            amount: raw_surplus_amount,
+        }])?;
+
+        emit!(BasketBalanceSynced {
+            token: mint_key,
+            amount: raw_surplus_amount,
+        });
+    } else {
+        let mint_account_info = ctx.accounts.mint.to_account_info();
+
+        let mut remaining_accounts_iter = ctx.remaining_accounts.iter();
+        let transfer_hook_accounts = TokenUtil::next_transfer_hook_accounts(
+            &mut remaining_accounts_iter,
+            &mint_account_info,
+        )?;
+
+        check_condition!(
+            remaining_accounts_iter.next().is_none(),
+            InvalidNumberOfRemainingAccounts
+        );
+
+        let signer_seeds = &[FOLIO_SEEDS, folio_token_mint.as_ref(), &[folio_bump]];
+
+        TokenUtil::transfer_checked(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.folio_token_account.to_account_info(),
+            &mint_account_info,
+            &ctx.accounts.recipient_token_account.to_account_info(),
+            &ctx.accounts.folio.to_account_info(),
+            &transfer_hook_accounts,
+            raw_surplus_amount,
+            ctx.accounts.mint.decimals,
+            &[signer_seeds],
+        )?;
+
+        emit!(NonBasketTokensSwept {
+            token: mint_key,
+            recipient: ctx.accounts.recipient_token_account.key(),
+            amount: raw_surplus_amount,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,136 @@
+use crate::events::{FolioUnkillTimelockStarted, FolioUnkilled};
+use crate::state::{Actor, Folio, KillRecord};
+use crate::utils::structs::{FolioStatus, Role};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use folio_admin::state::UnkillConfig;
+use folio_admin::ID as FOLIO_ADMIN_PROGRAM_ID;
+use shared::check_condition;
+use shared::constants::{ACTOR_SEEDS, KILL_RECORD_SEEDS, UNKILL_CONFIG_SEEDS};
+use shared::errors::ErrorCode;
+
+/// Unkill Folio
+///
+/// # Arguments
+/// * `system_program` - The system program.
+/// * `folio_owner` - The folio owner account (mut, signer).
+/// * `actor` - The actor account (PDA) of the Folio owner (not mut, not signer).
+/// * `folio` - The folio account (PDA) (mut, not signer).
+/// * `folio_token_mint` - The folio token mint account (not mut, not signer).
+/// * `kill_record` - The kill record account (PDA) (init if needed, not signer), closed to the folio owner once the
+///                   folio is unkilled.
+/// * `unkill_config` - The unkill config account (PDA) (not mut, not signer).
+#[derive(Accounts)]
+pub struct UnkillFolio<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub folio_owner: Signer<'info>,
+
+    #[account(
+        seeds = [ACTOR_SEEDS, folio_owner.key().as_ref(), folio.key().as_ref()],
+        bump = actor.bump,
+    )]
+    pub actor: Account<'info, Actor>,
+
+    #[account(mut)]
+    pub folio: AccountLoader<'info, Folio>,
+
+    #[account()]
+    pub folio_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = folio_owner,
+        space = KillRecord::SIZE,
+        seeds = [KILL_RECORD_SEEDS, folio.key().as_ref()],
+        bump,
+    )]
+    pub kill_record: Account<'info, KillRecord>,
+
+    /// CHECK: Could be empty or could be set, if set we use that one, else we use the default unkill timelock
+    #[account(
+        seeds = [UNKILL_CONFIG_SEEDS],
+        bump,
+        seeds::program = FOLIO_ADMIN_PROGRAM_ID,
+    )]
+    pub unkill_config: UncheckedAccount<'info>,
+}
+
+impl UnkillFolio<'_> {
+    /// Validate the instruction.
+    ///
+    /// # Checks
+    /// * Folio is killed (a folio that started migrating isn't killed anymore).
+    /// * Actor is the owner of the folio.
+    /// * Provided folio token mint account is the same as the one on the folio account.
+    /// * Folio is still the mint authority of the folio token mint (no migration started).
+    pub fn validate(&self, folio: &Folio) -> Result<()> {
+        folio.validate_folio(
+            &self.folio.key(),
+            Some(&self.actor),
+            Some(vec![Role::Owner]),
+            Some(vec![FolioStatus::Killed]),
+        )?;
+
+        check_condition!(
+            self.folio_token_mint.key() == folio.folio_token_mint,
+            InvalidFolioTokenMint
+        );
+
+        check_condition!(
+            self.folio_token_mint.mint_authority == Some(self.folio.key()).into(),
+            InvalidFolioStatus
+        );
+
+        Ok(())
+    }
+}
+
+/// Unkill Folio, restoring it to initialized so that minting is possible again.
+/// Only possible after a timelock since the kill, and if no migration was started.
+///
+/// Folios killed before kill records existed don't have one, so the first call creates it with the current time as
+/// the time of the kill and the unkill timelock of the unkill config, without unkilling the folio. The folio can be
+/// unkilled by calling this instruction again once the timelock has passed.
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+pub fn handler(ctx: Context<UnkillFolio>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    let folio = &mut ctx.accounts.folio.load_mut()?;
+
+    ctx.accounts.validate(folio)?;
+
+    let kill_record = &mut ctx.accounts.kill_record;
+
+    if kill_record.is_uninitialized() {
+        let unkill_timelock = UnkillConfig::get_unkill_timelock(&ctx.accounts.unkill_config)?;
+
+        kill_record.init(
+            ctx.bumps.kill_record,
+            ctx.accounts.folio.key(),
+            current_time,
+            unkill_timelock,
+        );
+
+        emit!(FolioUnkillTimelockStarted {
+            unkillable_at: kill_record.get_unkillable_at()?,
+        });
+
+        return Ok(());
+    }
+
+    kill_record.validate_unkill_timelock(current_time)?;
+
+    folio.status = FolioStatus::Initialized as u8;
+
+    ctx.accounts
+        .kill_record
+        .close(ctx.accounts.folio_owner.to_account_info())?;
+
+    emit!(FolioUnkilled {});
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use crate::events::FolioUnpaused;
+use crate::state::{Actor, Folio};
+use crate::utils::structs::{FolioStatus, Role};
+use anchor_lang::prelude::*;
+use shared::constants::ACTOR_SEEDS;
+
+/// Unpause Folio
+///
+/// # Arguments
+/// * `system_program` - The system program.
+/// * `guardian` - The guardian account (mut, signer).
+/// * `actor` - The actor account (PDA) of the guardian (not mut, not signer).
+/// * `folio` - The folio account (PDA) (mut, not signer).
+#[derive(Accounts)]
+pub struct UnpauseFolio<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    #[account(
+        seeds = [ACTOR_SEEDS, guardian.key().as_ref(), folio.key().as_ref()],
+        bump = actor.bump,
+    )]
+    pub actor: Account<'info, Actor>,
+
+    #[account(mut)]
+    pub folio: AccountLoader<'info, Folio>,
+}
+
+impl UnpauseFolio<'_> {
+    /// Validate the instruction.
+    ///
+    /// # Checks
+    /// * Folio is initializing, initialized or killed.
+    /// * Actor is the guardian or the owner of the folio.
+    pub fn validate(&self, folio: &Folio) -> Result<()> {
+        folio.validate_folio(
+            &self.folio.key(),
+            Some(&self.actor),
+            Some(vec![Role::Guardian, Role::Owner]),
+            Some(vec![
+                FolioStatus::Initializing,
+                FolioStatus::Initialized,
+                FolioStatus::Killed,
+            ]),
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Unpause Folio
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+/// * `pause_flags` - The bitmask of the actions to unpause, see `PauseFlag`.
+pub fn handler(ctx: Context<UnpauseFolio>, pause_flags: u8) -> Result<()> {
+    let folio = &mut ctx.accounts.folio.load_mut()?;
+
+    ctx.accounts.validate(folio)?;
+
+    folio.unpause(pause_flags)?;
+
+    emit!(FolioUnpaused {
+        pause_flags: folio.pause_flags,
+    });
+
+    Ok(())
+}
@@ -1,15 +1,18 @@
-use crate::events::{AuctionLengthSet, MintFeeSet};
+use crate::events::{
+    AuctionLengthSet, FeeDistributionFallbackSet, MintAllowlistEnabledSet, MintFeeSet,
+    MintLimitsSet,
+};
 
 use crate::instructions::distribute_fees;
-use crate::state::{Actor, FeeDistribution, FeeRecipients, Folio};
-use crate::utils::structs::{FeeRecipient, Role};
+use crate::state::{Actor, FeeDistribution, FeeRecipients, Folio, FolioMintLimits};
+use crate::utils::structs::{FeeDistributionFallback, FeeRecipient, Role};
 use crate::utils::{FixedSizeString, FolioStatus, MAX_PADDED_STRING_LENGTH};
 use crate::ID;
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
 use shared::constants::{
-    FEE_DISTRIBUTION_SEEDS, FEE_RECIPIENTS_SEEDS, MAX_AUCTION_LENGTH, MAX_MINT_FEE, MAX_TVL_FEE,
-    MIN_AUCTION_LENGTH,
+    FEE_DISTRIBUTION_SEEDS, FEE_RECIPIENTS_SEEDS, FOLIO_MINT_LIMITS_SEEDS, MAX_AUCTION_LENGTH,
+    MAX_MINT_FEE, MAX_TVL_FEE, MIN_AUCTION_LENGTH,
 };
 use shared::errors::ErrorCode;
 use shared::utils::init_pda_account_rent;
@@ -35,6 +38,7 @@ enum IndexPerAccount {
 /// * `actor` - The actor account (PDA) of the Folio owner (not mut, not signer).
 /// * `folio` - The folio account (PDA) (mut, not signer).
 /// * `fee_recipients` - The fee recipients account (PDA) (init if needed, not signer).
+/// * `folio_mint_limits` - The folio mint limits account (PDA) (mut, not signer), only required and initialized when setting the mint limits.
 ///
 /// * `remaining_accounts` - The remaining accounts will be used if we need to also distribute the fees. Modifying certain fields on the folio will require distributing the fees.
 ///
@@ -46,6 +50,7 @@ enum IndexPerAccount {
 /// - Folio token mint (mut)
 /// - Fee Distribution (mut)
 /// - DAO fee recipient (mut)
+/// - Other protocol fee recipients (mut), in the same order as in the DAO fee config
 #[derive(Accounts)]
 pub struct UpdateFolio<'info> {
     pub system_program: Program<'info, System>,
@@ -71,6 +76,13 @@ pub struct UpdateFolio<'info> {
         bump,
     )]
     pub fee_recipients: AccountLoader<'info, FeeRecipients>,
+
+    /// CHECK: Only required when setting the mint limits, could be empty or could be set, initialized if empty
+    #[account(mut,
+        seeds = [FOLIO_MINT_LIMITS_SEEDS, folio.key().as_ref()],
+        bump,
+    )]
+    pub folio_mint_limits: Option<UncheckedAccount<'info>>,
     /*
     Remaining accounts will be just for set tvl fee, where we need to distribute the fees
 
@@ -131,8 +143,7 @@ impl<'info> UpdateFolio<'info> {
                 return Err(error!(ErrorCode::MissingFeeDistributionIndex));
             }
 
-            let dao_fee_config =
-                Account::try_from(&remaining_accounts[IndexPerAccount::DAOFeeConfig as usize])?;
+            let dao_fee_config = &remaining_accounts[IndexPerAccount::DAOFeeConfig as usize];
 
             let folio_token_mint: Box<InterfaceAccount<Mint>> =
                 Box::new(InterfaceAccount::try_from(
@@ -185,13 +196,14 @@ impl<'info> UpdateFolio<'info> {
             distribute_fees(
                 &remaining_accounts[IndexPerAccount::TokenProgram as usize],
                 &self.folio_owner,
-                &dao_fee_config,
+                dao_fee_config,
                 &remaining_accounts[IndexPerAccount::FolioFeeConfig as usize],
                 &self.folio,
                 &folio_token_mint,
                 &self.fee_recipients,
                 &fee_distribution,
                 &remaining_accounts[IndexPerAccount::DAOFeeRecipient as usize],
+                &remaining_accounts[IndexPerAccount::DAOFeeRecipient as usize + 1..],
                 index_for_fee_distribution.unwrap(),
             )?;
         }
@@ -211,6 +223,10 @@ impl<'info> UpdateFolio<'info> {
 /// * `fee_recipients_to_add` - The fee recipients to add. [trigger fee distribution]
 /// * `fee_recipients_to_remove` - The fee recipients to remove. [trigger fee distribution]
 /// * `mandate` - The mandate if we want to update it.
+/// * `mint_allowlist_enabled` - Whether minting is restricted to the users on the mint allowlist, if we want to update it.
+/// * `max_supply` - The maximum total supply of the folio (D9) if we want to update it, 0 to remove the cap.
+/// * `max_mint_per_tx` - The maximum amount of shares minted in a single transaction (D9) if we want to update it, 0 to remove the limit.
+/// * `fee_distribution_fallback` - Where the undelivered shares of closed fee distributions go, if we want to update it.
 #[allow(clippy::too_many_arguments)]
 pub fn handler<'info>(
     ctx: Context<'_, '_, 'info, 'info, UpdateFolio<'info>>,
@@ -222,6 +238,10 @@ pub fn handler<'info>(
     fee_recipients_to_add: Vec<FeeRecipient>,
     fee_recipients_to_remove: Vec<Pubkey>,
     mandate: Option<String>,
+    mint_allowlist_enabled: Option<bool>,
+    max_supply: Option<u64>,
+    max_mint_per_tx: Option<u64>,
+    fee_distribution_fallback: Option<FeeDistributionFallback>,
 ) -> Result<()> {
     ctx.accounts.validate()?;
 
@@ -319,5 +339,56 @@ pub fn handler<'info>(
         }
     }
 
+    if let Some(mint_allowlist_enabled) = mint_allowlist_enabled {
+        {
+            let mut folio = ctx.accounts.folio.load_mut()?;
+            folio.set_mint_allowlist_enabled(mint_allowlist_enabled);
+        }
+
+        emit!(MintAllowlistEnabledSet {
+            enabled: mint_allowlist_enabled
+        });
+    }
+
+    if max_supply.is_some() || max_mint_per_tx.is_some() {
+        let (Some(folio_mint_limits_account), Some(folio_mint_limits_bump)) = (
+            ctx.accounts.folio_mint_limits.as_ref(),
+            ctx.bumps.folio_mint_limits,
+        ) else {
+            return Err(error!(ErrorCode::MissingFolioMintLimits));
+        };
+
+        let folio_mint_limits = FolioMintLimits::init_or_update_folio_mint_limits(
+            folio_mint_limits_account,
+            &ctx.accounts.folio_owner,
+            &ctx.accounts.system_program,
+            &ctx.accounts.folio.key(),
+            folio_mint_limits_bump,
+            max_supply,
+            max_mint_per_tx,
+        )?;
+
+        {
+            let mut folio = ctx.accounts.folio.load_mut()?;
+            folio.set_mint_limits_enabled(folio_mint_limits.has_limits());
+        }
+
+        emit!(MintLimitsSet {
+            max_supply: folio_mint_limits.max_supply,
+            max_mint_per_tx: folio_mint_limits.max_mint_per_tx,
+        });
+    }
+
+    if let Some(fee_distribution_fallback) = fee_distribution_fallback {
+        {
+            let mut folio = ctx.accounts.folio.load_mut()?;
+            folio.set_fee_distribution_fallback(fee_distribution_fallback);
+        }
+
+        emit!(FeeDistributionFallbackSet {
+            fee_distribution_fallback
+        });
+    }
+
     Ok(())
 }
@@ -0,0 +1,272 @@
+use crate::events::FolioBrandingUpdated;
+use crate::state::{Actor, Folio};
+use crate::utils::structs::{FolioStatus, Role};
+use crate::utils::{FixedSizeString, Metaplex, UpdateAuthority, MAX_PADDED_STRING_LENGTH};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::metadata::mpl_token_metadata;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{
+    spl_token_metadata_interface::state::Field, token_metadata_update_field, Mint, TokenInterface,
+    TokenMetadataUpdateField,
+};
+use shared::check_condition;
+use shared::constants::{ACTOR_SEEDS, FOLIO_SEEDS, METADATA_SEEDS};
+use shared::errors::ErrorCode;
+use spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use spl_token_metadata_interface::state::TokenMetadata;
+
+/// Update Folio Branding (name, symbol, uri of the folio token and mandate of the folio)
+///
+/// # Arguments
+/// * `system_program` - The system program.
+/// * `rent` - The rent sysvar.
+/// * `instructions_sysvar` - The instructions sysvar.
+/// * `token_program` - The token program.
+/// * `brand_manager` - The brand manager account (mut, signer).
+/// * `actor` - The actor account (PDA) of the brand manager (not mut, not signer).
+/// * `folio` - The folio account (PDA) (mut, not signer).
+/// * `folio_token_mint` - The folio token mint account (mut, not signer).
+/// * `token_metadata_program` - The token metadata program (not mut, not signer).
+/// * `metadata` - The metadata account (mut, not signer). Only used for SPL folio token mints.
+#[derive(Accounts)]
+pub struct UpdateFolioBranding<'info> {
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    /// CHECK: Instructions sysvar
+    #[account(address = instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+
+    #[account(mut)]
+    pub brand_manager: Signer<'info>,
+
+    #[account(
+        seeds = [ACTOR_SEEDS, brand_manager.key().as_ref(), folio.key().as_ref()],
+        bump = actor.bump,
+    )]
+    pub actor: Account<'info, Actor>,
+
+    #[account(mut)]
+    pub folio: AccountLoader<'info, Folio>,
+
+    #[account(mut)]
+    pub folio_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /*
+    Metaplex accounts for metadata
+     */
+    /// CHECK: Token metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    /// CHECK: Metadata account
+    #[account(
+        mut,
+        seeds = [
+            METADATA_SEEDS,
+            mpl_token_metadata::ID.as_ref(),
+            folio_token_mint.key().as_ref()
+        ],
+        seeds::program = mpl_token_metadata::ID,
+        bump
+    )]
+    pub metadata: UncheckedAccount<'info>,
+}
+
+impl UpdateFolioBranding<'_> {
+    /// Validate the instruction.
+    ///
+    /// # Checks
+    /// * Folio has the correct status.
+    /// * Actor has the brand manager or owner role.
+    /// * Provided folio token mint account is the same as the one on the folio account.
+    /// * Folio token mint is owned by the token program.
+    /// * Mandate is not too long.
+    pub fn validate(&self, folio: &Folio, mandate: &Option<String>) -> Result<()> {
+        // Can't update the branding while migrating, as the metadata authority is transferred to the new folio
+        folio.validate_folio(
+            &self.folio.key(),
+            Some(&self.actor),
+            Some(vec![Role::BrandManager, Role::Owner]),
+            Some(vec![
+                FolioStatus::Initializing,
+                FolioStatus::Initialized,
+                FolioStatus::Killed,
+            ]),
+        )?;
+
+        check_condition!(
+            self.folio_token_mint.key() == folio.folio_token_mint,
+            InvalidFolioTokenMint
+        );
+
+        check_condition!(
+            *self.folio_token_mint.to_account_info().owner == self.token_program.key(),
+            InvalidTokenMintProgram
+        );
+
+        if let Some(mandate) = mandate {
+            check_condition!(
+                mandate.len() <= MAX_PADDED_STRING_LENGTH,
+                InvalidMandateLength
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Update the Token-2022 metadata of the folio token mint.
+    /// Tops up the rent of the mint account first, as the metadata is stored on the mint itself.
+    ///
+    /// # Arguments
+    /// * `fields` - The fields to update with their new values.
+    /// * `signer_seeds` - The signer seeds of the folio.
+    #[cfg(not(tarpaulin_include))]
+    fn update_token_2022_metadata(
+        &self,
+        fields: Vec<(Field, String)>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let folio_token_mint = self.folio_token_mint.to_account_info();
+
+        let (current_data_len, token_metadata) = {
+            let mint_data = folio_token_mint.try_borrow_data()?;
+            let mint_with_extensions =
+                StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+
+            (
+                mint_data.len(),
+                mint_with_extensions.get_variable_len_extension::<TokenMetadata>()?,
+            )
+        };
+
+        // Each field update reallocates the mint account, so we fund for every field that grows
+        let mut required_data_len = current_data_len;
+        for (field, value) in fields.iter() {
+            let current_value = match field {
+                Field::Name => &token_metadata.name,
+                Field::Symbol => &token_metadata.symbol,
+                Field::Uri => &token_metadata.uri,
+                Field::Key(_) => return err!(ErrorCode::InvalidAccountData),
+            };
+
+            required_data_len = required_data_len
+                .checked_add(value.len().saturating_sub(current_value.len()))
+                .ok_or(ErrorCode::MathOverflow)?;
+        }
+
+        let required_lamports = Rent::get()?.minimum_balance(required_data_len);
+        let current_lamports = folio_token_mint.lamports();
+
+        if required_lamports > current_lamports {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.brand_manager.to_account_info(),
+                        to: folio_token_mint.clone(),
+                    },
+                ),
+                required_lamports - current_lamports,
+            )?;
+        }
+
+        for (field, value) in fields {
+            token_metadata_update_field(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    TokenMetadataUpdateField {
+                        program_id: self.token_program.to_account_info(),
+                        metadata: folio_token_mint.clone(),
+                        update_authority: self.folio.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                field,
+                value,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Update Folio Branding. Can be done by the brand manager or the owner of the folio, so that
+/// marketing changes don't require a governance vote of the owner.
+/// The metadata is updated via Metaplex for SPL folio token mints, and via the Token-2022 metadata
+/// extension for Token-2022 folio token mints.
+///
+/// The metadata of the SPL folio token mints created before this instruction was added is immutable, so
+/// updating their name, symbol or uri fails with `FolioMetadataImmutable`, only the mandate can be updated.
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+/// * `name` - The name of the folio token if we want to update it.
+/// * `symbol` - The symbol of the folio token if we want to update it.
+/// * `uri` - The URI of the folio token if we want to update it.
+/// * `mandate` - The mandate of the folio if we want to update it.
+pub fn handler(
+    ctx: Context<UpdateFolioBranding>,
+    name: Option<String>,
+    symbol: Option<String>,
+    uri: Option<String>,
+    mandate: Option<String>,
+) -> Result<()> {
+    let folio_bump = {
+        let folio = &ctx.accounts.folio.load()?;
+
+        ctx.accounts.validate(folio, &mandate)?;
+
+        folio.bump
+    };
+
+    let token_mint_key = ctx.accounts.folio_token_mint.key();
+    let folio_signer_seeds = &[FOLIO_SEEDS, token_mint_key.as_ref(), &[folio_bump]];
+    let folio_signer = &[&folio_signer_seeds[..]];
+
+    if name.is_some() || symbol.is_some() || uri.is_some() {
+        if ctx.accounts.token_program.key() == Token::id() {
+            Metaplex::update_metadata_data(
+                &UpdateAuthority {
+                    metadata: ctx.accounts.metadata.to_account_info(),
+                    mint: ctx.accounts.folio_token_mint.to_account_info(),
+                    mint_authority: ctx.accounts.folio.to_account_info(),
+                    payer: ctx.accounts.brand_manager.to_account_info(),
+                    update_authority: ctx.accounts.folio.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                    token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+                    sysvar_instructions: ctx.accounts.instructions_sysvar.to_account_info(),
+                },
+                name.clone(),
+                symbol.clone(),
+                uri.clone(),
+                folio_signer,
+            )?;
+        } else {
+            // The metadata is with token 2022 program
+            let fields = [
+                (Field::Name, name.clone()),
+                (Field::Symbol, symbol.clone()),
+                (Field::Uri, uri.clone()),
+            ]
+            .into_iter()
+            .filter_map(|(field, value)| value.map(|value| (field, value)))
+            .collect();
+
+            ctx.accounts
+                .update_token_2022_metadata(fields, folio_signer)?;
+        }
+    }
+
+    if let Some(mandate) = mandate {
+        let mut folio = ctx.accounts.folio.load_mut()?;
+        folio.mandate = FixedSizeString::new(&mandate);
+    }
+
+    emit!(FolioBrandingUpdated { name, symbol, uri });
+
+    Ok(())
+}
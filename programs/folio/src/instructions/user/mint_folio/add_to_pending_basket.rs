@@ -1,20 +1,24 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::get_associated_token_address_with_program_id,
-    token_interface::{self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked},
+    token_interface::{self, CloseAccount, Mint, TokenAccount, TokenInterface},
 };
+use folio_admin::state::TransferHookRegistrar;
+use folio_admin::ID as FOLIO_ADMIN_PROGRAM_ID;
 use shared::errors::ErrorCode;
+use shared::utils::TokenUtil;
 use shared::{
     check_condition,
-    constants::{FOLIO_BASKET_SEEDS, USER_PENDING_BASKET_SEEDS},
+    constants::{
+        FOLIO_BASKET_SEEDS, FOLIO_PENDING_BASKET_SEEDS, MINT_ALLOWLIST_ENTRY_SEEDS,
+        TRANSFER_HOOK_REGISTRAR_SEEDS, USER_PENDING_BASKET_SEEDS,
+    },
 };
 
-use crate::state::{Folio, FolioBasket, UserPendingBasket};
+use crate::state::{Folio, FolioBasket, FolioPendingBasket, MintAllowlistEntry, UserPendingBasket};
 use crate::utils::structs::{FolioStatus, TokenAmount};
 use shared::utils::account_util::next_account;
 
-const EXPECTED_REMAINING_ACCOUNTS_LENGTH: usize = 3;
-
 /// Add tokens to the user's pending basket.
 ///
 /// # Arguments
@@ -25,6 +29,9 @@ const EXPECTED_REMAINING_ACCOUNTS_LENGTH: usize = 3;
 /// * `folio` - The folio account (PDA) (not mut, not signer).
 /// * `folio_basket` - The folio basket account (PDA) (mut, not signer).
 /// * `user_pending_basket` - The user pending basket account (PDA) (init if needed, not signer).
+/// * `folio_pending_basket` - The folio pending basket account (PDA) (init if needed, not signer).
+/// * `mint_allowlist_entry` - The mint allowlist entry account of the user (PDA) (not mut, not signer).
+/// * `transfer_hook_registrar` - The transfer hook registrar account (PDA) of the folio admin program (not mut, not signer).
 ///
 /// * `remaining_accounts` - The remaining accounts will represent the tokens being added to the pending basket.
 ///
@@ -33,6 +40,10 @@ const EXPECTED_REMAINING_ACCOUNTS_LENGTH: usize = 3;
 /// - Token Mint (read)
 /// - Sender Token Account (needs to be owned by user) (mut)
 /// - Recipient Token Account (needs to be owned by folio) (this is expected to be the ATA and already exist, to save on compute) (mut)
+/// - Only if the mint has an active transfer hook:
+///     - Transfer Hook Program (read)
+///     - Extra Account Meta List (read)
+///     - Extra accounts of the transfer hook
 #[derive(Accounts)]
 pub struct AddToPendingBasket<'info> {
     pub system_program: Program<'info, System>,
@@ -58,6 +69,29 @@ pub struct AddToPendingBasket<'info> {
         bump
     )]
     pub user_pending_basket: AccountLoader<'info, UserPendingBasket>,
+
+    #[account(init_if_needed,
+        payer = user,
+        space = FolioPendingBasket::SIZE,
+        seeds = [FOLIO_PENDING_BASKET_SEEDS, folio.key().as_ref()],
+        bump
+    )]
+    pub folio_pending_basket: AccountLoader<'info, FolioPendingBasket>,
+
+    /// CHECK: Could be empty or could be set, only required to be set if the mint allowlist is enabled
+    #[account(
+        seeds = [MINT_ALLOWLIST_ENTRY_SEEDS, folio.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub mint_allowlist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: Could be empty or could be set, if set we use the transfer hook programs allowed in it, else none are allowed
+    #[account(
+        seeds = [TRANSFER_HOOK_REGISTRAR_SEEDS],
+        bump,
+        seeds::program = FOLIO_ADMIN_PROGRAM_ID,
+    )]
+    pub transfer_hook_registrar: UncheckedAccount<'info>,
     /*
     The remaining accounts need to match the order of amounts as parameter
 
@@ -65,6 +99,10 @@ pub struct AddToPendingBasket<'info> {
         - Token Mint (read)
         - Sender Token Account (needs to be owned by user) (mut)
         - Recipient Token Account (needs to be owned by folio) (this is expected to be the ATA and already exist, to save on compute) (mut)
+        - Only if the mint has an active transfer hook:
+            - Transfer Hook Program (read)
+            - Extra Account Meta List (read)
+            - Extra accounts of the transfer hook
      */
 }
 
@@ -73,6 +111,7 @@ impl AddToPendingBasket<'_> {
     ///
     /// # Checks
     /// * Folio is initialized.
+    /// * User is on the mint allowlist, if enabled.
     pub fn validate(&self) -> Result<()> {
         let folio = self.folio.load()?;
         folio.validate_folio(
@@ -82,6 +121,8 @@ impl AddToPendingBasket<'_> {
             Some(vec![FolioStatus::Initialized]),
         )?;
 
+        MintAllowlistEntry::validate_user_allowed(&folio, &self.mint_allowlist_entry)?;
+
         Ok(())
     }
 }
@@ -106,18 +147,11 @@ pub fn handler<'info>(
     let token_program_id = ctx.accounts.token_program.key();
     let user = ctx.accounts.user.to_account_info();
 
-    check_condition!(
-        remaining_accounts.len() % EXPECTED_REMAINING_ACCOUNTS_LENGTH == 0,
-        InvalidNumberOfRemainingAccounts
-    );
-
-    check_condition!(
-        remaining_accounts.len() / EXPECTED_REMAINING_ACCOUNTS_LENGTH == raw_amounts.len(),
-        InvalidNumberOfRemainingAccounts
-    );
-
     let mut added_mints: Vec<TokenAmount> = vec![];
 
+    let allowed_transfer_hook_programs =
+        TransferHookRegistrar::get_allowed_programs(&ctx.accounts.transfer_hook_registrar)?;
+
     for raw_amount in raw_amounts {
         let token_mint = next_account(
             &mut remaining_accounts_iter,
@@ -141,9 +175,23 @@ pub fn handler<'info>(
             InvalidRecipientTokenAccount
         );
 
+        // The transfer hook program of the mint could have been changed since it was added to the basket
+        check_condition!(
+            TokenUtil::is_transfer_hook_program_allowed(
+                token_mint,
+                &allowed_transfer_hook_programs
+            )?,
+            TransferHookProgramNotAllowed
+        );
+
+        let transfer_hook_accounts =
+            TokenUtil::next_transfer_hook_accounts(&mut remaining_accounts_iter, token_mint)?;
+
         // Get decimals from token mint
-        let data = token_mint.try_borrow_data()?;
-        let mint = Mint::try_deserialize(&mut &data[..])?;
+        let decimals = {
+            let data = token_mint.try_borrow_data()?;
+            Mint::try_deserialize(&mut &data[..])?.decimals
+        };
         let is_sender_ata_of_user_pending_basket = get_associated_token_address_with_program_id(
             &ctx.accounts.user_pending_basket.key(),
             token_mint.key,
@@ -163,33 +211,27 @@ pub fn handler<'info>(
             //
             // This ensures all dust ends up in user_pending_basket, making
             // transfer_from_user_pending_basket_ata rarely needed (though it exists as fallback).
-            let cpi_accounts = TransferChecked {
-                from: sender_token_account.to_account_info(),
-                to: recipient_token_account.to_account_info(),
-                authority: ctx.accounts.user_pending_basket.to_account_info(),
-                mint: token_mint.to_account_info(),
-            };
-
             let sender_account = TokenAccount::try_deserialize_unchecked(
                 &mut &sender_token_account.data.borrow()[..],
             )?;
 
             let raw_amount = sender_account.amount.max(raw_amount);
-            let cpi_program = ctx.accounts.token_program.to_account_info();
-
-            token_interface::transfer_checked(
-                CpiContext::new_with_signer(
-                    cpi_program,
-                    cpi_accounts,
-                    &[&[
-                        USER_PENDING_BASKET_SEEDS,
-                        ctx.accounts.folio.key().as_ref(),
-                        ctx.accounts.user.key().as_ref(),
-                        &[ctx.bumps.user_pending_basket],
-                    ]],
-                ),
+
+            TokenUtil::transfer_checked(
+                &ctx.accounts.token_program.to_account_info(),
+                sender_token_account,
+                token_mint,
+                recipient_token_account,
+                &ctx.accounts.user_pending_basket.to_account_info(),
+                &transfer_hook_accounts,
                 raw_amount,
-                mint.decimals,
+                decimals,
+                &[&[
+                    USER_PENDING_BASKET_SEEDS,
+                    ctx.accounts.folio.key().as_ref(),
+                    ctx.accounts.user.key().as_ref(),
+                    &[ctx.bumps.user_pending_basket],
+                ]],
             )?;
 
             // Closes the sender_token_account and transfers rent sol to user.
@@ -208,34 +250,47 @@ pub fn handler<'info>(
                 ]],
             ))?;
 
+            // Only account for what the folio received, net of the transfer fee (if any)
+            let raw_received_amount =
+                TokenUtil::get_amount_after_transfer_fee(token_mint, raw_amount)?;
+
             added_mints.push(TokenAmount {
                 mint: token_mint.key(),
-                amount_for_minting: raw_amount,
+                amount_for_minting: raw_received_amount,
                 amount_for_redeeming: 0,
             });
         } else {
-            let cpi_accounts = TransferChecked {
-                from: sender_token_account.to_account_info(),
-                to: recipient_token_account.to_account_info(),
-                authority: user.clone(),
-                mint: token_mint.to_account_info(),
-            };
-
-            let cpi_program = ctx.accounts.token_program.to_account_info();
-
-            token_interface::transfer_checked(
-                CpiContext::new(cpi_program, cpi_accounts),
+            TokenUtil::transfer_checked(
+                &ctx.accounts.token_program.to_account_info(),
+                sender_token_account,
+                token_mint,
+                recipient_token_account,
+                &user,
+                &transfer_hook_accounts,
                 raw_amount,
-                mint.decimals,
+                decimals,
+                &[],
             )?;
+            // Only account for what the folio received, net of the transfer fee (if any)
+            let raw_received_amount =
+                TokenUtil::get_amount_after_transfer_fee(token_mint, raw_amount)?;
+
             added_mints.push(TokenAmount {
                 mint: token_mint.key(),
-                amount_for_minting: raw_amount,
+                amount_for_minting: raw_received_amount,
                 amount_for_redeeming: 0,
             });
         }
     }
 
+    check_condition!(
+        remaining_accounts_iter.next().is_none(),
+        InvalidNumberOfRemainingAccounts
+    );
+
+    let tracked_token_amounts_before =
+        UserPendingBasket::get_tracked_token_amounts_of_account(&ctx.accounts.user_pending_basket)?;
+
     UserPendingBasket::process_init_if_needed(
         &mut ctx.accounts.user_pending_basket,
         ctx.bumps.user_pending_basket,
@@ -245,5 +300,15 @@ pub fn handler<'info>(
         true,
     )?;
 
+    let user_pending_basket = &mut ctx.accounts.user_pending_basket.load_mut()?;
+
+    FolioPendingBasket::process_user_pending_basket_change(
+        &ctx.accounts.folio_pending_basket,
+        ctx.bumps.folio_pending_basket,
+        &folio_key,
+        &tracked_token_amounts_before,
+        user_pending_basket,
+    )?;
+
     Ok(())
 }
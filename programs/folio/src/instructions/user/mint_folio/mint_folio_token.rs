@@ -1,5 +1,8 @@
-use crate::state::{Folio, FolioBasket, UserPendingBasket};
-use crate::utils::structs::FolioStatus;
+use crate::state::{
+    FeeDiscount, Folio, FolioBasket, FolioMintLimits, FolioPendingBasket, MintAllowlistEntry,
+    UserPendingBasket,
+};
+use crate::utils::structs::{FolioStatus, PauseFlag};
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface;
 use anchor_spl::{
@@ -9,7 +12,9 @@ use anchor_spl::{
 use folio_admin::state::DAOFeeConfig;
 use folio_admin::ID as FOLIO_ADMIN_PROGRAM_ID;
 use shared::constants::{
-    PendingBasketType, FOLIO_BASKET_SEEDS, FOLIO_FEE_CONFIG_SEEDS, USER_PENDING_BASKET_SEEDS,
+    PendingBasketType, FEE_DISCOUNT_SEEDS, FOLIO_BASKET_SEEDS, FOLIO_FEE_CONFIG_SEEDS,
+    FOLIO_MINT_LIMITS_SEEDS, FOLIO_PENDING_BASKET_SEEDS, MINT_ALLOWLIST_ENTRY_SEEDS,
+    USER_PENDING_BASKET_SEEDS,
 };
 use shared::errors::ErrorCode;
 use shared::{
@@ -30,7 +35,11 @@ use shared::{
 /// * `folio_token_mint` - The folio token mint account (PDA) (mut, not signer).
 /// * `folio_basket` - The folio basket account (PDA) (mut, not signer).
 /// * `user_pending_basket` - The user pending basket account (PDA) (mut, not signer).
+/// * `folio_pending_basket` - The folio pending basket account (PDA) (init if needed, not signer).
 /// * `user_folio_token_account` - The user folio token account (PDA) (mut, not signer).
+/// * `fee_discount` - The fee discount account of the user (PDA) (not mut, not signer).
+/// * `mint_allowlist_entry` - The mint allowlist entry account of the user (PDA) (not mut, not signer).
+/// * `folio_mint_limits` - The folio mint limits account (PDA) (not mut, not signer), only required if the folio has mint limits enabled.
 #[derive(Accounts)]
 pub struct MintFolioToken<'info> {
     pub system_program: Program<'info, System>,
@@ -45,7 +54,8 @@ pub struct MintFolioToken<'info> {
         bump,
         seeds::program = FOLIO_ADMIN_PROGRAM_ID,
     )]
-    pub dao_fee_config: Account<'info, DAOFeeConfig>,
+    /// CHECK: Could be a legacy account, loaded with the legacy layout support in the handler
+    pub dao_fee_config: UncheckedAccount<'info>,
 
     /// CHECK: Could be empty or could be set, if set we use that one, else we use dao fee config
     #[account(
@@ -73,12 +83,41 @@ pub struct MintFolioToken<'info> {
     )]
     pub user_pending_basket: AccountLoader<'info, UserPendingBasket>,
 
+    #[account(init_if_needed,
+        payer = user,
+        space = FolioPendingBasket::SIZE,
+        seeds = [FOLIO_PENDING_BASKET_SEEDS, folio.key().as_ref()],
+        bump
+    )]
+    pub folio_pending_basket: AccountLoader<'info, FolioPendingBasket>,
+
     #[account(mut,
         associated_token::mint = folio_token_mint,
         associated_token::authority = user,
         associated_token::token_program = folio_token_mint.to_account_info().owner,
     )]
     pub user_folio_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Could be empty or could be set, if set we apply the discount on the mint fee
+    #[account(
+        seeds = [FEE_DISCOUNT_SEEDS, folio.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub fee_discount: UncheckedAccount<'info>,
+
+    /// CHECK: Could be empty or could be set, only required to be set if the mint allowlist is enabled
+    #[account(
+        seeds = [MINT_ALLOWLIST_ENTRY_SEEDS, folio.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub mint_allowlist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: Only required if the folio has mint limits enabled, in which case we enforce them
+    #[account(
+        seeds = [FOLIO_MINT_LIMITS_SEEDS, folio.key().as_ref()],
+        bump,
+    )]
+    pub folio_mint_limits: Option<UncheckedAccount<'info>>,
 }
 
 impl MintFolioToken<'_> {
@@ -87,6 +126,8 @@ impl MintFolioToken<'_> {
     /// # Checks
     /// * Folio is valid PDA and initialized.
     /// * Folio token mint is the same as the one in the folio.
+    /// * Minting isn't paused.
+    /// * User is on the mint allowlist, if enabled.
     pub fn validate(&self, folio: &Folio) -> Result<()> {
         folio.validate_folio(
             &self.folio.key(),
@@ -95,6 +136,10 @@ impl MintFolioToken<'_> {
             Some(vec![FolioStatus::Initialized]),
         )?;
 
+        folio.validate_not_paused(PauseFlag::Mint)?;
+
+        MintAllowlistEntry::validate_user_allowed(folio, &self.mint_allowlist_entry)?;
+
         check_condition!(
             self.folio_token_mint.key() == folio.folio_token_mint,
             InvalidFolioTokenMint
@@ -137,13 +182,15 @@ pub fn handler<'info>(
 
     let folio_basket = &mut ctx.accounts.folio_basket.load_mut()?;
 
+    let tracked_token_amounts_before =
+        UserPendingBasket::get_tracked_token_amounts_of_account(&ctx.accounts.user_pending_basket)?;
+
     let token_amounts_user = &mut ctx.accounts.user_pending_basket.load_mut()?;
 
     // Get the related folio fees
-    let fee_details = ctx
-        .accounts
-        .dao_fee_config
-        .get_fee_details(&ctx.accounts.folio_fee_config)?;
+    let fee_details =
+        DAOFeeConfig::try_from_account_info_with_legacy_support(&ctx.accounts.dao_fee_config)?
+            .get_fee_details(&ctx.accounts.folio_fee_config)?;
 
     {
         let folio = &mut ctx.accounts.folio.load_mut()?;
@@ -153,6 +200,7 @@ pub fn handler<'info>(
             raw_shares,
             ctx.accounts.folio_token_mint.supply,
             folio_basket,
+            None,
             folio,
             PendingBasketType::MintProcess,
             current_time,
@@ -161,14 +209,36 @@ pub fn handler<'info>(
             fee_details.scaled_fee_floor,
             vec![],
         )?;
+
+        FolioPendingBasket::process_user_pending_basket_change(
+            &ctx.accounts.folio_pending_basket,
+            ctx.bumps.folio_pending_basket,
+            &ctx.accounts.folio.key(),
+            &tracked_token_amounts_before,
+            token_amounts_user,
+        )?;
+
+        // Checked after the poke, so that the pending fee shares are up to date
+        FolioMintLimits::get_folio_mint_limits(
+            folio,
+            ctx.accounts
+                .folio_mint_limits
+                .as_ref()
+                .map(|folio_mint_limits| folio_mint_limits.as_ref()),
+        )?
+        .validate_mint(folio, ctx.accounts.folio_token_mint.supply, raw_shares)?;
     }
 
+    // Get the discount on the mint fee if the user has one
+    let scaled_mint_fee_discount = FeeDiscount::get_scaled_discount(&ctx.accounts.fee_discount)?;
+
     // Mint folio token to user based on shares
     let fee_shares = ctx.accounts.folio.load_mut()?.calculate_fees_for_minting(
         raw_shares,
         fee_details.scaled_fee_numerator,
         fee_details.scaled_fee_denominator,
         fee_details.scaled_fee_floor,
+        scaled_mint_fee_discount,
     )?;
 
     let raw_folio_token_amount_to_mint = raw_shares
@@ -1,19 +1,21 @@
-use crate::state::{Folio, FolioBasket, UserPendingBasket};
+use crate::state::{Folio, FolioBasket, FolioPendingBasket, UserPendingBasket};
 use crate::utils::structs::TokenAmount;
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::get_associated_token_address_with_program_id,
-    token_interface::{self, Mint, TokenInterface, TransferChecked},
+    token_interface::{Mint, TokenInterface},
 };
 use shared::errors::ErrorCode;
 use shared::utils::account_util::next_account;
+use shared::utils::TokenUtil;
 use shared::{
     check_condition,
-    constants::{PendingBasketType, FOLIO_BASKET_SEEDS, FOLIO_SEEDS, USER_PENDING_BASKET_SEEDS},
+    constants::{
+        PendingBasketType, FOLIO_BASKET_SEEDS, FOLIO_PENDING_BASKET_SEEDS, FOLIO_SEEDS,
+        USER_PENDING_BASKET_SEEDS,
+    },
 };
 
-const EXPECTED_REMAINING_ACCOUNTS_LENGTH: usize = 3;
-
 /// Remove tokens from the user's pending basket.
 ///
 /// # Arguments
@@ -23,6 +25,7 @@ const EXPECTED_REMAINING_ACCOUNTS_LENGTH: usize = 3;
 /// * `folio` - The folio account (PDA) (not mut, not signer).
 /// * `folio_basket` - The folio basket account (PDA) (mut, not signer).
 /// * `user_pending_basket` - The user pending basket account (PDA) (mut, not signer).
+/// * `folio_pending_basket` - The folio pending basket account (PDA) (init if needed, not signer).
 ///
 /// * `remaining_accounts` - The remaining accounts will represent the tokens being removed from the pending basket.
 ///
@@ -31,6 +34,10 @@ const EXPECTED_REMAINING_ACCOUNTS_LENGTH: usize = 3;
 /// - Token Mint (read)
 /// - Sender Token Account (needs to be owned by folio) (mut)
 /// - Recipient Token Account (needs to be owned by user) (mut)
+/// - Only if the mint has an active transfer hook:
+///     - Transfer Hook Program (read)
+///     - Extra Account Meta List (read)
+///     - Extra accounts of the transfer hook
 #[derive(Accounts)]
 pub struct RemoveFromPendingBasket<'info> {
     pub system_program: Program<'info, System>,
@@ -53,6 +60,14 @@ pub struct RemoveFromPendingBasket<'info> {
         bump
     )]
     pub user_pending_basket: AccountLoader<'info, UserPendingBasket>,
+
+    #[account(init_if_needed,
+        payer = user,
+        space = FolioPendingBasket::SIZE,
+        seeds = [FOLIO_PENDING_BASKET_SEEDS, folio.key().as_ref()],
+        bump
+    )]
+    pub folio_pending_basket: AccountLoader<'info, FolioPendingBasket>,
     /*
     The remaining accounts need to match the order of amounts as parameter
 
@@ -60,6 +75,10 @@ pub struct RemoveFromPendingBasket<'info> {
         - Token Mint (read)
         - Sender Token Account (needs to be owned by folio) (mut)
         - Recipient Token Account (needs to be owned by user) (mut)
+        - Only if the mint has an active transfer hook:
+            - Transfer Hook Program (read)
+            - Extra Account Meta List (read)
+            - Extra accounts of the transfer hook
      */
 }
 
@@ -102,16 +121,6 @@ pub fn handler<'info>(
     let user_key = ctx.accounts.user.key();
     let token_program_id = ctx.accounts.token_program.key();
 
-    check_condition!(
-        remaining_accounts.len() % EXPECTED_REMAINING_ACCOUNTS_LENGTH == 0,
-        InvalidNumberOfRemainingAccounts
-    );
-
-    check_condition!(
-        remaining_accounts.len() / EXPECTED_REMAINING_ACCOUNTS_LENGTH == raw_amounts.len(),
-        InvalidNumberOfRemainingAccounts
-    );
-
     let mut removed_mints: Vec<TokenAmount> = vec![];
 
     for raw_amount in raw_amounts {
@@ -137,26 +146,28 @@ pub fn handler<'info>(
             InvalidRecipientTokenAccount
         );
 
+        let transfer_hook_accounts =
+            TokenUtil::next_transfer_hook_accounts(&mut remaining_accounts_iter, token_mint)?;
+
         // Get decimals from token mint
-        let data = token_mint.try_borrow_data()?;
-        let mint = Mint::try_deserialize(&mut &data[..])?;
-
-        let cpi_accounts = TransferChecked {
-            from: sender_token_account.to_account_info(),
-            to: recipient_token_account.to_account_info(),
-            authority: folio_info.clone(),
-            mint: token_mint.to_account_info(),
+        let decimals = {
+            let data = token_mint.try_borrow_data()?;
+            Mint::try_deserialize(&mut &data[..])?.decimals
         };
 
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-
         let folio_mint_key = folio.folio_token_mint;
         let signer_seeds = &[FOLIO_SEEDS, folio_mint_key.as_ref(), &[folio.bump]];
 
-        token_interface::transfer_checked(
-            CpiContext::new_with_signer(cpi_program, cpi_accounts, &[signer_seeds]),
+        TokenUtil::transfer_checked(
+            &ctx.accounts.token_program.to_account_info(),
+            sender_token_account,
+            token_mint,
+            recipient_token_account,
+            &folio_info,
+            &transfer_hook_accounts,
             raw_amount,
-            mint.decimals,
+            decimals,
+            &[signer_seeds],
         )?;
 
         removed_mints.push(TokenAmount {
@@ -166,10 +177,28 @@ pub fn handler<'info>(
         });
     }
 
-    ctx.accounts
-        .user_pending_basket
-        .load_mut()?
-        .remove_token_amounts_from_folio(&removed_mints, true, PendingBasketType::MintProcess)?;
+    check_condition!(
+        remaining_accounts_iter.next().is_none(),
+        InvalidNumberOfRemainingAccounts
+    );
+
+    let tracked_token_amounts_before =
+        UserPendingBasket::get_tracked_token_amounts_of_account(&ctx.accounts.user_pending_basket)?;
+
+    let user_pending_basket = &mut ctx.accounts.user_pending_basket.load_mut()?;
+    user_pending_basket.remove_token_amounts_from_folio(
+        &removed_mints,
+        true,
+        PendingBasketType::MintProcess,
+    )?;
+
+    FolioPendingBasket::process_user_pending_basket_change(
+        &ctx.accounts.folio_pending_basket,
+        ctx.bumps.folio_pending_basket,
+        &ctx.accounts.folio.key(),
+        &tracked_token_amounts_before,
+        user_pending_basket,
+    )?;
 
     Ok(())
 }
@@ -1,5 +1,5 @@
-use crate::state::{Folio, FolioBasket, UserPendingBasket};
-use crate::utils::structs::FolioStatus;
+use crate::state::{Folio, FolioBasket, FolioLegacyBasket, FolioPendingBasket, UserPendingBasket};
+use crate::utils::structs::{FolioStatus, PauseFlag};
 use crate::utils::MinimumOutForTokenAmount;
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface;
@@ -10,7 +10,8 @@ use anchor_spl::{
 use folio_admin::state::DAOFeeConfig;
 use folio_admin::ID as FOLIO_ADMIN_PROGRAM_ID;
 use shared::constants::{
-    DAO_FEE_CONFIG_SEEDS, FOLIO_BASKET_SEEDS, FOLIO_FEE_CONFIG_SEEDS, USER_PENDING_BASKET_SEEDS,
+    DAO_FEE_CONFIG_SEEDS, FOLIO_BASKET_SEEDS, FOLIO_FEE_CONFIG_SEEDS, FOLIO_LEGACY_BASKET_SEEDS,
+    FOLIO_PENDING_BASKET_SEEDS, USER_PENDING_BASKET_SEEDS,
 };
 use shared::errors::ErrorCode;
 use shared::{check_condition, constants::PendingBasketType};
@@ -18,6 +19,7 @@ use shared::{check_condition, constants::PendingBasketType};
 /// Burn folio tokens from a user's folio token account.
 ///
 /// # Arguments
+/// * `system_program` - The system program.
 /// * `token_program` - The token program.
 /// * `associated_token_program` - The associated token program.
 /// * `user` - The user account (mut, signer).
@@ -26,10 +28,13 @@ use shared::{check_condition, constants::PendingBasketType};
 /// * `folio` - The folio account (PDA) (mut, not signer).
 /// * `folio_token_mint` - The folio token mint account (PDA) (mut, not signer).
 /// * `folio_basket` - The folio basket account (PDA) (mut, not signer).
+/// * `folio_legacy_basket` - The folio legacy basket account (PDA) (mut, not signer).
 /// * `user_pending_basket` - The user pending basket account (PDA) (mut, not signer).
+/// * `folio_pending_basket` - The folio pending basket account (PDA) (init if needed, not signer).
 /// * `user_folio_token_account` - The user folio token account (PDA) (mut, not signer).
 #[derive(Accounts)]
 pub struct BurnFolioToken<'info> {
+    pub system_program: Program<'info, System>,
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
 
@@ -41,7 +46,8 @@ pub struct BurnFolioToken<'info> {
         bump,
         seeds::program = FOLIO_ADMIN_PROGRAM_ID,
     )]
-    pub dao_fee_config: Account<'info, DAOFeeConfig>,
+    /// CHECK: Could be a legacy account, loaded with the legacy layout support in the handler
+    pub dao_fee_config: UncheckedAccount<'info>,
 
     /// CHECK: Could be empty or could be set, if set we use that one, else we use dao fee config
     #[account(
@@ -63,12 +69,27 @@ pub struct BurnFolioToken<'info> {
     )]
     pub folio_basket: AccountLoader<'info, FolioBasket>,
 
+    /// CHECK: Could be empty or could be set, if set the legacy tokens are redeemed as well
+    #[account(mut,
+        seeds = [FOLIO_LEGACY_BASKET_SEEDS, folio.key().as_ref()],
+        bump
+    )]
+    pub folio_legacy_basket: UncheckedAccount<'info>,
+
     #[account(mut,
         seeds = [USER_PENDING_BASKET_SEEDS, folio.key().as_ref(), user.key().as_ref()],
         bump
     )]
     pub user_pending_basket: AccountLoader<'info, UserPendingBasket>,
 
+    #[account(init_if_needed,
+        payer = user,
+        space = FolioPendingBasket::SIZE,
+        seeds = [FOLIO_PENDING_BASKET_SEEDS, folio.key().as_ref()],
+        bump
+    )]
+    pub folio_pending_basket: AccountLoader<'info, FolioPendingBasket>,
+
     #[account(mut,
         associated_token::mint = folio_token_mint,
         associated_token::authority = user,
@@ -83,6 +104,7 @@ impl BurnFolioToken<'_> {
     /// # Checks
     /// * Folio is valid PDA and initialized or killed.
     /// * Folio token mint is the same as the one in the folio.
+    /// * Redeeming isn't paused.
     pub fn validate(&self, folio: &Folio) -> Result<()> {
         folio.validate_folio(
             &self.folio.key(),
@@ -91,6 +113,8 @@ impl BurnFolioToken<'_> {
             Some(vec![FolioStatus::Initialized, FolioStatus::Killed]),
         )?;
 
+        folio.validate_not_paused(PauseFlag::Redeem)?;
+
         check_condition!(
             self.folio_token_mint.key() == folio.folio_token_mint,
             InvalidFolioTokenMint
@@ -104,6 +128,8 @@ impl BurnFolioToken<'_> {
 /// This can only be called once atomically, as it will burn the folio token from the user's folio token account and requires ALL the token balances
 /// of the Folio's token accounts to be able to properly calculate the amount of shares the user can have.
 /// This action can't be rolled back.
+/// The tokens that have been removed from the basket but are still held for the holders (legacy tokens) are redeemed
+/// as well.
 ///
 /// # Arguments
 /// * `ctx` - The context of the instruction.
@@ -126,21 +152,29 @@ pub fn handler<'info>(
     }
 
     // Get the related folio fees
-    let fee_details = ctx
-        .accounts
-        .dao_fee_config
-        .get_fee_details(&ctx.accounts.folio_fee_config)?;
+    let fee_details =
+        DAOFeeConfig::try_from_account_info_with_legacy_support(&ctx.accounts.dao_fee_config)?
+            .get_fee_details(&ctx.accounts.folio_fee_config)?;
 
     {
+        let tracked_token_amounts_before = UserPendingBasket::get_tracked_token_amounts_of_account(
+            &ctx.accounts.user_pending_basket,
+        )?;
+
         let token_amounts_user = &mut ctx.accounts.user_pending_basket.load_mut()?;
         let folio = &mut ctx.accounts.folio.load_mut()?;
         let folio_basket = &mut ctx.accounts.folio_basket.load_mut()?;
 
+        let folio_legacy_basket_account_info = ctx.accounts.folio_legacy_basket.to_account_info();
+        let mut folio_legacy_basket =
+            FolioLegacyBasket::get_folio_legacy_basket(&folio_legacy_basket_account_info)?;
+
         // Folio is poked via the to_assets function, so don't need to poke it here
         token_amounts_user.to_assets(
             raw_shares,
             ctx.accounts.folio_token_mint.supply,
             folio_basket,
+            folio_legacy_basket.as_mut(),
             folio,
             PendingBasketType::RedeemProcess,
             current_time,
@@ -149,6 +183,30 @@ pub fn handler<'info>(
             fee_details.scaled_fee_floor,
             minimum_out_for_token_amounts,
         )?;
+
+        if let Some(folio_legacy_basket) = folio_legacy_basket.as_mut() {
+            // The shares are burned below, only the rest of the supply can still redeem the legacy tokens
+            let raw_remaining_folio_token_supply = ctx
+                .accounts
+                .folio_token_mint
+                .supply
+                .checked_sub(raw_shares)
+                .ok_or(ErrorCode::MathOverflow)?;
+
+            folio_legacy_basket.clear_empty_token_amounts(
+                raw_remaining_folio_token_supply,
+                &folio.get_total_supply(raw_remaining_folio_token_supply)?,
+            )?;
+            folio_legacy_basket.save(&folio_legacy_basket_account_info)?;
+        }
+
+        FolioPendingBasket::process_user_pending_basket_change(
+            &ctx.accounts.folio_pending_basket,
+            ctx.bumps.folio_pending_basket,
+            &ctx.accounts.folio.key(),
+            &tracked_token_amounts_before,
+            token_amounts_user,
+        )?;
     }
 
     // Burn folio token from user's folio token account
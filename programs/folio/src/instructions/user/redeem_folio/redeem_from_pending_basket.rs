@@ -2,18 +2,20 @@ use crate::utils::structs::TokenAmount;
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::get_associated_token_address_with_program_id,
-    token_interface::{self, Mint, TokenInterface, TransferChecked},
+    token_interface::{Mint, TokenInterface},
 };
 use shared::errors::ErrorCode;
 use shared::utils::account_util::next_account;
+use shared::utils::TokenUtil;
 use shared::{
     check_condition,
-    constants::{PendingBasketType, FOLIO_BASKET_SEEDS, FOLIO_SEEDS, USER_PENDING_BASKET_SEEDS},
+    constants::{
+        PendingBasketType, FOLIO_BASKET_SEEDS, FOLIO_PENDING_BASKET_SEEDS, FOLIO_SEEDS,
+        USER_PENDING_BASKET_SEEDS,
+    },
 };
 
-use crate::state::{Folio, FolioBasket, UserPendingBasket};
-
-const EXPECTED_REMAINING_ACCOUNTS_LENGTH: usize = 3;
+use crate::state::{Folio, FolioBasket, FolioPendingBasket, UserPendingBasket};
 
 /// Redeem tokens from the user's pending basket.
 ///
@@ -24,6 +26,7 @@ const EXPECTED_REMAINING_ACCOUNTS_LENGTH: usize = 3;
 /// * `user` - The user account (mut, signer).
 /// * `folio` - The folio account (PDA) (not mut, not signer).
 /// * `user_pending_basket` - The user pending basket account (PDA) (mut, not signer).
+/// * `folio_pending_basket` - The folio pending basket account (PDA) (init if needed, not signer).
 ///
 /// * `remaining_accounts` - The remaining accounts will represent the tokens being redeemed from the pending basket.
 ///
@@ -32,6 +35,10 @@ const EXPECTED_REMAINING_ACCOUNTS_LENGTH: usize = 3;
 /// - Token Mint (read)
 /// - Sender Token Account (needs to be owned by folio) (mut)
 /// - Recipient Token Account (needs to be owned by user) (mut)
+/// - Only if the mint has an active transfer hook:
+///     - Transfer Hook Program (read)
+///     - Extra Account Meta List (read)
+///     - Extra accounts of the transfer hook
 #[derive(Accounts)]
 pub struct RedeemFromPendingBasket<'info> {
     pub system_program: Program<'info, System>,
@@ -55,6 +62,14 @@ pub struct RedeemFromPendingBasket<'info> {
         bump
     )]
     pub user_pending_basket: AccountLoader<'info, UserPendingBasket>,
+
+    #[account(init_if_needed,
+        payer = user,
+        space = FolioPendingBasket::SIZE,
+        seeds = [FOLIO_PENDING_BASKET_SEEDS, folio.key().as_ref()],
+        bump
+    )]
+    pub folio_pending_basket: AccountLoader<'info, FolioPendingBasket>,
     /*
     The remaining accounts need to match the order of amounts as parameter
 
@@ -62,6 +77,10 @@ pub struct RedeemFromPendingBasket<'info> {
         - Token Mint (read)
         - Sender Token Account (needs to be owned by folio) (mut)
         - Recipient Token Account (needs to be owned by user) (this is expected to be the ATA and already exist, to save on compute) (mut)
+        - Only if the mint has an active transfer hook:
+            - Transfer Hook Program (read)
+            - Extra Account Meta List (read)
+            - Extra accounts of the transfer hook
      */
 }
 
@@ -106,16 +125,6 @@ pub fn handler<'info>(
     let folio = ctx.accounts.folio.to_account_info();
     let folio_data = ctx.accounts.folio.load()?;
 
-    check_condition!(
-        remaining_accounts.len() % EXPECTED_REMAINING_ACCOUNTS_LENGTH == 0,
-        InvalidNumberOfRemainingAccounts
-    );
-
-    check_condition!(
-        remaining_accounts.len() / EXPECTED_REMAINING_ACCOUNTS_LENGTH == raw_amounts.len(),
-        InvalidNumberOfRemainingAccounts
-    );
-
     let mut removed_mints: Vec<TokenAmount> = vec![];
 
     for raw_amount in raw_amounts {
@@ -141,29 +150,31 @@ pub fn handler<'info>(
             InvalidRecipientTokenAccount
         );
 
+        let transfer_hook_accounts =
+            TokenUtil::next_transfer_hook_accounts(&mut remaining_accounts_iter, token_mint)?;
+
         // Get decimals from token mint
-        let data = token_mint.try_borrow_data()?;
-        let mint = Mint::try_deserialize(&mut &data[..])?;
-
-        let cpi_accounts = TransferChecked {
-            from: sender_token_account.to_account_info(),
-            to: recipient_token_account.to_account_info(),
-            authority: folio.clone(),
-            mint: token_mint.to_account_info(),
+        let decimals = {
+            let data = token_mint.try_borrow_data()?;
+            Mint::try_deserialize(&mut &data[..])?.decimals
         };
 
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-
         let signer_seeds = &[
             FOLIO_SEEDS,
             folio_data.folio_token_mint.as_ref(),
             &[folio_data.bump],
         ];
 
-        token_interface::transfer_checked(
-            CpiContext::new_with_signer(cpi_program, cpi_accounts, &[&signer_seeds[..]]),
+        TokenUtil::transfer_checked(
+            &ctx.accounts.token_program.to_account_info(),
+            sender_token_account,
+            token_mint,
+            recipient_token_account,
+            &folio,
+            &transfer_hook_accounts,
             raw_amount,
-            mint.decimals,
+            decimals,
+            &[&signer_seeds[..]],
         )?;
 
         removed_mints.push(TokenAmount {
@@ -173,6 +184,14 @@ pub fn handler<'info>(
         });
     }
 
+    check_condition!(
+        remaining_accounts_iter.next().is_none(),
+        InvalidNumberOfRemainingAccounts
+    );
+
+    let tracked_token_amounts_before =
+        UserPendingBasket::get_tracked_token_amounts_of_account(&ctx.accounts.user_pending_basket)?;
+
     let user_pending_basket = &mut ctx.accounts.user_pending_basket.load_mut()?;
     user_pending_basket.remove_token_amounts_from_folio(
         &removed_mints,
@@ -180,5 +199,13 @@ pub fn handler<'info>(
         PendingBasketType::RedeemProcess,
     )?;
 
+    FolioPendingBasket::process_user_pending_basket_change(
+        &ctx.accounts.folio_pending_basket,
+        ctx.bumps.folio_pending_basket,
+        &ctx.accounts.folio.key(),
+        &tracked_token_amounts_before,
+        user_pending_basket,
+    )?;
+
     Ok(())
 }
@@ -20,13 +20,35 @@
 //!
 //! * `init_folio` - Initialize a folio.
 //! * `update_folio` - Update a folio.
+//! * `update_folio_branding` - Update the name, symbol, uri and mandate of a folio (brand manager or owner).
 //! * `init_or_update_actor` - Initialize or update an actor.
 //! * `remove_actor` - Remove an actor with the possibility to close the actor account.
+//! * `propose_owner` - Propose a new owner for a folio, which needs to be accepted by the new owner.
+//! * `accept_owner` - Accept the ownership of a folio, removing the owner role from the proposer.
+//! * `cancel_owner_proposal` - Cancel a pending ownership transfer of a folio.
+//! * `migrate_actor` - Migrate an actor created before the role expirations were added (permissionless).
+//! * `index_actor` - Index an actor created before the folio actors index existed (permissionless).
+//! * `upgrade_account` - Upgrade a folio, folio basket, rebalance or auction account to the current layout (permissionless).
+//! * `init_or_update_fee_discount` - Initialize or update a discount on the mint fee for a specific minter.
+//! * `remove_fee_discount` - Remove the discount on the mint fee of a specific minter.
+//! * `add_to_mint_allowlist` - Allow a user to mint a folio that has the mint allowlist enabled (allowlist manager or owner).
+//! * `remove_from_mint_allowlist` - Remove a user from the mint allowlist of a folio (allowlist manager or owner).
 //! * `add_to_basket` - Add tokens to the basket of a Folio, as well as mint the initial shares of the Folio.
+//! * `add_zero_balance_to_basket` - Add a token with a zero balance to the basket of a live Folio, to be bought through a rebalance.
 //! * `remove_from_basket` - Remove tokens from the basket of a Folio.
+//! * `execute_folio_action` - Execute an action on behalf of a folio (e.g. claim an airdrop), via a program allowed by the folio admin.
+//! * `sync_basket_balance` - Credit the surplus balance of a basket token to the basket, or sweep tokens that aren't in the basket to a recipient.
+//! * `track_user_pending_basket` - Track a user pending basket created before the folio pending basket existed (permissionless).
+//! * `set_pending_baskets_tracked` - Mark every user pending basket of a folio as tracked, once backfilled (admin only).
 //! * `kill_folio` - Kill a folio, which means prevent any further minting.
+//! * `unkill_folio` - Unkill a folio after a timelock since the kill (set in the unkill config of folio admin), if no migration was started.
+//! * `pause_folio` - Pause some actions of a folio (mint, redeem, auctions, fee distribution) during an incident (guardian or owner).
+//! * `unpause_folio` - Unpause some actions of a folio (guardian or owner).
 //! * `start_folio_migration` - Start a folio migration, which means moving the folio to a new version of the folio program.
+//! * `abort_folio_migration` - Abort a folio migration during its grace period, returning the folio token mint authorities to the folio.
 //! * `migrate_folio_tokens` - Migrate the tokens of a folio to the new version of the folio in the new folio program.
+//! * `migrate_user_pending_basket` - Migrate the pending basket of a user to the new version of the folio in the new folio program (permissionless).
+//! * `migrate_fee_distribution` - Migrate an undistributed fee distribution to the new version of the folio in the new folio program (permissionless).
 //! * `add_to_pending_basket` - Add tokens to the pending basket of a user trying to mint shares of a folio.
 //! * `remove_from_pending_basket` - Remove tokens from the pending basket of a user trying to mint shares of a folio.
 //! * `mint_folio_token` - Mint shares of the folio token to a user.
@@ -36,6 +58,8 @@
 //! * `poke_folio` - Poke a folio, which means update dao pending fee shares as well as fee recipients pending fee shares.
 //! * `distribute_fees` - Creates a fee distribution account that will be used to distribute fees to the fee recipients, also distributes the fee to the DAO.
 //! * `crank_fee_distribution` - Crank the fee distribution, which means distributing the fees to the fee recipients of a folio.
+//! * `close_fee_distribution` - Close a fee distribution after a grace period, sending the undistributed fees to the DAO.
+//! * `harvest_withheld_fees` - Harvest the transfer fees withheld in the folio's token account of a basket token to its mint.
 //! * `approve_auction` - Approve an auction.
 //! * `open_auction` - Open an auction.
 //! * `close_auction` - Close an auction.
@@ -131,6 +155,10 @@ pub mod folio {
         fee_recipients_to_add: Vec<FeeRecipient>,
         fee_recipients_to_remove: Vec<Pubkey>,
         mandate: Option<String>,
+        mint_allowlist_enabled: Option<bool>,
+        max_supply: Option<u64>,
+        max_mint_per_tx: Option<u64>,
+        fee_distribution_fallback: Option<FeeDistributionFallback>,
     ) -> Result<()> {
         update_folio::handler(
             ctx,
@@ -141,14 +169,29 @@ pub mod folio {
             fee_recipients_to_add,
             fee_recipients_to_remove,
             mandate,
+            mint_allowlist_enabled,
+            max_supply,
+            max_mint_per_tx,
+            fee_distribution_fallback,
         )
     }
 
+    pub fn update_folio_branding(
+        ctx: Context<UpdateFolioBranding>,
+        name: Option<String>,
+        symbol: Option<String>,
+        uri: Option<String>,
+        mandate: Option<String>,
+    ) -> Result<()> {
+        update_folio_branding::handler(ctx, name, symbol, uri, mandate)
+    }
+
     pub fn init_or_update_actor<'info>(
         ctx: Context<'_, '_, 'info, 'info, InitOrUpdateActor<'info>>,
         role: Role,
+        expires_at: Option<u64>,
     ) -> Result<()> {
-        init_or_update_actor::handler(ctx, role)
+        init_or_update_actor::handler(ctx, role, expires_at)
     }
 
     pub fn remove_actor<'info>(
@@ -159,6 +202,51 @@ pub mod folio {
         remove_actor::handler(ctx, role, close_actor)
     }
 
+    pub fn propose_owner(ctx: Context<ProposeOwner>) -> Result<()> {
+        propose_owner::handler(ctx)
+    }
+
+    pub fn accept_owner(ctx: Context<AcceptOwner>) -> Result<()> {
+        accept_owner::handler(ctx)
+    }
+
+    pub fn cancel_owner_proposal(ctx: Context<CancelOwnerProposal>) -> Result<()> {
+        cancel_owner_proposal::handler(ctx)
+    }
+
+    pub fn migrate_actor(ctx: Context<MigrateActor>) -> Result<()> {
+        migrate_actor::handler(ctx)
+    }
+
+    pub fn index_actor(ctx: Context<IndexActor>) -> Result<()> {
+        index_actor::handler(ctx)
+    }
+
+    pub fn upgrade_account(ctx: Context<UpgradeAccount>) -> Result<()> {
+        upgrade_account::handler(ctx)
+    }
+
+    pub fn init_or_update_fee_discount<'info>(
+        ctx: Context<'_, '_, 'info, 'info, InitOrUpdateFeeDiscount<'info>>,
+        scaled_discount: u128,
+    ) -> Result<()> {
+        init_or_update_fee_discount::handler(ctx, scaled_discount)
+    }
+
+    pub fn remove_fee_discount<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RemoveFeeDiscount<'info>>,
+    ) -> Result<()> {
+        remove_fee_discount::handler(ctx)
+    }
+
+    pub fn add_to_mint_allowlist(ctx: Context<AddToMintAllowlist>) -> Result<()> {
+        add_to_mint_allowlist::handler(ctx)
+    }
+
+    pub fn remove_from_mint_allowlist(ctx: Context<RemoveFromMintAllowlist>) -> Result<()> {
+        remove_from_mint_allowlist::handler(ctx)
+    }
+
     pub fn add_to_basket<'info>(
         ctx: Context<'_, '_, 'info, 'info, AddToBasket<'info>>,
         amounts: Vec<u64>,
@@ -167,24 +255,64 @@ pub mod folio {
         add_to_basket::handler(ctx, amounts, raw_initial_shares)
     }
 
+    pub fn add_zero_balance_to_basket(ctx: Context<AddZeroBalanceToBasket>) -> Result<()> {
+        add_zero_balance_to_basket::handler(ctx)
+    }
+
     pub fn remove_from_basket<'info>(
         ctx: Context<'_, '_, 'info, 'info, RemoveFromBasket<'info>>,
     ) -> Result<()> {
         remove_from_basket::handler(ctx)
     }
 
+    pub fn sync_basket_balance<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SyncBasketBalance<'info>>,
+    ) -> Result<()> {
+        sync_basket_balance::handler(ctx)
+    }
+
+    pub fn track_user_pending_basket(ctx: Context<TrackUserPendingBasket>) -> Result<()> {
+        track_user_pending_basket::handler(ctx)
+    }
+
+    pub fn set_pending_baskets_tracked(ctx: Context<SetPendingBasketsTracked>) -> Result<()> {
+        set_pending_baskets_tracked::handler(ctx)
+    }
+
+    pub fn execute_folio_action<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteFolioAction<'info>>,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        execute_folio_action::handler(ctx, data)
+    }
+
     pub fn kill_folio(ctx: Context<KillFolio>) -> Result<()> {
         kill_folio::handler(ctx)
     }
 
+    pub fn unkill_folio(ctx: Context<UnkillFolio>) -> Result<()> {
+        unkill_folio::handler(ctx)
+    }
+
+    pub fn pause_folio(ctx: Context<PauseFolio>, pause_flags: u8) -> Result<()> {
+        pause_folio::handler(ctx, pause_flags)
+    }
+
+    pub fn unpause_folio(ctx: Context<UnpauseFolio>, pause_flags: u8) -> Result<()> {
+        unpause_folio::handler(ctx, pause_flags)
+    }
+
     /*
     Migration functions
      */
     pub fn start_folio_migration<'info>(
         ctx: Context<'_, '_, 'info, 'info, StartFolioMigration<'info>>,
-        max_allowed_pending_fees: u128,
     ) -> Result<()> {
-        start_folio_migration::handler(ctx, max_allowed_pending_fees)
+        start_folio_migration::handler(ctx)
+    }
+
+    pub fn abort_folio_migration(ctx: Context<AbortFolioMigration>) -> Result<()> {
+        abort_folio_migration::handler(ctx)
     }
 
     pub fn migrate_folio_tokens<'info>(
@@ -193,6 +321,16 @@ pub mod folio {
         migrate_folio_tokens::handler(ctx)
     }
 
+    pub fn migrate_user_pending_basket<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MigrateUserPendingBasket<'info>>,
+    ) -> Result<()> {
+        migrate_user_pending_basket::handler(ctx)
+    }
+
+    pub fn migrate_fee_distribution(ctx: Context<MigrateFeeDistribution>) -> Result<()> {
+        migrate_fee_distribution::handler(ctx)
+    }
+
     /*
     User functions
      */
@@ -266,6 +404,16 @@ pub mod folio {
         crank_fee_distribution::handler(ctx, indices)
     }
 
+    pub fn close_fee_distribution<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseFeeDistribution<'info>>,
+    ) -> Result<()> {
+        close_fee_distribution::handler(ctx)
+    }
+
+    pub fn harvest_withheld_fees(ctx: Context<HarvestWithheldFees>) -> Result<()> {
+        harvest_withheld_fees::handler(ctx)
+    }
+
     /*
     Rebalancing and Auction functions
     */
@@ -275,6 +423,7 @@ pub mod folio {
         ttl: u64,
         prices_and_limits: Vec<RebalancePriceAndLimits>,
         all_rebalance_details_added: bool,
+        use_ui_amounts: bool,
     ) -> Result<()> {
         start_rebalance::handler(
             ctx,
@@ -282,6 +431,7 @@ pub mod folio {
             ttl,
             prices_and_limits,
             all_rebalance_details_added,
+            use_ui_amounts,
         )
     }
 
@@ -376,4 +526,56 @@ pub mod folio {
             Ok(())
         }
     }
+
+    /*
+    Development functions, used to show implementation for future folio program versions.
+     */
+    #[allow(unused_variables)]
+    pub fn update_user_pending_basket_in_new_folio_program<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateUserPendingBasketInNewFolioProgram<'info>>,
+    ) -> Result<()> {
+        #[cfg(feature = "test")]
+        {
+            update_user_pending_basket_in_new_folio_program::handler(ctx)
+        }
+        #[cfg(not(feature = "test"))]
+        {
+            Ok(())
+        }
+    }
+
+    /*
+    Development functions, used to show implementation for future folio program versions.
+     */
+    #[allow(unused_variables)]
+    pub fn create_fee_distribution_from_old_program<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateFeeDistributionFromOldProgram<'info>>,
+        index: u64,
+    ) -> Result<()> {
+        #[cfg(feature = "test")]
+        {
+            create_fee_distribution_from_old_program::handler(ctx, index)
+        }
+        #[cfg(not(feature = "test"))]
+        {
+            Ok(())
+        }
+    }
+
+    /*
+    Development functions, used to show implementation for future folio program versions.
+     */
+    #[allow(unused_variables)]
+    pub fn abort_folio_migration_from_old_program<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AbortFolioMigrationFromOldProgram<'info>>,
+    ) -> Result<()> {
+        #[cfg(feature = "test")]
+        {
+            abort_folio_migration_from_old_program::handler(ctx)
+        }
+        #[cfg(not(feature = "test"))]
+        {
+            Ok(())
+        }
+    }
 }
@@ -1,9 +1,12 @@
 use crate::utils::{
-    structs::FeeRecipient, FixedSizeString, FolioTokenBasket, PricesInAuction, RebalanceDetails,
+    structs::{ActorEntry, FeeRecipient},
+    FixedSizeString, FolioTokenAmount, FolioTokenBasket, PricesInAuction, RebalanceDetails,
     UserTokenBasket,
 };
 use anchor_lang::prelude::*;
-use shared::constants::MAX_FEE_RECIPIENTS;
+use shared::constants::{
+    MAX_FEE_RECIPIENTS, MAX_FOLIO_ACTORS, MAX_FOLIO_LEGACY_TOKEN_AMOUNTS, MAX_ROLES,
+};
 
 /// Actor is used to track permissions of different addresses on a folio. This is done via
 /// the role property and a bitwise operation.
@@ -24,6 +27,13 @@ pub struct Actor {
 
     /// The roles that the actor has.
     pub roles: u8,
+
+    /// The version of the actor account, 0 for actors created before the role expirations were added.
+    pub version: u8,
+
+    /// The expiration timestamp of each role, indexed by the bit position of the role in the bitmask.
+    /// 0 means the role doesn't expire.
+    pub role_expirations: [u64; MAX_ROLES],
 }
 
 impl Actor {
@@ -46,8 +56,26 @@ pub struct Folio {
 
     pub status: u8,
 
-    /// Padding for zero copy alignment
-    pub _padding: [u8; 6],
+    /// Bitmask of the paused actions, see `PauseFlag`
+    pub pause_flags: u8,
+
+    /// Whether minting is restricted to the users with a `MintAllowlistEntry`, 0 = disabled, 1 = enabled
+    pub mint_allowlist_enabled: u8,
+
+    /// Version of the account layout, 0 for folios created before the version header was added
+    pub version: u8,
+
+    /// Where the undelivered shares of a closed fee distribution go, see `FeeDistributionFallback`
+    pub fee_distribution_fallback: u8,
+
+    /// Whether every user pending basket of the folio is accounted for in the `FolioPendingBasket`, 0 = no, 1 = yes.
+    /// Set at creation for new folios, and by the admin for older folios once their pending baskets are backfilled
+    /// with `track_user_pending_basket`. Required to sync the basket balances.
+    pub pending_baskets_tracked: u8,
+
+    /// Whether the folio has mint limits set in its `FolioMintLimits` account, 0 = no, 1 = yes.
+    /// Takes over a padding byte, so the account only needs to be passed when minting if there are limits to enforce.
+    pub mint_limits_enabled: u8,
 
     pub initialized_at: u64,
 
@@ -133,8 +161,11 @@ impl Default for FeeRecipients {
 pub struct FeeDistribution {
     pub bump: u8,
 
+    /// Version of the account layout, 0 for fee distributions created before `created_at` was added
+    pub version: u8,
+
     /// Padding for zero copy alignment
-    pub _padding: [u8; 7],
+    pub _padding: [u8; 6],
 
     /// Index of the fee distribution, represents one distribute fee instruction call
     pub index: u64,
@@ -150,6 +181,12 @@ pub struct FeeDistribution {
     /// Represents the fee recipient account state at the time of the distribute fee instruction call.
     /// Default pubkey means the fee was distributed to that recipient.
     pub fee_recipients_state: [FeeRecipient; MAX_FEE_RECIPIENTS],
+
+    /// Timestamp of the distribute fee instruction call, used to know when undistributed fees can be reclaimed.
+    pub created_at: u64,
+
+    /// Padding for zero copy alignment
+    pub _padding_2: [u8; 8],
 }
 
 impl FeeDistribution {
@@ -160,12 +197,15 @@ impl Default for FeeDistribution {
     fn default() -> Self {
         Self {
             bump: 0,
-            _padding: [0; 7],
+            version: 0,
+            _padding: [0; 6],
             index: 0,
             folio: Pubkey::default(),
             cranker: Pubkey::default(),
             amount_to_distribute: 0,
             fee_recipients_state: [FeeRecipient::default(); MAX_FEE_RECIPIENTS],
+            created_at: 0,
+            _padding_2: [0; 8],
         }
     }
 }
@@ -184,8 +224,11 @@ impl Default for FeeDistribution {
 pub struct FolioBasket {
     pub bump: u8,
 
+    /// Version of the account layout, 0 for folio baskets created before the version header was added
+    pub version: u8,
+
     /// Padding for zero copy alignment
-    pub _padding: [u8; 7],
+    pub _padding: [u8; 6],
 
     /// Folio's pubkey
     pub folio: Pubkey,
@@ -199,6 +242,29 @@ impl FolioBasket {
     pub const SIZE: usize = 8 + FolioBasket::INIT_SPACE;
 }
 
+/// This is used to track the residual balances of the tokens removed from the basket, which are still owed to the
+/// holders. Those can't be used to mint, and are only distributed on redemption, pro rata of the total supply.
+/// Max of 10 tokens, so that the user's pending basket (110 tokens) can always hold both the basket and the legacy tokens.
+/// If the account doesn't exist, there are no legacy tokens.
+///
+/// PDA Seeds ["folio_legacy_basket", folio pubkey]
+#[account]
+#[derive(Default, InitSpace)]
+pub struct FolioLegacyBasket {
+    pub bump: u8,
+
+    /// The folio that the legacy basket is related to.
+    pub folio: Pubkey,
+
+    /// Raw amounts of the removed tokens still held for the holders.
+    /// Default pubkey means not set.
+    pub token_amounts: [FolioTokenAmount; MAX_FOLIO_LEGACY_TOKEN_AMOUNTS],
+}
+
+impl FolioLegacyBasket {
+    pub const SIZE: usize = 8 + FolioLegacyBasket::INIT_SPACE;
+}
+
 /// This is use to track the user's "pending" token amounts, for operations like minting or redeeming,
 /// because those operations are done in multiple steps. It directly relates to token_amounts in FolioBasket.
 ///
@@ -213,8 +279,12 @@ impl FolioBasket {
 pub struct UserPendingBasket {
     pub bump: u8,
 
+    /// Whether the amounts of the pending basket are accounted for in the `FolioPendingBasket`, 0 = no, 1 = yes.
+    /// Pending baskets created before the `FolioPendingBasket` existed are accounted for the first time they are updated.
+    pub is_tracked: u8,
+
     /// Padding for zero copy alignment
-    pub _padding: [u8; 7],
+    pub _padding: [u8; 6],
 
     /// User's wallet pubkey
     pub owner: Pubkey,
@@ -231,6 +301,32 @@ impl UserPendingBasket {
     pub const SIZE: usize = 8 + UserPendingBasket::INIT_SPACE;
 }
 
+/// This is used to track the sum of all the users' pending token amounts of a folio, since they are held in the
+/// folio's token accounts alongside the basket. It allows reconciling the folio's token balances with the basket.
+///
+/// zero_copy
+/// PDA Seeds ["folio_pending_basket", folio pubkey]
+#[account(zero_copy)]
+#[derive(InitSpace, Default)]
+pub struct FolioPendingBasket {
+    pub bump: u8,
+
+    /// Padding for zero copy alignment
+    pub _padding: [u8; 7],
+
+    /// Folio's pubkey
+    pub folio: Pubkey,
+
+    /// Represents the total amounts for minting as well as the total amounts for redeeming PER token, across
+    /// all the users' pending baskets.
+    /// Default pubkey means not set.
+    pub basket: UserTokenBasket,
+}
+
+impl FolioPendingBasket {
+    pub const SIZE: usize = 8 + FolioPendingBasket::INIT_SPACE;
+}
+
 /// This is used to track a rebalance's state. There is only 1 rebalance for a folio.
 /// The same account is updated if a new rebalance is started, and we close
 ///
@@ -253,8 +349,15 @@ pub struct Rebalance {
     /// In the last transaction, user need to pass `all_rebalance_details_added` to close the rebalance.
     pub all_rebalance_details_added: u8,
 
+    /// Version of the account layout, 0 for rebalances created before the version header was added
+    pub version: u8,
+
+    /// Whether the limits and prices of the rebalance are in UI amounts for the `ScaledUiAmount` and
+    /// `InterestBearingConfig` mints. Set when the rebalance is started and fixed for its whole duration.
+    pub use_ui_amounts: u8,
+
     /// Padding for zero copy alignment
-    pub _padding: [u8; 6],
+    pub _padding: [u8; 4],
 
     pub folio: Pubkey,
 
@@ -294,8 +397,11 @@ impl Rebalance {
 pub struct Auction {
     pub bump: u8,
 
+    /// Version of the account layout, 0 for auctions created before the version header was added
+    pub version: u8,
+
     /// Padding for zero copy alignment
-    pub _padding: [u8; 7],
+    pub _padding: [u8; 6],
 
     /// Auction id
     pub id: u64,
@@ -353,3 +459,181 @@ pub struct AuctionEnds {
 impl AuctionEnds {
     pub const SIZE: usize = 8 + AuctionEnds::INIT_SPACE;
 }
+
+/// FeeDiscount is used to reduce the mint fee for a specific minter of a folio (market makers, partner protocols, etc.).
+/// The DAO fee floor still applies, so the fee paid can never go below it.
+///
+/// PDA Seeds ["fee_discount", folio pubkey, minter pubkey]
+#[account]
+#[derive(Default, InitSpace)]
+pub struct FeeDiscount {
+    pub bump: u8,
+
+    /// The folio that the fee discount is related to.
+    pub folio: Pubkey,
+
+    /// The minter that benefits from the fee discount.
+    pub minter: Pubkey,
+
+    /// Discount applied on the mint fee, scaled in D18 (1e18 = 100% discount)
+    pub scaled_discount: u128,
+}
+
+impl FeeDiscount {
+    pub const SIZE: usize = 8 + FeeDiscount::INIT_SPACE;
+}
+
+/// KillRecord is used to track when a folio was killed, so that it can be unkilled after a timelock.
+/// Created when the folio is killed and closed when the folio is unkilled. Folios killed before kill records existed
+/// get theirs on the first `unkill_folio`, which starts their timelock.
+///
+/// PDA Seeds ["kill_record", folio pubkey]
+#[account]
+#[derive(Default, InitSpace)]
+pub struct KillRecord {
+    pub bump: u8,
+
+    /// The folio that was killed.
+    pub folio: Pubkey,
+
+    /// Timestamp of the kill, scaled in seconds
+    pub killed_at: u64,
+
+    /// Unkill timelock of the unkill config when the folio was killed, scaled in seconds
+    pub unkill_timelock: u64,
+}
+
+impl KillRecord {
+    pub const SIZE: usize = 8 + KillRecord::INIT_SPACE;
+}
+
+/// MigrationRecord is used to track when a folio migration was started, so that it can be aborted during a grace
+/// period. Created when the migration is started and closed if the migration is aborted.
+///
+/// PDA Seeds ["migration_record", folio pubkey]
+#[account]
+#[derive(Default, InitSpace)]
+pub struct MigrationRecord {
+    pub bump: u8,
+
+    /// The folio that is being migrated.
+    pub folio: Pubkey,
+
+    /// The folio in the new folio program.
+    pub new_folio: Pubkey,
+
+    /// The status of the folio before the migration, restored if the migration is aborted.
+    pub previous_status: u8,
+
+    /// Timestamp of the start of the migration, scaled in seconds
+    pub started_at: u64,
+}
+
+impl MigrationRecord {
+    pub const SIZE: usize = 8 + MigrationRecord::INIT_SPACE;
+}
+
+/// PendingOwner is used to track an ownership transfer of a folio that was proposed by an owner,
+/// and that needs to be accepted by the new owner.
+/// Created when the ownership transfer is proposed and closed when it is accepted or cancelled.
+///
+/// PDA Seeds ["pending_owner", folio pubkey]
+#[account]
+#[derive(Default, InitSpace)]
+pub struct PendingOwner {
+    pub bump: u8,
+
+    /// The folio the ownership transfer is related to.
+    pub folio: Pubkey,
+
+    /// The owner that proposed the ownership transfer, and that will lose the owner role on acceptance.
+    pub proposed_by: Pubkey,
+
+    /// The proposed new owner, that needs to sign to accept the ownership transfer.
+    pub new_owner: Pubkey,
+
+    /// Timestamp of the proposal, scaled in seconds
+    pub proposed_at: u64,
+}
+
+impl PendingOwner {
+    pub const SIZE: usize = 8 + PendingOwner::INIT_SPACE;
+}
+
+/// FolioActors is an index of the actors of a folio and their roles, so that every role holder
+/// can be enumerated without having to scan the program accounts.
+/// Kept in sync by the instructions that update the roles of an actor. Actors created before the index existed are
+/// added to it with the permissionless `index_actor`.
+///
+/// At most 32 actors are indexed. Once the index is full, the roles of the other actors are still updated, they are
+/// just not indexed and `is_incomplete` is set.
+///
+/// PDA Seeds ["folio_actors", folio pubkey]
+#[account]
+#[derive(Default, InitSpace)]
+pub struct FolioActors {
+    pub bump: u8,
+
+    /// The folio the actors belong to.
+    pub folio: Pubkey,
+
+    /// Set once an actor couldn't be indexed because the index was full, meaning the index might not list every
+    /// role holder anymore. Never unset.
+    pub is_incomplete: bool,
+
+    /// Max 32 actors, default pubkey means not set
+    pub actors: [ActorEntry; MAX_FOLIO_ACTORS],
+}
+
+impl FolioActors {
+    pub const SIZE: usize = 8 + FolioActors::INIT_SPACE;
+}
+
+/// MintAllowlistEntry is used to allow a user to mint a folio that has the mint allowlist enabled.
+/// Managed by the allowlist manager of the folio.
+///
+/// PDA Seeds ["mint_allowlist_entry", folio pubkey, user pubkey]
+#[account]
+#[derive(Default, InitSpace)]
+pub struct MintAllowlistEntry {
+    pub bump: u8,
+
+    /// The folio that the entry is related to.
+    pub folio: Pubkey,
+
+    /// The user that is allowed to mint.
+    pub user: Pubkey,
+}
+
+impl MintAllowlistEntry {
+    pub const SIZE: usize = 8 + MintAllowlistEntry::INIT_SPACE;
+}
+
+/// FolioMintLimits is used to bound how much a folio can grow, via a supply cap and a per transaction mint limit.
+/// If the account doesn't exist, or none of its limits are set, there are no limits.
+///
+/// Kept outside of the folio account on purpose. The folio is zero copy and has no padding left for two u64, so the
+/// limits would need a new folio version (see `VersionedAccount`), and every instruction loading a folio would then
+/// fail on the folios not upgraded yet via `upgrade_account`. An optional account keeps the existing folios working
+/// as they are, and only the instructions that enforce the limits need to load it, when `mint_limits_enabled` is set
+/// on the folio.
+///
+/// PDA Seeds ["folio_mint_limits", folio pubkey]
+#[account]
+#[derive(Default, InitSpace)]
+pub struct FolioMintLimits {
+    pub bump: u8,
+
+    /// The folio that the mint limits are related to.
+    pub folio: Pubkey,
+
+    /// Maximum total supply of the folio, including the pending fee shares (D9), 0 means no cap
+    pub max_supply: u64,
+
+    /// Maximum amount of shares that can be minted in a single transaction (D9), 0 means no limit
+    pub max_mint_per_tx: u64,
+}
+
+impl FolioMintLimits {
+    pub const SIZE: usize = 8 + FolioMintLimits::INIT_SPACE;
+}
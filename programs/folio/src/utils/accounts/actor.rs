@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 use shared::check_condition;
+use shared::constants::ACTOR_VERSION;
 
 use crate::state::Actor;
+use crate::utils::structs::Role;
 use shared::errors::ErrorCode;
 
 impl Actor {
@@ -29,15 +31,84 @@ impl Actor {
         self.authority = *authority;
         self.folio = *folio;
         self.roles = 0;
+        self.version = ACTOR_VERSION;
+        self.role_expirations = Default::default();
 
         Ok(())
     }
 
+    /// Add a role to the actor, with an optional expiration.
+    /// Granting a role that the actor already has overrides its expiration.
+    ///
+    /// # Arguments
+    /// * `role` - The role to add.
+    /// * `expires_at` - The timestamp at which the role expires, None if the role doesn't expire.
+    /// * `current_time` - The current time.
+    pub fn add_role(
+        &mut self,
+        role: Role,
+        expires_at: Option<u64>,
+        current_time: u64,
+    ) -> Result<()> {
+        if let Some(expires_at) = expires_at {
+            check_condition!(expires_at > current_time, InvalidRoleExpiration);
+        }
+
+        Role::add_role(&mut self.roles, role);
+        self.role_expirations[role.index()] = expires_at.unwrap_or(0);
+
+        Ok(())
+    }
+
+    /// Remove a role from the actor, as well as its expiration.
+    ///
+    /// # Arguments
+    /// * `role` - The role to remove.
+    pub fn remove_role(&mut self, role: Role) {
+        Role::remove_role(&mut self.roles, role);
+        self.role_expirations[role.index()] = 0;
+    }
+
+    /// Check if the actor has a role that isn't expired.
+    ///
+    /// # Arguments
+    /// * `role` - The role to check.
+    /// * `current_time` - The current time.
+    ///
+    /// Returns true if the actor has the role and it hasn't expired.
+    pub fn has_active_role(&self, role: Role, current_time: u64) -> bool {
+        if !Role::has_role(self.roles, role) {
+            return false;
+        }
+
+        let expires_at = self.role_expirations[role.index()];
+
+        expires_at == 0 || current_time < expires_at
+    }
+
     /// Reset the actor.
-    /// This will set the roles to 0, and the authority and folio to the default pubkey.
+    /// This will set the roles and their expirations to 0, and the authority and folio to the default pubkey.
     pub fn reset(&mut self) {
         self.roles = 0;
+        self.role_expirations = Default::default();
         self.authority = Pubkey::default();
         self.folio = Pubkey::default();
     }
+
+    /// Deserialize an Actor account, supporting the legacy layout (before the role expirations were added).
+    /// Legacy accounts are zero-padded, meaning none of their roles expire.
+    ///
+    /// # Arguments
+    /// * `data` - The data of the Actor account.
+    ///
+    /// Returns the deserialized Actor.
+    pub fn try_deserialize_with_legacy_support(data: &[u8]) -> Result<Actor> {
+        let mut padded_data = data.to_vec();
+
+        if padded_data.len() < Actor::SIZE {
+            padded_data.resize(Actor::SIZE, 0);
+        }
+
+        Actor::try_deserialize(&mut &padded_data[..])
+    }
 }
@@ -69,11 +69,109 @@ impl Auction {
         Ok(())
     }
 
+    /// Convert a limit of the rebalance to a limit in raw token amounts.
+    /// For mints with a UI amount multiplier (`ScaledUiAmount` or `InterestBearingConfig`), the limits of the rebalance
+    /// are in UI amounts, D18{uiTok/share}, otherwise they are already in D18{tok/share}.
+    /// D18{tok/share} = D18{uiTok/share} * D18 / D18{uiTok/tok}
+    ///
+    /// # Arguments
+    /// * `scaled_limit` - The limit of the rebalance (D18).
+    /// * `scaled_ui_multiplier` - The UI amount multiplier of the mint, if any (D18{uiTok/tok}).
+    /// * `rounding` - The rounding mode.
+    ///
+    /// Returns the limit in D18{tok/share}.
+    pub fn get_raw_limit(
+        scaled_limit: u128,
+        scaled_ui_multiplier: Option<u128>,
+        rounding: Rounding,
+    ) -> Result<u128> {
+        let Some(scaled_ui_multiplier) = scaled_ui_multiplier else {
+            return Ok(scaled_limit);
+        };
+
+        Auction::mul_div(
+            scaled_limit,
+            Decimal::ONE_E18.to_scaled(Rounding::Floor)?,
+            scaled_ui_multiplier,
+            rounding,
+        )
+    }
+
+    /// Convert a price of the auction to a price in raw token amounts.
+    /// For mints with a UI amount multiplier (`ScaledUiAmount` or `InterestBearingConfig`), the prices are in UI amounts.
+    /// D18{buyTok/sellTok} = D18{uiBuyTok/uiSellTok} * D18{uiSellTok/sellTok} / D18{uiBuyTok/buyTok}
+    ///
+    /// # Arguments
+    /// * `scaled_price` - The price of the auction (D18).
+    /// * `scaled_sell_ui_multiplier` - The UI amount multiplier of the sell mint, if any (D18{uiSellTok/sellTok}).
+    /// * `scaled_buy_ui_multiplier` - The UI amount multiplier of the buy mint, if any (D18{uiBuyTok/buyTok}).
+    ///
+    /// Returns the price in D18{buyTok/sellTok}, rounded up.
+    pub fn get_raw_price(
+        scaled_price: u128,
+        scaled_sell_ui_multiplier: Option<u128>,
+        scaled_buy_ui_multiplier: Option<u128>,
+    ) -> Result<u128> {
+        if scaled_sell_ui_multiplier.is_none() && scaled_buy_ui_multiplier.is_none() {
+            return Ok(scaled_price);
+        }
+
+        let scaled_one = Decimal::ONE_E18.to_scaled(Rounding::Floor)?;
+
+        Auction::mul_div(
+            scaled_price,
+            scaled_sell_ui_multiplier.unwrap_or(scaled_one),
+            scaled_buy_ui_multiplier.unwrap_or(scaled_one),
+            Rounding::Ceiling,
+        )
+    }
+
+    /// Get the sell and buy limits of the auction in raw token amounts.
+    ///
+    /// # Arguments
+    /// * `scaled_sell_ui_multiplier` - The UI amount multiplier of the sell mint, if any (D18{uiSellTok/sellTok}).
+    /// * `scaled_buy_ui_multiplier` - The UI amount multiplier of the buy mint, if any (D18{uiBuyTok/buyTok}).
+    ///
+    /// Returns (D18{sellTok/share}, D18{buyTok/share}).
+    pub fn get_raw_limits(
+        &self,
+        scaled_sell_ui_multiplier: Option<u128>,
+        scaled_buy_ui_multiplier: Option<u128>,
+    ) -> Result<(u128, u128)> {
+        Ok((
+            Auction::get_raw_limit(
+                self.sell_limit,
+                scaled_sell_ui_multiplier,
+                Rounding::Ceiling,
+            )?,
+            Auction::get_raw_limit(self.buy_limit, scaled_buy_ui_multiplier, Rounding::Floor)?,
+        ))
+    }
+
+    /// a * b / c, with the given rounding.
+    fn mul_div(a: u128, b: u128, c: u128, rounding: Rounding) -> Result<u128> {
+        check_condition!(c != 0, InvalidUiAmountMultiplier);
+
+        let numerator = Decimal::from_scaled(a).mul(&Decimal::from_scaled(b))?;
+        let numerator = match rounding {
+            Rounding::Floor => numerator,
+            Rounding::Ceiling => numerator.add(&Decimal::from_scaled(c - 1))?,
+        };
+
+        numerator
+            .div(&Decimal::from_scaled(c))?
+            .to_scaled(Rounding::Floor)
+    }
+
     /// Open the auction.
+    /// The limits and prices of the auction stay in the units of the rebalance, and are converted to raw token amounts
+    /// with the UI amount multipliers of the mints when compared to the basket balances.
     ///
     /// # Arguments
     /// * `folio` - The folio.
     /// * `current_time` - The current on-chain time (seconds).
+    /// * `scaled_sell_ui_multiplier` - The UI amount multiplier of the sell mint, if any (D18{uiSellTok/sellTok}).
+    /// * `scaled_buy_ui_multiplier` - The UI amount multiplier of the buy mint, if any (D18{uiBuyTok/buyTok}).
     pub fn open_auction(
         &mut self,
         folio: &mut RefMut<'_, Folio>,
@@ -89,6 +187,8 @@ impl Auction {
         // When the auction is launched permission less, config is always None.
         config: Option<OpenAuctionConfig>,
         is_permissionless: bool,
+        scaled_sell_ui_multiplier: Option<u128>,
+        scaled_buy_ui_multiplier: Option<u128>,
     ) -> Result<()> {
         // Do not open auctions that have timed out from ttl
         check_condition!(current_time <= rebalance.available_until, AuctionTimeout);
@@ -156,9 +256,23 @@ impl Auction {
         // Confirm sell is surplus and buy is deficit
         {
             let scaled_folio_token_total_supply = folio.get_total_supply(raw_folio_token_supply)?;
+
+            // D18{sellTok/share}
+            let raw_spot_sell_limit = Auction::get_raw_limit(
+                auction_spot_sell_limit,
+                scaled_sell_ui_multiplier,
+                Rounding::Ceiling,
+            )?;
+            // D18{buyTok/share}
+            let raw_spot_buy_limit = Auction::get_raw_limit(
+                auction_spot_buy_limit,
+                scaled_buy_ui_multiplier,
+                Rounding::Floor,
+            )?;
+
             // {sellTok} = D18{sellTok/share} * {share}{D9} / D18
             let sell_tokens = scaled_folio_token_total_supply
-                .mul(&Decimal::from_scaled(auction_spot_sell_limit))?
+                .mul(&Decimal::from_scaled(raw_spot_sell_limit))?
                 .div(&Decimal::ONE_E18)?
                 .to_token_amount(Rounding::Ceiling)?
                 .0;
@@ -169,7 +283,7 @@ impl Auction {
 
             // Confirm buy is deficit
             let buy_tokens = scaled_folio_token_total_supply
-                .mul(&Decimal::from_scaled(auction_spot_buy_limit))?
+                .mul(&Decimal::from_scaled(raw_spot_buy_limit))?
                 .div(&Decimal::ONE_E18)?
                 .to_token_amount(Rounding::Floor)?
                 .0;
@@ -346,6 +460,7 @@ impl Auction {
     }
 
     /// return (max_sell_amount, bid_amount, price D18{buyTok/sellTok}, scaled_folio_token_total_supply)
+    /// The UI amount multipliers of the mints (D18{uiTok/tok}) convert the limits and price of the auction to raw token amounts.
     pub fn get_bid(
         &self,
         folio: &Folio,
@@ -354,16 +469,25 @@ impl Auction {
         current_time: u64,
         raw_sell_amount: u64,
         raw_max_buy_amount: u64,
+        scaled_sell_ui_multiplier: Option<u128>,
+        scaled_buy_ui_multiplier: Option<u128>,
     ) -> Result<(u64, u64, Decimal, Decimal)> {
         // D18{buyTok/sellTok}
-        let scaled_price = Decimal::from_scaled(self.get_price(current_time)?);
+        let scaled_price = Decimal::from_scaled(Auction::get_raw_price(
+            self.get_price(current_time)?,
+            scaled_sell_ui_multiplier,
+            scaled_buy_ui_multiplier,
+        )?);
+
+        let (raw_sell_limit, raw_buy_limit) =
+            self.get_raw_limits(scaled_sell_ui_multiplier, scaled_buy_ui_multiplier)?;
 
         // totalSupply inflates over time due to TVL fee, causing buyLimits/sellLimits to be slightly stale
         let scaled_folio_token_total_supply = folio.get_total_supply(raw_folio_token_supply)?;
 
         let raw_sell_balance = folio_basket.get_token_amount_in_folio_basket(&self.sell_mint)?;
         // {sellTok} = (D18{sellTok/share} * {share}D9 / D18) / D9
-        let raw_limit_sell_balance = Decimal::from_scaled(self.sell_limit)
+        let raw_limit_sell_balance = Decimal::from_scaled(raw_sell_limit)
             .mul(&scaled_folio_token_total_supply)?
             .div(&Decimal::ONE_E18)?
             .to_token_amount(Rounding::Ceiling)?
@@ -374,7 +498,7 @@ impl Auction {
         let raw_buy_balance = folio_basket.get_token_amount_in_folio_basket_or_zero(&self.buy_mint);
 
         //  D18{buyTok/share} = ( D18{buyTok/share} * {share}D9 / D18 ) / D9
-        let buy_limit_balance = Decimal::from_scaled(self.buy_limit)
+        let buy_limit_balance = Decimal::from_scaled(raw_buy_limit)
             .mul(&scaled_folio_token_total_supply)?
             .div(&Decimal::ONE_E18)?
             .to_token_amount(Rounding::Floor)?
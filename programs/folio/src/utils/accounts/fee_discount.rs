@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use shared::check_condition;
+use shared::constants::MAX_FEE_DISCOUNT;
+
+use crate::state::FeeDiscount;
+use shared::errors::ErrorCode;
+
+impl FeeDiscount {
+    /// Process the init if needed, meaning we initialize the account if it's not initialized yet and if it already is
+    /// we check if the bump is correct.
+    ///
+    /// # Arguments
+    /// * `account_bump` - The bump of the account.
+    /// * `context_bump` - The bump of the account provided in the anchor context.
+    /// * `folio` - The folio the fee discount belongs to.
+    /// * `minter` - The minter that benefits from the fee discount.
+    pub fn process_init_if_needed(
+        &mut self,
+        account_bump: u8,
+        context_bump: u8,
+        folio: &Pubkey,
+        minter: &Pubkey,
+    ) -> Result<()> {
+        if account_bump != 0 {
+            check_condition!(account_bump == context_bump, InvalidBump);
+            return Ok(());
+        }
+
+        self.bump = context_bump;
+        self.folio = *folio;
+        self.minter = *minter;
+        self.scaled_discount = 0;
+
+        Ok(())
+    }
+
+    /// Set the discount applied on the mint fee.
+    ///
+    /// # Arguments
+    /// * `scaled_discount` - The discount applied on the mint fee, scaled in D18.
+    pub fn set_discount(&mut self, scaled_discount: u128) -> Result<()> {
+        check_condition!(scaled_discount <= MAX_FEE_DISCOUNT, InvalidFeeDiscount);
+
+        self.scaled_discount = scaled_discount;
+
+        Ok(())
+    }
+
+    /// Get the discount applied on the mint fee for a minter.
+    /// If the fee discount account isn't initialized, there is no discount.
+    ///
+    /// # Arguments
+    /// * `fee_discount` - The account info of the FeeDiscount account.
+    ///
+    /// Returns the discount applied on the mint fee, scaled in D18.
+    #[cfg(not(tarpaulin_include))]
+    pub fn get_scaled_discount(fee_discount: &AccountInfo) -> Result<u128> {
+        if fee_discount.data_is_empty() || *fee_discount.owner != crate::ID {
+            return Ok(0);
+        }
+
+        let fee_discount_data = fee_discount.try_borrow_data()?;
+
+        let fee_discount = FeeDiscount::try_deserialize(&mut &fee_discount_data[..])?;
+
+        Ok(fee_discount.scaled_discount)
+    }
+}
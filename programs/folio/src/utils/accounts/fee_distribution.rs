@@ -1,8 +1,22 @@
-use anchor_lang::prelude::Pubkey;
+use anchor_lang::prelude::*;
+use shared::constants::{FEE_DISTRIBUTION_CLOSE_GRACE_PERIOD, MAX_FEE_RECIPIENTS_PORTION};
+use shared::errors::ErrorCode;
+use shared::utils::{Decimal, Rounding};
 
 use crate::state::FeeDistribution;
 
 impl FeeDistribution {
+    /// Carry over a fee distribution of the old folio program during a migration.
+    /// The old fee distribution is upgraded before, so its `created_at` is set and the grace period isn't reset.
+    ///
+    /// # Arguments
+    /// * `old_fee_distribution` - The fee distribution in the old folio program.
+    pub fn init_from_old_fee_distribution(&mut self, old_fee_distribution: &FeeDistribution) {
+        self.amount_to_distribute = old_fee_distribution.amount_to_distribute;
+        self.fee_recipients_state = old_fee_distribution.fee_recipients_state;
+        self.created_at = old_fee_distribution.created_at;
+    }
+
     /// Check if the fee distribution is fully distributed.
     ///
     /// # Returns
@@ -12,4 +26,47 @@ impl FeeDistribution {
             .iter()
             .all(|f| f.recipient == Pubkey::default())
     }
+
+    /// Check if the fee distribution can be closed, meaning it's either fully distributed or the grace period
+    /// for the fee recipients to be cranked has passed.
+    ///
+    /// # Arguments
+    /// * `current_time` - The current time (seconds).
+    ///
+    /// # Returns
+    /// * `bool` - True if the fee distribution can be closed, false otherwise.
+    pub fn is_closable(&self, current_time: u64) -> bool {
+        self.is_fully_distributed()
+            || current_time
+                >= self
+                    .created_at
+                    .saturating_add(FEE_DISTRIBUTION_CLOSE_GRACE_PERIOD)
+    }
+
+    /// Get the raw amount of shares that hasn't been distributed to the fee recipients yet.
+    /// Computed per recipient the same way as the crank, so the sum matches what would have been minted.
+    ///
+    /// # Returns
+    /// * `u64` - The raw amount of undistributed shares.
+    pub fn get_raw_undistributed_amount(&self) -> Result<u64> {
+        let mut raw_undistributed_amount: u64 = 0;
+
+        for fee_recipient in self
+            .fee_recipients_state
+            .iter()
+            .filter(|f| f.recipient != Pubkey::default())
+        {
+            let raw_amount = Decimal::from_scaled(self.amount_to_distribute)
+                .mul(&Decimal::from_scaled(fee_recipient.portion))?
+                .div(&Decimal::from_scaled(MAX_FEE_RECIPIENTS_PORTION))?
+                .to_token_amount(Rounding::Floor)?
+                .0;
+
+            raw_undistributed_amount = raw_undistributed_amount
+                .checked_add(raw_amount)
+                .ok_or(ErrorCode::MathOverflow)?;
+        }
+
+        Ok(raw_undistributed_amount)
+    }
 }
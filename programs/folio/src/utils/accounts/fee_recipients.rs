@@ -124,6 +124,17 @@ impl FeeRecipients {
         Ok(())
     }
 
+    /// Carry over the fee recipients of the old folio program during a migration.
+    /// The distribution index is kept, so the fee distributions migrated from the old folio program don't collide
+    /// with the new ones.
+    ///
+    /// # Arguments
+    /// * `old_fee_recipients` - The fee recipients in the old folio program.
+    pub fn init_from_old_fee_recipients(&mut self, old_fee_recipients: &FeeRecipients) {
+        self.distribution_index = old_fee_recipients.distribution_index;
+        self.fee_recipients = old_fee_recipients.fee_recipients;
+    }
+
     /// Check if the fee recipients are empty.
     ///
     /// # Returns
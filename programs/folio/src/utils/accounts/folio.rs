@@ -1,4 +1,4 @@
-use crate::utils::structs::{FolioStatus, Role};
+use crate::utils::structs::{FeeDistributionFallback, FolioStatus, PauseFlag, Role};
 use crate::{
     events::TVLFeeSet,
     state::{Actor, Folio},
@@ -8,7 +8,7 @@ use shared::constants::{DAY_IN_SECONDS, YEAR_IN_SECONDS};
 use shared::utils::{Decimal, Rounding, TokenResult};
 use shared::{
     check_condition,
-    constants::{FOLIO_SEEDS, MAX_TVL_FEE},
+    constants::{FOLIO_SEEDS, FOLIO_VERSION, MAX_TVL_FEE},
     errors::ErrorCode,
 };
 
@@ -60,7 +60,84 @@ impl Folio {
         Ok(())
     }
 
-    /// Validate the permission for an action by using the roles.
+    /// Validate that the action isn't paused on the folio.
+    ///
+    /// # Arguments
+    /// * `flag` - The pause flag of the action.
+    pub fn validate_not_paused(&self, flag: PauseFlag) -> Result<()> {
+        check_condition!(!PauseFlag::is_paused(self.pause_flags, flag), FolioPaused);
+
+        Ok(())
+    }
+
+    /// Pause the given actions on the folio.
+    ///
+    /// # Arguments
+    /// * `pause_flags` - The bitmask of the actions to pause.
+    pub fn pause(&mut self, pause_flags: u8) -> Result<()> {
+        check_condition!(PauseFlag::is_valid(pause_flags), InvalidPauseFlags);
+
+        self.pause_flags |= pause_flags;
+
+        Ok(())
+    }
+
+    /// Unpause the given actions on the folio.
+    ///
+    /// # Arguments
+    /// * `pause_flags` - The bitmask of the actions to unpause.
+    pub fn unpause(&mut self, pause_flags: u8) -> Result<()> {
+        check_condition!(PauseFlag::is_valid(pause_flags), InvalidPauseFlags);
+
+        self.pause_flags &= !pause_flags;
+
+        Ok(())
+    }
+
+    /// Enable or disable the mint allowlist on the folio.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether minting is restricted to the users on the mint allowlist.
+    pub fn set_mint_allowlist_enabled(&mut self, enabled: bool) {
+        self.mint_allowlist_enabled = enabled as u8;
+    }
+
+    /// Check if minting is restricted to the users on the mint allowlist.
+    pub fn is_mint_allowlist_enabled(&self) -> bool {
+        self.mint_allowlist_enabled != 0
+    }
+
+    /// Set whether the folio has mint limits to enforce.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether at least one of the mint limits is set.
+    pub fn set_mint_limits_enabled(&mut self, enabled: bool) {
+        self.mint_limits_enabled = enabled as u8;
+    }
+
+    /// Check if the folio has mint limits to enforce, in which case its FolioMintLimits account is required to mint.
+    pub fn is_mint_limits_enabled(&self) -> bool {
+        self.mint_limits_enabled != 0
+    }
+
+    /// Set where the undelivered shares of a closed fee distribution go.
+    ///
+    /// # Arguments
+    /// * `fee_distribution_fallback` - The fallback for the undelivered shares.
+    pub fn set_fee_distribution_fallback(
+        &mut self,
+        fee_distribution_fallback: FeeDistributionFallback,
+    ) {
+        self.fee_distribution_fallback = fee_distribution_fallback as u8;
+    }
+
+    /// Get where the undelivered shares of a closed fee distribution go, the DAO for folios that never set it.
+    pub fn get_fee_distribution_fallback(&self) -> Result<FeeDistributionFallback> {
+        FeeDistributionFallback::try_from(self.fee_distribution_fallback)
+            .ok_or(error!(ErrorCode::InvalidFeeDistributionFallback))
+    }
+
+    /// Validate the permission for an action by using the roles. Expired roles are ignored.
     ///
     /// # Arguments
     /// * `actor` - The actor account that is performing the action.
@@ -70,10 +147,12 @@ impl Folio {
         actor: &Account<'_, Actor>,
         required_roles: Vec<Role>,
     ) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp as u64;
+
         let mut has_one_of_the_roles = false;
 
         for required_role in required_roles {
-            if Role::has_role(actor.roles, required_role) {
+            if actor.has_active_role(required_role, current_time) {
                 has_one_of_the_roles = true;
                 break;
             }
@@ -84,6 +163,41 @@ impl Folio {
         Ok(())
     }
 
+    /// Initialize the new folio of a migration from the folio of the old folio program.
+    /// The configuration and the pending fee shares are carried over as they are, and the new folio is migrating
+    /// until the tokens of the basket are migrated.
+    ///
+    /// # Arguments
+    /// * `old_folio` - The folio in the old folio program.
+    /// * `bump` - The bump of the new folio.
+    pub fn init_from_old_folio(&mut self, old_folio: &Folio, bump: u8) {
+        self.bump = bump;
+        self.version = FOLIO_VERSION;
+        self.status = FolioStatus::Migrating as u8;
+        self.pause_flags = old_folio.pause_flags;
+        self.mint_allowlist_enabled = old_folio.mint_allowlist_enabled;
+        // The FolioMintLimits account is carried over with the flag, in `create_folio_from_old_program`
+        self.mint_limits_enabled = old_folio.mint_limits_enabled;
+        self.fee_distribution_fallback = old_folio.fee_distribution_fallback;
+        // The pending amounts only reach the new folio through `migrate_user_pending_basket`, which tracks them
+        self.pending_baskets_tracked = 1;
+        self.initialized_at = old_folio.initialized_at;
+        self.folio_token_mint = old_folio.folio_token_mint;
+        // Already stored per second, so it isn't converted again with `set_tvl_fee`
+        self.tvl_fee = old_folio.tvl_fee;
+        self.mint_fee = old_folio.mint_fee;
+        self.auction_length = old_folio.auction_length;
+        self.last_poke = old_folio.last_poke;
+        self.mandate = old_folio.mandate;
+
+        // The old folio was poked before the migration, so the pending fee shares are up to date and the
+        // new folio takes them over, to be distributed from the new folio program.
+        self.dao_pending_fee_shares = old_folio.dao_pending_fee_shares;
+        self.fee_recipients_pending_fee_shares = old_folio.fee_recipients_pending_fee_shares;
+        self.fee_recipients_pending_fee_shares_to_be_minted =
+            old_folio.fee_recipients_pending_fee_shares_to_be_minted;
+    }
+
     /// Set TVL fee by annual percentage. Different from how it is stored!
     ///
     /// # Arguments
@@ -130,6 +244,7 @@ impl Folio {
     /// * `scaled_dao_fee_numerator` - The numerator of the DAO fee (D18).
     /// * `scaled_dao_fee_denominator` - The denominator of the DAO fee (D18).
     /// * `scaled_dao_fee_floor` - The floor of the DAO fee (D18).
+    /// * `scaled_mint_fee_discount` - The discount applied on the mint fee for the minter (D18), the DAO fee floor still applies.
     ///
     /// # Returns
     /// * `TokenResult` - The number of shares to remove from the user's mint action (the total fees, in D9).
@@ -139,9 +254,14 @@ impl Folio {
         scaled_dao_fee_numerator: u128,
         scaled_dao_fee_denominator: u128,
         scaled_dao_fee_floor: u128,
+        scaled_mint_fee_discount: u128,
     ) -> Result<TokenResult> {
         let scaled_user_shares = Decimal::from_token_amount(raw_user_shares)?;
-        let scaled_mint_fee = Decimal::from_scaled(self.mint_fee);
+
+        // D18{1} = D18{1} * D18{1} / D18
+        let scaled_mint_fee = Decimal::from_scaled(self.mint_fee)
+            .mul(&Decimal::ONE_E18.sub(&Decimal::from_scaled(scaled_mint_fee_discount))?)?
+            .div(&Decimal::ONE_E18)?;
 
         let scaled_dao_fee_numerator = Decimal::from_scaled(scaled_dao_fee_numerator);
         let scaled_dao_fee_denominator = Decimal::from_scaled(scaled_dao_fee_denominator);
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use shared::check_condition;
+
+use crate::state::FolioActors;
+use crate::utils::structs::ActorEntry;
+use shared::errors::ErrorCode;
+
+impl FolioActors {
+    /// Process the init if needed, meaning we initialize the account if it's not initialized yet and if it already is
+    /// we check if the bump is correct.
+    ///
+    /// # Arguments
+    /// * `account_bump` - The bump of the account.
+    /// * `context_bump` - The bump of the account provided in the anchor context.
+    /// * `folio` - The folio the actors belong to.
+    pub fn process_init_if_needed(
+        &mut self,
+        account_bump: u8,
+        context_bump: u8,
+        folio: &Pubkey,
+    ) -> Result<()> {
+        if account_bump != 0 {
+            check_condition!(account_bump == context_bump, InvalidBump);
+            return Ok(());
+        }
+
+        self.bump = context_bump;
+        self.folio = *folio;
+
+        Ok(())
+    }
+
+    /// Set the roles of an actor in the index. An actor without any role is removed from the index.
+    /// If the index is full, the actor isn't indexed and the index is marked as incomplete, so that updating the
+    /// roles of an actor never fails because of the index.
+    ///
+    /// # Arguments
+    /// * `authority` - The authority of the actor.
+    /// * `roles` - The roles bitmask of the actor.
+    pub fn set_actor_roles(&mut self, authority: &Pubkey, roles: u8) {
+        if roles == 0 {
+            self.remove_actor(authority);
+            return;
+        }
+
+        if let Some(entry) = self
+            .actors
+            .iter_mut()
+            .find(|entry| entry.authority == *authority)
+        {
+            entry.roles = roles;
+            return;
+        }
+
+        match self
+            .actors
+            .iter_mut()
+            .find(|entry| entry.authority == Pubkey::default())
+        {
+            Some(empty_slot) => {
+                *empty_slot = ActorEntry {
+                    authority: *authority,
+                    roles,
+                };
+            }
+            None => self.is_incomplete = true,
+        }
+    }
+
+    /// Remove an actor from the index, if it's present.
+    ///
+    /// # Arguments
+    /// * `authority` - The authority of the actor.
+    pub fn remove_actor(&mut self, authority: &Pubkey) {
+        if let Some(entry) = self
+            .actors
+            .iter_mut()
+            .find(|entry| entry.authority == *authority)
+        {
+            *entry = ActorEntry::default();
+        }
+    }
+}
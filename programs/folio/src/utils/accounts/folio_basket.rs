@@ -3,7 +3,7 @@ use crate::state::FolioBasket;
 use crate::FolioTokenAmount;
 use anchor_lang::prelude::*;
 use shared::check_condition;
-use shared::constants::MAX_FOLIO_TOKEN_AMOUNTS;
+use shared::constants::{FOLIO_BASKET_VERSION, MAX_FOLIO_TOKEN_AMOUNTS};
 use shared::errors::ErrorCode;
 use shared::errors::ErrorCode::*;
 use shared::utils::{Decimal, Rounding};
@@ -39,6 +39,7 @@ impl FolioBasket {
             let folio_basket = &mut account_loader_folio_basket.load_init()?;
 
             folio_basket.bump = context_bump;
+            folio_basket.version = FOLIO_BASKET_VERSION;
             folio_basket.folio = *folio;
             folio_basket.basket.token_amounts =
                 [FolioTokenAmount::default(); MAX_FOLIO_TOKEN_AMOUNTS];
@@ -158,6 +159,38 @@ impl FolioBasket {
         Ok(())
     }
 
+    /// Move a token of the basket of the old folio program to this basket during a migration.
+    ///
+    /// # Arguments
+    /// * `old_folio_basket` - The folio basket in the old folio program, before the token is removed from it.
+    /// * `mint` - The mint of the token to migrate.
+    /// * `raw_received_amount` - The amount received by the new folio, net of the transfer fee (if any).
+    ///
+    /// # Returns whether the old basket still has other tokens to migrate.
+    pub fn migrate_token_from_old_folio_basket(
+        &mut self,
+        old_folio_basket: &FolioBasket,
+        mint: &Pubkey,
+        raw_received_amount: u64,
+    ) -> Result<bool> {
+        let raw_sent_amount = old_folio_basket.get_token_amount_in_folio_basket(mint)?;
+
+        check_condition!(raw_received_amount <= raw_sent_amount, InvalidTokenBalance);
+
+        let has_tokens_left = old_folio_basket
+            .basket
+            .token_amounts
+            .iter()
+            .any(|ta| ta.mint != Pubkey::default() && ta.mint != *mint);
+
+        self.add_tokens_to_basket(&vec![FolioTokenAmount {
+            mint: *mint,
+            amount: raw_received_amount,
+        }])?;
+
+        Ok(has_tokens_left)
+    }
+
     /// Get the total number of mints in the basket.
     ///
     /// # Returns the total number of mints in the basket (non default pubkey).
@@ -0,0 +1,188 @@
+use anchor_lang::prelude::*;
+use shared::check_condition;
+use shared::constants::FOLIO_LEGACY_BASKET_SEEDS;
+use shared::errors::ErrorCode;
+use shared::utils::account_util::init_pda_account_rent;
+use shared::utils::{Decimal, Rounding};
+
+use crate::state::FolioLegacyBasket;
+
+impl FolioLegacyBasket {
+    /// Get the legacy basket of a folio, initializing it if it doesn't exist yet.
+    ///
+    /// # Arguments
+    /// * `folio_legacy_basket` - The account info of the FolioLegacyBasket account.
+    /// * `payer` - The payer of the rent for the FolioLegacyBasket account.
+    /// * `system_program` - The system program.
+    /// * `folio` - The folio the FolioLegacyBasket account belongs to.
+    /// * `context_bump` - The bump of the folio legacy basket account in the context.
+    ///
+    /// Returns the FolioLegacyBasket, to be saved with `save` after being updated.
+    #[cfg(not(tarpaulin_include))]
+    pub fn get_or_init_folio_legacy_basket<'info>(
+        folio_legacy_basket: &AccountInfo<'info>,
+        payer: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+        folio: &Pubkey,
+        context_bump: u8,
+    ) -> Result<FolioLegacyBasket> {
+        if folio_legacy_basket.data_is_empty() {
+            // Not initialized yet
+            init_pda_account_rent(
+                folio_legacy_basket,
+                FolioLegacyBasket::SIZE,
+                payer,
+                &crate::ID,
+                system_program,
+                &[&[FOLIO_LEGACY_BASKET_SEEDS, folio.as_ref(), &[context_bump]]],
+            )?;
+
+            return Ok(FolioLegacyBasket {
+                bump: context_bump,
+                folio: *folio,
+                ..FolioLegacyBasket::default()
+            });
+        }
+
+        let existing_folio_legacy_basket =
+            FolioLegacyBasket::get_folio_legacy_basket(folio_legacy_basket)?
+                .ok_or(ErrorCode::InvalidAccountOwner)?;
+
+        check_condition!(
+            existing_folio_legacy_basket.bump == context_bump,
+            InvalidBump
+        );
+
+        Ok(existing_folio_legacy_basket)
+    }
+
+    /// Get the legacy basket of a folio.
+    /// If the folio legacy basket account isn't initialized, there are no legacy tokens.
+    ///
+    /// # Arguments
+    /// * `folio_legacy_basket` - The account info of the FolioLegacyBasket account (seeds checked by the caller).
+    #[cfg(not(tarpaulin_include))]
+    pub fn get_folio_legacy_basket(
+        folio_legacy_basket: &AccountInfo,
+    ) -> Result<Option<FolioLegacyBasket>> {
+        if folio_legacy_basket.data_is_empty() {
+            return Ok(None);
+        }
+
+        check_condition!(*folio_legacy_basket.owner == crate::ID, InvalidAccountOwner);
+
+        Ok(Some(FolioLegacyBasket::try_deserialize(
+            &mut &folio_legacy_basket.try_borrow_data()?[..],
+        )?))
+    }
+
+    /// Save the legacy basket to its account.
+    ///
+    /// # Arguments
+    /// * `folio_legacy_basket` - The account info of the FolioLegacyBasket account (seeds checked by the caller).
+    #[cfg(not(tarpaulin_include))]
+    pub fn save(&self, folio_legacy_basket: &AccountInfo) -> Result<()> {
+        self.try_serialize(&mut &mut folio_legacy_basket.try_borrow_mut_data()?[..])
+    }
+
+    /// Add the residual amount of a token removed from the basket. Nothing is added if the amount is 0.
+    /// Slots of fully redeemed tokens are freed first if there is no empty slot left.
+    ///
+    /// # Arguments
+    /// * `mint` - The mint of the removed token.
+    /// * `raw_amount` - The raw amount of the removed token still held for the holders.
+    /// * `raw_folio_token_supply` - The supply of the folio token mint (D9).
+    /// * `scaled_total_supply_folio_token` - The total supply of the folio, including the pending fee shares (D18).
+    pub fn add_token_amount(
+        &mut self,
+        mint: &Pubkey,
+        raw_amount: u64,
+        raw_folio_token_supply: u64,
+        scaled_total_supply_folio_token: &Decimal,
+    ) -> Result<()> {
+        if raw_amount == 0 {
+            return Ok(());
+        }
+
+        check_condition!(*mint != Pubkey::default(), InvalidAddedTokenMints);
+
+        if let Some(slot_to_update) = self.token_amounts.iter_mut().find(|ta| ta.mint == *mint) {
+            slot_to_update.amount = slot_to_update
+                .amount
+                .checked_add(raw_amount)
+                .ok_or(ErrorCode::MathOverflow)?;
+
+            return Ok(());
+        }
+
+        self.clear_empty_token_amounts(raw_folio_token_supply, scaled_total_supply_folio_token)?;
+
+        let empty_slot = self
+            .token_amounts
+            .iter_mut()
+            .find(|ta| ta.mint == Pubkey::default())
+            .ok_or(ErrorCode::MaxNumberOfLegacyTokensReached)?;
+
+        empty_slot.mint = *mint;
+        empty_slot.amount = raw_amount;
+
+        Ok(())
+    }
+
+    /// Clear the slots of the legacy tokens that have been fully redeemed, to free them for other removed tokens.
+    /// A legacy token is fully redeemed once nothing is left, or once the share of what's left for the whole
+    /// remaining folio token supply rounds down to zero, as no holder can redeem any of it anymore. That dust stays in
+    /// the folio's token account, where it isn't accounted for anymore and can be swept with `sync_basket_balance`.
+    ///
+    /// # Arguments
+    /// * `raw_folio_token_supply` - The supply of the folio token mint (D9).
+    /// * `scaled_total_supply_folio_token` - The total supply of the folio, including the pending fee shares (D18).
+    pub fn clear_empty_token_amounts(
+        &mut self,
+        raw_folio_token_supply: u64,
+        scaled_total_supply_folio_token: &Decimal,
+    ) -> Result<()> {
+        let scaled_folio_token_supply = Decimal::from_token_amount(raw_folio_token_supply)?;
+
+        for token_amount in self.token_amounts.iter_mut() {
+            if token_amount.mint == Pubkey::default() {
+                continue;
+            }
+
+            let is_fully_redeemed = token_amount.amount == 0
+                || scaled_total_supply_folio_token.is_zero()
+                || scaled_folio_token_supply
+                    .mul(&Decimal::from_token_amount(token_amount.amount)?)?
+                    .div(scaled_total_supply_folio_token)?
+                    .to_token_amount(Rounding::Floor)?
+                    .0
+                    == 0;
+
+            if is_fully_redeemed {
+                token_amount.mint = Pubkey::default();
+                token_amount.amount = 0;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check if no legacy token is still owed to the holders.
+    pub fn is_empty(&self) -> bool {
+        self.token_amounts
+            .iter()
+            .all(|ta| ta.mint == Pubkey::default())
+    }
+
+    /// Get the raw amount of a legacy token, or zero if the token isn't in the legacy basket.
+    ///
+    /// # Arguments
+    /// * `mint` - The mint of the token.
+    pub fn get_token_amount_or_zero(&self, mint: &Pubkey) -> u64 {
+        self.token_amounts
+            .iter()
+            .find(|ta| ta.mint == *mint)
+            .map(|ta| ta.amount)
+            .unwrap_or(0)
+    }
+}
@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+use shared::check_condition;
+use shared::constants::FOLIO_MINT_LIMITS_SEEDS;
+use shared::errors::ErrorCode;
+use shared::utils::account_util::init_pda_account_rent;
+use shared::utils::math_util::Decimal;
+
+use crate::state::{Folio, FolioMintLimits};
+
+impl FolioMintLimits {
+    /// Initialize or update the mint limits of a folio.
+    /// If the account is not initialized, it will be initialized without any limits before applying the updates.
+    ///
+    /// # Arguments
+    /// * `folio_mint_limits` - The account info of the FolioMintLimits account.
+    /// * `payer` - The payer of the rent for the FolioMintLimits account.
+    /// * `system_program` - The system program.
+    /// * `folio` - The folio the FolioMintLimits account belongs to.
+    /// * `context_bump` - The bump of the folio mint limits account in the context.
+    /// * `max_supply` - The maximum total supply of the folio (D9), 0 to remove the cap.
+    /// * `max_mint_per_tx` - The maximum amount of shares that can be minted in a single transaction (D9), 0 to remove the limit.
+    ///
+    /// Returns the updated FolioMintLimits.
+    #[cfg(not(tarpaulin_include))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn init_or_update_folio_mint_limits<'info>(
+        folio_mint_limits: &AccountInfo<'info>,
+        payer: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+        folio: &Pubkey,
+        context_bump: u8,
+        max_supply: Option<u64>,
+        max_mint_per_tx: Option<u64>,
+    ) -> Result<FolioMintLimits> {
+        let mut updated_folio_mint_limits = if folio_mint_limits.data_is_empty() {
+            // Not initialized yet
+            init_pda_account_rent(
+                folio_mint_limits,
+                FolioMintLimits::SIZE,
+                payer,
+                &crate::ID,
+                system_program,
+                &[&[FOLIO_MINT_LIMITS_SEEDS, folio.as_ref(), &[context_bump]]],
+            )?;
+
+            FolioMintLimits {
+                bump: context_bump,
+                folio: *folio,
+                max_supply: 0,
+                max_mint_per_tx: 0,
+            }
+        } else {
+            check_condition!(*folio_mint_limits.owner == crate::ID, InvalidAccountOwner);
+
+            let existing_folio_mint_limits =
+                FolioMintLimits::try_deserialize(&mut &folio_mint_limits.try_borrow_data()?[..])?;
+
+            check_condition!(existing_folio_mint_limits.bump == context_bump, InvalidBump);
+
+            existing_folio_mint_limits
+        };
+
+        updated_folio_mint_limits.set_limits(max_supply, max_mint_per_tx);
+
+        updated_folio_mint_limits
+            .try_serialize(&mut &mut folio_mint_limits.try_borrow_mut_data()?[..])?;
+
+        Ok(updated_folio_mint_limits)
+    }
+
+    /// Get the mint limits of a folio.
+    /// If the folio doesn't have mint limits enabled, there are no limits and the account isn't needed.
+    ///
+    /// # Arguments
+    /// * `folio` - The folio the mint limits are related to.
+    /// * `folio_mint_limits` - The account info of the FolioMintLimits account, if provided (seeds checked by the caller).
+    #[cfg(not(tarpaulin_include))]
+    pub fn get_folio_mint_limits(
+        folio: &Folio,
+        folio_mint_limits: Option<&AccountInfo>,
+    ) -> Result<FolioMintLimits> {
+        if !folio.is_mint_limits_enabled() {
+            return Ok(FolioMintLimits::default());
+        }
+
+        let folio_mint_limits = folio_mint_limits.ok_or(ErrorCode::MissingFolioMintLimits)?;
+
+        check_condition!(*folio_mint_limits.owner == crate::ID, InvalidAccountOwner);
+
+        FolioMintLimits::try_deserialize(&mut &folio_mint_limits.try_borrow_data()?[..])
+    }
+
+    /// Check if at least one of the mint limits is set.
+    pub fn has_limits(&self) -> bool {
+        self.max_supply != 0 || self.max_mint_per_tx != 0
+    }
+
+    /// Set the mint limits, only updating the provided ones.
+    ///
+    /// # Arguments
+    /// * `max_supply` - The maximum total supply of the folio (D9), 0 to remove the cap.
+    /// * `max_mint_per_tx` - The maximum amount of shares that can be minted in a single transaction (D9), 0 to remove the limit.
+    pub fn set_limits(&mut self, max_supply: Option<u64>, max_mint_per_tx: Option<u64>) {
+        if let Some(max_supply) = max_supply {
+            self.max_supply = max_supply;
+        }
+
+        if let Some(max_mint_per_tx) = max_mint_per_tx {
+            self.max_mint_per_tx = max_mint_per_tx;
+        }
+    }
+
+    /// Validate that minting the shares doesn't exceed the mint limits.
+    /// The total supply includes the pending fee shares, so the folio needs to be poked before.
+    ///
+    /// # Arguments
+    /// * `folio` - The folio being minted.
+    /// * `raw_folio_token_supply` - The supply of the folio token before the mint (D9).
+    /// * `raw_shares` - The amount of shares being minted, including the fee shares (D9).
+    pub fn validate_mint(
+        &self,
+        folio: &Folio,
+        raw_folio_token_supply: u64,
+        raw_shares: u64,
+    ) -> Result<()> {
+        if self.max_mint_per_tx != 0 {
+            check_condition!(
+                raw_shares <= self.max_mint_per_tx,
+                MaxMintPerTransactionExceeded
+            );
+        }
+
+        if self.max_supply != 0 {
+            let scaled_total_supply_after_mint = folio
+                .get_total_supply(raw_folio_token_supply)?
+                .add(&Decimal::from_token_amount(raw_shares)?)?;
+
+            check_condition!(
+                scaled_total_supply_after_mint.0 <= Decimal::from_token_amount(self.max_supply)?.0,
+                MaxSupplyExceeded
+            );
+        }
+
+        Ok(())
+    }
+}
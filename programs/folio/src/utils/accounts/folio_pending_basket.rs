@@ -0,0 +1,193 @@
+use crate::state::{FolioPendingBasket, UserPendingBasket};
+use crate::utils::structs::TokenAmount;
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use shared::check_condition;
+use shared::errors::ErrorCode;
+use shared::errors::ErrorCode::*;
+
+impl FolioPendingBasket {
+    /// Process the init if needed, meaning we initialize the account if it's not initialized yet and if it already is
+    /// we check if the bump is correct.
+    ///
+    /// # Arguments
+    /// * `account_loader_folio_pending_basket` - The account loader for the FolioPendingBasket account.
+    /// * `context_bump` - The bump of the account provided in the anchor context.
+    /// * `folio` - The folio the FolioPendingBasket account belongs to.
+    #[cfg(not(tarpaulin_include))]
+    pub fn process_init_if_needed(
+        account_loader_folio_pending_basket: &AccountLoader<FolioPendingBasket>,
+        context_bump: u8,
+        folio: &Pubkey,
+    ) -> Result<()> {
+        let account_info_folio_pending_basket =
+            account_loader_folio_pending_basket.to_account_info();
+
+        let data = account_info_folio_pending_basket.try_borrow_data()?;
+        let mut disc_bytes = [0u8; 8];
+        disc_bytes.copy_from_slice(&data[..8]);
+
+        let discriminator = u64::from_le_bytes(disc_bytes);
+
+        drop(data);
+
+        if discriminator == 0 {
+            // Not initialized yet, the basket is already zeroed
+            {
+                let folio_pending_basket = &mut account_loader_folio_pending_basket.load_init()?;
+
+                folio_pending_basket.bump = context_bump;
+                folio_pending_basket.folio = *folio;
+            }
+
+            // Write the discriminator right away, so the account can be loaded within the same instruction
+            account_info_folio_pending_basket.try_borrow_mut_data()?[..8]
+                .copy_from_slice(FolioPendingBasket::DISCRIMINATOR);
+        } else {
+            let folio_pending_basket = account_loader_folio_pending_basket.load()?;
+
+            check_condition!(folio_pending_basket.bump == context_bump, InvalidBump);
+        }
+
+        Ok(())
+    }
+
+    /// Process a change of a user's pending basket, meaning we initialize the FolioPendingBasket account if needed and
+    /// update the total pending amounts with the difference. The user's pending basket is marked as tracked.
+    ///
+    /// # Arguments
+    /// * `account_loader_folio_pending_basket` - The account loader for the FolioPendingBasket account.
+    /// * `context_bump` - The bump of the account provided in the anchor context.
+    /// * `folio` - The folio the FolioPendingBasket account belongs to.
+    /// * `tracked_token_amounts_before` - The token amounts of the user's pending basket that were tracked before the change.
+    /// * `user_pending_basket` - The user's pending basket, after the change.
+    #[cfg(not(tarpaulin_include))]
+    pub fn process_user_pending_basket_change(
+        account_loader_folio_pending_basket: &AccountLoader<FolioPendingBasket>,
+        context_bump: u8,
+        folio: &Pubkey,
+        tracked_token_amounts_before: &[TokenAmount],
+        user_pending_basket: &mut UserPendingBasket,
+    ) -> Result<()> {
+        FolioPendingBasket::process_init_if_needed(
+            account_loader_folio_pending_basket,
+            context_bump,
+            folio,
+        )?;
+
+        let token_amounts_after = user_pending_basket.track_token_amounts();
+
+        account_loader_folio_pending_basket
+            .load_mut()?
+            .update_from_user_pending_basket(tracked_token_amounts_before, &token_amounts_after)
+    }
+
+    /// Update the total pending amounts after a user's pending basket changed.
+    ///
+    /// # Arguments
+    /// * `tracked_token_amounts_before` - The token amounts of the user's pending basket that were tracked before the change.
+    /// * `token_amounts_after` - The token amounts of the user's pending basket after the change.
+    pub fn update_from_user_pending_basket(
+        &mut self,
+        tracked_token_amounts_before: &[TokenAmount],
+        token_amounts_after: &[TokenAmount],
+    ) -> Result<()> {
+        self.remove_token_amounts(tracked_token_amounts_before);
+        self.add_token_amounts(token_amounts_after)
+    }
+
+    /// Add token amounts (both for minting and for redeeming) to the total pending amounts.
+    ///
+    /// # Arguments
+    /// * `token_amounts` - The token amounts to add.
+    pub fn add_token_amounts(&mut self, token_amounts: &[TokenAmount]) -> Result<()> {
+        for token_amount in token_amounts {
+            if token_amount.mint == Pubkey::default()
+                || (token_amount.amount_for_minting == 0 && token_amount.amount_for_redeeming == 0)
+            {
+                continue;
+            }
+
+            let slot = match self
+                .basket
+                .token_amounts
+                .iter()
+                .position(|ta| ta.mint == token_amount.mint)
+            {
+                Some(index) => &mut self.basket.token_amounts[index],
+                None => {
+                    let slot = self
+                        .basket
+                        .token_amounts
+                        .iter_mut()
+                        .find(|ta| ta.mint == Pubkey::default())
+                        // No available slot found
+                        .ok_or(InvalidAddedTokenMints)?;
+
+                    slot.mint = token_amount.mint;
+                    slot
+                }
+            };
+
+            slot.amount_for_minting = slot
+                .amount_for_minting
+                .checked_add(token_amount.amount_for_minting)
+                .ok_or(ErrorCode::MathOverflow)?;
+            slot.amount_for_redeeming = slot
+                .amount_for_redeeming
+                .checked_add(token_amount.amount_for_redeeming)
+                .ok_or(ErrorCode::MathOverflow)?;
+        }
+
+        Ok(())
+    }
+
+    /// Remove token amounts (both for minting and for redeeming) from the total pending amounts.
+    /// Saturates at 0, so that a user can never be blocked by the totals.
+    ///
+    /// # Arguments
+    /// * `token_amounts` - The token amounts to remove.
+    pub fn remove_token_amounts(&mut self, token_amounts: &[TokenAmount]) {
+        for token_amount in token_amounts {
+            if token_amount.mint == Pubkey::default() {
+                continue;
+            }
+
+            if let Some(slot) = self
+                .basket
+                .token_amounts
+                .iter_mut()
+                .find(|ta| ta.mint == token_amount.mint)
+            {
+                slot.amount_for_minting = slot
+                    .amount_for_minting
+                    .saturating_sub(token_amount.amount_for_minting);
+                slot.amount_for_redeeming = slot
+                    .amount_for_redeeming
+                    .saturating_sub(token_amount.amount_for_redeeming);
+
+                // Clear the slot if both amounts are 0, to free it for other mints.
+                if slot.amount_for_minting == 0 && slot.amount_for_redeeming == 0 {
+                    slot.mint = Pubkey::default();
+                }
+            }
+        }
+    }
+
+    /// Get the total pending amount of a mint, meaning the amount for minting and the amount for redeeming of all
+    /// the users, which is held in the folio's token account but isn't part of the basket.
+    ///
+    /// # Arguments
+    /// * `mint` - The mint to get the total pending amount of.
+    ///
+    /// Returns the total pending amount of the mint (raw).
+    pub fn get_total_pending_amount(&self, mint: &Pubkey) -> Result<u64> {
+        match self.basket.token_amounts.iter().find(|ta| ta.mint == *mint) {
+            Some(token_amount) => Ok(token_amount
+                .amount_for_minting
+                .checked_add(token_amount.amount_for_redeeming)
+                .ok_or(ErrorCode::MathOverflow)?),
+            None => Ok(0),
+        }
+    }
+}
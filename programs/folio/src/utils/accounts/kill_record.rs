@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use shared::check_condition;
+
+use crate::state::KillRecord;
+use shared::errors::ErrorCode;
+
+impl KillRecord {
+    /// Record the kill of a folio, with the unkill timelock that applies to it.
+    ///
+    /// # Arguments
+    /// * `bump` - The bump of the kill record.
+    /// * `folio` - The folio that was killed.
+    /// * `killed_at` - The time of the kill, scaled in seconds.
+    /// * `unkill_timelock` - The unkill timelock of the unkill config, scaled in seconds.
+    pub fn init(&mut self, bump: u8, folio: Pubkey, killed_at: u64, unkill_timelock: u64) {
+        self.bump = bump;
+        self.folio = folio;
+        self.killed_at = killed_at;
+        self.unkill_timelock = unkill_timelock;
+    }
+
+    /// Check if the kill record was just created, meaning the folio was killed before kill records existed.
+    pub fn is_uninitialized(&self) -> bool {
+        self.folio == Pubkey::default()
+    }
+
+    /// Get the time from which the folio can be unkilled.
+    ///
+    /// Returns the time, scaled in seconds.
+    pub fn get_unkillable_at(&self) -> Result<u64> {
+        Ok(self
+            .killed_at
+            .checked_add(self.unkill_timelock)
+            .ok_or(ErrorCode::MathOverflow)?)
+    }
+
+    /// Validate that the unkill timelock has passed since the folio was killed.
+    ///
+    /// # Arguments
+    /// * `current_time` - The current time, scaled in seconds.
+    pub fn validate_unkill_timelock(&self, current_time: u64) -> Result<()> {
+        check_condition!(
+            current_time >= self.get_unkillable_at()?,
+            UnkillTimelockNotPassed
+        );
+
+        Ok(())
+    }
+}
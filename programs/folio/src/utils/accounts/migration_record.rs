@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use shared::check_condition;
+use shared::constants::MIGRATION_ABORT_GRACE_PERIOD;
+
+use crate::state::MigrationRecord;
+use shared::errors::ErrorCode;
+
+impl MigrationRecord {
+    /// Get the time until which the migration can be aborted, scaled in seconds.
+    pub fn get_abortable_until(&self) -> Result<u64> {
+        Ok(self
+            .started_at
+            .checked_add(MIGRATION_ABORT_GRACE_PERIOD)
+            .ok_or(ErrorCode::MathOverflow)?)
+    }
+
+    /// Validate that the migration is still within its abort grace period.
+    ///
+    /// # Arguments
+    /// * `current_time` - The current time, scaled in seconds.
+    pub fn validate_abort_grace_period(&self, current_time: u64) -> Result<()> {
+        check_condition!(
+            current_time < self.get_abortable_until()?,
+            MigrationAbortGracePeriodPassed
+        );
+
+        Ok(())
+    }
+
+    /// Validate that the abort grace period of the migration has passed, so the assets of the folio can be migrated.
+    ///
+    /// # Arguments
+    /// * `current_time` - The current time, scaled in seconds.
+    pub fn validate_abort_grace_period_passed(&self, current_time: u64) -> Result<()> {
+        check_condition!(
+            current_time >= self.get_abortable_until()?,
+            MigrationAbortGracePeriodNotPassed
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use shared::check_condition;
+
+use crate::state::{Folio, MintAllowlistEntry};
+use shared::errors::ErrorCode;
+
+impl MintAllowlistEntry {
+    /// Validate that the user is allowed to mint the folio.
+    /// If the mint allowlist isn't enabled on the folio, every user is allowed to mint.
+    ///
+    /// # Arguments
+    /// * `folio` - The folio the user is minting.
+    /// * `mint_allowlist_entry` - The account info of the MintAllowlistEntry account of the user (seeds checked by the caller).
+    #[cfg(not(tarpaulin_include))]
+    pub fn validate_user_allowed(folio: &Folio, mint_allowlist_entry: &AccountInfo) -> Result<()> {
+        if !folio.is_mint_allowlist_enabled() {
+            return Ok(());
+        }
+
+        check_condition!(
+            !mint_allowlist_entry.data_is_empty() && *mint_allowlist_entry.owner == crate::ID,
+            UserNotOnMintAllowlist
+        );
+
+        Ok(())
+    }
+}
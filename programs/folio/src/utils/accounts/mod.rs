@@ -2,9 +2,19 @@
 pub mod actor;
 pub mod auction;
 pub mod auction_ends;
+pub mod fee_discount;
 pub mod fee_distribution;
 pub mod fee_recipients;
 pub mod folio;
+pub mod folio_actors;
 pub mod folio_basket;
+pub mod folio_legacy_basket;
+pub mod folio_mint_limits;
+pub mod folio_pending_basket;
+pub mod kill_record;
+pub mod migration_record;
+pub mod mint_allowlist_entry;
+pub mod pending_owner;
 pub mod rebalance;
 pub mod user_pending_basket;
+pub mod versioned_account;
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use shared::check_condition;
+
+use crate::state::PendingOwner;
+use shared::errors::ErrorCode;
+
+impl PendingOwner {
+    /// Process the init if needed, meaning we initialize the account if it's not initialized yet and if it already is
+    /// we check if the bump is correct.
+    ///
+    /// # Arguments
+    /// * `account_bump` - The bump of the account.
+    /// * `context_bump` - The bump of the account provided in the anchor context.
+    /// * `folio` - The folio the pending owner belongs to.
+    pub fn process_init_if_needed(
+        &mut self,
+        account_bump: u8,
+        context_bump: u8,
+        folio: &Pubkey,
+    ) -> Result<()> {
+        if account_bump != 0 {
+            check_condition!(account_bump == context_bump, InvalidBump);
+            return Ok(());
+        }
+
+        self.bump = context_bump;
+        self.folio = *folio;
+
+        Ok(())
+    }
+
+    /// Propose a new owner, overriding any previous proposal.
+    ///
+    /// # Arguments
+    /// * `proposed_by` - The owner proposing the ownership transfer.
+    /// * `new_owner` - The proposed new owner.
+    /// * `current_time` - The current time, scaled in seconds.
+    pub fn propose(
+        &mut self,
+        proposed_by: &Pubkey,
+        new_owner: &Pubkey,
+        current_time: u64,
+    ) -> Result<()> {
+        check_condition!(
+            *new_owner != Pubkey::default() && new_owner != proposed_by,
+            InvalidPendingOwner
+        );
+
+        self.proposed_by = *proposed_by;
+        self.new_owner = *new_owner;
+        self.proposed_at = current_time;
+
+        Ok(())
+    }
+
+    /// Validate that the ownership transfer is accepted by the proposed new owner, and that the
+    /// owner losing the role is the one that proposed it.
+    ///
+    /// # Arguments
+    /// * `new_owner` - The new owner accepting the ownership transfer.
+    /// * `previous_owner` - The owner that will lose the owner role.
+    pub fn validate_acceptance(&self, new_owner: &Pubkey, previous_owner: &Pubkey) -> Result<()> {
+        check_condition!(
+            self.new_owner == *new_owner && self.proposed_by == *previous_owner,
+            InvalidPendingOwner
+        );
+
+        Ok(())
+    }
+}
@@ -3,8 +3,11 @@ use std::collections::HashSet;
 use crate::utils::{RebalanceDetails, RebalanceDetailsToken, RebalancePriceAndLimits};
 use anchor_lang::prelude::*;
 use shared::check_condition;
-use shared::constants::{MAX_RATE, MAX_TOKEN_PRICE, MAX_TOKEN_PRICE_RANGE, MAX_TTL};
+use shared::constants::{
+    MAX_RATE, MAX_TOKEN_PRICE, MAX_TOKEN_PRICE_RANGE, MAX_TTL, REBALANCE_VERSION,
+};
 use shared::errors::ErrorCode;
+use shared::utils::TokenUtil;
 
 use crate::state::Rebalance;
 
@@ -32,6 +35,7 @@ impl Rebalance {
             let rebalance = &mut account_loader_rebalance.load_init()?;
 
             rebalance.bump = context_bump;
+            rebalance.version = REBALANCE_VERSION;
             rebalance.folio = *folio;
             rebalance.nonce = 0;
         } else {
@@ -56,6 +60,7 @@ impl Rebalance {
         mints: &[AccountInfo],
         prices_and_limits: Vec<RebalancePriceAndLimits>,
         all_rebalance_details_added: bool,
+        use_ui_amounts: bool,
     ) -> Result<()> {
         check_condition!(ttl <= MAX_TTL, RebalanceTTLExceeded);
         check_condition!(
@@ -72,12 +77,39 @@ impl Rebalance {
         self.available_until = current_time + ttl;
         // clear old rebalance details.
         self.clear();
+        self.use_ui_amounts = use_ui_amounts as u8;
 
         self.add_rebalance_details(mints, prices_and_limits, all_rebalance_details_added)?;
 
         Ok(())
     }
 
+    /// Get the UI amount multipliers of the sell and buy mints, only if the rebalance was started with
+    /// `use_ui_amounts`, otherwise the limits and prices are in raw amounts.
+    ///
+    /// # Arguments
+    /// * `sell_mint` - The sell mint account info.
+    /// * `buy_mint` - The buy mint account info.
+    /// * `current_time` - The current on-chain time (seconds).
+    ///
+    /// Returns the D18{uiTok/tok} multipliers of the sell and buy mints.
+    #[cfg(not(tarpaulin_include))]
+    pub fn get_scaled_ui_multipliers(
+        &self,
+        sell_mint: &AccountInfo,
+        buy_mint: &AccountInfo,
+        current_time: i64,
+    ) -> Result<(Option<u128>, Option<u128>)> {
+        if self.use_ui_amounts == 0 {
+            return Ok((None, None));
+        }
+
+        Ok((
+            TokenUtil::get_scaled_ui_amount_multiplier(sell_mint, current_time)?,
+            TokenUtil::get_scaled_ui_amount_multiplier(buy_mint, current_time)?,
+        ))
+    }
+
     pub fn add_rebalance_details(
         &mut self,
         mints: &[AccountInfo],
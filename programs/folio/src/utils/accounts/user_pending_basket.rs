@@ -3,6 +3,7 @@ use std::cell::RefMut;
 use crate::utils::token_amount::{MinimumOutForTokenAmount, TokenAmount};
 use crate::utils::FolioTokenAmount;
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
 use shared::check_condition;
 use shared::constants::{PendingBasketType, MAX_USER_PENDING_BASKET_TOKEN_AMOUNTS};
 use shared::errors::ErrorCode;
@@ -11,7 +12,7 @@ use shared::errors::ErrorCode::*;
 use shared::utils::math_util::Decimal;
 use shared::utils::Rounding;
 
-use crate::state::{Folio, FolioBasket, UserPendingBasket};
+use crate::state::{Folio, FolioBasket, FolioLegacyBasket, UserPendingBasket};
 
 impl UserPendingBasket {
     /// Process the init if needed, meaning we initialize the account if it's not initialized yet and if it already is
@@ -45,19 +46,25 @@ impl UserPendingBasket {
 
         if discriminator == 0 {
             // Not initialized yet
-            let user_pending_basket = &mut account_loader_user_pending_basket.load_init()?;
-
-            user_pending_basket.bump = context_bump;
-            user_pending_basket.owner = *owner;
-            user_pending_basket.folio = *folio;
-            user_pending_basket.basket.token_amounts =
-                [TokenAmount::default(); MAX_USER_PENDING_BASKET_TOKEN_AMOUNTS];
+            {
+                let user_pending_basket = &mut account_loader_user_pending_basket.load_init()?;
+
+                user_pending_basket.bump = context_bump;
+                user_pending_basket.owner = *owner;
+                user_pending_basket.folio = *folio;
+                user_pending_basket.basket.token_amounts =
+                    [TokenAmount::default(); MAX_USER_PENDING_BASKET_TOKEN_AMOUNTS];
+
+                user_pending_basket.add_token_amounts_to_folio(
+                    added_token_amounts,
+                    can_add_new_mints,
+                    PendingBasketType::MintProcess,
+                )?;
+            }
 
-            user_pending_basket.add_token_amounts_to_folio(
-                added_token_amounts,
-                can_add_new_mints,
-                PendingBasketType::MintProcess,
-            )?;
+            // Write the discriminator right away, so the account can be loaded within the same instruction
+            account_info_user_pending_basket.try_borrow_mut_data()?[..8]
+                .copy_from_slice(UserPendingBasket::DISCRIMINATOR);
         } else {
             let user_pending_basket = &mut account_loader_user_pending_basket.load_mut()?;
 
@@ -217,6 +224,122 @@ impl UserPendingBasket {
         self.basket.token_amounts = [TokenAmount::default(); MAX_USER_PENDING_BASKET_TOKEN_AMOUNTS];
     }
 
+    /// Take the token amounts of a mint out of the pending basket of the user, clearing its slot.
+    /// Used when migrating the pending basket to a new folio program.
+    ///
+    /// # Arguments
+    /// * `mint` - The mint to take the token amounts of.
+    ///
+    /// Returns the token amounts (for minting and for redeeming) that were in the pending basket.
+    pub fn take_token_amount(&mut self, mint: &Pubkey) -> Result<TokenAmount> {
+        check_condition!(*mint != Pubkey::default(), InvalidRemovedTokenMints);
+
+        let slot = self
+            .basket
+            .token_amounts
+            .iter_mut()
+            .find(|ta| ta.mint == *mint)
+            .ok_or(InvalidRemovedTokenMints)?;
+
+        let token_amount = *slot;
+
+        *slot = TokenAmount::default();
+
+        Ok(token_amount)
+    }
+
+    /// Add token amounts migrated from the old folio program, both for minting and for redeeming.
+    /// The transfer fee (if any) is taken from both amounts pro-rata, rounding in favor of the amount for minting.
+    ///
+    /// # Arguments
+    /// * `token_amount` - The token amounts sent by the old folio program.
+    /// * `raw_received_amount` - The total amount received by the new folio, net of the transfer fee (if any).
+    pub fn add_migrated_token_amount(
+        &mut self,
+        token_amount: &TokenAmount,
+        raw_received_amount: u64,
+    ) -> Result<()> {
+        let raw_sent_amount = token_amount
+            .amount_for_minting
+            .checked_add(token_amount.amount_for_redeeming)
+            .ok_or(MathOverflow)?;
+
+        check_condition!(raw_received_amount <= raw_sent_amount, InvalidTokenBalance);
+
+        let amount_for_redeeming = if raw_sent_amount == 0 {
+            0
+        } else {
+            ((token_amount.amount_for_redeeming as u128) * (raw_received_amount as u128)
+                / (raw_sent_amount as u128)) as u64
+        };
+
+        let token_amounts = vec![TokenAmount {
+            mint: token_amount.mint,
+            amount_for_minting: raw_received_amount - amount_for_redeeming,
+            amount_for_redeeming,
+        }];
+
+        self.add_token_amounts_to_folio(&token_amounts, true, PendingBasketType::MintProcess)?;
+        self.add_token_amounts_to_folio(&token_amounts, true, PendingBasketType::RedeemProcess)
+    }
+
+    /// Get the token amounts of the pending basket of the user that are accounted for in the FolioPendingBasket.
+    /// Used before updating the pending basket, so that the FolioPendingBasket can be updated with the difference.
+    ///
+    /// # Arguments
+    /// * `account_loader_user_pending_basket` - The account loader for the UserPendingBasket account, could be not initialized yet.
+    ///
+    /// Returns the tracked token amounts, empty if the pending basket isn't initialized or tracked yet.
+    #[cfg(not(tarpaulin_include))]
+    pub fn get_tracked_token_amounts_of_account(
+        account_loader_user_pending_basket: &AccountLoader<UserPendingBasket>,
+    ) -> Result<Vec<TokenAmount>> {
+        let account_info_user_pending_basket = account_loader_user_pending_basket.to_account_info();
+
+        let is_initialized = account_info_user_pending_basket.try_borrow_data()?[..8]
+            .iter()
+            .any(|b| *b != 0);
+
+        if !is_initialized {
+            return Ok(vec![]);
+        }
+
+        Ok(account_loader_user_pending_basket
+            .load()?
+            .get_tracked_token_amounts())
+    }
+
+    /// Get the token amounts of the pending basket of the user that are accounted for in the FolioPendingBasket.
+    ///
+    /// Returns the non empty token amounts, empty if the pending basket isn't tracked yet.
+    pub fn get_tracked_token_amounts(&self) -> Vec<TokenAmount> {
+        if self.is_tracked == 0 {
+            return vec![];
+        }
+
+        self.get_token_amounts()
+    }
+
+    /// Mark the pending basket of the user as tracked, as its token amounts are about to be accounted for in the
+    /// FolioPendingBasket.
+    ///
+    /// Returns the non empty token amounts.
+    pub fn track_token_amounts(&mut self) -> Vec<TokenAmount> {
+        self.is_tracked = 1;
+
+        self.get_token_amounts()
+    }
+
+    /// Get the non empty token amounts of the pending basket of the user.
+    fn get_token_amounts(&self) -> Vec<TokenAmount> {
+        self.basket
+            .token_amounts
+            .iter()
+            .filter(|ta| ta.mint != Pubkey::default())
+            .copied()
+            .collect()
+    }
+
     /// This function pokes the folio to get the latest pending fee shares, and then calculates the user's pending amounts in shares.
     ///
     /// # Arguments
@@ -225,6 +348,7 @@ impl UserPendingBasket {
     /// * `folio_key` - The key of the folio.
     /// * `token_program_id` - The token program id.
     /// * `folio_basket` - The basket of the folio.
+    /// * `folio_legacy_basket` - The legacy basket of the folio (tokens removed from the basket), only distributed when redeeming.
     /// * `folio` - The folio.
     /// * `pending_basket_type` - The type of pending basket, wether it's for minting or redeeming.
     /// * `current_time` - The current time.
@@ -238,6 +362,7 @@ impl UserPendingBasket {
         raw_shares: u64,
         raw_folio_token_supply: u64,
         folio_basket: &mut RefMut<'_, FolioBasket>,
+        folio_legacy_basket: Option<&mut FolioLegacyBasket>,
         folio: &mut RefMut<'_, Folio>,
         pending_basket_type: PendingBasketType,
         current_time: i64,
@@ -259,11 +384,33 @@ impl UserPendingBasket {
         let scaled_total_supply_folio_token = folio.get_total_supply(raw_folio_token_supply)?;
         let raw_shares = Decimal::from_token_amount(raw_shares)?;
 
-        for folio_token_account in folio_basket.basket.token_amounts.iter_mut() {
+        // Legacy tokens can only be redeemed, never minted with
+        let legacy_token_amounts: &mut [FolioTokenAmount] = match folio_legacy_basket {
+            Some(folio_legacy_basket)
+                if pending_basket_type == PendingBasketType::RedeemProcess =>
+            {
+                &mut folio_legacy_basket.token_amounts
+            }
+            _ => &mut [],
+        };
+
+        for folio_token_account in folio_basket
+            .basket
+            .token_amounts
+            .iter_mut()
+            .chain(legacy_token_amounts.iter_mut())
+        {
             if folio_token_account.mint == Pubkey::default() {
                 continue;
             }
 
+            // Tokens with a zero balance (e.g. just added to be bought through a rebalance) aren't required for minting
+            if pending_basket_type == PendingBasketType::MintProcess
+                && folio_token_account.amount == 0
+            {
+                continue;
+            }
+
             let raw_user_amount = match self
                 .basket
                 .token_amounts
@@ -0,0 +1,159 @@
+use crate::state::{Auction, FeeDistribution, Folio, FolioBasket, Rebalance};
+use anchor_lang::{prelude::*, Discriminator};
+use shared::check_condition;
+use shared::constants::{
+    AUCTION_VERSION, FEE_DISTRIBUTION_VERSION, FOLIO_BASKET_VERSION, FOLIO_VERSION,
+    REBALANCE_VERSION,
+};
+use shared::errors::ErrorCode;
+use shared::utils::account_util::resize_account_rent;
+use std::mem::offset_of;
+
+/// A zero copy account with a version header, that can be upgraded in place with `upgrade_account`.
+///
+/// Changes to the layout must be additive: new fields are appended at the end of the struct, or take over
+/// padding bytes, and the version is bumped. Older accounts are resized to the new size, with the new bytes
+/// zeroed, and `migrate_from_version` is called for every version in between, to set the non-zero defaults.
+pub trait VersionedAccount: Discriminator {
+    /// Current version of the layout.
+    const VERSION: u8;
+
+    /// Offset of the version byte in the account data, discriminator included.
+    const VERSION_OFFSET: usize;
+
+    /// Size of the account with the current layout, discriminator included.
+    const CURRENT_SIZE: usize;
+
+    /// Migrate the data of an account from `version` to `version + 1`. The data is already resized to the current
+    /// size, with the new bytes zeroed.
+    ///
+    /// # Arguments
+    /// * `data` - The data of the account, discriminator included.
+    /// * `version` - The version to migrate from.
+    /// * `current_time` - The current on-chain time (seconds).
+    fn migrate_from_version(_data: &mut [u8], _version: u8, _current_time: u64) -> Result<()> {
+        Ok(())
+    }
+
+    /// Get the version of the layout of an account.
+    ///
+    /// # Arguments
+    /// * `data` - The data of the account, discriminator included.
+    fn get_version(data: &[u8]) -> Result<u8> {
+        check_condition!(
+            data.len() > Self::VERSION_OFFSET && data[..8] == Self::DISCRIMINATOR[..],
+            AccountNotUpgradable
+        );
+
+        Ok(data[Self::VERSION_OFFSET])
+    }
+
+    /// Upgrade the data of an account to the current layout, the data needs to be resized to the current size first.
+    ///
+    /// # Arguments
+    /// * `data` - The data of the account, discriminator included.
+    /// * `current_time` - The current on-chain time (seconds).
+    fn upgrade_data(data: &mut [u8], current_time: u64) -> Result<()> {
+        let version = Self::get_version(data)?;
+
+        check_condition!(version < Self::VERSION, AccountAlreadyUpgraded);
+        check_condition!(data.len() == Self::CURRENT_SIZE, InvalidAccountData);
+
+        for from_version in version..Self::VERSION {
+            Self::migrate_from_version(data, from_version, current_time)?;
+        }
+
+        data[Self::VERSION_OFFSET] = Self::VERSION;
+
+        Ok(())
+    }
+
+    /// Resize an account to the current layout, paying for the additional rent, and upgrade its data.
+    ///
+    /// # Arguments
+    /// * `account` - The account to upgrade.
+    /// * `payer` - The payer of the additional rent.
+    /// * `system_program` - The system program.
+    #[cfg(not(tarpaulin_include))]
+    fn upgrade<'info>(
+        account: &AccountInfo<'info>,
+        payer: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+    ) -> Result<()> {
+        let version = Self::get_version(&account.try_borrow_data()?)?;
+
+        // Checked before resizing, so the payer doesn't pay for an account that can't be upgraded
+        check_condition!(version < Self::VERSION, AccountAlreadyUpgraded);
+
+        resize_account_rent(account, Self::CURRENT_SIZE, payer, system_program)?;
+
+        let current_time = Clock::get()?.unix_timestamp as u64;
+
+        Self::upgrade_data(&mut account.try_borrow_mut_data()?, current_time)
+    }
+
+    /// Upgrade an account only if it has an older layout, for the instructions that need to load accounts created
+    /// before a change that grew the account.
+    ///
+    /// # Arguments
+    /// * `account` - The account to upgrade.
+    /// * `payer` - The payer of the additional rent.
+    /// * `system_program` - The system program.
+    #[cfg(not(tarpaulin_include))]
+    fn upgrade_if_needed<'info>(
+        account: &AccountInfo<'info>,
+        payer: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+    ) -> Result<()> {
+        let version = Self::get_version(&account.try_borrow_data()?)?;
+
+        if version < Self::VERSION {
+            Self::upgrade(account, payer, system_program)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl VersionedAccount for Folio {
+    const VERSION: u8 = FOLIO_VERSION;
+    const VERSION_OFFSET: usize = 8 + offset_of!(Folio, version);
+    const CURRENT_SIZE: usize = Folio::SIZE;
+}
+
+impl VersionedAccount for FolioBasket {
+    const VERSION: u8 = FOLIO_BASKET_VERSION;
+    const VERSION_OFFSET: usize = 8 + offset_of!(FolioBasket, version);
+    const CURRENT_SIZE: usize = FolioBasket::SIZE;
+}
+
+impl VersionedAccount for Rebalance {
+    const VERSION: u8 = REBALANCE_VERSION;
+    const VERSION_OFFSET: usize = 8 + offset_of!(Rebalance, version);
+    const CURRENT_SIZE: usize = Rebalance::SIZE;
+}
+
+impl VersionedAccount for Auction {
+    const VERSION: u8 = AUCTION_VERSION;
+    const VERSION_OFFSET: usize = 8 + offset_of!(Auction, version);
+    const CURRENT_SIZE: usize = Auction::SIZE;
+}
+
+impl VersionedAccount for FeeDistribution {
+    const VERSION: u8 = FEE_DISTRIBUTION_VERSION;
+    const VERSION_OFFSET: usize = 8 + offset_of!(FeeDistribution, version);
+    const CURRENT_SIZE: usize = FeeDistribution::SIZE;
+
+    /// Fee distributions created before `created_at` was added are considered created at the upgrade, so their
+    /// recipients still get the whole grace period to be cranked before it can be closed.
+    fn migrate_from_version(data: &mut [u8], version: u8, current_time: u64) -> Result<()> {
+        if version == 0 {
+            let created_at_offset = 8 + offset_of!(FeeDistribution, created_at);
+
+            data[created_at_offset..created_at_offset + 8]
+                .copy_from_slice(&current_time.to_le_bytes());
+        }
+
+        Ok(())
+    }
+}
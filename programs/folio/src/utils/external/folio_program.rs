@@ -32,6 +32,18 @@ impl NewFolioProgram {
     const UPDATE_BASKET_IN_NEW_FOLIO_PROGRAM_FUNCTION_NAME: &'static str =
         "update_basket_in_new_folio_program";
 
+    /// The name of the update user pending basket function in the new Folio program
+    const UPDATE_USER_PENDING_BASKET_IN_NEW_FOLIO_PROGRAM_FUNCTION_NAME: &'static str =
+        "update_user_pending_basket_in_new_folio_program";
+
+    /// The name of the instruction to create a fee distribution in the new Folio program
+    const CREATE_FEE_DISTRIBUTION_FROM_OLD_PROGRAM_FUNCTION_NAME: &'static str =
+        "create_fee_distribution_from_old_program";
+
+    /// The name of the instruction to abort the migration in the new Folio program
+    const ABORT_FOLIO_MIGRATION_FROM_OLD_PROGRAM_FUNCTION_NAME: &'static str =
+        "abort_folio_migration_from_old_program";
+
     /// Get the instruction discriminator for a given instruction name.
     ///
     /// # Arguments
@@ -63,6 +75,10 @@ impl NewFolioProgram {
     /// * `folio` - The folio to use
     /// * `upgraded_folio` - The upgraded folio to use
     /// * `folio_token_mint` - The folio token mint to use
+    /// * `old_fee_recipients` - The fee recipients of the old folio, copied over to the new folio
+    /// * `new_fee_recipients` - The fee recipients of the new folio
+    /// * `old_folio_mint_limits` - The mint limits of the old folio, copied over to the new folio
+    /// * `new_folio_mint_limits` - The mint limits of the new folio
     /// * `to` - The account to mint the token to
     /// * `signer_seeds` - The signer seeds to use (folio needs to sign)
     /// * `amount` - The amount to mint
@@ -76,6 +92,10 @@ impl NewFolioProgram {
         actor: &AccountInfo<'info>,
         new_folio_basket: &AccountInfo<'info>,
         folio_token_mint: &AccountInfo<'info>,
+        old_fee_recipients: &AccountInfo<'info>,
+        new_fee_recipients: &AccountInfo<'info>,
+        old_folio_mint_limits: &AccountInfo<'info>,
+        new_folio_mint_limits: &AccountInfo<'info>,
         remaining_accounts: &[AccountInfo<'info>],
         signer_seeds: &[&[&[u8]]],
     ) -> Result<()> {
@@ -87,6 +107,10 @@ impl NewFolioProgram {
             AccountMeta::new(actor.key(), false),
             AccountMeta::new(new_folio_basket.key(), false),
             AccountMeta::new_readonly(folio_token_mint.key(), false),
+            AccountMeta::new_readonly(old_fee_recipients.key(), false),
+            AccountMeta::new(new_fee_recipients.key(), false),
+            AccountMeta::new_readonly(old_folio_mint_limits.key(), false),
+            AccountMeta::new(new_folio_mint_limits.key(), false),
         ];
 
         let mut requited_account_infos: Vec<AccountInfo> = vec![
@@ -98,6 +122,10 @@ impl NewFolioProgram {
             actor.to_account_info(),
             new_folio_basket.to_account_info(),
             folio_token_mint.to_account_info(),
+            old_fee_recipients.to_account_info(),
+            new_fee_recipients.to_account_info(),
+            old_folio_mint_limits.to_account_info(),
+            new_folio_mint_limits.to_account_info(),
         ];
 
         for account_info in remaining_accounts {
@@ -175,4 +203,237 @@ impl NewFolioProgram {
 
         Ok(())
     }
+
+    /// Updates the pending basket of a user in the new folio program, with the pending amounts of a token mint
+    /// migrated from the old folio program.
+    ///
+    /// # Arguments
+    /// * `system_program` - The system program
+    /// * `payer` - The payer of the rent of the new accounts
+    /// * `old_folio` - The old folio (needs to sign)
+    /// * `new_folio` - The new folio
+    /// * `pending_basket_owner` - The owner of the pending basket
+    /// * `old_user_pending_basket` - The user pending basket in the old folio program
+    /// * `new_user_pending_basket` - The user pending basket in the new folio program
+    /// * `new_folio_pending_basket` - The folio pending basket in the new folio program
+    /// * `token_mint` - The token mint being migrated
+    /// * `folio_token_account` - The new folio's token account of the token mint
+    /// * `program_registrar` - The program registrar
+    /// * `new_folio_program` - The new folio program to call
+    /// * `signer_seeds` - The signer seeds to use (old folio needs to sign)
+    #[cfg(not(tarpaulin_include))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_user_pending_basket_in_new_folio_program<'info>(
+        system_program: &AccountInfo<'info>,
+        payer: &AccountInfo<'info>,
+        old_folio: &AccountInfo<'info>,
+        new_folio: &AccountInfo<'info>,
+        pending_basket_owner: &AccountInfo<'info>,
+        old_user_pending_basket: &AccountInfo<'info>,
+        new_user_pending_basket: &AccountInfo<'info>,
+        new_folio_pending_basket: &AccountInfo<'info>,
+        token_mint: &AccountInfo<'info>,
+        folio_token_account: &AccountInfo<'info>,
+        program_registrar: &AccountInfo<'info>,
+        new_folio_program: &AccountInfo<'info>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let account_metas = vec![
+            AccountMeta::new_readonly(system_program.key(), false),
+            AccountMeta::new(payer.key(), true),
+            AccountMeta::new_readonly(old_folio.key(), true),
+            AccountMeta::new_readonly(new_folio.key(), false),
+            AccountMeta::new_readonly(pending_basket_owner.key(), false),
+            AccountMeta::new_readonly(old_user_pending_basket.key(), false),
+            AccountMeta::new(new_user_pending_basket.key(), false),
+            AccountMeta::new(new_folio_pending_basket.key(), false),
+            AccountMeta::new_readonly(token_mint.key(), false),
+            AccountMeta::new_readonly(folio_token_account.key(), false),
+            AccountMeta::new_readonly(program_registrar.key(), false),
+        ];
+
+        let data = NewFolioProgram::get_instruction_discriminator(
+            Self::UPDATE_USER_PENDING_BASKET_IN_NEW_FOLIO_PROGRAM_FUNCTION_NAME,
+        )
+        .to_vec();
+
+        invoke_signed(
+            &Instruction {
+                program_id: new_folio_program.key(),
+                accounts: account_metas,
+                data,
+            },
+            &[
+                system_program.to_account_info(),
+                payer.to_account_info(),
+                old_folio.to_account_info(),
+                new_folio.to_account_info(),
+                pending_basket_owner.to_account_info(),
+                old_user_pending_basket.to_account_info(),
+                new_user_pending_basket.to_account_info(),
+                new_folio_pending_basket.to_account_info(),
+                token_mint.to_account_info(),
+                folio_token_account.to_account_info(),
+                program_registrar.to_account_info(),
+            ],
+            signer_seeds,
+        )?;
+
+        Ok(())
+    }
+
+    /// Creates a fee distribution in the new folio program, from an undistributed fee distribution of the old folio
+    /// program.
+    ///
+    /// # Arguments
+    /// * `system_program` - The system program
+    /// * `payer` - The payer of the rent of the new fee distribution
+    /// * `old_folio` - The old folio (needs to sign)
+    /// * `new_folio` - The new folio
+    /// * `old_fee_distribution` - The fee distribution in the old folio program
+    /// * `new_fee_distribution` - The fee distribution in the new folio program
+    /// * `program_registrar` - The program registrar
+    /// * `new_folio_program` - The new folio program to call
+    /// * `index` - The index of the fee distribution
+    /// * `signer_seeds` - The signer seeds to use (old folio needs to sign)
+    #[cfg(not(tarpaulin_include))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_fee_distribution_from_old_program<'info>(
+        system_program: &AccountInfo<'info>,
+        payer: &AccountInfo<'info>,
+        old_folio: &AccountInfo<'info>,
+        new_folio: &AccountInfo<'info>,
+        old_fee_distribution: &AccountInfo<'info>,
+        new_fee_distribution: &AccountInfo<'info>,
+        program_registrar: &AccountInfo<'info>,
+        new_folio_program: &AccountInfo<'info>,
+        index: u64,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let account_metas = vec![
+            AccountMeta::new_readonly(system_program.key(), false),
+            AccountMeta::new(payer.key(), true),
+            AccountMeta::new_readonly(old_folio.key(), true),
+            AccountMeta::new_readonly(new_folio.key(), false),
+            AccountMeta::new_readonly(old_fee_distribution.key(), false),
+            AccountMeta::new(new_fee_distribution.key(), false),
+            AccountMeta::new_readonly(program_registrar.key(), false),
+        ];
+
+        let mut data = NewFolioProgram::get_instruction_discriminator(
+            Self::CREATE_FEE_DISTRIBUTION_FROM_OLD_PROGRAM_FUNCTION_NAME,
+        )
+        .to_vec();
+        data.extend_from_slice(&index.to_le_bytes());
+
+        invoke_signed(
+            &Instruction {
+                program_id: new_folio_program.key(),
+                accounts: account_metas,
+                data,
+            },
+            &[
+                system_program.to_account_info(),
+                payer.to_account_info(),
+                old_folio.to_account_info(),
+                new_folio.to_account_info(),
+                old_fee_distribution.to_account_info(),
+                new_fee_distribution.to_account_info(),
+                program_registrar.to_account_info(),
+            ],
+            signer_seeds,
+        )?;
+
+        Ok(())
+    }
+
+    /// Aborts the migration in the new folio program, which returns the mint, freeze and metadata authorities
+    /// of the folio token mint to the old folio and closes the accounts created for the new folio.
+    ///
+    /// # Arguments
+    /// * `system_program` - The system program
+    /// * `rent` - The rent sysvar
+    /// * `instructions_sysvar` - The instructions sysvar
+    /// * `token_program` - The token program of the folio token mint
+    /// * `owner` - The folio owner, receives the rent of the closed accounts
+    /// * `old_folio` - The old folio
+    /// * `new_folio` - The new folio
+    /// * `new_actor` - The actor of the new folio
+    /// * `new_folio_basket` - The folio basket of the new folio
+    /// * `new_fee_recipients` - The fee recipients of the new folio
+    /// * `folio_token_mint` - The folio token mint
+    /// * `token_metadata_program` - The token metadata program
+    /// * `metadata` - The metaplex metadata account of the folio token mint
+    /// * `program_registrar` - The program registrar
+    /// * `new_folio_program` - The new folio program to call
+    /// * `signer_seeds` - The signer seeds to use (old folio needs to sign)
+    #[cfg(not(tarpaulin_include))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn abort_folio_migration_from_old_program<'info>(
+        system_program: &AccountInfo<'info>,
+        rent: &AccountInfo<'info>,
+        instructions_sysvar: &AccountInfo<'info>,
+        token_program: &AccountInfo<'info>,
+        owner: &AccountInfo<'info>,
+        old_folio: &AccountInfo<'info>,
+        new_folio: &AccountInfo<'info>,
+        new_actor: &AccountInfo<'info>,
+        new_folio_basket: &AccountInfo<'info>,
+        new_fee_recipients: &AccountInfo<'info>,
+        folio_token_mint: &AccountInfo<'info>,
+        token_metadata_program: &AccountInfo<'info>,
+        metadata: &AccountInfo<'info>,
+        program_registrar: &AccountInfo<'info>,
+        new_folio_program: &AccountInfo<'info>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let account_metas = vec![
+            AccountMeta::new_readonly(system_program.key(), false),
+            AccountMeta::new_readonly(rent.key(), false),
+            AccountMeta::new_readonly(instructions_sysvar.key(), false),
+            AccountMeta::new_readonly(token_program.key(), false),
+            AccountMeta::new(owner.key(), true),
+            AccountMeta::new_readonly(old_folio.key(), true),
+            AccountMeta::new(new_folio.key(), false),
+            AccountMeta::new(new_actor.key(), false),
+            AccountMeta::new(new_folio_basket.key(), false),
+            AccountMeta::new(new_fee_recipients.key(), false),
+            AccountMeta::new(folio_token_mint.key(), false),
+            AccountMeta::new_readonly(token_metadata_program.key(), false),
+            AccountMeta::new(metadata.key(), false),
+            AccountMeta::new_readonly(program_registrar.key(), false),
+        ];
+
+        let data = NewFolioProgram::get_instruction_discriminator(
+            Self::ABORT_FOLIO_MIGRATION_FROM_OLD_PROGRAM_FUNCTION_NAME,
+        )
+        .to_vec();
+
+        invoke_signed(
+            &Instruction {
+                program_id: new_folio_program.key(),
+                accounts: account_metas,
+                data,
+            },
+            &[
+                system_program.to_account_info(),
+                rent.to_account_info(),
+                instructions_sysvar.to_account_info(),
+                token_program.to_account_info(),
+                owner.to_account_info(),
+                old_folio.to_account_info(),
+                new_folio.to_account_info(),
+                new_actor.to_account_info(),
+                new_folio_basket.to_account_info(),
+                new_fee_recipients.to_account_info(),
+                folio_token_mint.to_account_info(),
+                token_metadata_program.to_account_info(),
+                metadata.to_account_info(),
+                program_registrar.to_account_info(),
+            ],
+            signer_seeds,
+        )?;
+
+        Ok(())
+    }
 }
@@ -3,6 +3,8 @@ use anchor_spl::metadata::mpl_token_metadata::{
     self,
     types::{CollectionDetailsToggle, CollectionToggle, RuleSetToggle, UsesToggle},
 };
+use shared::check_condition;
+use shared::errors::ErrorCode;
 
 /// Utility struct for the metaplex program.
 pub struct Metaplex {}
@@ -69,7 +71,10 @@ impl Metaplex {
                     collection: None,
                     uses: None,
                 },
-                is_mutable: false,
+                // Mutable so that the brand manager can update the name, symbol and uri. Folios created
+                // before `update_folio_branding` have immutable metadata, which can't be made mutable again,
+                // so only their mandate can be updated.
+                is_mutable: true,
                 collection_details: None,
             };
 
@@ -126,4 +131,74 @@ impl Metaplex {
 
         Ok(())
     }
+
+    /// Update the name, symbol and uri of a metadata account, keeping the other fields as they are.
+    /// Only possible if the metadata account is mutable, which isn't the case for the folios created before
+    /// `update_folio_branding` was added.
+    ///
+    /// # Arguments
+    /// * `ctx` - The update authority context.
+    /// * `name` - The new name of the token, if we want to update it.
+    /// * `symbol` - The new symbol of the token, if we want to update it.
+    /// * `uri` - The new uri of the token, if we want to update it.
+    /// * `signers_seeds` - The signers seeds of the update authority.
+    #[cfg(not(tarpaulin_include))]
+    pub fn update_metadata_data(
+        ctx: &UpdateAuthority,
+        name: Option<String>,
+        symbol: Option<String>,
+        uri: Option<String>,
+        signers_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let metadata =
+            mpl_token_metadata::accounts::Metadata::from_bytes(&ctx.metadata.try_borrow_data()?)?;
+
+        check_condition!(metadata.is_mutable, FolioMetadataImmutable);
+
+        // Metaplex pads the strings with null characters
+        let data = mpl_token_metadata::types::Data {
+            name: name.unwrap_or(metadata.name.trim_end_matches('\0').to_string()),
+            symbol: symbol.unwrap_or(metadata.symbol.trim_end_matches('\0').to_string()),
+            uri: uri.unwrap_or(metadata.uri.trim_end_matches('\0').to_string()),
+            seller_fee_basis_points: metadata.seller_fee_basis_points,
+            creators: metadata.creators,
+        };
+
+        let cpi_accounts = mpl_token_metadata::instructions::UpdateAsUpdateAuthorityV2CpiAccounts {
+            metadata: &ctx.metadata,
+            mint: &ctx.mint,
+            payer: &ctx.payer,
+            authority: &ctx.update_authority,
+            delegate_record: None,
+            token: None,
+            edition: None,
+            system_program: &ctx.system_program,
+            sysvar_instructions: &ctx.sysvar_instructions,
+            authorization_rules_program: None,
+            authorization_rules: None,
+        };
+        let update_data_args =
+            mpl_token_metadata::instructions::UpdateAsUpdateAuthorityV2InstructionArgs {
+                new_update_authority: None,
+                data: Some(data),
+                primary_sale_happened: None,
+                is_mutable: None,
+                collection: CollectionToggle::None,
+                collection_details: CollectionDetailsToggle::None,
+                uses: UsesToggle::None,
+                rule_set: RuleSetToggle::None,
+                token_standard: None,
+                authorization_data: None,
+            };
+
+        let cpi = mpl_token_metadata::instructions::UpdateAsUpdateAuthorityV2Cpi::new(
+            &ctx.token_metadata_program,
+            cpi_accounts,
+            update_data_args,
+        );
+
+        cpi.invoke_signed(signers_seeds)?;
+
+        Ok(())
+    }
 }
@@ -5,3 +5,5 @@ pub use callback::*;
 pub use metaplex::*;
 pub mod folio_program;
 pub use folio_program::*;
+pub mod rewards_program;
+pub use rewards_program::*;
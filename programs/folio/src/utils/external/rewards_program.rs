@@ -0,0 +1,125 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{hash, instruction::Instruction, program::invoke},
+};
+use shared::check_condition;
+use shared::constants::{REWARDS_PROGRAM_ID, REWARD_TOKENS_SEEDS};
+use shared::errors::ErrorCode;
+
+/// Utility struct to interact with the Rewards program.
+pub struct RewardsProgram {}
+
+impl RewardsProgram {
+    /// The size of the instruction discriminator in Anchor.
+    const INSTRUCTION_DISCRIMINATOR_SIZE: usize = 8;
+
+    /// The name of the accrue reward info function in the Rewards program
+    const ACCRUE_REWARD_INFO_FUNCTION_NAME: &'static str = "accrue_reward_info";
+
+    /// Get the instruction discriminator for a given instruction name.
+    ///
+    /// # Arguments
+    /// * `instruction_name` - The name of the instruction.
+    ///
+    /// Returns the instruction discriminator.
+    fn get_instruction_discriminator(instruction_name: &str) -> [u8; 8] {
+        // Anchor's instruction discriminator is a hash of the instruction name prepended with "global:"
+        let preimage = format!("global:{}", instruction_name);
+
+        let mut hasher = hash::Hasher::default();
+
+        hasher.hash(preimage.as_bytes());
+
+        let hash_result = hasher.result();
+
+        let mut discriminator = [0u8; Self::INSTRUCTION_DISCRIMINATOR_SIZE];
+
+        discriminator.copy_from_slice(&hash_result.to_bytes()[..8]);
+
+        discriminator
+    }
+
+    /// Check if a fee recipient is a reward tokens account of the Rewards program.
+    ///
+    /// # Arguments
+    /// * `account` - The account that could be the reward tokens account.
+    /// * `recipient` - The fee recipient.
+    ///
+    /// Returns true if the account is the fee recipient and is owned by the Rewards program.
+    pub fn is_reward_tokens_recipient(account: &AccountInfo, recipient: &Pubkey) -> bool {
+        account.key() == *recipient && *account.owner == REWARDS_PROGRAM_ID
+    }
+
+    /// CPI from the folio program to the rewards program to accrue the rewards of a reward token of a realm, on its
+    /// reward info only. Permissionless, so it doesn't need any user with a token owner record in the realm.
+    ///
+    /// # Arguments
+    /// * `rewards_program` - The rewards program account.
+    /// * `token_program` - The token program account.
+    /// * `realm` - The realm account.
+    /// * `reward_tokens` - The reward tokens account of the realm.
+    /// * `governance_token_mint` - The governance token mint (community mint) of the realm.
+    /// * `governance_staked_token_account` - The governance staked token account of the realm.
+    /// * `reward_token_mint` - The reward token mint.
+    /// * `reward_info` - The reward info account of the reward token (mut).
+    /// * `token_rewards_token_account` - The reward tokens' token account of the reward token.
+    #[cfg(not(tarpaulin_include))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn accrue_reward_info_cpi<'a>(
+        rewards_program: &AccountInfo<'a>,
+        token_program: &AccountInfo<'a>,
+        realm: &AccountInfo<'a>,
+        reward_tokens: &AccountInfo<'a>,
+        governance_token_mint: &AccountInfo<'a>,
+        governance_staked_token_account: &AccountInfo<'a>,
+        reward_token_mint: &AccountInfo<'a>,
+        reward_info: &AccountInfo<'a>,
+        token_rewards_token_account: &AccountInfo<'a>,
+    ) -> Result<()> {
+        check_condition!(rewards_program.key() == REWARDS_PROGRAM_ID, InvalidProgram);
+
+        check_condition!(
+            reward_tokens.key()
+                == Pubkey::find_program_address(
+                    &[REWARD_TOKENS_SEEDS, realm.key().as_ref()],
+                    &REWARDS_PROGRAM_ID
+                )
+                .0,
+            InvalidPda
+        );
+
+        let accounts = vec![
+            token_program.clone(),
+            realm.clone(),
+            reward_tokens.clone(),
+            governance_token_mint.clone(),
+            governance_staked_token_account.clone(),
+            reward_token_mint.clone(),
+            reward_info.clone(),
+            token_rewards_token_account.clone(),
+        ];
+
+        let account_metas = vec![
+            AccountMeta::new_readonly(token_program.key(), false),
+            AccountMeta::new_readonly(realm.key(), false),
+            AccountMeta::new_readonly(reward_tokens.key(), false),
+            AccountMeta::new_readonly(governance_token_mint.key(), false),
+            AccountMeta::new_readonly(governance_staked_token_account.key(), false),
+            AccountMeta::new_readonly(reward_token_mint.key(), false),
+            AccountMeta::new(reward_info.key(), false),
+            AccountMeta::new_readonly(token_rewards_token_account.key(), false),
+        ];
+
+        let data = Self::get_instruction_discriminator(Self::ACCRUE_REWARD_INFO_FUNCTION_NAME);
+
+        let instruction = Instruction {
+            program_id: *rewards_program.key,
+            accounts: account_metas,
+            data: data.to_vec(),
+        };
+
+        invoke(&instruction, &accounts)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+/// An entry of the actors index of a Folio.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace, PartialEq, Debug)]
+pub struct ActorEntry {
+    /// The authority of the actor.
+    pub authority: Pubkey,
+
+    /// The roles that the actor has, as a bitmask (see Role).
+    pub roles: u8,
+}
@@ -16,6 +16,7 @@ use bytemuck::{Pod, Zeroable};
 )]
 #[repr(C)]
 /// Scaled in D18
+/// For mints with a UI amount multiplier (`ScaledUiAmount` or `InterestBearingConfig`), the limits are in UI amounts, D18{uiTok/share}.
 pub struct BasketRange {
     /// D18{tok/share}
     pub spot: u128,
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+/// Recipient of the shares of a fee distribution that couldn't be delivered before it was closed.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Default, Clone, Copy, PartialEq, Eq, Debug, InitSpace,
+)]
+pub enum FeeDistributionFallback {
+    #[default]
+    /// Shares are minted to the DAO fee recipient
+    Dao = 0,
+    /// Shares are added back to the pending fee shares of the fee recipients, to be distributed with the next fees
+    FeeRecipients = 1,
+}
+
+impl FeeDistributionFallback {
+    /// Tries to convert a u8 to a FeeDistributionFallback.
+    ///
+    /// # Arguments
+    /// * `value`: The u8 value to convert.
+    ///
+    /// # Returns
+    /// * `Option<FeeDistributionFallback>`: The FeeDistributionFallback.
+    pub fn try_from(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(FeeDistributionFallback::Dao),
+            1 => Some(FeeDistributionFallback::FeeRecipients),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::ID as TOKEN_PROGRAM_ID;
+use anchor_spl::token_2022::ID as TOKEN_2022_PROGRAM_ID;
+use anchor_spl::token_interface::TokenAccount;
+use shared::check_condition;
+use shared::errors::ErrorCode;
+
+/// Snapshot of a token account owned by the folio, taken before a folio action, to make sure the action
+/// can't take tokens away from the folio (directly, or later via a delegate or a new authority).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FolioTokenAccountSnapshot {
+    /// The token account's pubkey.
+    pub key: Pubkey,
+
+    /// The owner (authority) of the token account.
+    pub owner: Pubkey,
+
+    /// Raw amount of the token.
+    pub amount: u64,
+
+    /// The delegate of the token account, if any.
+    pub delegate: Option<Pubkey>,
+
+    /// The close authority of the token account, if any.
+    pub close_authority: Option<Pubkey>,
+}
+
+impl FolioTokenAccountSnapshot {
+    /// Take a snapshot of the account if it's a token account owned by the folio.
+    ///
+    /// # Arguments
+    /// * `account` - The account to take a snapshot of.
+    /// * `folio` - The folio's pubkey.
+    ///
+    /// Returns the snapshot, or None if the account isn't a token account owned by the folio.
+    #[cfg(not(tarpaulin_include))]
+    pub fn try_from_account_info(account: &AccountInfo, folio: &Pubkey) -> Option<Self> {
+        if *account.owner != TOKEN_PROGRAM_ID && *account.owner != TOKEN_2022_PROGRAM_ID {
+            return None;
+        }
+
+        let data = account.try_borrow_data().ok()?;
+        let token_account = TokenAccount::try_deserialize(&mut &data[..]).ok()?;
+
+        if token_account.owner != *folio {
+            return None;
+        }
+
+        Some(FolioTokenAccountSnapshot {
+            key: account.key(),
+            owner: token_account.owner,
+            amount: token_account.amount,
+            delegate: token_account.delegate.into(),
+            close_authority: token_account.close_authority.into(),
+        })
+    }
+
+    /// Validate the token account after the folio action, compared to the snapshot taken before.
+    ///
+    /// # Arguments
+    /// * `after` - The snapshot of the token account after the folio action, None if it isn't a token account of the folio anymore.
+    ///
+    /// # Checks
+    /// * The token account is still owned by the folio.
+    /// * The balance didn't decrease.
+    /// * The delegate and close authority didn't change.
+    pub fn validate_after_folio_action(
+        &self,
+        after: Option<&FolioTokenAccountSnapshot>,
+    ) -> Result<()> {
+        let Some(after) = after else {
+            return Err(error!(ErrorCode::FolioActionInvariantViolated));
+        };
+
+        check_condition!(
+            after.key == self.key && after.owner == self.owner,
+            FolioActionInvariantViolated
+        );
+
+        check_condition!(after.amount >= self.amount, FolioActionInvariantViolated);
+
+        check_condition!(
+            after.delegate == self.delegate && after.close_authority == self.close_authority,
+            FolioActionInvariantViolated
+        );
+
+        Ok(())
+    }
+}
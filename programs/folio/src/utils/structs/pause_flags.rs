@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+/// Pause flags for the Folios.
+///
+/// The pause flags are stored as a bitmask in a u8.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub enum PauseFlag {
+    /// Minting of folio shares is paused.
+    Mint = 0b0000_0001,
+    /// Redeeming (burning) of folio shares is paused.
+    Redeem = 0b0000_0010,
+    /// Opening auctions and bidding on auctions is paused.
+    Auctions = 0b0000_0100,
+    /// Fee distribution is paused.
+    FeeDistribution = 0b0000_1000,
+}
+
+impl PauseFlag {
+    /// All the pause flags combined.
+    pub const ALL: u8 = PauseFlag::Mint as u8
+        | PauseFlag::Redeem as u8
+        | PauseFlag::Auctions as u8
+        | PauseFlag::FeeDistribution as u8;
+
+    /// Checks if the flag is set in the bitmask.
+    ///
+    /// # Arguments
+    /// * `pause_flags`: The bitmask of pause flags.
+    /// * `flag`: The flag to check.
+    ///
+    /// # Returns
+    /// * `bool`: True if the flag is set, false otherwise.
+    pub fn is_paused(pause_flags: u8, flag: PauseFlag) -> bool {
+        (pause_flags & (flag as u8)) != 0
+    }
+
+    /// Checks if the bitmask only contains known pause flags and at least one of them.
+    ///
+    /// # Arguments
+    /// * `pause_flags`: The bitmask of pause flags.
+    ///
+    /// # Returns
+    /// * `bool`: True if the bitmask is valid, false otherwise.
+    pub fn is_valid(pause_flags: u8) -> bool {
+        pause_flags != 0 && (pause_flags & !PauseFlag::ALL) == 0
+    }
+}
@@ -41,6 +41,7 @@ pub struct PricesInAuction {
 #[repr(C)]
 /// Scaled in D18, It is completely same as the PricesInAuction struct, but the difference
 /// is terms of use, this is for a token price in terms of a common token(USDC/ or some common token) in rebalance details.
+/// For mints with a UI amount multiplier (`ScaledUiAmount` or `InterestBearingConfig`), the prices are per UI token, D18{UoA/uiTok}.
 pub struct PricesInRebalance {
     /// D18{UoA/tok}
     pub low: u128,
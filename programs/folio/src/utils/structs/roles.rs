@@ -3,7 +3,7 @@ use anchor_lang::prelude::*;
 /// Roles for the Folios.
 ///
 /// The roles are stored as a bitmask in a u8.
-#[derive(AnchorSerialize, AnchorDeserialize, Debug)]
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy)]
 pub enum Role {
     /// The owner of the Folio.
     Owner = 0b0000_0001,
@@ -13,6 +13,10 @@ pub enum Role {
     AuctionLauncher = 0b0000_0100,
     /// The brand manager of the Folio.
     BrandManager = 0b0000_1000,
+    /// The guardian of the Folio, can pause and unpause the Folio during an incident.
+    Guardian = 0b0001_0000,
+    /// The allowlist manager of the Folio, can add and remove users from the mint allowlist.
+    AllowlistManager = 0b0010_0000,
 }
 
 impl Role {
@@ -45,4 +49,12 @@ impl Role {
     pub fn remove_role(roles: &mut u8, role: Role) {
         *roles &= !(role as u8);
     }
+
+    /// Gets the bit position of the role in the bitmask, used to index the role expirations.
+    ///
+    /// # Returns
+    /// * `usize`: The bit position of the role.
+    pub fn index(self) -> usize {
+        (self as u8).trailing_zeros() as usize
+    }
 }
@@ -0,0 +1,124 @@
+use crate::state::{RewardInfo, RewardTokens};
+use crate::GovernanceUtil;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use shared::check_condition;
+use shared::constants::{REWARD_INFO_SEEDS, REWARD_TOKENS_SEEDS};
+use shared::errors::ErrorCode;
+
+/// Accrue the rewards of a reward token on its reward info only.
+/// Permissionless.
+///
+/// # Arguments
+/// * `token_program` - The token program.
+/// * `realm` - The realm account (PDA) (not mut, not signer).
+/// * `reward_tokens` - The reward tokens account (PDA) (not mut, not signer).
+/// * `governance_token_mint` - The governance token mint (community mint) (PDA) (not mut, not signer).
+/// * `governance_staked_token_account` - The governance staked token account of all tokens staked in the Realm (PDA) (not mut, not signer).
+/// * `reward_token` - The reward token mint (not mut, not signer).
+/// * `reward_info` - The reward info account of the reward token (PDA) (mut, not signer).
+/// * `token_rewards_token_account` - The reward tokens' token account of the reward token (not mut, not signer).
+#[derive(Accounts)]
+pub struct AccrueRewardInfo<'info> {
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: Realm
+    #[account()]
+    pub realm: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [REWARD_TOKENS_SEEDS, realm.key().as_ref()],
+        bump,
+    )]
+    pub reward_tokens: AccountLoader<'info, RewardTokens>,
+
+    /// CHECK: the governance's token mint (community mint)
+    #[account()]
+    pub governance_token_mint: UncheckedAccount<'info>,
+
+    /// CHECK: the governance's token account of all tokens staked
+    #[account()]
+    pub governance_staked_token_account: UncheckedAccount<'info>,
+
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub reward_token: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut,
+        seeds = [REWARD_INFO_SEEDS, realm.key().as_ref(), reward_token.key().as_ref()],
+        bump = reward_info.bump,
+    )]
+    pub reward_info: Account<'info, RewardInfo>,
+
+    #[account(
+        associated_token::mint = reward_token,
+        associated_token::authority = reward_tokens,
+        associated_token::token_program = token_program,
+    )]
+    pub token_rewards_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+}
+
+impl AccrueRewardInfo<'_> {
+    /// Validate the instruction.
+    ///
+    /// # Checks
+    /// * Governance token mint is the community mint of the realm.
+    pub fn validate(&self) -> Result<()> {
+        let realm_data = self.realm.try_borrow_data()?;
+
+        /*
+        Skip GovernanceAccountType (1 byte)
+         */
+        let start_index_for_community_mint = 1;
+
+        check_condition!(
+            realm_data.len() >= start_index_for_community_mint + 32,
+            InvalidAccountData
+        );
+
+        let community_mint = Pubkey::new_from_array(
+            realm_data[start_index_for_community_mint..start_index_for_community_mint + 32]
+                .try_into()
+                .unwrap(),
+        );
+
+        check_condition!(
+            community_mint == self.governance_token_mint.key(),
+            InvalidCommunityMint
+        );
+
+        Ok(())
+    }
+}
+
+/// Accrue the rewards of a reward token on its reward info only, without accruing them for any user.
+/// Users accrue their share of the reward index the next time they accrue or claim, so this can be called by anyone,
+/// e.g. right after reward tokens are sent to the realm, without needing a token owner record in the realm.
+///
+/// # Arguments
+/// * `ctx` - The context of the instruction.
+pub fn handler(ctx: Context<AccrueRewardInfo>) -> Result<()> {
+    ctx.accounts.validate()?;
+
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    let (raw_governance_staked_token_account_balance, governance_token_decimals) =
+        GovernanceUtil::get_realm_staked_balance_and_mint_decimals(
+            &ctx.accounts.realm,
+            &ctx.accounts.governance_token_mint,
+            &ctx.accounts.governance_staked_token_account,
+        )?;
+
+    let reward_ratio = ctx.accounts.reward_tokens.load()?.reward_ratio;
+
+    ctx.accounts.reward_info.accrue_rewards(
+        reward_ratio,
+        ctx.accounts.token_rewards_token_account.amount,
+        raw_governance_staked_token_account_balance,
+        governance_token_decimals,
+        current_time,
+    )?;
+
+    Ok(())
+}
@@ -1,3 +1,4 @@
+pub mod accrue_reward_info;
 pub mod accrue_rewards;
 pub mod add_reward_token;
 pub mod claim_rewards;
@@ -6,6 +7,7 @@ pub mod init_or_set_reward_ratio;
 pub mod remove_reward_token;
 pub mod set_rewards_admin;
 
+pub use accrue_reward_info::*;
 pub use accrue_rewards::*;
 pub use add_reward_token::*;
 pub use claim_rewards::*;
@@ -11,6 +11,7 @@
 //! * `remove_reward_token` - Remove a tracked reward token.
 //! * `claim_rewards` - Claim rewards from a token, which means transferring the rewards accrued by a user to the user.
 //! * `accrue_rewards` - Accrue rewards to a token, meaning updating accrued rewards.
+//! * `accrue_reward_info` - Accrue the rewards of a token on its reward info only, without any user (permissionless).
 #![allow(unexpected_cfgs)]
 #![allow(clippy::doc_overindented_list_items)]
 #![allow(
@@ -71,6 +72,10 @@ pub mod rewards {
         accrue_rewards::handler(ctx)
     }
 
+    pub fn accrue_reward_info(ctx: Context<AccrueRewardInfo>) -> Result<()> {
+        accrue_reward_info::handler(ctx)
+    }
+
     /*
     Dummy functions
      */
@@ -0,0 +1,184 @@
+//! Transfer Hook Mock Program
+//!
+//! Transfer hook program only used by the tests, to cover the basket tokens with an active transfer hook.
+//! The extra account meta list of a mint requires a single extra account, a counter of the transfers of the mint,
+//! which is incremented on every transfer, so the tests can check that the hook was invoked.
+//!
+//! It can also forward an instruction to another program, so the tests of the folio actions can route an action to
+//! the token program through a program of the folio action registrar.
+//!
+//! # Instructions
+//!
+//! * `initialize_extra_account_meta_list` - Initialize the extra account meta list and the counter of a mint.
+//! * `transfer_hook` - Invoked by the token program on every transfer of a mint using this program as transfer hook.
+//! * `forward` - Forward an instruction to another program, keeping the signers of the caller.
+#![allow(unexpected_cfgs)]
+#![allow(
+    deprecated,
+    reason = "Anchor internally calls AccountInfo::realloc (see PR #3803)"
+)]
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke;
+use spl_discriminator::SplDiscriminate;
+use spl_tlv_account_resolution::{
+    account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList,
+};
+use spl_transfer_hook_interface::instruction::{
+    ExecuteInstruction, InitializeExtraAccountMetaListInstruction,
+};
+
+declare_id!("8Wk8isEVeMSy2u6hxTywq5EV8ctUo2tF8u3g8PUgvtKw");
+
+pub const EXTRA_ACCOUNT_METAS_SEEDS: &[u8] = b"extra-account-metas";
+pub const TRANSFER_COUNTER_SEEDS: &[u8] = b"transfer_counter";
+
+#[allow(deprecated)]
+#[program]
+pub mod transfer_hook_mock {
+    use super::*;
+
+    #[instruction(discriminator = InitializeExtraAccountMetaListInstruction::SPL_DISCRIMINATOR_SLICE)]
+    pub fn initialize_extra_account_meta_list(
+        ctx: Context<InitializeExtraAccountMetaList>,
+    ) -> Result<()> {
+        ctx.accounts.transfer_counter.bump = ctx.bumps.transfer_counter;
+
+        let mut data = ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?;
+        ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &extra_account_metas()?)?;
+
+        Ok(())
+    }
+
+    #[instruction(discriminator = ExecuteInstruction::SPL_DISCRIMINATOR_SLICE)]
+    pub fn transfer_hook(ctx: Context<TransferHook>, amount: u64) -> Result<()> {
+        let transfer_counter = &mut ctx.accounts.transfer_counter;
+
+        transfer_counter.transfers += 1;
+        transfer_counter.amount += amount;
+
+        Ok(())
+    }
+
+    pub fn forward<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Forward<'info>>,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        let instruction = Instruction {
+            program_id: ctx.accounts.target_program.key(),
+            accounts: ctx
+                .remaining_accounts
+                .iter()
+                .map(|account| AccountMeta {
+                    pubkey: account.key(),
+                    is_signer: account.is_signer,
+                    is_writable: account.is_writable,
+                })
+                .collect(),
+            data,
+        };
+
+        let mut account_infos = ctx.remaining_accounts.to_vec();
+        account_infos.push(ctx.accounts.target_program.to_account_info());
+
+        invoke(&instruction, &account_infos)?;
+
+        Ok(())
+    }
+}
+
+/// The extra accounts required by the transfer hook, the transfer counter of the mint (index 1 of the execute
+/// instruction).
+fn extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
+    Ok(vec![ExtraAccountMeta::new_with_seeds(
+        &[
+            Seed::Literal {
+                bytes: TRANSFER_COUNTER_SEEDS.to_vec(),
+            },
+            Seed::AccountKey { index: 1 },
+        ],
+        false,
+        true,
+    )?])
+}
+
+/// Counts the transfers of a mint.
+///
+/// PDA Seeds ["transfer_counter", mint pubkey]
+#[account]
+#[derive(Default, InitSpace)]
+pub struct TransferCounter {
+    pub bump: u8,
+
+    pub transfers: u64,
+
+    pub amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct InitializeExtraAccountMetaList<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Initialized in the handler, with the layout of the transfer hook interface
+    #[account(
+        init,
+        payer = payer,
+        space = ExtraAccountMetaList::size_of(1)?,
+        seeds = [EXTRA_ACCOUNT_METAS_SEEDS, mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    /// CHECK: Mint using this program as transfer hook
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + TransferCounter::INIT_SPACE,
+        seeds = [TRANSFER_COUNTER_SEEDS, mint.key().as_ref()],
+        bump
+    )]
+    pub transfer_counter: Account<'info, TransferCounter>,
+}
+
+/// Accounts of the execute instruction of the transfer hook interface, in the order passed by the token program.
+#[derive(Accounts)]
+pub struct TransferHook<'info> {
+    /// CHECK: Source token account of the transfer
+    pub source_token: UncheckedAccount<'info>,
+
+    /// CHECK: Mint of the transfer
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: Destination token account of the transfer
+    pub destination_token: UncheckedAccount<'info>,
+
+    /// CHECK: Authority of the source token account
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: Extra account meta list of the mint
+    #[account(
+        seeds = [EXTRA_ACCOUNT_METAS_SEEDS, mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [TRANSFER_COUNTER_SEEDS, mint.key().as_ref()],
+        bump = transfer_counter.bump
+    )]
+    pub transfer_counter: Account<'info, TransferCounter>,
+}
+
+/// The program the instruction is forwarded to, the accounts of the instruction are the remaining accounts.
+#[derive(Accounts)]
+pub struct Forward<'info> {
+    /// CHECK: Program the instruction is forwarded to
+    #[account(executable)]
+    pub target_program: UncheckedAccount<'info>,
+}
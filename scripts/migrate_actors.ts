@@ -0,0 +1,63 @@
+// This script migrates every actor created before the role expirations were added to the current layout.
+// Legacy actors can't be loaded by any instruction of the folio program (including kill_folio and pause_folio)
+// until they are migrated, so it needs to be run right after the program is deployed.
+// migrate_actor is permissionless, the payer only pays for the additional rent.
+//
+// Run by using: ANCHOR_WALLET=$HOME/.config/solana/id.json ANCHOR_PROVIDER_URL=rpc npx ts-node --project scripts/tsconfig.scripts.json scripts/migrate_actors.ts
+import { Folio } from "../target/types/folio";
+import IDL from "../target/idl/folio.json";
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { SystemProgram, Transaction } from "@solana/web3.js";
+
+// Discriminator + bump + authority + folio + roles
+const LEGACY_ACTOR_SIZE = 8 + 1 + 32 + 32 + 1;
+
+const ACTORS_PER_TRANSACTION = 10;
+
+const setUp = () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const idl = IDL as Folio;
+  const program = new Program<Folio>(idl, anchor.getProvider());
+  return program;
+};
+
+const migrateActors = async () => {
+  const folio = setUp();
+  const provider = folio.provider as anchor.AnchorProvider;
+
+  const legacyActors = await provider.connection.getProgramAccounts(
+    folio.programId,
+    {
+      filters: [
+        { memcmp: folio.coder.accounts.memcmp("actor") },
+        { dataSize: LEGACY_ACTOR_SIZE },
+      ],
+    }
+  );
+
+  console.log(`Found ${legacyActors.length} legacy actors`);
+
+  for (let i = 0; i < legacyActors.length; i += ACTORS_PER_TRANSACTION) {
+    const instructions = await Promise.all(
+      legacyActors.slice(i, i + ACTORS_PER_TRANSACTION).map(({ pubkey }) =>
+        folio.methods
+          .migrateActor()
+          .accountsPartial({
+            systemProgram: SystemProgram.programId,
+            payer: provider.publicKey,
+            actor: pubkey,
+          })
+          .instruction()
+      )
+    );
+
+    const tx = await provider.sendAndConfirm(
+      new Transaction().add(...instructions)
+    );
+    console.log(`Transaction hash: ${tx}`);
+  }
+};
+
+migrateActors();
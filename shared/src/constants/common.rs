@@ -55,6 +55,24 @@ pub const YEAR_IN_SECONDS: u64 = 365 * DAY_IN_SECONDS;
 /// MAX_MINT_FEE is the maximum fee that can be set for the mint fee, 5% in D18 (D18{1} 5%).
 pub const MAX_MINT_FEE: u128 = 50_000_000_000_000_000;
 
+/// UNKILL_FOLIO_TIMELOCK is the default time that needs to pass after a folio is killed before it can be unkilled,
+/// 1 week. Used until the admin sets the unkill config.
+pub const UNKILL_FOLIO_TIMELOCK: u64 = 604800;
+
+/// MAX_UNKILL_FOLIO_TIMELOCK is the maximum unkill timelock that can be set in the unkill config, 4 weeks.
+pub const MAX_UNKILL_FOLIO_TIMELOCK: u64 = 2419200;
+
+/// MIGRATION_ABORT_GRACE_PERIOD is the time after the start of a migration during which the folio owner can abort it,
+/// the assets of the folio can only be migrated once it has passed, 1 week.
+/// Shortened to 5 seconds in the test build, as the localnet tests can't move the clock forward.
+#[cfg(not(feature = "test"))]
+pub const MIGRATION_ABORT_GRACE_PERIOD: u64 = 604800;
+#[cfg(feature = "test")]
+pub const MIGRATION_ABORT_GRACE_PERIOD: u64 = 5;
+
+/// MAX_FEE_DISCOUNT is the maximum discount that can be set on the mint fee for a minter, 100% in D18.
+pub const MAX_FEE_DISCOUNT: u128 = 1_000_000_000_000_000_000;
+
 /// MIN_AUCTION_LENGTH is the minimum auction length, 1 minute.
 pub const MIN_AUCTION_LENGTH: u64 = 60;
 /// MAX_AUCTION_LENGTH is the maximum auction length, 1 week.
@@ -81,16 +99,43 @@ pub const RESTRICTED_AUCTION_BUFFER: u64 = 0;
 pub const MAX_FEE_RECIPIENTS: usize = 64;
 /// MAX_FEE_RECIPIENTS_PORTION is the maximum portion of the fee that can be set for a fee recipient, 1e18.
 pub const MAX_FEE_RECIPIENTS_PORTION: u128 = 1_000_000_000_000_000_000;
+/// FEE_DISTRIBUTION_CLOSE_GRACE_PERIOD is the time after which a fee distribution that wasn't fully cranked can be closed, 4 weeks.
+pub const FEE_DISTRIBUTION_CLOSE_GRACE_PERIOD: u64 = 604800 * 4;
+/// MAX_PROTOCOL_FEE_RECIPIENTS is the maximum number of protocol level recipients the DAO fee can be split between, 4.
+pub const MAX_PROTOCOL_FEE_RECIPIENTS: usize = 4;
 /// MAX_FOLIO_TOKEN_AMOUNTS is the maximum number of token amounts that can be set for a folio, 16.
 pub const MAX_FOLIO_TOKEN_AMOUNTS: usize = 100;
 
 /// MAX_USER_PENDING_BASKET_TOKEN_AMOUNTS is the maximum number of token amounts that can be set for a user pending
 /// basket, 110, higher than our 100 maximum token, since they can have tokens that have been removed / added
 pub const MAX_USER_PENDING_BASKET_TOKEN_AMOUNTS: usize = 110;
+/// MAX_FOLIO_LEGACY_TOKEN_AMOUNTS is the maximum number of removed tokens a folio can still owe to its holders, 10,
+/// so that a user pending basket can always hold the basket and the legacy tokens when redeeming.
+pub const MAX_FOLIO_LEGACY_TOKEN_AMOUNTS: usize =
+    MAX_USER_PENDING_BASKET_TOKEN_AMOUNTS - MAX_FOLIO_TOKEN_AMOUNTS;
 
 /// MAX_REBALANCE_DETAILS_TOKENS is the maximum number of tokens that can be rebalanced at once, 30.
 pub const MAX_REBALANCE_DETAILS_TOKENS: usize = 30;
 
+/// MAX_ROLES is the maximum number of roles an actor can have, one per bit of the roles bitmask, 8.
+pub const MAX_ROLES: usize = 8;
+/// MAX_FOLIO_ACTORS is the maximum number of actors that can be indexed for a folio, 32. Actors past it still get
+/// their roles, they are just not indexed.
+pub const MAX_FOLIO_ACTORS: usize = 32;
+/// ACTOR_VERSION is the current version of the actor account, which added the role expirations.
+pub const ACTOR_VERSION: u8 = 1;
+
+/// FOLIO_VERSION is the current version of the folio account layout.
+pub const FOLIO_VERSION: u8 = 1;
+/// FOLIO_BASKET_VERSION is the current version of the folio basket account layout.
+pub const FOLIO_BASKET_VERSION: u8 = 1;
+/// REBALANCE_VERSION is the current version of the rebalance account layout.
+pub const REBALANCE_VERSION: u8 = 1;
+/// AUCTION_VERSION is the current version of the auction account layout.
+pub const AUCTION_VERSION: u8 = 1;
+/// FEE_DISTRIBUTION_VERSION is the current version of the fee distribution account layout, which added `created_at`.
+pub const FEE_DISTRIBUTION_VERSION: u8 = 1;
+
 /// MAX_CONCURRENT_AUCTIONS is the maximum number of concurrent auctions that can be set for a folio, 16.
 pub const MAX_CONCURRENT_AUCTIONS: usize = 16;
 /// MAX_REWARD_TOKENS is the maximum number of reward tokens that can be set for a folio, 4.
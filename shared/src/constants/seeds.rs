@@ -3,6 +3,9 @@
 pub const ACTOR_SEEDS: &[u8] = b"actor";
 pub const FOLIO_TOKEN_METADATA_SEEDS: &[u8] = b"folio_token_metadata";
 pub const PROGRAM_REGISTRAR_SEEDS: &[u8] = b"program_registrar";
+pub const FOLIO_ACTION_REGISTRAR_SEEDS: &[u8] = b"folio_action_registrar";
+pub const UNKILL_CONFIG_SEEDS: &[u8] = b"unkill_config";
+pub const TRANSFER_HOOK_REGISTRAR_SEEDS: &[u8] = b"transfer_hook_registrar";
 pub const FOLIO_SEEDS: &[u8] = b"folio";
 pub const AUCTION_SEEDS: &[u8] = b"auction";
 pub const REBALANCE_SEEDS: &[u8] = b"rebalance";
@@ -10,9 +13,18 @@ pub const AUCTION_ENDS_SEEDS: &[u8] = b"auction_ends";
 pub const DAO_FEE_CONFIG_SEEDS: &[u8] = b"dao_fee_config";
 pub const FEE_RECIPIENTS_SEEDS: &[u8] = b"fee_recipients";
 pub const FOLIO_BASKET_SEEDS: &[u8] = b"folio_basket";
+pub const FOLIO_LEGACY_BASKET_SEEDS: &[u8] = b"folio_legacy_basket";
 pub const FOLIO_FEE_CONFIG_SEEDS: &[u8] = b"folio_fee_config";
 pub const USER_PENDING_BASKET_SEEDS: &[u8] = b"user_pending_basket";
+pub const FOLIO_PENDING_BASKET_SEEDS: &[u8] = b"folio_pending_basket";
 pub const FEE_DISTRIBUTION_SEEDS: &[u8] = b"fee_distribution";
+pub const FEE_DISCOUNT_SEEDS: &[u8] = b"fee_discount";
+pub const KILL_RECORD_SEEDS: &[u8] = b"kill_record";
+pub const MIGRATION_RECORD_SEEDS: &[u8] = b"migration_record";
+pub const PENDING_OWNER_SEEDS: &[u8] = b"pending_owner";
+pub const FOLIO_ACTORS_SEEDS: &[u8] = b"folio_actors";
+pub const MINT_ALLOWLIST_ENTRY_SEEDS: &[u8] = b"mint_allowlist_entry";
+pub const FOLIO_MINT_LIMITS_SEEDS: &[u8] = b"folio_mint_limits";
 pub const REWARD_TOKENS_SEEDS: &[u8] = b"reward_tokens";
 pub const REWARD_INFO_SEEDS: &[u8] = b"reward_info";
 pub const USER_REWARD_INFO_SEEDS: &[u8] = b"user_reward_info";
@@ -342,6 +342,105 @@ pub enum ErrorCode {
 
     #[msg("Invalid Update Authority")]
     InvalidUpdateAuthority,
+
+    #[msg("Fee distribution can't be closed yet")]
+    FeeDistributionNotClosable,
+
+    #[msg("Invalid Fee Discount")]
+    InvalidFeeDiscount,
+
+    #[msg("Folio is paused for this action")]
+    FolioPaused,
+
+    #[msg("Invalid Pause Flags")]
+    InvalidPauseFlags,
+
+    #[msg("Unkill timelock has not passed yet")]
+    UnkillTimelockNotPassed,
+
+    #[msg("Invalid Role Expiration")]
+    InvalidRoleExpiration,
+
+    #[msg("Actor is already migrated")]
+    ActorAlreadyMigrated,
+
+    #[msg("Invalid Pending Owner")]
+    InvalidPendingOwner,
+
+    #[msg("Max Number of Actors Reached")]
+    MaxNumberOfActorsReached,
+
+    #[msg("User is not on the mint allowlist")]
+    UserNotOnMintAllowlist,
+
+    #[msg("Max Mint Per Transaction Exceeded")]
+    MaxMintPerTransactionExceeded,
+
+    #[msg("Max Supply Exceeded")]
+    MaxSupplyExceeded,
+
+    #[msg("Invalid Transfer Hook Program")]
+    InvalidTransferHookProgram,
+
+    #[msg("Invalid Extra Account Meta List")]
+    InvalidExtraAccountMetaList,
+
+    #[msg("Invalid UI Amount Multiplier")]
+    InvalidUiAmountMultiplier,
+
+    #[msg("No Surplus Balance To Sync")]
+    NoSurplusBalanceToSync,
+
+    #[msg("Folio Action Invariant Violated")]
+    FolioActionInvariantViolated,
+
+    #[msg("Max Number Of Legacy Tokens Reached")]
+    MaxNumberOfLegacyTokensReached,
+
+    #[msg("Migration Abort Grace Period Passed")]
+    MigrationAbortGracePeriodPassed,
+
+    #[msg("Migration Abort Grace Period Not Passed")]
+    MigrationAbortGracePeriodNotPassed,
+
+    #[msg("Migration Authorities Not Returned")]
+    MigrationAuthoritiesNotReturned,
+
+    #[msg("Account is already upgraded")]
+    AccountAlreadyUpgraded,
+
+    #[msg("Account is not upgradable")]
+    AccountNotUpgradable,
+
+    #[msg("Invalid Fee Distribution Fallback")]
+    InvalidFeeDistributionFallback,
+
+    #[msg("Folio Metadata Is Immutable")]
+    FolioMetadataImmutable,
+
+    #[msg("Invalid Unkill Timelock")]
+    InvalidUnkillTimelock,
+
+    #[msg("Owner Role Requires Ownership Transfer")]
+    OwnerRoleRequiresOwnershipTransfer,
+
+    #[msg("Transfer Hook Program Not Allowed")]
+    TransferHookProgramNotAllowed,
+
+    #[msg("User Pending Basket Already Tracked")]
+    UserPendingBasketAlreadyTracked,
+
+    #[msg("Pending Baskets Already Tracked")]
+    PendingBasketsAlreadyTracked,
+
+    #[msg("Pending Baskets Not Tracked")]
+    PendingBasketsNotTracked,
+
+    #[msg("Legacy Basket Not Empty")]
+    LegacyBasketNotEmpty,
+
+    #[msg("Missing Folio Mint Limits")]
+    MissingFolioMintLimits,
 }
 
 /// Check a condition and return an error if it is not met.
@@ -132,3 +132,42 @@ pub fn init_pda_account_rent<'info>(
 
     Ok(())
 }
+
+/// Helper function to resize an account, paying for the additional rent if needed.
+///
+/// # Arguments
+///
+/// * `account_to_resize`: The account to resize (must be owned by the calling program).
+/// * `space`: The new space of the account.
+/// * `payer`: The payer of the additional rent for the account.
+/// * `system_program`: The system program.
+///
+/// # Returns
+///
+/// * `Ok(())`: The account was resized successfully.
+#[cfg(not(tarpaulin_include))]
+pub fn resize_account_rent<'info>(
+    account_to_resize: &AccountInfo<'info>,
+    space: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let lamports_needed = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(account_to_resize.lamports());
+
+    if lamports_needed > 0 {
+        invoke(
+            &transfer(payer.key, account_to_resize.key, lamports_needed),
+            &[
+                payer.clone(),
+                account_to_resize.clone(),
+                system_program.clone(),
+            ],
+        )?;
+    }
+
+    account_to_resize.resize(space)?;
+
+    Ok(())
+}
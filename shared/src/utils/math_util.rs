@@ -116,6 +116,50 @@ impl Decimal {
 
         Decimal(result)
     }
+
+    /// Create a new Decimal from a f64, decoded exactly from its IEEE-754 bits and rounded down to D18,
+    /// so no floating point arithmetic is involved.
+    ///
+    /// # Arguments
+    /// * `value` - The f64 to create the Decimal from, must be finite and not negative
+    ///
+    /// Returns the Decimal in D18
+    pub fn from_f64(value: f64) -> Result<Self> {
+        if !value.is_finite() || value.is_sign_negative() {
+            return Err(MathOverflow.into());
+        }
+
+        let bits = value.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = bits & ((1u64 << 52) - 1);
+
+        // value = mantissa * 2^exponent, subnormals don't have the implicit leading bit
+        let (mantissa, exponent) = if biased_exponent == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | (1u64 << 52), biased_exponent - 1075)
+        };
+
+        let scaled_mantissa = U256::from(mantissa)
+            .checked_mul(D18_U256)
+            .ok_or(MathOverflow)?;
+
+        if exponent >= 0 {
+            // The shift must not drop any bit
+            if scaled_mantissa.leading_zeros() < exponent as u32 {
+                return Err(MathOverflow.into());
+            }
+
+            Ok(Decimal(scaled_mantissa << exponent as usize))
+        } else {
+            let shift = exponent.unsigned_abs() as usize;
+            if shift >= 256 {
+                return Ok(Decimal::ZERO);
+            }
+
+            Ok(Decimal(scaled_mantissa >> shift))
+        }
+    }
 }
 
 impl Decimal {
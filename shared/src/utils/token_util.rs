@@ -1,11 +1,30 @@
+use crate::utils::math_util::Decimal;
+use crate::utils::Rounding;
+use crate::{check_condition, errors::ErrorCode};
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, TransferChecked};
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
 use spl_token_2022::{
-    extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+    extension::{
+        interest_bearing_mint::InterestBearingConfig, scaled_ui_amount::ScaledUiAmountConfig,
+        transfer_fee::TransferFeeConfig, transfer_hook, BaseStateWithExtensions, ExtensionType,
+        StateWithExtensions,
+    },
+    onchain::invoke_transfer_checked,
     state::{Account, Mint},
 };
+use spl_transfer_hook_interface::{
+    get_extra_account_metas_address, instruction::ExecuteInstruction,
+};
+use spl_type_length_value::state::TlvStateBorrowed;
+
+/// Seconds per year multiplied by one in basis points, as used by Token-2022 for the interest bearing mints
+/// (a year being 365.24 days).
+const INTEREST_BEARING_SECONDS_PER_YEAR_IN_BASIS_POINTS: u64 = 31_556_736 * 10_000;
 
 /// Utility struct to do some verifications on the provided mints, make sure we don't have any extensions (spl-2022) that could break the folio.
 /// They could break the folio program by requiring additional accounts when doing CPIs, which could lead to transaction size issues.
+/// The only exception are transfer hooks on basket tokens, for which the extra accounts are resolved from the remaining accounts.
 pub struct TokenUtil;
 
 impl TokenUtil {
@@ -34,7 +53,8 @@ impl TokenUtil {
         ExtensionType::PermanentDelegate,
         ExtensionType::Pausable,
         ExtensionType::ConfidentialTransferMint,
-        // Only if the program_id is None.
+        // Only if the program_id is None, unless the token is a basket token and the program is allowed by the admin
+        // (see `is_supported_basket_spl_token`).
         ExtensionType::TransferHook,
     ];
 
@@ -47,21 +67,39 @@ impl TokenUtil {
         ExtensionType::PausableAccount,
     ];
 
+    /// The mint extension types that are only allowed for basket tokens.
+    /// `TransferFeeConfig` is supported as the basket accounting uses the amounts received net of the transfer fee.
+    pub const ALLOWED_BASKET_MINT_EXTENSION_TYPES: [ExtensionType; 1] =
+        [ExtensionType::TransferFeeConfig];
+
+    /// The token extension types that are only allowed for basket tokens.
+    pub const ALLOWED_BASKET_TOKEN_EXTENSION_TYPES: [ExtensionType; 1] =
+        [ExtensionType::TransferFeeAmount];
+
     /// Check if the mint has any extensions.
     ///
     /// # Arguments
     /// * `mint_account_info` - The mint account info.
+    /// * `is_basket_token` - Whether the mint is a basket token, which allows a `TransferHook` extension with an allowed
+    ///   program id as well as the `ALLOWED_BASKET_MINT_EXTENSION_TYPES`.
+    /// * `allowed_transfer_hook_programs` - The transfer hook programs allowed on basket tokens.
     ///
     /// Returns true if mint has any extensions that are not allowed, false otherwise.
     #[cfg(not(tarpaulin_include))]
-    fn mint_has_extensions(mint_account_info: &AccountInfo) -> Result<bool> {
+    fn mint_has_extensions(
+        mint_account_info: &AccountInfo,
+        is_basket_token: bool,
+        allowed_transfer_hook_programs: &[Pubkey],
+    ) -> Result<bool> {
         let mint_data = mint_account_info.data.borrow();
 
         let mint_with_extensions = StateWithExtensions::<Mint>::unpack(&mint_data)?;
 
         let mint_extension_types = mint_with_extensions.get_extension_types()?;
         let all_extensions_are_allowed = mint_extension_types.iter().all(|extension_type| {
-            TokenUtil::ALLOWED_MINT_EXTENSION_TYPES.contains(extension_type)
+            (TokenUtil::ALLOWED_MINT_EXTENSION_TYPES.contains(extension_type)
+                || (is_basket_token
+                    && TokenUtil::ALLOWED_BASKET_MINT_EXTENSION_TYPES.contains(extension_type)))
                 && match extension_type {
                     ExtensionType::DefaultAccountState => {
                         use spl_token_2022::extension::default_account_state::DefaultAccountState;
@@ -83,7 +121,10 @@ impl TokenUtil {
                         {
                             let program_id: Option<Pubkey> =
                                 transfer_hook_extension.program_id.into();
-                            program_id.is_none()
+                            program_id.is_none_or(|program_id| {
+                                is_basket_token
+                                    && allowed_transfer_hook_programs.contains(&program_id)
+                            })
                         } else {
                             false
                         }
@@ -98,16 +139,22 @@ impl TokenUtil {
     ///
     /// # Arguments
     /// * `token_account_info` - The token account info.
+    /// * `is_basket_token` - Whether the token is a basket token, which allows the `ALLOWED_BASKET_TOKEN_EXTENSION_TYPES`.
     ///
     /// Returns true if token has any extensions that are not allowed, false otherwise.
     #[cfg(not(tarpaulin_include))]
-    fn token_has_extensions(token_account_info: &AccountInfo) -> Result<bool> {
+    fn token_has_extensions(
+        token_account_info: &AccountInfo,
+        is_basket_token: bool,
+    ) -> Result<bool> {
         let token_data = token_account_info.data.borrow();
         let token_with_extensions = StateWithExtensions::<Account>::unpack(&token_data)?;
         let token_extension_types = token_with_extensions.get_extension_types()?;
 
         let all_extensions_are_allowed = token_extension_types.iter().all(|extension_type| {
             TokenUtil::ALLOWED_TOKEN_EXTENSION_TYPES.contains(extension_type)
+                || (is_basket_token
+                    && TokenUtil::ALLOWED_BASKET_TOKEN_EXTENSION_TYPES.contains(extension_type))
         });
 
         Ok(!all_extensions_are_allowed)
@@ -124,18 +171,429 @@ impl TokenUtil {
     pub fn is_supported_spl_token(
         mint_account_info: Option<&AccountInfo>,
         token_account_info: Option<&AccountInfo>,
+    ) -> Result<bool> {
+        TokenUtil::is_supported_token(mint_account_info, token_account_info, false, &[])
+    }
+
+    /// Check if the mint and token have forbidden extensions, for tokens that are part of a folio's basket.
+    /// Basket tokens are allowed to have an active transfer hook, as the folio resolves the extra accounts
+    /// required by the hook when transferring them, as long as the hook program is in the transfer hook registrar
+    /// of the admin, and a transfer fee, as the folio accounts for the amounts received net of the fee.
+    ///
+    /// # Arguments
+    /// * `mint_account_info` - The mint account info.
+    /// * `token_account_info` - The token account info.
+    /// * `allowed_transfer_hook_programs` - The transfer hook programs allowed on basket tokens.
+    ///
+    /// Returns true if the mint and token don't have forbidden extensions, false otherwise.
+    #[cfg(not(tarpaulin_include))]
+    pub fn is_supported_basket_spl_token(
+        mint_account_info: Option<&AccountInfo>,
+        token_account_info: Option<&AccountInfo>,
+        allowed_transfer_hook_programs: &[Pubkey],
+    ) -> Result<bool> {
+        TokenUtil::is_supported_token(
+            mint_account_info,
+            token_account_info,
+            true,
+            allowed_transfer_hook_programs,
+        )
+    }
+
+    #[cfg(not(tarpaulin_include))]
+    fn is_supported_token(
+        mint_account_info: Option<&AccountInfo>,
+        token_account_info: Option<&AccountInfo>,
+        is_basket_token: bool,
+        allowed_transfer_hook_programs: &[Pubkey],
     ) -> Result<bool> {
         let mint_has_extensions = if let Some(mint_account_info) = mint_account_info {
-            TokenUtil::mint_has_extensions(mint_account_info)?
+            TokenUtil::mint_has_extensions(
+                mint_account_info,
+                is_basket_token,
+                allowed_transfer_hook_programs,
+            )?
         } else {
             false
         };
         let token_has_extensions = if let Some(token_account_info) = token_account_info {
-            TokenUtil::token_has_extensions(token_account_info)?
+            TokenUtil::token_has_extensions(token_account_info, is_basket_token)?
         } else {
             false
         };
 
         Ok(!mint_has_extensions && !token_has_extensions)
     }
+
+    /// Get the transfer hook program id of the mint, if the mint has an active transfer hook.
+    ///
+    /// # Arguments
+    /// * `mint_account_info` - The mint account info.
+    ///
+    /// Returns the transfer hook program id, None if the mint doesn't have an active transfer hook.
+    #[cfg(not(tarpaulin_include))]
+    pub fn get_transfer_hook_program_id(mint_account_info: &AccountInfo) -> Result<Option<Pubkey>> {
+        if *mint_account_info.owner != spl_token_2022::ID {
+            return Ok(None);
+        }
+
+        let mint_data = mint_account_info.data.borrow();
+        let mint_with_extensions = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+
+        Ok(transfer_hook::get_program_id(&mint_with_extensions))
+    }
+
+    /// Check if the transfer hook program of the mint is allowed on basket tokens, for the transfers of tokens into a
+    /// folio, as the transfer hook program of a mint can be changed after the token was added to the basket.
+    ///
+    /// # Arguments
+    /// * `mint_account_info` - The mint account info.
+    /// * `allowed_transfer_hook_programs` - The transfer hook programs allowed on basket tokens.
+    ///
+    /// Returns true if the mint doesn't have an active transfer hook or if its program is allowed, false otherwise.
+    #[cfg(not(tarpaulin_include))]
+    pub fn is_transfer_hook_program_allowed(
+        mint_account_info: &AccountInfo,
+        allowed_transfer_hook_programs: &[Pubkey],
+    ) -> Result<bool> {
+        Ok(TokenUtil::get_transfer_hook_program_id(mint_account_info)?
+            .is_none_or(|program_id| allowed_transfer_hook_programs.contains(&program_id)))
+    }
+
+    /// Get the transfer fee config of the mint, if the mint has one.
+    ///
+    /// # Arguments
+    /// * `mint_account_info` - The mint account info.
+    ///
+    /// Returns the transfer fee config, None if the mint doesn't have the `TransferFeeConfig` extension.
+    #[cfg(not(tarpaulin_include))]
+    fn get_transfer_fee_config(
+        mint_account_info: &AccountInfo,
+    ) -> Result<Option<TransferFeeConfig>> {
+        if *mint_account_info.owner != spl_token_2022::ID {
+            return Ok(None);
+        }
+
+        let mint_data = mint_account_info.data.borrow();
+        let mint_with_extensions = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+
+        Ok(mint_with_extensions
+            .get_extension::<TransferFeeConfig>()
+            .ok()
+            .copied())
+    }
+
+    /// Get the amount received by the recipient when transferring a raw amount of the mint, in the current epoch.
+    ///
+    /// # Arguments
+    /// * `mint_account_info` - The mint account info.
+    /// * `raw_amount` - The amount sent.
+    ///
+    /// Returns the amount received, net of the transfer fee (if any).
+    #[cfg(not(tarpaulin_include))]
+    pub fn get_amount_after_transfer_fee(
+        mint_account_info: &AccountInfo,
+        raw_amount: u64,
+    ) -> Result<u64> {
+        TokenUtil::get_amount_after_transfer_fee_in_epoch(
+            TokenUtil::get_transfer_fee_config(mint_account_info)?.as_ref(),
+            Clock::get()?.epoch,
+            raw_amount,
+        )
+    }
+
+    /// Get the amount received by the recipient when transferring a raw amount, with the transfer fee of an epoch.
+    ///
+    /// # Arguments
+    /// * `transfer_fee_config` - The transfer fee config of the mint, None if the mint doesn't have one.
+    /// * `epoch` - The epoch of the transfer.
+    /// * `raw_amount` - The amount sent.
+    ///
+    /// Returns the amount received, net of the transfer fee (if any).
+    pub fn get_amount_after_transfer_fee_in_epoch(
+        transfer_fee_config: Option<&TransferFeeConfig>,
+        epoch: u64,
+        raw_amount: u64,
+    ) -> Result<u64> {
+        let Some(transfer_fee_config) = transfer_fee_config else {
+            return Ok(raw_amount);
+        };
+
+        let raw_fee = transfer_fee_config
+            .calculate_epoch_fee(epoch, raw_amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        Ok(raw_amount
+            .checked_sub(raw_fee)
+            .ok_or(ErrorCode::MathOverflow)?)
+    }
+
+    /// Get the amount to send for the recipient to receive a raw amount of the mint, in the current epoch.
+    ///
+    /// # Arguments
+    /// * `mint_account_info` - The mint account info.
+    /// * `raw_amount_received` - The amount to be received.
+    ///
+    /// Returns the amount to send, including the transfer fee (if any).
+    #[cfg(not(tarpaulin_include))]
+    pub fn get_amount_before_transfer_fee(
+        mint_account_info: &AccountInfo,
+        raw_amount_received: u64,
+    ) -> Result<u64> {
+        TokenUtil::get_amount_before_transfer_fee_in_epoch(
+            TokenUtil::get_transfer_fee_config(mint_account_info)?.as_ref(),
+            Clock::get()?.epoch,
+            raw_amount_received,
+        )
+    }
+
+    /// Get the amount to send for the recipient to receive a raw amount, with the transfer fee of an epoch.
+    ///
+    /// # Arguments
+    /// * `transfer_fee_config` - The transfer fee config of the mint, None if the mint doesn't have one.
+    /// * `epoch` - The epoch of the transfer.
+    /// * `raw_amount_received` - The amount to be received.
+    ///
+    /// Returns the amount to send, including the transfer fee (if any).
+    pub fn get_amount_before_transfer_fee_in_epoch(
+        transfer_fee_config: Option<&TransferFeeConfig>,
+        epoch: u64,
+        raw_amount_received: u64,
+    ) -> Result<u64> {
+        let Some(transfer_fee_config) = transfer_fee_config else {
+            return Ok(raw_amount_received);
+        };
+
+        let raw_fee = transfer_fee_config
+            .calculate_inverse_epoch_fee(epoch, raw_amount_received)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        Ok(raw_amount_received
+            .checked_add(raw_fee)
+            .ok_or(ErrorCode::MathOverflow)?)
+    }
+
+    /// Convert the f64 multiplier of a `ScaledUiAmount` mint to D18, rounded down.
+    ///
+    /// # Arguments
+    /// * `multiplier` - The multiplier of the mint.
+    ///
+    /// Returns the D18{uiTok/tok} multiplier.
+    pub fn get_scaled_ui_amount_multiplier_from_f64(multiplier: f64) -> Result<u128> {
+        check_condition!(
+            multiplier.is_finite() && multiplier.is_sign_positive(),
+            InvalidUiAmountMultiplier
+        );
+
+        let scaled_multiplier = Decimal::from_f64(multiplier)?;
+        check_condition!(
+            scaled_multiplier <= Decimal::from_scaled(u128::MAX),
+            MathOverflow
+        );
+
+        let scaled_multiplier = scaled_multiplier.to_scaled(Rounding::Floor)?;
+        check_condition!(scaled_multiplier > 0, InvalidUiAmountMultiplier);
+
+        Ok(scaled_multiplier)
+    }
+
+    /// Get the current UI amount multiplier of the mint, for `ScaledUiAmount` and `InterestBearingConfig` mints.
+    /// The multiplier doesn't include the decimals of the mint, so it converts raw amounts to raw UI amounts.
+    ///
+    /// # Arguments
+    /// * `mint_account_info` - The mint account info.
+    /// * `current_time` - The current on-chain time (seconds).
+    ///
+    /// Returns the D18{uiTok/tok} multiplier, None if the mint doesn't have a UI amount multiplier.
+    #[cfg(not(tarpaulin_include))]
+    pub fn get_scaled_ui_amount_multiplier(
+        mint_account_info: &AccountInfo,
+        current_time: i64,
+    ) -> Result<Option<u128>> {
+        if *mint_account_info.owner != spl_token_2022::ID {
+            return Ok(None);
+        }
+
+        let mint_data = mint_account_info.data.borrow();
+        let mint_with_extensions = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+
+        if let Ok(scaled_ui_amount_config) =
+            mint_with_extensions.get_extension::<ScaledUiAmountConfig>()
+        {
+            let multiplier: f64 = if current_time
+                >= i64::from(scaled_ui_amount_config.new_multiplier_effective_timestamp)
+            {
+                scaled_ui_amount_config.new_multiplier.into()
+            } else {
+                scaled_ui_amount_config.multiplier.into()
+            };
+
+            return Ok(Some(TokenUtil::get_scaled_ui_amount_multiplier_from_f64(
+                multiplier,
+            )?));
+        }
+
+        if let Ok(interest_bearing_config) =
+            mint_with_extensions.get_extension::<InterestBearingConfig>()
+        {
+            // Same as Token-2022: e^((pre_update_rate * pre_update_timespan + current_rate * post_update_timespan) / (seconds per year * 10_000))
+            let last_update_timestamp = i64::from(interest_bearing_config.last_update_timestamp);
+            let pre_update_timespan = last_update_timestamp
+                .checked_sub(interest_bearing_config.initialization_timestamp.into())
+                .ok_or(ErrorCode::MathOverflow)?;
+            let post_update_timespan = current_time
+                .checked_sub(last_update_timestamp)
+                .ok_or(ErrorCode::MathOverflow)?;
+
+            let exponent_numerator = (i16::from(interest_bearing_config.pre_update_average_rate)
+                as i128)
+                .checked_mul(pre_update_timespan as i128)
+                .and_then(|pre_update| {
+                    (i16::from(interest_bearing_config.current_rate) as i128)
+                        .checked_mul(post_update_timespan as i128)
+                        .and_then(|post_update| pre_update.checked_add(post_update))
+                })
+                .ok_or(ErrorCode::MathOverflow)?;
+
+            // D18{1}
+            let scaled_exponent = Decimal::from_scaled(
+                exponent_numerator
+                    .unsigned_abs()
+                    .checked_mul(Decimal::ONE_E18.to_scaled(Rounding::Floor)?)
+                    .ok_or(ErrorCode::MathOverflow)?,
+            )
+            .div(&Decimal::from_scaled(
+                INTEREST_BEARING_SECONDS_PER_YEAR_IN_BASIS_POINTS,
+            ))?;
+
+            let scaled_multiplier = scaled_exponent
+                .exp(exponent_numerator < 0)?
+                .ok_or(ErrorCode::MathOverflow)?
+                .to_scaled(Rounding::Floor)?;
+
+            check_condition!(scaled_multiplier > 0, InvalidUiAmountMultiplier);
+
+            return Ok(Some(scaled_multiplier));
+        }
+
+        Ok(None)
+    }
+
+    /// Consume the accounts required by the transfer hook of the mint from the remaining accounts.
+    /// Nothing is consumed if the mint doesn't have an active transfer hook.
+    ///
+    /// Order is
+    ///
+    /// - Transfer Hook Program (read)
+    /// - Extra Account Meta List (PDA of the transfer hook program) (read)
+    /// - Extra accounts, in the order defined in the extra account meta list
+    ///
+    /// # Arguments
+    /// * `iter` - The iterator of the remaining accounts.
+    /// * `mint_account_info` - The mint account info.
+    ///
+    /// Returns the transfer hook accounts, to be passed to `transfer_checked`.
+    #[cfg(not(tarpaulin_include))]
+    pub fn next_transfer_hook_accounts<'b>(
+        iter: &mut std::slice::Iter<'b, AccountInfo<'b>>,
+        mint_account_info: &AccountInfo,
+    ) -> Result<Vec<AccountInfo<'b>>> {
+        let Some(transfer_hook_program_id) =
+            TokenUtil::get_transfer_hook_program_id(mint_account_info)?
+        else {
+            return Ok(vec![]);
+        };
+
+        let transfer_hook_program = iter.next().ok_or(ErrorCode::MissingRemainingAccount)?;
+        check_condition!(
+            transfer_hook_program.key() == transfer_hook_program_id,
+            InvalidTransferHookProgram
+        );
+
+        let extra_account_meta_list = iter.next().ok_or(ErrorCode::MissingRemainingAccount)?;
+        check_condition!(
+            extra_account_meta_list.key()
+                == get_extra_account_metas_address(
+                    mint_account_info.key,
+                    &transfer_hook_program_id
+                ),
+            InvalidExtraAccountMetaList
+        );
+
+        let number_of_extra_accounts = {
+            let data = extra_account_meta_list.try_borrow_data()?;
+            let tlv_state = TlvStateBorrowed::unpack(&data)?;
+            ExtraAccountMetaList::unpack_with_tlv_state::<ExecuteInstruction>(&tlv_state)?
+                .data()
+                .len()
+        };
+
+        let mut transfer_hook_accounts = vec![
+            transfer_hook_program.clone(),
+            extra_account_meta_list.clone(),
+        ];
+        for _ in 0..number_of_extra_accounts {
+            let extra_account = iter.next().ok_or(ErrorCode::MissingRemainingAccount)?;
+            transfer_hook_accounts.push(extra_account.clone());
+        }
+
+        Ok(transfer_hook_accounts)
+    }
+
+    /// Transfer tokens with `transfer_checked`, passing the accounts required by the transfer hook of the mint if any.
+    ///
+    /// # Arguments
+    /// * `token_program` - The token program.
+    /// * `from` - The sender token account.
+    /// * `mint` - The token mint.
+    /// * `to` - The recipient token account.
+    /// * `authority` - The authority of the sender token account.
+    /// * `transfer_hook_accounts` - The transfer hook accounts, from `next_transfer_hook_accounts`.
+    /// * `raw_amount` - The amount to transfer.
+    /// * `decimals` - The decimals of the mint.
+    /// * `signer_seeds` - The signer seeds of the authority, if it's a PDA.
+    #[allow(clippy::too_many_arguments)]
+    #[cfg(not(tarpaulin_include))]
+    pub fn transfer_checked<'info>(
+        token_program: &AccountInfo<'info>,
+        from: &AccountInfo<'info>,
+        mint: &AccountInfo<'info>,
+        to: &AccountInfo<'info>,
+        authority: &AccountInfo<'info>,
+        transfer_hook_accounts: &[AccountInfo<'info>],
+        raw_amount: u64,
+        decimals: u8,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        if transfer_hook_accounts.is_empty() {
+            return token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    token_program.clone(),
+                    TransferChecked {
+                        from: from.clone(),
+                        mint: mint.clone(),
+                        to: to.clone(),
+                        authority: authority.clone(),
+                    },
+                    signer_seeds,
+                ),
+                raw_amount,
+                decimals,
+            );
+        }
+
+        invoke_transfer_checked(
+            token_program.key,
+            from.clone(),
+            mint.clone(),
+            to.clone(),
+            authority.clone(),
+            transfer_hook_accounts,
+            raw_amount,
+            decimals,
+            signer_seeds,
+        )?;
+
+        Ok(())
+    }
 }
@@ -9,6 +9,7 @@ import {
   getFolioPDAWithBump,
   getRewardTokensPDAWithBump,
   getProgramRegistrarPDAWithBump,
+  getTransferHookRegistrarPDAWithBump,
   getRewardInfoPDA,
   getRewardInfoPDAWithBump,
   getAuctionPDAWithBump,
@@ -24,6 +25,9 @@ import {
   getRebalancePDAWithBump,
   getAuctionEndsPDAWithBump,
   getMetadataPDA,
+  getMigrationRecordPDAWithBump,
+  getFolioLegacyBasketPDAWithBump,
+  getGovernanceHoldingPDA,
 } from "../../utils/pda-helper";
 import * as crypto from "crypto";
 import { Folio } from "../../target/types/folio";
@@ -35,6 +39,7 @@ import {
   MAX_AUCTION_LENGTH,
   MAX_FOLIO_TOKEN_AMOUNTS,
   MAX_USER_PENDING_BASKET_TOKEN_AMOUNTS,
+  MAX_FOLIO_LEGACY_TOKEN_AMOUNTS,
   MAX_REWARD_TOKENS,
   FOLIO_ADMIN_PROGRAM_ID,
   MAX_MINT_FEE,
@@ -45,7 +50,7 @@ import {
   MAX_REBALANCE_DETAILS,
   TOKEN_METADATA_PROGRAM_ID,
 } from "../../utils/constants";
-import { getOrCreateAtaAddress } from "./bankrun-token-helper";
+import { getAtaAddress, getOrCreateAtaAddress } from "./bankrun-token-helper";
 import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { LiteSVM } from "litesvm";
 
@@ -71,6 +76,8 @@ export enum Role {
   RebalanceManager = 0b00000010, // 2
   AuctionLauncher = 0b00000100, // 4
   BrandManager = 0b00001000, // 8
+  Guardian = 0b00010000, // 16
+  AllowlistManager = 0b00100000, // 32
 }
 
 // For anchor serialization, anchor's enums are sent using {enumValue: {}}
@@ -80,6 +87,8 @@ export function roleToStruct(role: Role) {
     [Role.RebalanceManager]: { rebalanceManager: {} },
     [Role.AuctionLauncher]: { auctionLauncher: {} },
     [Role.BrandManager]: { brandManager: {} },
+    [Role.Guardian]: { guardian: {} },
+    [Role.AllowlistManager]: { allowlistManager: {} },
   }[role];
 }
 
@@ -90,6 +99,13 @@ export enum FolioStatus {
   Migrating = 3,
 }
 
+export enum PauseFlag {
+  Mint = 0b00000001, // 1
+  Redeem = 0b00000010, // 2
+  Auctions = 0b00000100, // 4
+  FeeDistribution = 0b00001000, // 8
+}
+
 export class FeeRecipient {
   recipient: PublicKey;
   portion: BN;
@@ -326,6 +342,36 @@ export async function setFolioAccountInfo(
   ctx.setAccount(accountAddress, accountInfo);
 }
 
+// Overwrites the pause flags of an existing folio, keeping the rest of its state
+export async function setFolioPauseFlags(
+  ctx: LiteSVM,
+  folio: PublicKey,
+  pauseFlags: number
+) {
+  const folioAccount = await ctx.getAccount(folio);
+  const data = Buffer.from(folioAccount.data);
+
+  // Discriminator + bump + status
+  data.writeUInt8(pauseFlags, 8 + 1 + 1);
+
+  ctx.setAccount(folio, { ...folioAccount, data });
+}
+
+// Overwrites whether the mint allowlist is enabled on an existing folio, keeping the rest of its state
+export async function setFolioMintAllowlistEnabled(
+  ctx: LiteSVM,
+  folio: PublicKey,
+  enabled: boolean
+) {
+  const folioAccount = await ctx.getAccount(folio);
+  const data = Buffer.from(folioAccount.data);
+
+  // Discriminator + bump + status + pause flags
+  data.writeUInt8(enabled ? 1 : 0, 8 + 1 + 1 + 1);
+
+  ctx.setAccount(folio, { ...folioAccount, data });
+}
+
 export async function createAndSetRebalanceAccount(
   ctx: LiteSVM,
   program: Program<Folio> | Program<FolioSecond>,
@@ -442,7 +488,8 @@ export async function createAndSetMetadataAccount(
   ctx: LiteSVM,
   folio: PublicKey,
   mint: PublicKey,
-  metadataProgramId: PublicKey = TOKEN_METADATA_PROGRAM_ID
+  metadataProgramId: PublicKey = TOKEN_METADATA_PROGRAM_ID,
+  isMutable: boolean = false
 ) {
   const metadataAddress = getMetadataPDA(mint);
   const METADATA_KEY = 4;
@@ -460,6 +507,13 @@ export async function createAndSetMetadataAccount(
   mint.toBuffer().copy(buffer, offset);
   offset += 32;
 
+  // Empty name, symbol and uri, no seller fee basis points, no creators and
+  // primary sale not happened
+  offset += 4 + 4 + 4 + 2 + 1 + 1;
+
+  buffer.writeUInt8(isMutable ? 1 : 0, offset);
+  offset += 1;
+
   ctx.setAccount(metadataAddress, {
     lamports: 1_000_000_000,
     data: buffer,
@@ -535,7 +589,8 @@ export async function createAndSetFolio(
   feeRecipientsPendingFeeShares: BN = new BN(0),
   useSecondFolioProgram: boolean = false,
   mandate: string = "",
-  feeRecipientsPendingFeeSharesToBeMinted: BN = new BN(0)
+  feeRecipientsPendingFeeSharesToBeMinted: BN = new BN(0),
+  pendingBasketsTracked: boolean = true
 ) {
   // Set last poke as current time stamp, else 0 would make the elapsed time huge
   if (lastPoke.isZero()) {
@@ -563,9 +618,17 @@ export async function createAndSetFolio(
   buffer.writeUInt8(status, offset);
   offset += 1;
 
-  // Write padding
-  buffer.fill(0, offset, offset + 6);
-  offset += 6;
+  // Write pause flags, mint allowlist enabled, version and fee distribution fallback
+  buffer.fill(0, offset, offset + 4);
+  offset += 4;
+
+  // Write pending baskets tracked
+  buffer.writeUInt8(pendingBasketsTracked ? 1 : 0, offset);
+  offset += 1;
+
+  // Write mint limits enabled
+  buffer.fill(0, offset, offset + 1);
+  offset += 1;
 
   const currentTime = (await ctx.getClock()).unixTimestamp;
   buffer.writeBigUInt64LE(currentTime, offset);
@@ -634,11 +697,68 @@ export async function createAndSetActor(
     authority: actorKeypairToUse,
     folio: folio,
     roles: roles,
+    version: 1,
+    roleExpirations: Array(8).fill(new BN(0)),
   };
 
   await setFolioAccountInfo(ctx, program, actorPDAWithBump[0], "actor", actor);
 }
 
+export async function createAndSetMigrationRecord(
+  ctx: LiteSVM,
+  program: Program<Folio>,
+  folio: PublicKey,
+  newFolio: PublicKey,
+  previousStatus: number,
+  startedAt: BN
+) {
+  const migrationRecordPDAWithBump = getMigrationRecordPDAWithBump(folio);
+
+  const migrationRecord = {
+    bump: migrationRecordPDAWithBump[1],
+    folio,
+    newFolio,
+    previousStatus,
+    startedAt,
+  };
+
+  await setFolioAccountInfo(
+    ctx,
+    program,
+    migrationRecordPDAWithBump[0],
+    "migrationRecord",
+    migrationRecord
+  );
+}
+
+export async function createAndSetFolioLegacyBasket(
+  ctx: LiteSVM,
+  program: Program<Folio>,
+  folio: PublicKey,
+  legacyTokenAmounts: FolioTokenAmount[]
+) {
+  const folioLegacyBasketPDAWithBump = getFolioLegacyBasketPDAWithBump(folio);
+
+  const folioLegacyBasket = {
+    bump: folioLegacyBasketPDAWithBump[1],
+    folio,
+    tokenAmounts: [
+      ...legacyTokenAmounts,
+      ...Array(MAX_FOLIO_LEGACY_TOKEN_AMOUNTS - legacyTokenAmounts.length).fill(
+        new FolioTokenAmount(PublicKey.default, new BN(0))
+      ),
+    ],
+  };
+
+  await setFolioAccountInfo(
+    ctx,
+    program,
+    folioLegacyBasketPDAWithBump[0],
+    "folioLegacyBasket",
+    folioLegacyBasket
+  );
+}
+
 export async function createAndSetFeeRecipients(
   ctx: LiteSVM,
   program: Program<Folio>,
@@ -714,7 +834,9 @@ export async function createAndSetFeeDistribution(
   cranker: PublicKey,
   distribtionIndex: BN,
   amountToDistribute: BN,
-  feeRecipients: FeeRecipient[]
+  feeRecipients: FeeRecipient[],
+  // Null for the legacy layout, before the version and `created_at` were added
+  createdAt: BN | null = null
 ) {
   const feeDistributionPDAWithBump = getFeeDistributionPDAWithBump(
     folio,
@@ -735,7 +857,7 @@ export async function createAndSetFeeDistribution(
   };
 
   // Manual encoding for fee recipients
-  const buffer = Buffer.alloc(3176);
+  const buffer = Buffer.alloc(createdAt === null ? 3176 : 3192);
   let offset = 0;
 
   // Encode discriminator
@@ -782,6 +904,15 @@ export async function createAndSetFeeDistribution(
     offset += 16;
   });
 
+  if (createdAt !== null) {
+    // Encode version (first byte after the bump)
+    buffer.writeUInt8(1, 9);
+
+    // Encode created_at, after all the fee recipients
+    offset = 8 + 1 + 7 + 8 + 32 + 32 + 16 + 64 * 48;
+    buffer.writeBigUInt64LE(BigInt(createdAt.toString()), offset);
+  }
+
   await setFolioAccountInfo(
     ctx,
     program,
@@ -869,7 +1000,8 @@ export async function createAndSetUserPendingBasket(
 
   const userPendingBasket = {
     bump: userPendingBasketPDAWithBump[1],
-    _padding: [0, 0, 0, 0, 0, 0, 0],
+    isTracked: 0,
+    _padding: [0, 0, 0, 0, 0, 0],
     owner: owner,
     folio: folio,
     tokenAmounts: tokenAmounts,
@@ -887,6 +1019,10 @@ export async function createAndSetUserPendingBasket(
   buffer.writeUInt8(userPendingBasket.bump, offset);
   offset += 1;
 
+  // Encode is tracked
+  buffer.writeUInt8(userPendingBasket.isTracked, offset);
+  offset += 1;
+
   // Encode padding
   userPendingBasket._padding.forEach((pad: number) => {
     buffer.writeUInt8(pad, offset);
@@ -1172,6 +1308,30 @@ export async function createAndSetProgramRegistrar(
   );
 }
 
+export async function createAndSetTransferHookRegistrar(
+  ctx: LiteSVM,
+  program: Program<FolioAdmin>,
+  allowedPrograms: PublicKey[]
+) {
+  const transferHookRegistrarPDAWithBump =
+    getTransferHookRegistrarPDAWithBump();
+
+  const transferHookRegistrar = {
+    bump: transferHookRegistrarPDAWithBump[1],
+    allowedPrograms: allowedPrograms.concat(
+      Array(10 - allowedPrograms.length).fill(PublicKey.default)
+    ),
+  };
+
+  await setFolioAdminAccountInfo(
+    ctx,
+    program,
+    transferHookRegistrarPDAWithBump[0],
+    "transferHookRegistrar",
+    transferHookRegistrar
+  );
+}
+
 /*
 Folio Admin Accounts
 */
@@ -1574,6 +1734,54 @@ export async function buildRemainingAccounts(
   return remainingAccounts;
 }
 
+/**
+ * Accounts required by the transfer hook of a mint using the transfer hook
+ * mock program: the program, the extra account meta list and the transfer
+ * counter of the mint, which is incremented by the hook.
+ */
+export function buildRemainingAccountsForTransferHook(
+  mint: PublicKey,
+  transferHookProgramId: PublicKey
+): AccountMeta[] {
+  return [
+    {
+      pubkey: transferHookProgramId,
+      isSigner: false,
+      isWritable: false,
+    },
+    {
+      pubkey: getExtraAccountMetaListPDA(mint, transferHookProgramId),
+      isSigner: false,
+      isWritable: false,
+    },
+    {
+      pubkey: getTransferCounterPDA(mint, transferHookProgramId),
+      isSigner: false,
+      isWritable: true,
+    },
+  ];
+}
+
+export function getExtraAccountMetaListPDA(
+  mint: PublicKey,
+  transferHookProgramId: PublicKey
+) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("extra-account-metas"), mint.toBuffer()],
+    transferHookProgramId
+  )[0];
+}
+
+export function getTransferCounterPDA(
+  mint: PublicKey,
+  transferHookProgramId: PublicKey
+) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("transfer_counter"), mint.toBuffer()],
+    transferHookProgramId
+  )[0];
+}
+
 export async function buildRemainingAccountsForAccruesRewards(
   context: LiteSVM,
   callerKeypair: Keypair,
@@ -1622,6 +1830,38 @@ export async function buildRemainingAccountsForAccruesRewards(
   return remainingAccounts;
 }
 
+// Token account of a reward tokens fee recipient, followed by the accounts to accrue the rewards of its realm
+export function buildRemainingAccountsForRewardTokensRecipient(
+  folioTokenMint: PublicKey,
+  realm: PublicKey,
+  governanceTokenMint: PublicKey,
+  tokenProgram: PublicKey = TOKEN_PROGRAM_ID
+): AccountMeta[] {
+  const rewardTokensPDA = getRewardTokensPDA(realm);
+
+  return [
+    {
+      pubkey: getAtaAddress(folioTokenMint, rewardTokensPDA, tokenProgram),
+      isSigner: false,
+      isWritable: true,
+    },
+    { pubkey: rewardTokensPDA, isSigner: false, isWritable: false },
+    { pubkey: REWARDS_PROGRAM_ID, isSigner: false, isWritable: false },
+    { pubkey: realm, isSigner: false, isWritable: false },
+    { pubkey: governanceTokenMint, isSigner: false, isWritable: false },
+    {
+      pubkey: getGovernanceHoldingPDA(realm, governanceTokenMint),
+      isSigner: false,
+      isWritable: false,
+    },
+    {
+      pubkey: getRewardInfoPDA(realm, folioTokenMint),
+      isSigner: false,
+      isWritable: true,
+    },
+  ];
+}
+
 export async function buildRemainingAccountsForClaimRewards(
   context: LiteSVM,
   callerKeypair: Keypair,
@@ -1676,7 +1916,8 @@ export async function buildRemainingAccountsForMigrateFolioTokens(
   userKeypair: Keypair,
   oldFolio: PublicKey,
   newFolio: PublicKey,
-  tokens: PublicKey[]
+  tokens: PublicKey[],
+  tokenProgram: PublicKey = TOKEN_PROGRAM_ID
 ) {
   const remainingAccounts: AccountMeta[] = [];
 
@@ -1688,13 +1929,23 @@ export async function buildRemainingAccountsForMigrateFolioTokens(
     });
 
     remainingAccounts.push({
-      pubkey: await getOrCreateAtaAddress(context, token, oldFolio),
+      pubkey: await getOrCreateAtaAddress(
+        context,
+        token,
+        oldFolio,
+        tokenProgram
+      ),
       isSigner: false,
       isWritable: true,
     });
 
     remainingAccounts.push({
-      pubkey: await getOrCreateAtaAddress(context, token, newFolio),
+      pubkey: await getOrCreateAtaAddress(
+        context,
+        token,
+        newFolio,
+        tokenProgram
+      ),
       isSigner: false,
       isWritable: true,
     });
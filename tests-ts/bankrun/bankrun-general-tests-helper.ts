@@ -7,7 +7,13 @@ import {
   createAndProcessTransaction,
   travelFutureSlot,
 } from "./bankrun-program-helper";
-import { createAndSetFolio, FolioStatus, Role } from "./bankrun-account-helper";
+import {
+  createAndSetFolio,
+  FolioStatus,
+  PauseFlag,
+  Role,
+  setFolioPauseFlags,
+} from "./bankrun-account-helper";
 import { createAndSetActor } from "./bankrun-account-helper";
 import { Program } from "@coral-xyz/anchor";
 import { Folio } from "../../target/types/folio";
@@ -22,6 +28,7 @@ export enum GeneralTestCases {
   NotAdmin = "not admin",
   NotRole = "not role",
   InvalidFolioStatus = "invalid folio status",
+  Paused = "paused",
 }
 
 export async function assertNonAdminTestCase(
@@ -91,3 +98,18 @@ export async function assertInvalidFolioStatusTestCase(
 
   assertError(txnResult, "InvalidFolioStatus");
 }
+
+export async function assertPausedTestCase(
+  context: LiteSVM,
+  folioPDA: PublicKey,
+  executeTxn: () => Promise<BanksTransactionResultWithMeta>,
+  pauseFlag: PauseFlag
+) {
+  await setFolioPauseFlags(context, folioPDA, pauseFlag);
+
+  await travelFutureSlot(context);
+
+  const txnResult = await executeTxn();
+
+  assertError(txnResult, "FolioPaused");
+}
@@ -4,23 +4,36 @@ import {
 } from "./bankrun-program-helper";
 import {
   getActorPDA,
+  getFolioActorsPDA,
   getDAOFeeConfigPDA,
   getFeeDistributionPDA,
   getFolioBasketPDA,
   getTVLFeeRecipientsPDA,
   getFolioPDA,
   getMetadataPDA,
+  getMigrationRecordPDA,
   getProgramRegistrarPDA,
+  getFolioActionRegistrarPDA,
+  getTransferHookRegistrarPDA,
   getRewardInfoPDA,
   getUserPendingBasketPDA,
+  getFolioLegacyBasketPDA,
+  getFolioPendingBasketPDA,
   getFolioFeeConfigPDA,
   getRewardTokensPDA,
   getAuctionEndsPDA,
   getGovernanceHoldingPDA,
+  getFeeDiscountPDA,
+  getMintAllowlistEntryPDA,
+  getFolioMintLimitsPDA,
+  getKillRecordPDA,
+  getUnkillConfigPDA,
+  getPendingOwnerPDA,
 } from "../../utils/pda-helper";
 import {
   AccountMeta,
   PublicKey,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
   SYSVAR_RENT_PUBKEY,
   TransactionInstruction,
 } from "@solana/web3.js";
@@ -30,10 +43,9 @@ import { Keypair } from "@solana/web3.js";
 import { LiteSVM } from "litesvm";
 import { ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { Folio } from "../../target/types/folio";
-import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { getComputeLimitInstruction } from "../../utils/program-helper";
 import {
-  D9,
   FOLIO_PROGRAM_ID,
   OTHER_ADMIN_KEY,
   REWARDS_PROGRAM_ID,
@@ -48,8 +60,9 @@ import {
   buildRemainingAccountsForAccruesRewards,
   buildRemainingAccountsForUpdateFolio,
 } from "./bankrun-account-helper";
-import { getOrCreateAtaAddress } from "./bankrun-token-helper";
+import { getAtaAddress, getOrCreateAtaAddress } from "./bankrun-token-helper";
 import { FolioAdmin } from "../../target/types/folio_admin";
+import { TransferHookMock } from "../../target/types/transfer_hook_mock";
 // import { SplGovernance } from "governance-idl-sdk";
 import { Rewards } from "../../target/types/rewards";
 import { SplGovernance } from "governance-idl-sdk";
@@ -76,14 +89,20 @@ export async function setDaoFeeConfig<T extends boolean = true>(
   feeRecipient: PublicKey,
   feeNumerator: BN,
   feeFloor: BN,
-  executeTxn: T = true as T
+  executeTxn: T = true as T,
+  protocolFeeRecipients: { recipient: PublicKey; portion: BN }[] | null = null
 ): Promise<
   T extends true
     ? BanksTransactionResultWithMeta
     : { ix: TransactionInstruction; extraSigners: any[] }
 > {
   const ix = await programFolioAdmin.methods
-    .setDaoFeeConfig(feeRecipient, feeNumerator, feeFloor)
+    .setDaoFeeConfig(
+      feeRecipient,
+      feeNumerator,
+      feeFloor,
+      protocolFeeRecipients
+    )
     .accountsPartial({
       systemProgram: SystemProgram.programId,
       rent: SYSVAR_RENT_PUBKEY,
@@ -108,14 +127,15 @@ export async function setFolioFeeConfig<T extends boolean = true>(
   feeNumerator: BN,
   feeFloor: BN,
   feeRecipient: PublicKey,
-  executeTxn: T = true as T
+  executeTxn: T = true as T,
+  folioFeeRecipient: PublicKey | null = null
 ): Promise<
   T extends true
     ? BanksTransactionResultWithMeta
     : { ix: TransactionInstruction; extraSigners: any[] }
 > {
   const ix = await programFolioAdmin.methods
-    .setFolioFeeConfig(feeNumerator, feeFloor)
+    .setFolioFeeConfig(feeNumerator, feeFloor, folioFeeRecipient)
     .accountsPartial({
       systemProgram: SystemProgram.programId,
       rent: SYSVAR_RENT_PUBKEY,
@@ -142,6 +162,33 @@ export async function setFolioFeeConfig<T extends boolean = true>(
   return { ix, extraSigners: [] } as any;
 }
 
+export async function setUnkillConfig<T extends boolean = true>(
+  client: LiteSVM,
+  programFolioAdmin: Program<FolioAdmin>,
+  adminKeypair: Keypair,
+  unkillTimelock: BN,
+  executeTxn: T = true as T
+): Promise<
+  T extends true
+    ? BanksTransactionResultWithMeta
+    : { ix: TransactionInstruction; extraSigners: any[] }
+> {
+  const ix = await programFolioAdmin.methods
+    .setUnkillConfig(unkillTimelock)
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      admin: !executeTxn ? OTHER_ADMIN_KEY.publicKey : adminKeypair.publicKey,
+      unkillConfig: getUnkillConfigPDA(),
+    })
+    .instruction();
+
+  if (executeTxn) {
+    return createAndProcessTransaction(client, adminKeypair, [ix]) as any;
+  }
+
+  return { ix, extraSigners: [] } as any;
+}
+
 export async function initProgramRegistrar<T extends boolean = true>(
   client: LiteSVM,
   programFolioAdmin: Program<FolioAdmin>,
@@ -201,6 +248,89 @@ export async function updateProgramRegistrar<T extends boolean = true>(
   return { ix: updateProgramRegistrar, extraSigners: [] } as any;
 }
 
+export async function updateFolioActionRegistrar<T extends boolean = true>(
+  client: LiteSVM,
+  programFolioAdmin: Program<FolioAdmin>,
+  adminKeypair: Keypair,
+  programIds: PublicKey[],
+  toRemove: boolean,
+  executeTxn: T = true as T
+): Promise<
+  T extends true
+    ? BanksTransactionResultWithMeta
+    : { ix: TransactionInstruction; extraSigners: any[] }
+> {
+  const updateFolioActionRegistrar = await programFolioAdmin.methods
+    .updateFolioActionRegistrar(programIds, toRemove)
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      admin: !executeTxn ? OTHER_ADMIN_KEY.publicKey : adminKeypair.publicKey,
+      folioActionRegistrar: getFolioActionRegistrarPDA(),
+    })
+    .instruction();
+
+  if (executeTxn) {
+    return createAndProcessTransaction(client, adminKeypair, [
+      updateFolioActionRegistrar,
+    ]) as any;
+  }
+
+  return { ix: updateFolioActionRegistrar, extraSigners: [] } as any;
+}
+
+export async function updateTransferHookRegistrar<T extends boolean = true>(
+  client: LiteSVM,
+  programFolioAdmin: Program<FolioAdmin>,
+  adminKeypair: Keypair,
+  programIds: PublicKey[],
+  toRemove: boolean,
+  executeTxn: T = true as T
+): Promise<
+  T extends true
+    ? BanksTransactionResultWithMeta
+    : { ix: TransactionInstruction; extraSigners: any[] }
+> {
+  const updateTransferHookRegistrar = await programFolioAdmin.methods
+    .updateTransferHookRegistrar(programIds, toRemove)
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      admin: !executeTxn ? OTHER_ADMIN_KEY.publicKey : adminKeypair.publicKey,
+      transferHookRegistrar: getTransferHookRegistrarPDA(),
+    })
+    .instruction();
+
+  if (executeTxn) {
+    return createAndProcessTransaction(client, adminKeypair, [
+      updateTransferHookRegistrar,
+    ]) as any;
+  }
+
+  return { ix: updateTransferHookRegistrar, extraSigners: [] } as any;
+}
+
+/*
+Transfer Hook Mock
+*/
+export async function initializeTransferHookExtraAccountMetaList(
+  client: LiteSVM,
+  programTransferHookMock: Program<TransferHookMock>,
+  payerKeypair: Keypair,
+  mint: PublicKey
+) {
+  const initializeExtraAccountMetaList = await programTransferHookMock.methods
+    .initializeExtraAccountMetaList()
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      payer: payerKeypair.publicKey,
+      mint,
+    })
+    .instruction();
+
+  return createAndProcessTransaction(client, payerKeypair, [
+    initializeExtraAccountMetaList,
+  ]);
+}
+
 /*
 Folio
 */
@@ -279,7 +409,14 @@ export async function updateFolio<T extends boolean = true>(
   feeRecipientsToAdd: { recipient: PublicKey; portion: BN }[],
   feeRecipientsToRemove: PublicKey[],
   mandate: string | null,
-  executeTxn: T = true as T
+  executeTxn: T = true as T,
+  mintAllowlistEnabled: boolean | null = null,
+  maxSupply: BN | null = null,
+  maxMintPerTx: BN | null = null,
+  // { dao: {} } or { feeRecipients: {} }
+  feeDistributionFallback: object | null = null,
+  // Only required when setting the mint limits
+  useFolioMintLimits: boolean = maxSupply !== null || maxMintPerTx !== null
 ): Promise<
   T extends true
     ? BanksTransactionResultWithMeta
@@ -293,7 +430,11 @@ export async function updateFolio<T extends boolean = true>(
       auctionLength,
       feeRecipientsToAdd,
       feeRecipientsToRemove,
-      mandate
+      mandate,
+      mintAllowlistEnabled,
+      maxSupply,
+      maxMintPerTx,
+      feeDistributionFallback as any
     )
     .accountsPartial({
       systemProgram: SystemProgram.programId,
@@ -304,6 +445,7 @@ export async function updateFolio<T extends boolean = true>(
       actor: getActorPDA(folioOwnerKeypair.publicKey, folio),
       folio: folio,
       feeRecipients: getTVLFeeRecipientsPDA(folio),
+      folioMintLimits: useFolioMintLimits ? getFolioMintLimitsPDA(folio) : null,
     })
     .remainingAccounts(
       await buildRemainingAccountsForUpdateFolio(
@@ -325,6 +467,48 @@ export async function updateFolio<T extends boolean = true>(
   return { ix: updateFolio, extraSigners: [] } as any;
 }
 
+export async function updateFolioBranding<T extends boolean = true>(
+  client: LiteSVM,
+  programFolio: Program<Folio>,
+  brandManagerKeypair: Keypair,
+  folio: PublicKey,
+  folioTokenMint: PublicKey,
+  name: string | null,
+  symbol: string | null,
+  uri: string | null,
+  mandate: string | null,
+  executeTxn: T = true as T,
+  tokenProgram: PublicKey = TOKEN_PROGRAM_ID
+): Promise<
+  T extends true
+    ? BanksTransactionResultWithMeta
+    : { ix: TransactionInstruction; extraSigners: any[] }
+> {
+  const updateFolioBranding = await programFolio.methods
+    .updateFolioBranding(name, symbol, uri, mandate)
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
+      instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+      tokenProgram: tokenProgram,
+      brandManager: brandManagerKeypair.publicKey,
+      actor: getActorPDA(brandManagerKeypair.publicKey, folio),
+      folio: folio,
+      folioTokenMint: folioTokenMint,
+      tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+      metadata: getMetadataPDA(folioTokenMint),
+    })
+    .instruction();
+
+  if (executeTxn) {
+    return createAndProcessTransaction(client, brandManagerKeypair, [
+      updateFolioBranding,
+    ]) as any;
+  }
+
+  return { ix: updateFolioBranding, extraSigners: [] } as any;
+}
+
 export async function addOrUpdateActor<T extends boolean = true>(
   client: LiteSVM,
   programFolio: Program<Folio>,
@@ -333,14 +517,15 @@ export async function addOrUpdateActor<T extends boolean = true>(
   newActorAuthority: PublicKey,
   role: number,
 
-  executeTxn: T = true as T
+  executeTxn: T = true as T,
+  expiresAt: BN | null = null
 ): Promise<
   T extends true
     ? BanksTransactionResultWithMeta
     : { ix: TransactionInstruction; extraSigners: any[] }
 > {
   const addOrUpdateActor = await programFolio.methods
-    .initOrUpdateActor(roleToStruct(role) as any)
+    .initOrUpdateActor(roleToStruct(role) as any, expiresAt)
     .accountsPartial({
       systemProgram: SystemProgram.programId,
       rent: SYSVAR_RENT_PUBKEY,
@@ -352,6 +537,7 @@ export async function addOrUpdateActor<T extends boolean = true>(
       newActor: getActorPDA(newActorAuthority, folio),
 
       folio: folio,
+      folioActors: getFolioActorsPDA(folio),
     })
     .instruction();
 
@@ -392,6 +578,7 @@ export async function removeActor<T extends boolean = true>(
       actorToRemove: getActorPDA(actorAuthority, folio),
 
       folio: folio,
+      folioActors: getFolioActorsPDA(folio),
     })
     .instruction();
 
@@ -404,112 +591,701 @@ export async function removeActor<T extends boolean = true>(
   return { ix: removeActor, extraSigners: [] } as any;
 }
 
-export async function addToBasket<T extends boolean = true>(
-  context: LiteSVM,
+export async function indexActor<T extends boolean = true>(
+  client: LiteSVM,
+  programFolio: Program<Folio>,
+  payerKeypair: Keypair,
+  folio: PublicKey,
+  actorAuthority: PublicKey,
+
+  executeTxn: T = true as T
+): Promise<
+  T extends true
+    ? BanksTransactionResultWithMeta
+    : { ix: TransactionInstruction; extraSigners: any[] }
+> {
+  const indexActor = await programFolio.methods
+    .indexActor()
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
+      payer: payerKeypair.publicKey,
+      actor: getActorPDA(actorAuthority, folio),
+      folioActors: getFolioActorsPDA(folio),
+    })
+    .instruction();
+
+  if (executeTxn) {
+    return createAndProcessTransaction(client, payerKeypair, [
+      indexActor,
+    ]) as any;
+  }
+
+  return { ix: indexActor, extraSigners: [] } as any;
+}
+
+export async function addToMintAllowlist<T extends boolean = true>(
+  client: LiteSVM,
+  programFolio: Program<Folio>,
+  allowlistManagerKeypair: Keypair,
+  folio: PublicKey,
+  user: PublicKey,
+
+  executeTxn: T = true as T
+): Promise<
+  T extends true
+    ? BanksTransactionResultWithMeta
+    : { ix: TransactionInstruction; extraSigners: any[] }
+> {
+  const addToMintAllowlist = await programFolio.methods
+    .addToMintAllowlist()
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
+      allowlistManager: allowlistManagerKeypair.publicKey,
+      actor: getActorPDA(allowlistManagerKeypair.publicKey, folio),
+      folio,
+      user,
+      mintAllowlistEntry: getMintAllowlistEntryPDA(folio, user),
+    })
+    .instruction();
+
+  if (executeTxn) {
+    return createAndProcessTransaction(client, allowlistManagerKeypair, [
+      addToMintAllowlist,
+    ]) as any;
+  }
+
+  return { ix: addToMintAllowlist, extraSigners: [] } as any;
+}
+
+export async function removeFromMintAllowlist<T extends boolean = true>(
+  client: LiteSVM,
+  programFolio: Program<Folio>,
+  allowlistManagerKeypair: Keypair,
+  folio: PublicKey,
+  user: PublicKey,
+
+  executeTxn: T = true as T
+): Promise<
+  T extends true
+    ? BanksTransactionResultWithMeta
+    : { ix: TransactionInstruction; extraSigners: any[] }
+> {
+  const removeFromMintAllowlist = await programFolio.methods
+    .removeFromMintAllowlist()
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      allowlistManager: allowlistManagerKeypair.publicKey,
+      actor: getActorPDA(allowlistManagerKeypair.publicKey, folio),
+      folio,
+      user,
+      mintAllowlistEntry: getMintAllowlistEntryPDA(folio, user),
+    })
+    .instruction();
+
+  if (executeTxn) {
+    return createAndProcessTransaction(client, allowlistManagerKeypair, [
+      removeFromMintAllowlist,
+    ]) as any;
+  }
+
+  return { ix: removeFromMintAllowlist, extraSigners: [] } as any;
+}
+
+export async function initOrUpdateFeeDiscount<T extends boolean = true>(
   client: LiteSVM,
   programFolio: Program<Folio>,
   folioOwnerKeypair: Keypair,
   folio: PublicKey,
-  tokens: { mint: PublicKey; amount: BN }[],
-  initialShares: BN,
-  folioTokenMint: PublicKey,
+  minter: PublicKey,
+  scaledDiscount: BN,
 
-  executeTxn: T = true as T,
-  useUserPendingBasketAtas: boolean = false,
-  remainingAccounts: AccountMeta[] = [],
+  executeTxn: T = true as T
+): Promise<
+  T extends true
+    ? BanksTransactionResultWithMeta
+    : { ix: TransactionInstruction; extraSigners: any[] }
+> {
+  const initOrUpdateFeeDiscount = await programFolio.methods
+    .initOrUpdateFeeDiscount(scaledDiscount)
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
+      folioOwner: folioOwnerKeypair.publicKey,
+      actor: getActorPDA(folioOwnerKeypair.publicKey, folio),
+      folio,
+      minter,
+      feeDiscount: getFeeDiscountPDA(folio, minter),
+    })
+    .instruction();
 
-  tokenProgram: PublicKey = TOKEN_PROGRAM_ID,
-  folioMintTokenProgram: PublicKey = TOKEN_PROGRAM_ID
+  if (executeTxn) {
+    return createAndProcessTransaction(client, folioOwnerKeypair, [
+      initOrUpdateFeeDiscount,
+    ]) as any;
+  }
+
+  return { ix: initOrUpdateFeeDiscount, extraSigners: [] } as any;
+}
+
+export async function removeFeeDiscount<T extends boolean = true>(
+  client: LiteSVM,
+  programFolio: Program<Folio>,
+  folioOwnerKeypair: Keypair,
+  folio: PublicKey,
+  minter: PublicKey,
+
+  executeTxn: T = true as T
 ): Promise<
   T extends true
     ? BanksTransactionResultWithMeta
     : { ix: TransactionInstruction; extraSigners: any[] }
 > {
-  const addToBasket = await programFolio.methods
-    .addToBasket(
-      tokens.map((token) => token.amount),
-      initialShares
-    )
+  const removeFeeDiscount = await programFolio.methods
+    .removeFeeDiscount()
     .accountsPartial({
       systemProgram: SystemProgram.programId,
-      tokenProgram,
-      folioOwner: !executeTxn
-        ? OTHER_ADMIN_KEY.publicKey
-        : folioOwnerKeypair.publicKey,
+      folioOwner: folioOwnerKeypair.publicKey,
       actor: getActorPDA(folioOwnerKeypair.publicKey, folio),
-      folio: folio,
-      folioTokenMint,
-      ownerFolioTokenAccount: await getOrCreateAtaAddress(
-        context,
-        folioTokenMint,
-        folioOwnerKeypair.publicKey,
-        folioMintTokenProgram
-      ),
-      folioBasket: getFolioBasketPDA(folio),
+      folio,
+      minter,
+      feeDiscount: getFeeDiscountPDA(folio, minter),
     })
-    .remainingAccounts(
-      remainingAccounts.length > 0
-        ? remainingAccounts
-        : await buildRemainingAccounts(
-            context,
-            tokens,
-            useUserPendingBasketAtas
-              ? getUserPendingBasketPDA(folio, folioOwnerKeypair.publicKey)
-              : folioOwnerKeypair.publicKey,
-            folio,
-            true,
-            true,
-            tokenProgram
-          )
+    .instruction();
+
+  if (executeTxn) {
+    return createAndProcessTransaction(client, folioOwnerKeypair, [
+      removeFeeDiscount,
+    ]) as any;
+  }
+
+  return { ix: removeFeeDiscount, extraSigners: [] } as any;
+}
+
+export async function killFolio<T extends boolean = true>(
+  client: LiteSVM,
+  programFolio: Program<Folio>,
+  folioOwnerKeypair: Keypair,
+  folio: PublicKey,
+
+  executeTxn: T = true as T
+): Promise<
+  T extends true
+    ? BanksTransactionResultWithMeta
+    : { ix: TransactionInstruction; extraSigners: any[] }
+> {
+  const killFolio = await programFolio.methods
+    .killFolio()
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      folioOwner: folioOwnerKeypair.publicKey,
+      actor: getActorPDA(folioOwnerKeypair.publicKey, folio),
+      folio,
+      killRecord: getKillRecordPDA(folio),
+      unkillConfig: getUnkillConfigPDA(),
+    })
+    .instruction();
+
+  if (executeTxn) {
+    return createAndProcessTransaction(client, folioOwnerKeypair, [
+      killFolio,
+    ]) as any;
+  }
+
+  return { ix: killFolio, extraSigners: [] } as any;
+}
+
+export async function unkillFolio<T extends boolean = true>(
+  client: LiteSVM,
+  programFolio: Program<Folio>,
+  folioOwnerKeypair: Keypair,
+  folio: PublicKey,
+  folioTokenMint: PublicKey,
+
+  executeTxn: T = true as T
+): Promise<
+  T extends true
+    ? BanksTransactionResultWithMeta
+    : { ix: TransactionInstruction; extraSigners: any[] }
+> {
+  const unkillFolio = await programFolio.methods
+    .unkillFolio()
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      folioOwner: folioOwnerKeypair.publicKey,
+      actor: getActorPDA(folioOwnerKeypair.publicKey, folio),
+      folio,
+      folioTokenMint,
+      killRecord: getKillRecordPDA(folio),
+      unkillConfig: getUnkillConfigPDA(),
+    })
+    .instruction();
+
+  if (executeTxn) {
+    return createAndProcessTransaction(client, folioOwnerKeypair, [
+      unkillFolio,
+    ]) as any;
+  }
+
+  return { ix: unkillFolio, extraSigners: [] } as any;
+}
+
+export async function pauseFolio<T extends boolean = true>(
+  client: LiteSVM,
+  programFolio: Program<Folio>,
+  guardianKeypair: Keypair,
+  folio: PublicKey,
+  pauseFlags: number,
+
+  executeTxn: T = true as T
+): Promise<
+  T extends true
+    ? BanksTransactionResultWithMeta
+    : { ix: TransactionInstruction; extraSigners: any[] }
+> {
+  const pauseFolio = await programFolio.methods
+    .pauseFolio(pauseFlags)
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      guardian: guardianKeypair.publicKey,
+      actor: getActorPDA(guardianKeypair.publicKey, folio),
+      folio,
+    })
+    .instruction();
+
+  if (executeTxn) {
+    return createAndProcessTransaction(client, guardianKeypair, [
+      pauseFolio,
+    ]) as any;
+  }
+
+  return { ix: pauseFolio, extraSigners: [] } as any;
+}
+
+export async function unpauseFolio<T extends boolean = true>(
+  client: LiteSVM,
+  programFolio: Program<Folio>,
+  guardianKeypair: Keypair,
+  folio: PublicKey,
+  pauseFlags: number,
+
+  executeTxn: T = true as T
+): Promise<
+  T extends true
+    ? BanksTransactionResultWithMeta
+    : { ix: TransactionInstruction; extraSigners: any[] }
+> {
+  const unpauseFolio = await programFolio.methods
+    .unpauseFolio(pauseFlags)
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      guardian: guardianKeypair.publicKey,
+      actor: getActorPDA(guardianKeypair.publicKey, folio),
+      folio,
+    })
+    .instruction();
+
+  if (executeTxn) {
+    return createAndProcessTransaction(client, guardianKeypair, [
+      unpauseFolio,
+    ]) as any;
+  }
+
+  return { ix: unpauseFolio, extraSigners: [] } as any;
+}
+
+export async function proposeOwner<T extends boolean = true>(
+  client: LiteSVM,
+  programFolio: Program<Folio>,
+  folioOwnerKeypair: Keypair,
+  folio: PublicKey,
+  newOwner: PublicKey,
+
+  executeTxn: T = true as T
+): Promise<
+  T extends true
+    ? BanksTransactionResultWithMeta
+    : { ix: TransactionInstruction; extraSigners: any[] }
+> {
+  const proposeOwner = await programFolio.methods
+    .proposeOwner()
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
+      folioOwner: folioOwnerKeypair.publicKey,
+      folioOwnerActor: getActorPDA(folioOwnerKeypair.publicKey, folio),
+      newOwner,
+      pendingOwner: getPendingOwnerPDA(folio),
+      folio,
+    })
+    .instruction();
+
+  if (executeTxn) {
+    return createAndProcessTransaction(client, folioOwnerKeypair, [
+      proposeOwner,
+    ]) as any;
+  }
+
+  return { ix: proposeOwner, extraSigners: [] } as any;
+}
+
+export async function acceptOwner<T extends boolean = true>(
+  client: LiteSVM,
+  programFolio: Program<Folio>,
+  newOwnerKeypair: Keypair,
+  folio: PublicKey,
+  previousOwner: PublicKey,
+
+  executeTxn: T = true as T
+): Promise<
+  T extends true
+    ? BanksTransactionResultWithMeta
+    : { ix: TransactionInstruction; extraSigners: any[] }
+> {
+  const acceptOwner = await programFolio.methods
+    .acceptOwner()
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
+      newOwner: newOwnerKeypair.publicKey,
+      newOwnerActor: getActorPDA(newOwnerKeypair.publicKey, folio),
+      previousOwner,
+      previousOwnerActor: getActorPDA(previousOwner, folio),
+      pendingOwner: getPendingOwnerPDA(folio),
+      folio,
+      folioActors: getFolioActorsPDA(folio),
+    })
+    .instruction();
+
+  if (executeTxn) {
+    return createAndProcessTransaction(client, newOwnerKeypair, [
+      acceptOwner,
+    ]) as any;
+  }
+
+  return { ix: acceptOwner, extraSigners: [] } as any;
+}
+
+export async function cancelOwnerProposal<T extends boolean = true>(
+  client: LiteSVM,
+  programFolio: Program<Folio>,
+  folioOwnerKeypair: Keypair,
+  folio: PublicKey,
+  proposedBy: PublicKey,
+
+  executeTxn: T = true as T
+): Promise<
+  T extends true
+    ? BanksTransactionResultWithMeta
+    : { ix: TransactionInstruction; extraSigners: any[] }
+> {
+  const cancelOwnerProposal = await programFolio.methods
+    .cancelOwnerProposal()
+    .accountsPartial({
+      folioOwner: folioOwnerKeypair.publicKey,
+      folioOwnerActor: getActorPDA(folioOwnerKeypair.publicKey, folio),
+      proposedBy,
+      pendingOwner: getPendingOwnerPDA(folio),
+      folio,
+    })
+    .instruction();
+
+  if (executeTxn) {
+    return createAndProcessTransaction(client, folioOwnerKeypair, [
+      cancelOwnerProposal,
+    ]) as any;
+  }
+
+  return { ix: cancelOwnerProposal, extraSigners: [] } as any;
+}
+
+export async function addToBasket<T extends boolean = true>(
+  context: LiteSVM,
+  client: LiteSVM,
+  programFolio: Program<Folio>,
+  folioOwnerKeypair: Keypair,
+  folio: PublicKey,
+  tokens: { mint: PublicKey; amount: BN }[],
+  initialShares: BN,
+  folioTokenMint: PublicKey,
+
+  executeTxn: T = true as T,
+  useUserPendingBasketAtas: boolean = false,
+  remainingAccounts: AccountMeta[] = [],
+
+  tokenProgram: PublicKey = TOKEN_PROGRAM_ID,
+  folioMintTokenProgram: PublicKey = TOKEN_PROGRAM_ID
+): Promise<
+  T extends true
+    ? BanksTransactionResultWithMeta
+    : { ix: TransactionInstruction; extraSigners: any[] }
+> {
+  const addToBasket = await programFolio.methods
+    .addToBasket(
+      tokens.map((token) => token.amount),
+      initialShares
+    )
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      tokenProgram,
+      folioOwner: !executeTxn
+        ? OTHER_ADMIN_KEY.publicKey
+        : folioOwnerKeypair.publicKey,
+      actor: getActorPDA(folioOwnerKeypair.publicKey, folio),
+      folio: folio,
+      folioTokenMint,
+      ownerFolioTokenAccount: await getOrCreateAtaAddress(
+        context,
+        folioTokenMint,
+        folioOwnerKeypair.publicKey,
+        folioMintTokenProgram
+      ),
+      folioBasket: getFolioBasketPDA(folio),
+    })
+    .remainingAccounts(
+      remainingAccounts.length > 0
+        ? remainingAccounts
+        : await buildRemainingAccounts(
+            context,
+            tokens,
+            useUserPendingBasketAtas
+              ? getUserPendingBasketPDA(folio, folioOwnerKeypair.publicKey)
+              : folioOwnerKeypair.publicKey,
+            folio,
+            true,
+            true,
+            tokenProgram
+          )
     )
     .instruction();
 
   if (executeTxn) {
-    return createAndProcessTransaction(client, folioOwnerKeypair, [
-      ...getComputeLimitInstruction(400_000),
-      addToBasket,
+    return createAndProcessTransaction(client, folioOwnerKeypair, [
+      ...getComputeLimitInstruction(400_000),
+      addToBasket,
+    ]) as any;
+  }
+
+  return { ix: addToBasket, extraSigners: [] } as any;
+}
+
+export async function addZeroBalanceToBasket<T extends boolean = true>(
+  client: LiteSVM,
+  programFolio: Program<Folio>,
+  folioOwnerKeypair: Keypair,
+  folio: PublicKey,
+  tokenToAdd: PublicKey,
+  executeTxn: T = true as T
+): Promise<
+  T extends true
+    ? BanksTransactionResultWithMeta
+    : { ix: TransactionInstruction; extraSigners: any[] }
+> {
+  const addZeroBalanceToBasket = await programFolio.methods
+    .addZeroBalanceToBasket()
+    .accountsPartial({
+      folioOwner: folioOwnerKeypair.publicKey,
+      actor: getActorPDA(folioOwnerKeypair.publicKey, folio),
+      folio,
+      folioBasket: getFolioBasketPDA(folio),
+      tokenMint: tokenToAdd,
+    })
+    .instruction();
+
+  if (executeTxn) {
+    return createAndProcessTransaction(client, folioOwnerKeypair, [
+      addZeroBalanceToBasket,
+    ]) as any;
+  }
+
+  return { ix: addZeroBalanceToBasket, extraSigners: [] } as any;
+}
+
+export async function removeFromBasket<T extends boolean = true>(
+  client: LiteSVM,
+  programFolio: Program<Folio>,
+  folioOwnerKeypair: Keypair,
+  folio: PublicKey,
+  folioTokenMint: PublicKey,
+  tokenToRemove: PublicKey,
+  executeTxn: T = true as T
+): Promise<
+  T extends true
+    ? BanksTransactionResultWithMeta
+    : { ix: TransactionInstruction; extraSigners: any[] }
+> {
+  const removeFromBasket = await programFolio.methods
+    .removeFromBasket()
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      folioOwner: !executeTxn
+        ? OTHER_ADMIN_KEY.publicKey
+        : folioOwnerKeypair.publicKey,
+      actor: getActorPDA(folioOwnerKeypair.publicKey, folio),
+
+      folio: folio,
+      folioBasket: getFolioBasketPDA(folio),
+      folioLegacyBasket: getFolioLegacyBasketPDA(folio),
+      folioTokenMint: folioTokenMint,
+      tokenMint: tokenToRemove,
+    })
+    .instruction();
+
+  if (executeTxn) {
+    return createAndProcessTransaction(client, folioOwnerKeypair, [
+      removeFromBasket,
+    ]) as any;
+  }
+
+  return { ix: removeFromBasket, extraSigners: [] } as any;
+}
+
+export async function executeFolioAction<T extends boolean = true>(
+  client: LiteSVM,
+  programFolio: Program<Folio>,
+  folioOwnerKeypair: Keypair,
+  folio: PublicKey,
+  targetProgram: PublicKey,
+  data: Buffer,
+  accounts: AccountMeta[],
+  executeTxn: T = true as T
+): Promise<
+  T extends true
+    ? BanksTransactionResultWithMeta
+    : { ix: TransactionInstruction; extraSigners: any[] }
+> {
+  const executeFolioAction = await programFolio.methods
+    .executeFolioAction(data)
+    .accountsPartial({
+      folioOwner: folioOwnerKeypair.publicKey,
+      actor: getActorPDA(folioOwnerKeypair.publicKey, folio),
+      folio,
+      folioActionRegistrar: getFolioActionRegistrarPDA(),
+      targetProgram,
+    })
+    .remainingAccounts(accounts)
+    .instruction();
+
+  if (executeTxn) {
+    return createAndProcessTransaction(client, folioOwnerKeypair, [
+      executeFolioAction,
+    ]) as any;
+  }
+
+  return { ix: executeFolioAction, extraSigners: [] } as any;
+}
+
+export async function syncBasketBalance<T extends boolean = true>(
+  context: LiteSVM,
+  client: LiteSVM,
+  programFolio: Program<Folio>,
+  folioOwnerKeypair: Keypair,
+  folio: PublicKey,
+  mint: PublicKey,
+  recipient: PublicKey,
+  tokenProgram: PublicKey = TOKEN_PROGRAM_ID,
+  executeTxn: T = true as T
+): Promise<
+  T extends true
+    ? BanksTransactionResultWithMeta
+    : { ix: TransactionInstruction; extraSigners: any[] }
+> {
+  const syncBasketBalance = await programFolio.methods
+    .syncBasketBalance()
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      tokenProgram,
+      folioOwner: folioOwnerKeypair.publicKey,
+      actor: getActorPDA(folioOwnerKeypair.publicKey, folio),
+      folio,
+      folioBasket: getFolioBasketPDA(folio),
+      folioPendingBasket: getFolioPendingBasketPDA(folio),
+      folioLegacyBasket: getFolioLegacyBasketPDA(folio),
+      mint,
+      folioTokenAccount: await getOrCreateAtaAddress(
+        context,
+        mint,
+        folio,
+        tokenProgram
+      ),
+      recipientTokenAccount: await getOrCreateAtaAddress(
+        context,
+        mint,
+        recipient,
+        tokenProgram
+      ),
+    })
+    .instruction();
+
+  if (executeTxn) {
+    return createAndProcessTransaction(client, folioOwnerKeypair, [
+      syncBasketBalance,
+    ]) as any;
+  }
+
+  return { ix: syncBasketBalance, extraSigners: [] } as any;
+}
+
+export async function trackUserPendingBasket<T extends boolean = true>(
+  client: LiteSVM,
+  programFolio: Program<Folio>,
+  payerKeypair: Keypair,
+  folio: PublicKey,
+  owner: PublicKey,
+  executeTxn: T = true as T
+): Promise<
+  T extends true
+    ? BanksTransactionResultWithMeta
+    : { ix: TransactionInstruction; extraSigners: any[] }
+> {
+  const trackUserPendingBasket = await programFolio.methods
+    .trackUserPendingBasket()
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      payer: payerKeypair.publicKey,
+      folio,
+      owner,
+      userPendingBasket: getUserPendingBasketPDA(folio, owner),
+      folioPendingBasket: getFolioPendingBasketPDA(folio),
+    })
+    .instruction();
+
+  if (executeTxn) {
+    return createAndProcessTransaction(client, payerKeypair, [
+      trackUserPendingBasket,
     ]) as any;
   }
 
-  return { ix: addToBasket, extraSigners: [] } as any;
+  return { ix: trackUserPendingBasket, extraSigners: [] } as any;
 }
 
-export async function removeFromBasket<T extends boolean = true>(
+export async function setPendingBasketsTracked<T extends boolean = true>(
   client: LiteSVM,
   programFolio: Program<Folio>,
-  folioOwnerKeypair: Keypair,
+  adminKeypair: Keypair,
   folio: PublicKey,
-  folioTokenMint: PublicKey,
-  tokenToRemove: PublicKey,
   executeTxn: T = true as T
 ): Promise<
   T extends true
     ? BanksTransactionResultWithMeta
     : { ix: TransactionInstruction; extraSigners: any[] }
 > {
-  const removeFromBasket = await programFolio.methods
-    .removeFromBasket()
+  const setPendingBasketsTracked = await programFolio.methods
+    .setPendingBasketsTracked()
     .accountsPartial({
-      systemProgram: SystemProgram.programId,
-      folioOwner: !executeTxn
-        ? OTHER_ADMIN_KEY.publicKey
-        : folioOwnerKeypair.publicKey,
-      actor: getActorPDA(folioOwnerKeypair.publicKey, folio),
-
-      folio: folio,
-      folioBasket: getFolioBasketPDA(folio),
-      folioTokenMint: folioTokenMint,
-      tokenMint: tokenToRemove,
+      admin: adminKeypair.publicKey,
+      folio,
     })
     .instruction();
 
   if (executeTxn) {
-    return createAndProcessTransaction(client, folioOwnerKeypair, [
-      removeFromBasket,
+    return createAndProcessTransaction(client, adminKeypair, [
+      setPendingBasketsTracked,
     ]) as any;
   }
 
-  return { ix: removeFromBasket, extraSigners: [] } as any;
+  return { ix: setPendingBasketsTracked, extraSigners: [] } as any;
 }
 
 export async function addToPendingBasket<T extends boolean = true>(
@@ -521,7 +1297,8 @@ export async function addToPendingBasket<T extends boolean = true>(
   tokens: { mint: PublicKey; amount: BN }[],
   executeTxn: T = true as T,
   remainingAccounts: AccountMeta[] = [],
-  useUserPendingBasketAtas: boolean = false
+  useUserPendingBasketAtas: boolean = false,
+  tokenProgram: PublicKey = TOKEN_PROGRAM_ID
 ): Promise<
   T extends true
     ? BanksTransactionResultWithMeta
@@ -535,12 +1312,17 @@ export async function addToPendingBasket<T extends boolean = true>(
     .accountsPartial({
       systemProgram: SystemProgram.programId,
       rent: SYSVAR_RENT_PUBKEY,
-      tokenProgram: TOKEN_PROGRAM_ID,
+      tokenProgram,
       user: userKeypair.publicKey,
 
       folio,
       folioBasket: getFolioBasketPDA(folio),
       userPendingBasket: getUserPendingBasketPDA(folio, userKeypair.publicKey),
+      folioPendingBasket: getFolioPendingBasketPDA(folio),
+      mintAllowlistEntry: getMintAllowlistEntryPDA(
+        folio,
+        userKeypair.publicKey
+      ),
     })
     .remainingAccounts(
       remainingAccounts.length > 0
@@ -567,7 +1349,8 @@ export async function removeFromPendingBasket<T extends boolean = true>(
   folio: PublicKey,
   tokens: { mint: PublicKey; amount: BN }[],
   executeTxn: T = true as T,
-  remainingAccounts: AccountMeta[] = []
+  remainingAccounts: AccountMeta[] = [],
+  tokenProgram: PublicKey = TOKEN_PROGRAM_ID
 ): Promise<
   T extends true
     ? BanksTransactionResultWithMeta
@@ -577,12 +1360,13 @@ export async function removeFromPendingBasket<T extends boolean = true>(
     .removeFromPendingBasket(tokens.map((token) => token.amount))
     .accountsPartial({
       systemProgram: SystemProgram.programId,
-      tokenProgram: TOKEN_PROGRAM_ID,
+      tokenProgram,
       user: userKeypair.publicKey,
 
       folio,
       folioBasket: getFolioBasketPDA(folio),
       userPendingBasket: getUserPendingBasketPDA(folio, userKeypair.publicKey),
+      folioPendingBasket: getFolioPendingBasketPDA(folio),
     })
     .remainingAccounts(
       remainingAccounts.length > 0
@@ -615,7 +1399,9 @@ export async function mintFolioToken<T extends boolean = true>(
   tokens: { mint: PublicKey; amount: BN }[],
   shares: BN,
   executeTxn: T = true as T,
-  minRawShares: BN | null = null
+  minRawShares: BN | null = null,
+  // Only required when the folio has mint limits enabled
+  useFolioMintLimits: boolean = true
 ) {
   const mintFolioToken = await programFolio.methods
     .mintFolioToken(shares, minRawShares)
@@ -629,11 +1415,18 @@ export async function mintFolioToken<T extends boolean = true>(
       folioTokenMint,
       folioBasket: getFolioBasketPDA(folio),
       userPendingBasket: getUserPendingBasketPDA(folio, userKeypair.publicKey),
+      folioPendingBasket: getFolioPendingBasketPDA(folio),
       userFolioTokenAccount: await getOrCreateAtaAddress(
         context,
         folioTokenMint,
         userKeypair.publicKey
       ),
+      feeDiscount: getFeeDiscountPDA(folio, userKeypair.publicKey),
+      mintAllowlistEntry: getMintAllowlistEntryPDA(
+        folio,
+        userKeypair.publicKey
+      ),
+      folioMintLimits: useFolioMintLimits ? getFolioMintLimitsPDA(folio) : null,
     })
     .instruction();
 
@@ -661,6 +1454,7 @@ export async function burnFolioToken<T extends boolean = true>(
   const burnFolioTokenIx = await programFolio.methods
     .burnFolioToken(amountToBurn, tokens)
     .accountsPartial({
+      systemProgram: SystemProgram.programId,
       tokenProgram: TOKEN_PROGRAM_ID,
       associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       user: userKeypair.publicKey,
@@ -670,6 +1464,8 @@ export async function burnFolioToken<T extends boolean = true>(
       folioTokenMint,
       folioBasket: getFolioBasketPDA(folio),
       userPendingBasket: getUserPendingBasketPDA(folio, userKeypair.publicKey),
+      folioPendingBasket: getFolioPendingBasketPDA(folio),
+      folioLegacyBasket: getFolioLegacyBasketPDA(folio),
       userFolioTokenAccount: await getOrCreateAtaAddress(
         context,
         folioTokenMint,
@@ -696,19 +1492,21 @@ export async function redeemFromPendingBasket<T extends boolean = true>(
   folio: PublicKey,
   tokens: { mint: PublicKey; amount: BN }[],
   executeTxn: T = true as T,
-  remainingAccounts: AccountMeta[] = []
+  remainingAccounts: AccountMeta[] = [],
+  tokenProgram: PublicKey = TOKEN_PROGRAM_ID
 ) {
   const redeemFromPendingBasket = await programFolio.methods
     .redeemFromPendingBasket(tokens.map((token) => token.amount))
     .accountsPartial({
       systemProgram: SystemProgram.programId,
       rent: SYSVAR_RENT_PUBKEY,
-      tokenProgram: TOKEN_PROGRAM_ID,
+      tokenProgram,
       user: userKeypair.publicKey,
 
       folio,
       folioBasket: getFolioBasketPDA(folio),
       userPendingBasket: getUserPendingBasketPDA(folio, userKeypair.publicKey),
+      folioPendingBasket: getFolioPendingBasketPDA(folio),
     })
     .remainingAccounts(
       remainingAccounts.length > 0
@@ -796,6 +1594,38 @@ export async function pokeFolio<T extends boolean = true>(
   return { ix: pokeFolio, extraSigners: [] } as any;
 }
 
+export async function harvestWithheldFees<T extends boolean = true>(
+  client: LiteSVM,
+  programFolio: Program<Folio>,
+  userKeypair: Keypair,
+  folioPDA: PublicKey,
+  mint: PublicKey,
+  executeTxn: T = true as T
+): Promise<
+  T extends true
+    ? BanksTransactionResultWithMeta
+    : { ix: TransactionInstruction; extraSigners: any[] }
+> {
+  const harvestWithheldFees = await programFolio.methods
+    .harvestWithheldFees()
+    .accountsPartial({
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      user: userKeypair.publicKey,
+      folio: folioPDA,
+      mint,
+      folioTokenAccount: getAtaAddress(mint, folioPDA, TOKEN_2022_PROGRAM_ID),
+    })
+    .instruction();
+
+  if (executeTxn) {
+    return createAndProcessTransaction(client, userKeypair, [
+      harvestWithheldFees,
+    ]) as any;
+  }
+
+  return { ix: harvestWithheldFees, extraSigners: [] } as any;
+}
+
 export async function distributeFees<T extends boolean = true>(
   client: LiteSVM,
   programFolio: Program<Folio>,
@@ -890,6 +1720,76 @@ export async function crankFeeDistribution<T extends boolean = true>(
   return { ix: crankFeeDistribution, extraSigners: [] } as any;
 }
 
+export async function closeFeeDistribution<T extends boolean = true>(
+  client: LiteSVM,
+  programFolio: Program<Folio>,
+  userKeypair: Keypair,
+  folio: PublicKey,
+  folioTokenMint: PublicKey,
+  cranker: PublicKey,
+  feeDistributionIndex: BN,
+  daoFeeRecipient: PublicKey | null,
+  executeTxn: T = true as T,
+  tokenProgram: PublicKey = TOKEN_PROGRAM_ID
+): Promise<
+  T extends true
+    ? BanksTransactionResultWithMeta
+    : { ix: TransactionInstruction; extraSigners: any[] }
+> {
+  const closeFeeDistribution = await programFolio.methods
+    .closeFeeDistribution()
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      tokenProgram,
+      user: userKeypair.publicKey,
+      cranker,
+      daoFeeConfig: getDAOFeeConfigPDA(),
+      folioFeeConfig: getFolioFeeConfigPDA(folio),
+      folio,
+      folioTokenMint,
+      feeDistribution: getFeeDistributionPDA(folio, feeDistributionIndex),
+      daoFeeRecipient,
+    })
+    .instruction();
+
+  if (executeTxn) {
+    return createAndProcessTransaction(client, userKeypair, [
+      closeFeeDistribution,
+    ]) as any;
+  }
+
+  return { ix: closeFeeDistribution, extraSigners: [] } as any;
+}
+
+export async function upgradeAccount<T extends boolean = true>(
+  client: LiteSVM,
+  programFolio: Program<Folio>,
+  payerKeypair: Keypair,
+  account: PublicKey,
+  executeTxn: T = true as T
+): Promise<
+  T extends true
+    ? BanksTransactionResultWithMeta
+    : { ix: TransactionInstruction; extraSigners: any[] }
+> {
+  const upgradeAccount = await programFolio.methods
+    .upgradeAccount()
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      payer: payerKeypair.publicKey,
+      account,
+    })
+    .instruction();
+
+  if (executeTxn) {
+    return createAndProcessTransaction(client, payerKeypair, [
+      upgradeAccount,
+    ]) as any;
+  }
+
+  return { ix: upgradeAccount, extraSigners: [] } as any;
+}
+
 export async function startRebalance<T extends boolean = true>(
   client: LiteSVM,
   programFolio: Program<Folio>,
@@ -904,6 +1804,7 @@ export async function startRebalance<T extends boolean = true>(
   }[],
   allRebalanceDetailsAdded: boolean,
   mints: PublicKey[],
+  useUiAmounts: boolean = false,
   executeTxn: T = true as T
 ): Promise<
   T extends true
@@ -922,7 +1823,8 @@ export async function startRebalance<T extends boolean = true>(
       new BN(auctionLauncherWindow.toString()),
       new BN(ttl.toString()),
       pricesAndLimits,
-      allRebalanceDetailsAdded
+      allRebalanceDetailsAdded,
+      useUiAmounts
     )
     .accountsPartial({
       systemProgram: SystemProgram.programId,
@@ -1236,7 +2138,6 @@ export async function startFolioMigration<T extends boolean = true>(
   oldFolio: PublicKey,
   newFolio: PublicKey,
   newFolioProgram: PublicKey,
-  max_allowed_pending_fees: BN = new BN(D9),
   executeTxn: T = true as T,
   tokenProgram: PublicKey = TOKEN_PROGRAM_ID
 ): Promise<
@@ -1245,7 +2146,7 @@ export async function startFolioMigration<T extends boolean = true>(
     : { ix: TransactionInstruction; extraSigners: any[] }
 > {
   const startFolioMigration = await programFolio.methods
-    .startFolioMigration(max_allowed_pending_fees)
+    .startFolioMigration()
     .accountsPartial({
       systemProgram: SystemProgram.programId,
       tokenProgram,
@@ -1258,6 +2159,12 @@ export async function startFolioMigration<T extends boolean = true>(
       folioTokenMint,
       newFolioBasket: getFolioBasketPDA(newFolio, newFolioProgram),
       newActor: getActorPDA(folioOwnerKeypair.publicKey, newFolio, true),
+      oldFeeRecipients: getTVLFeeRecipientsPDA(oldFolio),
+      newFeeRecipients: getTVLFeeRecipientsPDA(newFolio, newFolioProgram),
+      oldFolioMintLimits: getFolioMintLimitsPDA(oldFolio),
+      newFolioMintLimits: getFolioMintLimitsPDA(newFolio, newFolioProgram),
+      oldFolioLegacyBasket: getFolioLegacyBasketPDA(oldFolio),
+      migrationRecord: getMigrationRecordPDA(oldFolio),
     })
     .instruction();
 
@@ -1270,6 +2177,51 @@ export async function startFolioMigration<T extends boolean = true>(
   return { ix: startFolioMigration, extraSigners: [] } as any;
 }
 
+export async function abortFolioMigration<T extends boolean = true>(
+  client: LiteSVM,
+  programFolio: Program<Folio>,
+  folioOwnerKeypair: Keypair,
+  folioTokenMint: PublicKey,
+  oldFolio: PublicKey,
+  newFolio: PublicKey,
+  newFolioProgram: PublicKey,
+  executeTxn: T = true as T,
+  tokenProgram: PublicKey = TOKEN_PROGRAM_ID
+): Promise<
+  T extends true
+    ? BanksTransactionResultWithMeta
+    : { ix: TransactionInstruction; extraSigners: any[] }
+> {
+  const abortFolioMigration = await programFolio.methods
+    .abortFolioMigration()
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      tokenProgram,
+      folioOwner: folioOwnerKeypair.publicKey,
+      actor: getActorPDA(folioOwnerKeypair.publicKey, oldFolio),
+      programRegistrar: getProgramRegistrarPDA(),
+      newFolioProgram,
+      oldFolio,
+      newFolio,
+      folioTokenMint,
+      newActor: getActorPDA(folioOwnerKeypair.publicKey, newFolio, true),
+      newFolioBasket: getFolioBasketPDA(newFolio, newFolioProgram),
+      newFeeRecipients: getTVLFeeRecipientsPDA(newFolio, newFolioProgram),
+      migrationRecord: getMigrationRecordPDA(oldFolio),
+      tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+      metadata: getMetadataPDA(folioTokenMint),
+    })
+    .instruction();
+
+  if (executeTxn) {
+    return createAndProcessTransaction(client, folioOwnerKeypair, [
+      abortFolioMigration,
+    ]) as any;
+  }
+
+  return { ix: abortFolioMigration, extraSigners: [] } as any;
+}
+
 export async function migrateFolioTokens<T extends boolean = true>(
   context: LiteSVM,
   client: LiteSVM,
@@ -1281,7 +2233,8 @@ export async function migrateFolioTokens<T extends boolean = true>(
   folioTokenMint: PublicKey,
   tokenMints: PublicKey[],
   executeTxn: T = true as T,
-  remainingAccounts: AccountMeta[] = []
+  remainingAccounts: AccountMeta[] = [],
+  tokenProgram: PublicKey = TOKEN_PROGRAM_ID
 ): Promise<
   T extends true
     ? BanksTransactionResultWithMeta
@@ -1290,7 +2243,7 @@ export async function migrateFolioTokens<T extends boolean = true>(
   const migrateFolioTokens = await programFolio.methods
     .migrateFolioTokens()
     .accountsPartial({
-      tokenProgram: TOKEN_PROGRAM_ID,
+      tokenProgram,
       user: userKeypair.publicKey,
       programRegistrar: getProgramRegistrarPDA(),
       newFolioProgram,
@@ -1299,6 +2252,7 @@ export async function migrateFolioTokens<T extends boolean = true>(
       newFolio,
       folioTokenMint,
       newFolioBasket: getFolioBasketPDA(newFolio, newFolioProgram),
+      migrationRecord: getMigrationRecordPDA(oldFolio),
     })
     .remainingAccounts(
       remainingAccounts.length > 0
@@ -1308,7 +2262,8 @@ export async function migrateFolioTokens<T extends boolean = true>(
             userKeypair,
             oldFolio,
             newFolio,
-            tokenMints
+            tokenMints,
+            tokenProgram
           )
     )
     .instruction();
@@ -1322,6 +2277,123 @@ export async function migrateFolioTokens<T extends boolean = true>(
   return { ix: migrateFolioTokens, extraSigners: [] } as any;
 }
 
+export async function migrateUserPendingBasket<T extends boolean = true>(
+  context: LiteSVM,
+  client: LiteSVM,
+  programFolio: Program<Folio>,
+  userKeypair: Keypair,
+  oldFolio: PublicKey,
+  newFolio: PublicKey,
+  newFolioProgram: PublicKey,
+  folioTokenMint: PublicKey,
+  pendingBasketOwner: PublicKey,
+  tokenMints: PublicKey[],
+  executeTxn: T = true as T,
+  remainingAccounts: AccountMeta[] = [],
+  tokenProgram: PublicKey = TOKEN_PROGRAM_ID
+): Promise<
+  T extends true
+    ? BanksTransactionResultWithMeta
+    : { ix: TransactionInstruction; extraSigners: any[] }
+> {
+  const migrateUserPendingBasket = await programFolio.methods
+    .migrateUserPendingBasket()
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      tokenProgram,
+      user: userKeypair.publicKey,
+      programRegistrar: getProgramRegistrarPDA(),
+      newFolioProgram,
+      oldFolio,
+      newFolio,
+      folioTokenMint,
+      pendingBasketOwner,
+      oldUserPendingBasket: getUserPendingBasketPDA(
+        oldFolio,
+        pendingBasketOwner
+      ),
+      oldFolioPendingBasket: getFolioPendingBasketPDA(oldFolio),
+      newUserPendingBasket: getUserPendingBasketPDA(
+        newFolio,
+        pendingBasketOwner,
+        newFolioProgram
+      ),
+      newFolioPendingBasket: getFolioPendingBasketPDA(
+        newFolio,
+        newFolioProgram
+      ),
+      migrationRecord: getMigrationRecordPDA(oldFolio),
+    })
+    .remainingAccounts(
+      remainingAccounts.length > 0
+        ? remainingAccounts
+        : await buildRemainingAccountsForMigrateFolioTokens(
+            context,
+            userKeypair,
+            oldFolio,
+            newFolio,
+            tokenMints,
+            tokenProgram
+          )
+    )
+    .instruction();
+
+  if (executeTxn) {
+    return createAndProcessTransaction(client, userKeypair, [
+      ...getComputeLimitInstruction(1_400_000),
+      migrateUserPendingBasket,
+    ]) as any;
+  }
+
+  return { ix: migrateUserPendingBasket, extraSigners: [] } as any;
+}
+
+export async function migrateFeeDistribution<T extends boolean = true>(
+  client: LiteSVM,
+  programFolio: Program<Folio>,
+  userKeypair: Keypair,
+  cranker: PublicKey,
+  oldFolio: PublicKey,
+  newFolio: PublicKey,
+  newFolioProgram: PublicKey,
+  folioTokenMint: PublicKey,
+  index: BN,
+  executeTxn: T = true as T
+): Promise<
+  T extends true
+    ? BanksTransactionResultWithMeta
+    : { ix: TransactionInstruction; extraSigners: any[] }
+> {
+  const migrateFeeDistribution = await programFolio.methods
+    .migrateFeeDistribution()
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      user: userKeypair.publicKey,
+      cranker,
+      programRegistrar: getProgramRegistrarPDA(),
+      newFolioProgram,
+      oldFolio,
+      newFolio,
+      folioTokenMint,
+      oldFeeDistribution: getFeeDistributionPDA(oldFolio, index),
+      newFeeDistribution: getFeeDistributionPDA(
+        newFolio,
+        index,
+        newFolioProgram
+      ),
+      migrationRecord: getMigrationRecordPDA(oldFolio),
+    })
+    .instruction();
+
+  if (executeTxn) {
+    return createAndProcessTransaction(client, userKeypair, [
+      migrateFeeDistribution,
+    ]) as any;
+  }
+
+  return { ix: migrateFeeDistribution, extraSigners: [] } as any;
+}
+
 /*
 Rewards instructions
 */
@@ -12,6 +12,7 @@ import idlFolio from "../../target/idl/folio.json";
 import idlSecondFolio from "../../target/idl/second_folio.json";
 import idlFolioAdmin from "../../target/idl/folio_admin.json";
 import idlRewards from "../../target/idl/rewards.json";
+import idlTransferHookMock from "../../target/idl/transfer_hook_mock.json";
 import * as assert from "assert";
 import { AnchorError } from "@coral-xyz/anchor";
 import {
@@ -21,6 +22,7 @@ import {
 import { FolioAdmin } from "../../target/types/folio_admin";
 import { Rewards } from "../../target/types/rewards";
 import { Folio as FolioSecond } from "../../target/types/second_folio";
+import { TransferHookMock } from "../../target/types/transfer_hook_mock";
 import {
   FailedTransactionMetadata,
   LiteSVM,
@@ -76,6 +78,10 @@ export async function getConnectors() {
     new PublicKey(idlSecondFolio.address),
     path.join(__dirname, "../../target/deploy/second_folio.so")
   );
+  context.addProgramFromFile(
+    new PublicKey(idlTransferHookMock.address),
+    path.join(__dirname, "../../target/deploy/transfer_hook_mock.so")
+  );
   context.addProgramFromFile(
     new PublicKey(TOKEN_METADATA_PROGRAM_ID),
     path.join(__dirname, "../../target/deploy/metadata.so")
@@ -100,6 +106,9 @@ export async function getConnectors() {
       idlSecondFolio as FolioSecond
     ),
     programRewards: new anchor.Program<Rewards>(idlRewards as Rewards),
+    programTransferHookMock: new anchor.Program<TransferHookMock>(
+      idlTransferHookMock as TransferHookMock
+    ),
     provider: anchor.getProvider(),
   };
 }
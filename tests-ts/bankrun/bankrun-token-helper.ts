@@ -16,6 +16,7 @@ import {
   createMintToInstruction,
   createAssociatedTokenAccountInstruction,
   createInitializeNonTransferableMintInstruction,
+  createInitializeTransferFeeConfigInstruction,
 } from "@solana/spl-token";
 import * as assert from "assert";
 import { BN } from "@coral-xyz/anchor";
@@ -293,7 +294,9 @@ export async function initToken2022Tx(
   mintAuthority: Keypair,
   mint: Keypair = Keypair.generate(),
   extension: ExtensionType,
-  decimals: number = DEFAULT_DECIMALS
+  decimals: number = DEFAULT_DECIMALS,
+  transferHookProgramId: PublicKey = null,
+  transferFee: { basisPoints: number; maximumFee: BN } = null
 ) {
   const rent = await context.getRent();
 
@@ -314,8 +317,8 @@ export async function initToken2022Tx(
       createInitializeTransferHookInstruction(
         mint.publicKey,
         mintAuthority.publicKey,
-        // programId for the hook, don't really care here
-        mintAuthority.publicKey,
+        // programId for the hook, only matters for the tests running the hook
+        transferHookProgramId ?? mintAuthority.publicKey,
         TOKEN_2022_PROGRAM_ID
       )
     );
@@ -327,6 +330,17 @@ export async function initToken2022Tx(
         TOKEN_2022_PROGRAM_ID
       )
     );
+  } else if (extension === ExtensionType.TransferFeeConfig) {
+    instructions.push(
+      createInitializeTransferFeeConfigInstruction(
+        mint.publicKey,
+        mintAuthority.publicKey,
+        mintAuthority.publicKey,
+        transferFee?.basisPoints ?? 0,
+        BigInt((transferFee?.maximumFee ?? new BN(0)).toString()),
+        TOKEN_2022_PROGRAM_ID
+      )
+    );
   } else if (extension === ExtensionType.NonTransferable) {
     instructions.push(
       createInitializeNonTransferableMintInstruction(
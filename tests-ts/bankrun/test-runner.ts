@@ -5,6 +5,7 @@ describe("Bankrun", () => {
   require("./tests/tests-init-folio.ts");
   require("./tests/tests-program-registrar");
   require("./tests/tests-update-folio.ts");
+  require("./tests/tests-folio-branding");
   require("./tests/tests-folio-minting");
   require("./tests/tests-folio-redeeming");
   require("./tests/tests-migration");
@@ -16,4 +17,17 @@ describe("Bankrun", () => {
   require("./tests/tests-staking-governance");
   require("./tests/tests-staking-admin-2022");
   require("./tests/tests-bids-and-kill-auction");
+  require("./tests/tests-transfer-hook");
+  require("./tests/tests-transfer-fee");
+  require("./tests/tests-sync-basket-balance");
+  require("./tests/tests-close-fee-distribution");
+  require("./tests/tests-folio-action");
+  require("./tests/tests-add-zero-balance-to-basket");
+  require("./tests/tests-pause-flags");
+  require("./tests/tests-fee-discount");
+  require("./tests/tests-unkill-folio");
+  require("./tests/tests-owner-transfer");
+  require("./tests/tests-mint-allowlist");
+  require("./tests/tests-upgrade-account");
+  require("./tests/tests-folio-mint-limits");
 });
@@ -2,7 +2,11 @@ import { Program, Provider } from "@coral-xyz/anchor";
 import { Keypair, PublicKey } from "@solana/web3.js";
 import { Folio } from "../../../target/types/folio";
 
-import { getActorPDA, getFolioPDA } from "../../../utils/pda-helper";
+import {
+  getActorPDA,
+  getFolioActorsPDA,
+  getFolioPDA,
+} from "../../../utils/pda-helper";
 
 import {
   airdrop,
@@ -20,7 +24,11 @@ import {
   assertNotValidRoleTestCase,
   GeneralTestCases,
 } from "../bankrun-general-tests-helper";
-import { addOrUpdateActor, removeActor } from "../bankrun-ix-helper";
+import {
+  addOrUpdateActor,
+  indexActor,
+  removeActor,
+} from "../bankrun-ix-helper";
 import * as assert from "assert";
 import { LiteSVM } from "litesvm";
 import { TestHelper } from "../../../utils/test-helper";
@@ -31,6 +39,7 @@ import { TestHelper } from "../../../utils/test-helper";
  * - Removing actors and their roles
  * - Role validation and permissions
  * - Account closure when all roles are removed
+ * - Indexing existing actors in the folio actors index
  */
 
 describe("Bankrun - Actor", () => {
@@ -57,6 +66,12 @@ describe("Bankrun - Actor", () => {
       newRole: 0b10000000,
       expectedError: "InstructionDidNotDeserialize",
     },
+    {
+      desc: "(gives owner role, failure)",
+      currentRole: null,
+      newRole: Role.Owner,
+      expectedError: "OwnerRoleRequiresOwnershipTransfer",
+    },
     {
       desc: "(already have role to give, doesn't change anything, success)",
       currentRole: Role.RebalanceManager,
@@ -142,7 +157,7 @@ describe("Bankrun - Actor", () => {
         folioOwnerKeypair,
         folioPDA,
         newActorKeypair.publicKey,
-        Role.Owner
+        Role.RebalanceManager
       );
 
     const generalIxRemoveActor = () =>
@@ -313,4 +328,53 @@ describe("Bankrun - Actor", () => {
       }
     );
   });
+
+  describe("Specific Cases - Index Actor", () => {
+    it("should index the roles of an existing actor", async () => {
+      await createAndSetActor(
+        context,
+        programFolio,
+        newActorKeypair,
+        folioPDA,
+        Role.RebalanceManager | Role.AuctionLauncher
+      );
+
+      await travelFutureSlot(context);
+
+      await indexActor<true>(
+        context,
+        programFolio,
+        payerKeypair,
+        folioPDA,
+        newActorKeypair.publicKey
+      );
+
+      await travelFutureSlot(context);
+
+      const folioActors = await programFolio.account.folioActors.fetch(
+        getFolioActorsPDA(folioPDA)
+      );
+      assert.equal(folioActors.folio.toString(), folioPDA.toString());
+
+      const actorEntry = folioActors.actors.find((entry) =>
+        entry.authority.equals(newActorKeypair.publicKey)
+      );
+      assert.equal(
+        actorEntry.roles,
+        Role.RebalanceManager | Role.AuctionLauncher
+      );
+    });
+
+    it("should fail if the actor doesn't exist", async () => {
+      const txnResult = await indexActor<true>(
+        context,
+        programFolio,
+        payerKeypair,
+        folioPDA,
+        newActorKeypair.publicKey
+      );
+
+      assertError(txnResult, "AccountNotInitialized");
+    });
+  });
 });
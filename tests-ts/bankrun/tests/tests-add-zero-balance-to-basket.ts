@@ -0,0 +1,380 @@
+import { BN, Program, Provider } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { ExtensionType } from "@solana/spl-token";
+import {
+  airdrop,
+  assertError,
+  getConnectors,
+  travelFutureSlot,
+} from "../bankrun-program-helper";
+
+import {
+  getFolioBasketPDA,
+  getFolioFeeConfigPDA,
+  getFolioPDA,
+  getUserPendingBasketPDA,
+} from "../../../utils/pda-helper";
+import {
+  addToPendingBasket,
+  addZeroBalanceToBasket,
+  burnFolioToken,
+  mintFolioToken,
+  redeemFromPendingBasket,
+} from "../bankrun-ix-helper";
+import {
+  closeAccount,
+  createAndSetActor,
+  createAndSetDaoFeeConfig,
+  createAndSetFolio,
+  createAndSetFolioBasket,
+  FolioStatus,
+  FolioTokenAmount,
+  Role,
+} from "../bankrun-account-helper";
+import { Folio } from "../../../target/types/folio";
+import { FolioAdmin } from "../../../target/types/folio_admin";
+import {
+  assertInvalidFolioStatusTestCase,
+  assertNotValidRoleTestCase,
+  GeneralTestCases,
+} from "../bankrun-general-tests-helper";
+import { D9, DEFAULT_DECIMALS } from "../../../utils/constants";
+import {
+  getAtaAddress,
+  getTokenBalance,
+  initToken,
+  initToken2022Tx,
+  mintToken,
+} from "../bankrun-token-helper";
+import { LiteSVM } from "litesvm";
+import * as assert from "assert";
+
+/**
+ * Tests for adding a token with a zero balance to the basket, including:
+ * - Only the owner of an initialized folio can add it
+ * - Unsupported tokens (e.g. with a transfer hook that isn't allowed) are rejected
+ * - Minting and redeeming keep working while the token has a zero balance
+ */
+describe("Bankrun - Add Zero Balance To Basket", () => {
+  let context: LiteSVM;
+  let provider: Provider;
+  let banksClient: LiteSVM;
+
+  let programFolioAdmin: Program<FolioAdmin>;
+  let programFolio: Program<Folio>;
+  let keys: any;
+
+  let payerKeypair: Keypair;
+  let adminKeypair: Keypair;
+
+  let folioOwnerKeypair: Keypair;
+  let userKeypair: Keypair;
+
+  let folioTokenMint: Keypair;
+  let basketMint: Keypair;
+  let zeroBalanceMint: Keypair;
+
+  let folioPDA: PublicKey;
+
+  const feeRecipient: PublicKey = Keypair.generate().publicKey;
+
+  // Whole tokens, 1 basket token per folio token
+  const FOLIO_TOKEN_SUPPLY = 1_000;
+  const BASKET_AMOUNT = 1_000;
+  const USER_AMOUNT = 100;
+
+  const generalIxAddZeroBalanceToBasket = () =>
+    addZeroBalanceToBasket<true>(
+      banksClient,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      zeroBalanceMint.publicKey
+    );
+
+  async function getBasketTokenAmount(mint: PublicKey) {
+    const folioBasket = await programFolio.account.folioBasket.fetch(
+      getFolioBasketPDA(folioPDA)
+    );
+
+    return folioBasket.basket.tokenAmounts.find((ta) => ta.mint.equals(mint));
+  }
+
+  async function getUserPendingTokenAmount(mint: PublicKey) {
+    const userPendingBasket =
+      await programFolio.account.userPendingBasket.fetch(
+        getUserPendingBasketPDA(folioPDA, userKeypair.publicKey)
+      );
+
+    return userPendingBasket.basket.tokenAmounts.find((ta) =>
+      ta.mint.equals(mint)
+    );
+  }
+
+  beforeEach(async () => {
+    ({ keys, programFolioAdmin, programFolio, provider, context } =
+      await getConnectors());
+
+    banksClient = context;
+
+    payerKeypair = provider.wallet.payer;
+
+    adminKeypair = Keypair.fromSecretKey(Uint8Array.from(keys.admin));
+
+    folioOwnerKeypair = Keypair.generate();
+    userKeypair = Keypair.generate();
+    folioTokenMint = Keypair.generate();
+    basketMint = Keypair.generate();
+    zeroBalanceMint = Keypair.generate();
+
+    await airdrop(context, payerKeypair.publicKey, 1000);
+    await airdrop(context, adminKeypair.publicKey, 1000);
+    await airdrop(context, folioOwnerKeypair.publicKey, 1000);
+    await airdrop(context, userKeypair.publicKey, 1000);
+
+    folioPDA = getFolioPDA(folioTokenMint.publicKey);
+
+    // No fees, so the minted shares are exact
+    await createAndSetDaoFeeConfig(
+      context,
+      programFolioAdmin,
+      feeRecipient,
+      new BN(0),
+      new BN(0)
+    );
+    await closeAccount(context, getFolioFeeConfigPDA(folioPDA));
+
+    await createAndSetFolio(
+      context,
+      programFolio,
+      folioTokenMint.publicKey,
+      FolioStatus.Initialized,
+      new BN(0)
+    );
+
+    initToken(
+      context,
+      folioPDA,
+      folioTokenMint,
+      DEFAULT_DECIMALS,
+      new BN(FOLIO_TOKEN_SUPPLY).mul(D9)
+    );
+    initToken(context, adminKeypair.publicKey, basketMint, DEFAULT_DECIMALS);
+    initToken(
+      context,
+      adminKeypair.publicKey,
+      zeroBalanceMint,
+      DEFAULT_DECIMALS
+    );
+
+    mintToken(context, basketMint.publicKey, BASKET_AMOUNT, folioPDA);
+    mintToken(context, zeroBalanceMint.publicKey, 0, folioPDA);
+    mintToken(
+      context,
+      basketMint.publicKey,
+      USER_AMOUNT,
+      userKeypair.publicKey
+    );
+
+    await createAndSetFolioBasket(context, programFolio, folioPDA, [
+      new FolioTokenAmount(
+        basketMint.publicKey,
+        new BN(BASKET_AMOUNT).mul(D9)
+      ),
+    ]);
+
+    await createAndSetActor(
+      context,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      Role.Owner
+    );
+
+    await closeAccount(
+      context,
+      getUserPendingBasketPDA(folioPDA, userKeypair.publicKey)
+    );
+  });
+
+  describe("General Tests", () => {
+    it(`should run ${GeneralTestCases.NotRole}`, async () => {
+      await assertNotValidRoleTestCase(
+        context,
+        programFolio,
+        folioOwnerKeypair,
+        folioPDA,
+        generalIxAddZeroBalanceToBasket
+      );
+    });
+
+    it(`should run ${GeneralTestCases.InvalidFolioStatus} for INITIALIZING, MIGRATING & KILLED`, async () => {
+      for (const status of [
+        FolioStatus.Initializing,
+        FolioStatus.Migrating,
+        FolioStatus.Killed,
+      ]) {
+        await assertInvalidFolioStatusTestCase(
+          context,
+          programFolio,
+          folioTokenMint.publicKey,
+          generalIxAddZeroBalanceToBasket,
+          status
+        );
+      }
+    });
+  });
+
+  it("should add the token to the basket with a zero balance", async () => {
+    await generalIxAddZeroBalanceToBasket();
+
+    await travelFutureSlot(context);
+
+    const tokenAmount = await getBasketTokenAmount(zeroBalanceMint.publicKey);
+    assert.equal(tokenAmount.amount.isZero(), true);
+  });
+
+  it("should fail if the token is the folio token mint", async () => {
+    const txnResult = await addZeroBalanceToBasket<true>(
+      banksClient,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      folioTokenMint.publicKey
+    );
+
+    assertError(txnResult, "InvalidFolioTokenMint");
+  });
+
+  it("should fail if the token is already in the basket", async () => {
+    const txnResult = await addZeroBalanceToBasket<true>(
+      banksClient,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      basketMint.publicKey
+    );
+
+    assertError(txnResult, "InvalidAddedTokenMints");
+  });
+
+  it("should fail if the token has a transfer hook that isn't allowed", async () => {
+    const hookMint = Keypair.generate();
+    // The hook program isn't in the transfer hook registrar
+    await initToken2022Tx(
+      context,
+      adminKeypair,
+      hookMint,
+      ExtensionType.TransferHook,
+      DEFAULT_DECIMALS,
+      Keypair.generate().publicKey
+    );
+
+    await travelFutureSlot(context);
+
+    const txnResult = await addZeroBalanceToBasket<true>(
+      banksClient,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      hookMint.publicKey
+    );
+
+    assertError(txnResult, "UnsupportedSPLToken");
+  });
+
+  it("should still mint and redeem with the zero balance token in the basket", async () => {
+    await generalIxAddZeroBalanceToBasket();
+
+    await travelFutureSlot(context);
+
+    // The user never deposits the zero balance token
+    await addToPendingBasket<true>(
+      context,
+      banksClient,
+      programFolio,
+      userKeypair,
+      folioPDA,
+      [{ mint: basketMint.publicKey, amount: new BN(USER_AMOUNT).mul(D9) }]
+    );
+
+    await travelFutureSlot(context);
+
+    const shares = new BN(USER_AMOUNT).mul(D9);
+
+    await mintFolioToken<true>(
+      context,
+      banksClient,
+      programFolio,
+      userKeypair,
+      folioPDA,
+      folioTokenMint.publicKey,
+      [
+        { mint: basketMint.publicKey, amount: new BN(0) },
+        { mint: zeroBalanceMint.publicKey, amount: new BN(0) },
+      ],
+      shares
+    );
+
+    await travelFutureSlot(context);
+
+    const userFolioTokenATA = getAtaAddress(
+      folioTokenMint.publicKey,
+      userKeypair.publicKey
+    );
+    assert.equal(
+      await getTokenBalance(banksClient, userFolioTokenATA),
+      BigInt(shares.toString())
+    );
+
+    await burnFolioToken<true>(
+      context,
+      banksClient,
+      programFolio,
+      userKeypair,
+      folioPDA,
+      folioTokenMint.publicKey,
+      shares
+    );
+
+    await travelFutureSlot(context);
+
+    const basketTokenAmount = await getUserPendingTokenAmount(
+      basketMint.publicKey
+    );
+    assert.equal(basketTokenAmount.amountForRedeeming.gtn(0), true);
+
+    // Nothing is given for the zero balance token
+    const zeroBalanceTokenAmount = await getUserPendingTokenAmount(
+      zeroBalanceMint.publicKey
+    );
+    assert.equal(
+      zeroBalanceTokenAmount?.amountForRedeeming.isZero() ?? true,
+      true
+    );
+
+    await redeemFromPendingBasket<true>(
+      context,
+      banksClient,
+      programFolio,
+      userKeypair,
+      folioPDA,
+      [
+        {
+          mint: basketMint.publicKey,
+          amount: basketTokenAmount.amountForRedeeming,
+        },
+      ]
+    );
+
+    await travelFutureSlot(context);
+
+    assert.equal(
+      await getTokenBalance(
+        banksClient,
+        getAtaAddress(basketMint.publicKey, userKeypair.publicKey)
+      ),
+      BigInt(basketTokenAmount.amountForRedeeming.toString())
+    );
+  });
+});
@@ -30,11 +30,13 @@ import {
   BasketRange,
   AuctionPrices,
   createAndSetAuctionEndsAccount,
+  PauseFlag,
 } from "../bankrun-account-helper";
 import { Folio } from "../../../target/types/folio";
 import {
   assertInvalidFolioStatusTestCase,
   assertNotValidRoleTestCase,
+  assertPausedTestCase,
   GeneralTestCases,
 } from "../bankrun-general-tests-helper";
 import {
@@ -519,6 +521,15 @@ describe("Bankrun - Auction", () => {
     });
 
     describe("should run general tests for open auction", () => {
+      it(`should run ${GeneralTestCases.Paused} for AUCTIONS`, async () => {
+        await assertPausedTestCase(
+          context,
+          folioPDA,
+          generalIxOpenAuction,
+          PauseFlag.Auctions
+        );
+      });
+
       it(`should run ${GeneralTestCases.NotRole}`, async () => {
         await assertNotValidRoleTestCase(
           context,
@@ -562,6 +573,15 @@ describe("Bankrun - Auction", () => {
     });
 
     describe("should run general tests for open auction permissionless", () => {
+      it(`should run ${GeneralTestCases.Paused} for AUCTIONS`, async () => {
+        await assertPausedTestCase(
+          context,
+          folioPDA,
+          generalIxOpenAuctionPermissionless,
+          PauseFlag.Auctions
+        );
+      });
+
       it(`should run ${GeneralTestCases.InvalidFolioStatus} for MIGRATING & KILLED & INITIALIZING`, async () => {
         await assertInvalidFolioStatusTestCase(
           context,
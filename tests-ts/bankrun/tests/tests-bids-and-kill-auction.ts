@@ -33,11 +33,13 @@ import {
   createAndSetRebalanceAccount,
   createAndSetAuctionEndsAccount,
   createAndSetDaoFeeConfig,
+  PauseFlag,
 } from "../bankrun-account-helper";
 import { Folio } from "../../../target/types/folio";
 import {
   assertInvalidFolioStatusTestCase,
   assertNotValidRoleTestCase,
+  assertPausedTestCase,
   GeneralTestCases,
 } from "../bankrun-general-tests-helper";
 import * as assert from "assert";
@@ -538,6 +540,15 @@ describe("Bankrun - Bids and Kill Auction", () => {
         );
       });
 
+      it(`should run ${GeneralTestCases.Paused} for AUCTIONS`, async () => {
+        await assertPausedTestCase(
+          context,
+          folioPDA,
+          generalIxBid,
+          PauseFlag.Auctions
+        );
+      });
+
       it(`should run ${GeneralTestCases.InvalidFolioStatus} for MIGRATING & KILLED & INITIALIZING`, async () => {
         await assertInvalidFolioStatusTestCase(
           context,
@@ -1202,7 +1213,6 @@ describe("Bankrun - Bids and Kill Auction", () => {
       },
 
       ...[
-        ExtensionType.TransferFeeAmount,
         ExtensionType.CpiGuard,
         ExtensionType.NonTransferableAccount,
         ExtensionType.MemoTransfer,
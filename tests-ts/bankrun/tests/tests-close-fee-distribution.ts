@@ -0,0 +1,315 @@
+import { BN, Program, Provider } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import {
+  airdrop,
+  assertError,
+  getConnectors,
+  setClock,
+  travelFutureSlot,
+} from "../bankrun-program-helper";
+
+import {
+  getFeeDistributionPDA,
+  getFolioPDA,
+} from "../../../utils/pda-helper";
+import { closeFeeDistribution, upgradeAccount } from "../bankrun-ix-helper";
+import {
+  createAndSetActor,
+  createAndSetDaoFeeConfig,
+  createAndSetFeeDistribution,
+  createAndSetFeeRecipients,
+  createAndSetFolio,
+  createAndSetFolioBasket,
+  FeeRecipient,
+  FolioStatus,
+  Role,
+} from "../bankrun-account-helper";
+import { Folio } from "../../../target/types/folio";
+import { FolioAdmin } from "../../../target/types/folio_admin";
+import {
+  D18,
+  D9,
+  DEFAULT_DECIMALS,
+  FEE_DISTRIBUTION_CLOSE_GRACE_PERIOD,
+  MAX_MINT_FEE,
+  TOTAL_PORTION_FEE_RECIPIENT,
+} from "../../../utils/constants";
+import {
+  getOrCreateAtaAddress,
+  getTokenBalance,
+  initToken,
+} from "../bankrun-token-helper";
+import { LiteSVM } from "litesvm";
+import { TestHelper } from "../../../utils/test-helper";
+import * as assert from "assert";
+
+/**
+ * Tests for closing fee distributions, including:
+ * - Fully distributed fee distributions can be closed right away
+ * - Undistributed shares go to the DAO once the grace period has passed
+ * - Legacy fee distributions (without `created_at`) start their grace period
+ *   when they are upgraded, so they can't be closed right away
+ */
+describe("Bankrun - Close Fee Distribution", () => {
+  let context: LiteSVM;
+  let provider: Provider;
+  let banksClient: LiteSVM;
+
+  let programFolioAdmin: Program<FolioAdmin>;
+  let programFolio: Program<Folio>;
+
+  let payerKeypair: Keypair;
+
+  let folioOwnerKeypair: Keypair;
+  let userKeypair: Keypair;
+  let cranker: Keypair;
+  let daoFeeRecipient: Keypair;
+
+  let folioTokenMint: Keypair;
+
+  let folioPDA: PublicKey;
+  let daoFeeRecipientATA: PublicKey;
+
+  const FEE_DISTRIBUTION_INDEX = new BN(1);
+  // 1000 shares, scaled in D18
+  const AMOUNT_TO_DISTRIBUTE = new BN(1000).mul(D18);
+
+  const FEE_RECIPIENTS: FeeRecipient[] = [
+    {
+      recipient: Keypair.generate().publicKey,
+      portion: TOTAL_PORTION_FEE_RECIPIENT.div(new BN(2)),
+    },
+    {
+      recipient: Keypair.generate().publicKey,
+      portion: TOTAL_PORTION_FEE_RECIPIENT.div(new BN(2)),
+    },
+  ];
+
+  const FULLY_DISTRIBUTED_FEE_RECIPIENTS: FeeRecipient[] = FEE_RECIPIENTS.map(
+    (f) => ({ ...f, recipient: PublicKey.default })
+  );
+
+  function getCurrentTime() {
+    return Number(context.getClock().unixTimestamp);
+  }
+
+  async function setFeeDistribution(
+    feeRecipients: FeeRecipient[],
+    createdAt: BN | null
+  ) {
+    await createAndSetFeeDistribution(
+      context,
+      programFolio,
+      folioPDA,
+      cranker.publicKey,
+      FEE_DISTRIBUTION_INDEX,
+      AMOUNT_TO_DISTRIBUTE,
+      feeRecipients,
+      createdAt
+    );
+  }
+
+  const closeIx = (crankerToUse: PublicKey = cranker.publicKey) =>
+    closeFeeDistribution<true>(
+      banksClient,
+      programFolio,
+      userKeypair,
+      folioPDA,
+      folioTokenMint.publicKey,
+      crankerToUse,
+      FEE_DISTRIBUTION_INDEX,
+      daoFeeRecipientATA
+    );
+
+  beforeEach(async () => {
+    ({ programFolioAdmin, programFolio, provider, context } =
+      await getConnectors());
+
+    banksClient = context;
+
+    payerKeypair = provider.wallet.payer;
+
+    folioOwnerKeypair = Keypair.generate();
+    userKeypair = Keypair.generate();
+    cranker = Keypair.generate();
+    daoFeeRecipient = Keypair.generate();
+    folioTokenMint = Keypair.generate();
+
+    await airdrop(context, payerKeypair.publicKey, 1000);
+    await airdrop(context, folioOwnerKeypair.publicKey, 1000);
+    await airdrop(context, userKeypair.publicKey, 1000);
+    await airdrop(context, cranker.publicKey, 1000);
+
+    folioPDA = getFolioPDA(folioTokenMint.publicKey);
+
+    await createAndSetDaoFeeConfig(
+      context,
+      programFolioAdmin,
+      daoFeeRecipient.publicKey,
+      MAX_MINT_FEE
+    );
+
+    // The pending shares of the fee recipients include the ones of the fee distribution
+    await createAndSetFolio(
+      context,
+      programFolio,
+      folioTokenMint.publicKey,
+      FolioStatus.Initialized,
+      null,
+      new BN(0),
+      new BN(0),
+      new BN(0),
+      false,
+      "",
+      AMOUNT_TO_DISTRIBUTE
+    );
+
+    initToken(context, folioPDA, folioTokenMint, DEFAULT_DECIMALS);
+
+    daoFeeRecipientATA = await getOrCreateAtaAddress(
+      context,
+      folioTokenMint.publicKey,
+      daoFeeRecipient.publicKey
+    );
+
+    await createAndSetActor(
+      context,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      Role.Owner
+    );
+
+    await createAndSetFolioBasket(context, programFolio, folioPDA, []);
+
+    await createAndSetFeeRecipients(context, programFolio, folioPDA, []);
+  });
+
+  it("should close a fully distributed fee distribution right away", async () => {
+    await setFeeDistribution(
+      FULLY_DISTRIBUTED_FEE_RECIPIENTS,
+      new BN(getCurrentTime())
+    );
+
+    const crankerLamportsBefore = context.getBalance(cranker.publicKey);
+
+    await closeIx();
+
+    await travelFutureSlot(context);
+
+    TestHelper.assertAccountIsClosed(
+      banksClient.getAccount(
+        getFeeDistributionPDA(folioPDA, FEE_DISTRIBUTION_INDEX)
+      )
+    );
+
+    // Rent goes back to the cranker, and nothing is left to mint
+    assert.equal(
+      context.getBalance(cranker.publicKey) > crankerLamportsBefore,
+      true
+    );
+    assert.equal(
+      await getTokenBalance(banksClient, daoFeeRecipientATA),
+      BigInt(0)
+    );
+  });
+
+  it("should fail to close an undistributed fee distribution before the grace period", async () => {
+    await setFeeDistribution(FEE_RECIPIENTS, new BN(getCurrentTime()));
+
+    const txnResult = await closeIx();
+
+    assertError(txnResult, "FeeDistributionNotClosable");
+  });
+
+  it("should fail if the cranker isn't the one of the fee distribution", async () => {
+    await setFeeDistribution(
+      FULLY_DISTRIBUTED_FEE_RECIPIENTS,
+      new BN(getCurrentTime())
+    );
+
+    const txnResult = await closeIx(Keypair.generate().publicKey);
+
+    assertError(txnResult, "InvalidCranker");
+  });
+
+  it("should mint the undistributed shares to the DAO after the grace period", async () => {
+    await setFeeDistribution(FEE_RECIPIENTS, new BN(getCurrentTime()));
+
+    setClock(
+      context,
+      getCurrentTime() + FEE_DISTRIBUTION_CLOSE_GRACE_PERIOD.toNumber()
+    );
+
+    await closeIx();
+
+    await travelFutureSlot(context);
+
+    TestHelper.assertAccountIsClosed(
+      banksClient.getAccount(
+        getFeeDistributionPDA(folioPDA, FEE_DISTRIBUTION_INDEX)
+      )
+    );
+
+    // All the shares of the distribution, in raw amount
+    assert.equal(
+      await getTokenBalance(banksClient, daoFeeRecipientATA),
+      BigInt(AMOUNT_TO_DISTRIBUTE.div(D9).toString())
+    );
+
+    const folioAfter = await programFolio.account.folio.fetch(folioPDA);
+    assert.equal(
+      folioAfter.feeRecipientsPendingFeeSharesToBeMinted.isZero(),
+      true
+    );
+  });
+
+  describe("Legacy fee distribution", () => {
+    beforeEach(async () => {
+      await setFeeDistribution(FEE_RECIPIENTS, null);
+    });
+
+    it("should fail to close it right away", async () => {
+      const txnResult = await closeIx();
+
+      assertError(txnResult, "FeeDistributionNotClosable");
+    });
+
+    it("should start the grace period when it's upgraded", async () => {
+      const upgradedAt = getCurrentTime();
+
+      await upgradeAccount<true>(
+        banksClient,
+        programFolio,
+        payerKeypair,
+        getFeeDistributionPDA(folioPDA, FEE_DISTRIBUTION_INDEX)
+      );
+
+      await travelFutureSlot(context);
+
+      const feeDistribution = await programFolio.account.feeDistribution.fetch(
+        getFeeDistributionPDA(folioPDA, FEE_DISTRIBUTION_INDEX)
+      );
+      assert.equal(feeDistribution.version, 1);
+      assert.equal(feeDistribution.createdAt.toNumber() >= upgradedAt, true);
+
+      // Still within the grace period
+      assertError(await closeIx(), "FeeDistributionNotClosable");
+
+      setClock(
+        context,
+        feeDistribution.createdAt.toNumber() +
+          FEE_DISTRIBUTION_CLOSE_GRACE_PERIOD.toNumber()
+      );
+
+      await closeIx();
+
+      await travelFutureSlot(context);
+
+      assert.equal(
+        await getTokenBalance(banksClient, daoFeeRecipientATA),
+        BigInt(AMOUNT_TO_DISTRIBUTE.div(D9).toString())
+      );
+    });
+  });
+});
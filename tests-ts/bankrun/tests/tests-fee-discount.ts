@@ -0,0 +1,304 @@
+import { BN, Program, Provider } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import {
+  airdrop,
+  assertError,
+  getConnectors,
+  travelFutureSlot,
+} from "../bankrun-program-helper";
+
+import {
+  getFeeDiscountPDA,
+  getFolioFeeConfigPDA,
+  getFolioPDA,
+  getUserPendingBasketPDA,
+} from "../../../utils/pda-helper";
+import {
+  addToPendingBasket,
+  initOrUpdateFeeDiscount,
+  mintFolioToken,
+  removeFeeDiscount,
+} from "../bankrun-ix-helper";
+import {
+  closeAccount,
+  createAndSetActor,
+  createAndSetDaoFeeConfig,
+  createAndSetFolio,
+  createAndSetFolioBasket,
+  FolioTokenAmount,
+  Role,
+} from "../bankrun-account-helper";
+import { Folio } from "../../../target/types/folio";
+import { FolioAdmin } from "../../../target/types/folio_admin";
+import {
+  assertNotValidRoleTestCase,
+  GeneralTestCases,
+} from "../bankrun-general-tests-helper";
+import { D18, D9, DEFAULT_DECIMALS } from "../../../utils/constants";
+import {
+  getAtaAddress,
+  getTokenBalance,
+  initToken,
+  mintToken,
+} from "../bankrun-token-helper";
+import { LiteSVM } from "litesvm";
+import * as assert from "assert";
+import { TestHelper } from "../../../utils/test-helper";
+
+/**
+ * Tests for the fee discounts on the mint fee, including:
+ * - Only the owner can set, update and remove a fee discount
+ * - The discount can't be over 100%
+ * - The discount is applied on the mint fee of the minter
+ */
+describe("Bankrun - Fee Discount", () => {
+  let context: LiteSVM;
+  let provider: Provider;
+  let banksClient: LiteSVM;
+
+  let programFolioAdmin: Program<FolioAdmin>;
+  let programFolio: Program<Folio>;
+  let keys: any;
+
+  let payerKeypair: Keypair;
+  let adminKeypair: Keypair;
+
+  let folioOwnerKeypair: Keypair;
+  let userKeypair: Keypair;
+
+  let folioTokenMint: Keypair;
+  let basketMint: Keypair;
+
+  let folioPDA: PublicKey;
+
+  const feeRecipient: PublicKey = Keypair.generate().publicKey;
+
+  // Whole tokens, 1 basket token per folio token
+  const FOLIO_TOKEN_SUPPLY = 1_000;
+  const BASKET_AMOUNT = 1_000;
+  const USER_AMOUNT = 100;
+
+  const generalIxInitOrUpdateFeeDiscount = () =>
+    initOrUpdateFeeDiscount<true>(
+      banksClient,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      userKeypair.publicKey,
+      D18.divn(2)
+    );
+
+  const generalIxRemoveFeeDiscount = () =>
+    removeFeeDiscount<true>(
+      banksClient,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      userKeypair.publicKey
+    );
+
+  async function getScaledDiscount() {
+    const feeDiscount = await programFolio.account.feeDiscount.fetch(
+      getFeeDiscountPDA(folioPDA, userKeypair.publicKey)
+    );
+
+    return feeDiscount.scaledDiscount;
+  }
+
+  beforeEach(async () => {
+    ({ keys, programFolioAdmin, programFolio, provider, context } =
+      await getConnectors());
+
+    banksClient = context;
+
+    payerKeypair = provider.wallet.payer;
+
+    adminKeypair = Keypair.fromSecretKey(Uint8Array.from(keys.admin));
+
+    folioOwnerKeypair = Keypair.generate();
+    userKeypair = Keypair.generate();
+    folioTokenMint = Keypair.generate();
+    basketMint = Keypair.generate();
+
+    await airdrop(context, payerKeypair.publicKey, 1000);
+    await airdrop(context, adminKeypair.publicKey, 1000);
+    await airdrop(context, folioOwnerKeypair.publicKey, 1000);
+    await airdrop(context, userKeypair.publicKey, 1000);
+
+    folioPDA = getFolioPDA(folioTokenMint.publicKey);
+
+    // No DAO fee, so only the mint fee of the folio is charged
+    await createAndSetDaoFeeConfig(
+      context,
+      programFolioAdmin,
+      feeRecipient,
+      new BN(0),
+      new BN(0)
+    );
+    await closeAccount(context, getFolioFeeConfigPDA(folioPDA));
+
+    await createAndSetFolio(context, programFolio, folioTokenMint.publicKey);
+
+    initToken(
+      context,
+      folioPDA,
+      folioTokenMint,
+      DEFAULT_DECIMALS,
+      new BN(FOLIO_TOKEN_SUPPLY).mul(D9)
+    );
+    initToken(context, adminKeypair.publicKey, basketMint, DEFAULT_DECIMALS);
+
+    mintToken(context, basketMint.publicKey, BASKET_AMOUNT, folioPDA);
+    mintToken(
+      context,
+      basketMint.publicKey,
+      USER_AMOUNT,
+      userKeypair.publicKey
+    );
+
+    await createAndSetFolioBasket(context, programFolio, folioPDA, [
+      new FolioTokenAmount(
+        basketMint.publicKey,
+        new BN(BASKET_AMOUNT).mul(D9)
+      ),
+    ]);
+
+    await createAndSetActor(
+      context,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      Role.Owner
+    );
+
+    await closeAccount(
+      context,
+      getUserPendingBasketPDA(folioPDA, userKeypair.publicKey)
+    );
+  });
+
+  describe("General Tests", () => {
+    it(`should run ${GeneralTestCases.NotRole} for init or update fee discount`, async () => {
+      await assertNotValidRoleTestCase(
+        context,
+        programFolio,
+        folioOwnerKeypair,
+        folioPDA,
+        generalIxInitOrUpdateFeeDiscount
+      );
+    });
+
+    it(`should run ${GeneralTestCases.NotRole} for remove fee discount`, async () => {
+      await generalIxInitOrUpdateFeeDiscount();
+
+      await travelFutureSlot(context);
+
+      await assertNotValidRoleTestCase(
+        context,
+        programFolio,
+        folioOwnerKeypair,
+        folioPDA,
+        generalIxRemoveFeeDiscount
+      );
+    });
+  });
+
+  it("should set and update the fee discount of a minter", async () => {
+    await generalIxInitOrUpdateFeeDiscount();
+
+    await travelFutureSlot(context);
+
+    assert.equal((await getScaledDiscount()).eq(D18.divn(2)), true);
+
+    await initOrUpdateFeeDiscount<true>(
+      banksClient,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      userKeypair.publicKey,
+      D18
+    );
+
+    await travelFutureSlot(context);
+
+    assert.equal((await getScaledDiscount()).eq(D18), true);
+  });
+
+  it("should fail if the fee discount is over 100%", async () => {
+    const txnResult = await initOrUpdateFeeDiscount<true>(
+      banksClient,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      userKeypair.publicKey,
+      D18.addn(1)
+    );
+
+    assertError(txnResult, "InvalidFeeDiscount");
+  });
+
+  it("should remove the fee discount of a minter", async () => {
+    await generalIxInitOrUpdateFeeDiscount();
+
+    await travelFutureSlot(context);
+
+    await generalIxRemoveFeeDiscount();
+
+    await travelFutureSlot(context);
+
+    TestHelper.assertAccountIsClosed(
+      banksClient.getAccount(getFeeDiscountPDA(folioPDA, userKeypair.publicKey))
+    );
+  });
+
+  it("should fail to remove a fee discount that doesn't exist", async () => {
+    const txnResult = await generalIxRemoveFeeDiscount();
+
+    assertError(txnResult, "AccountNotInitialized");
+  });
+
+  it("should not charge the mint fee to a minter with a full discount", async () => {
+    await initOrUpdateFeeDiscount<true>(
+      banksClient,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      userKeypair.publicKey,
+      D18
+    );
+
+    await addToPendingBasket<true>(
+      context,
+      banksClient,
+      programFolio,
+      userKeypair,
+      folioPDA,
+      [{ mint: basketMint.publicKey, amount: new BN(USER_AMOUNT).mul(D9) }]
+    );
+
+    await travelFutureSlot(context);
+
+    const shares = new BN(USER_AMOUNT).mul(D9);
+
+    await mintFolioToken<true>(
+      context,
+      banksClient,
+      programFolio,
+      userKeypair,
+      folioPDA,
+      folioTokenMint.publicKey,
+      [{ mint: basketMint.publicKey, amount: new BN(0) }],
+      shares
+    );
+
+    await travelFutureSlot(context);
+
+    assert.equal(
+      await getTokenBalance(
+        banksClient,
+        getAtaAddress(folioTokenMint.publicKey, userKeypair.publicKey)
+      ),
+      BigInt(shares.toString())
+    );
+  });
+});
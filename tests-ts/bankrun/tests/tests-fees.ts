@@ -11,6 +11,11 @@ import {
   FeeRecipient,
   createAndSetFolioFeeConfig,
   closeAccount,
+  createAndSetRewardTokens,
+  createAndSetRewardInfo,
+  buildRemainingAccountsForRewardTokensRecipient,
+  RewardInfo,
+  PauseFlag,
 } from "../bankrun-account-helper";
 import { Folio } from "../../../target/types/folio";
 import { FolioAdmin } from "../../../target/types/folio_admin";
@@ -43,6 +48,10 @@ import {
   getTVLFeeRecipientsPDA,
   getFolioPDA,
   getFolioFeeConfigPDA,
+  getRewardTokensPDA,
+  getRewardInfoPDA,
+  getGovernanceHoldingPDA,
+  getUserTokenRecordRealmsPDA,
 } from "../../../utils/pda-helper";
 import {
   crankFeeDistribution,
@@ -51,12 +60,18 @@ import {
 } from "../bankrun-ix-helper";
 import {
   assertInvalidFolioStatusTestCase,
+  assertPausedTestCase,
   GeneralTestCases,
 } from "../bankrun-general-tests-helper";
 import * as assert from "assert";
 import { TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { Clock, LiteSVM } from "litesvm";
 import { TestHelper } from "../../../utils/test-helper";
+import { Rewards } from "../../../target/types/rewards";
+import {
+  createGovernanceHoldingAccount,
+  setupGovernanceAccounts,
+} from "../bankrun-governance-helper";
 
 /**
  * Tests for fee-related functionality in the Folio program, including:
@@ -74,6 +89,7 @@ describe("Bankrun - Fees", () => {
 
   let programFolioAdmin: Program<FolioAdmin>;
   let programFolio: Program<Folio>;
+  let programRewards: Program<Rewards>;
 
   let keys: any;
 
@@ -92,6 +108,10 @@ describe("Bankrun - Fees", () => {
   const feeRecipient: Keypair = Keypair.generate();
   const cranker: Keypair = Keypair.generate();
 
+  // Reward tokens account of a realm, owned by the Rewards program
+  const rewardsRealm: PublicKey = Keypair.generate().publicKey;
+  const rewardTokensFeeRecipient: PublicKey = getRewardTokensPDA(rewardsRealm);
+
   let userKeypair: Keypair;
 
   const DEFAULT_PARAMS: {
@@ -450,6 +470,52 @@ describe("Bankrun - Fees", () => {
       expectedFeeDistributed: [new BN(4_000_000_000), new BN(4_000_000_000)],
       shouldCloseAccount: true,
     },
+    {
+      desc: "(reward tokens fee recipient without the accounts to accrue the rewards, still distributes)",
+      expectedError: null,
+      amountToDistribute: new BN(8_000_000_000).mul(D9),
+      feeRecipients: [
+        {
+          recipient: rewardTokensFeeRecipient,
+          portion: TOTAL_PORTION_FEE_RECIPIENT,
+        },
+      ],
+      feeRecipientNotClaiming: [],
+      feeRecipientsToDistributeTo: [
+        {
+          recipient: rewardTokensFeeRecipient,
+          portion: TOTAL_PORTION_FEE_RECIPIENT,
+        },
+      ],
+      expectedFeeDistributed: [new BN(8_000_000_000)],
+      shouldCloseAccount: true,
+    },
+    {
+      desc: "(reward tokens fee recipient whose realm doesn't have the folio token as reward token, still distributes)",
+      expectedError: null,
+      amountToDistribute: new BN(8_000_000_000).mul(D9),
+      feeRecipients: [
+        {
+          recipient: rewardTokensFeeRecipient,
+          portion: TOTAL_PORTION_FEE_RECIPIENT,
+        },
+      ],
+      feeRecipientNotClaiming: [],
+      feeRecipientsToDistributeTo: [
+        {
+          recipient: rewardTokensFeeRecipient,
+          portion: TOTAL_PORTION_FEE_RECIPIENT,
+        },
+      ],
+      remainingAccounts: () =>
+        buildRemainingAccountsForRewardTokensRecipient(
+          folioTokenMint.publicKey,
+          rewardsRealm,
+          Keypair.generate().publicKey
+        ),
+      expectedFeeDistributed: [new BN(8_000_000_000)],
+      shouldCloseAccount: true,
+    },
   ];
 
   async function setFeeRegistry(customFolioFeeConfig: boolean) {
@@ -558,8 +624,14 @@ describe("Bankrun - Fees", () => {
   }
 
   beforeEach(async () => {
-    ({ keys, programFolioAdmin, programFolio, provider, context } =
-      await getConnectors());
+    ({
+      keys,
+      programFolioAdmin,
+      programFolio,
+      programRewards,
+      provider,
+      context,
+    } = await getConnectors());
 
     banksClient = context;
 
@@ -644,6 +716,15 @@ describe("Bankrun - Fees", () => {
     });
 
     describe("should run general tests for distribute fees", () => {
+      it(`should run ${GeneralTestCases.Paused} for FEE DISTRIBUTION`, async () => {
+        await assertPausedTestCase(
+          context,
+          folioPDA,
+          generalIxDistributeFees,
+          PauseFlag.FeeDistribution
+        );
+      });
+
       it(`should run ${GeneralTestCases.InvalidFolioStatus} for INITIALIZING & MIGRATING`, async () => {
         await assertInvalidFolioStatusTestCase(
           context,
@@ -676,6 +757,15 @@ describe("Bankrun - Fees", () => {
         );
       });
 
+      it(`should run ${GeneralTestCases.Paused} for FEE DISTRIBUTION`, async () => {
+        await assertPausedTestCase(
+          context,
+          folioPDA,
+          generalIxCrankFeeDistribution,
+          PauseFlag.FeeDistribution
+        );
+      });
+
       it(`should run ${GeneralTestCases.InvalidFolioStatus} for INITIALIZING & MIGRATING`, async () => {
         await assertInvalidFolioStatusTestCase(
           context,
@@ -987,6 +1077,7 @@ describe("Bankrun - Fees", () => {
             feeRecipientsToDistributeTo,
             shouldCloseAccount,
             useToken2022ForFolioTokenMint,
+            remainingAccounts,
           } = {
             ...DEFAULT_PARAMS,
             ...restOfParams,
@@ -1013,6 +1104,15 @@ describe("Bankrun - Fees", () => {
 
             currentClock = await context.getClock();
 
+            await createAndSetRewardTokens(
+              context,
+              programRewards,
+              rewardsRealm,
+              adminKeypair.publicKey,
+              new BN(8_022_536_812_037),
+              [folioTokenMint.publicKey]
+            );
+
             // Get the ATAs for the fee recipients we want to send to the instruction
             for (const feeRecipient of feeRecipientsToDistributeTo) {
               const feeRecipientATA = await getOrCreateAtaAddress(
@@ -1092,7 +1192,7 @@ describe("Bankrun - Fees", () => {
                 feeRecipientsATA,
 
                 true,
-                [],
+                remainingAccounts(),
                 useToken2022ForFolioTokenMint
                   ? TOKEN_2022_PROGRAM_ID
                   : TOKEN_PROGRAM_ID
@@ -1186,4 +1286,183 @@ describe("Bankrun - Fees", () => {
       }
     );
   });
+
+  describe("Specific Cases - Crank fee distribution to a reward tokens fee recipient", () => {
+    const GOVERNANCE_MINT = Keypair.generate();
+    const FEE_DISTRIBUTION_INDEX = new BN(1);
+    const AMOUNT_TO_DISTRIBUTE = new BN(8_000_000_000).mul(D9);
+
+    let realmPDA: PublicKey;
+    let rewardTokensFeeRecipientATA: PublicKey;
+
+    const crankIx = (remainingAccounts: AccountMeta[]) =>
+      crankFeeDistribution<true>(
+        banksClient,
+        programFolio,
+        userKeypair,
+        folioPDA,
+        folioTokenMint.publicKey,
+        cranker.publicKey,
+        FEE_DISTRIBUTION_INDEX,
+        [new BN(0)],
+        [],
+        true,
+        remainingAccounts
+      );
+
+    beforeEach(async () => {
+      await initBaseCase(
+        null,
+        new BN(1000_000_000_000),
+        undefined,
+        AMOUNT_TO_DISTRIBUTE
+      );
+
+      ({ realmPDA } = await setupGovernanceAccounts(
+        context,
+        adminKeypair,
+        GOVERNANCE_MINT.publicKey
+      ));
+
+      createGovernanceHoldingAccount(
+        context,
+        // We don't care about who owns it
+        adminKeypair.publicKey,
+        GOVERNANCE_MINT.publicKey,
+        getGovernanceHoldingPDA(realmPDA, GOVERNANCE_MINT.publicKey),
+        new BN(1000).mul(D9)
+      );
+
+      await createAndSetRewardTokens(
+        context,
+        programRewards,
+        realmPDA,
+        adminKeypair.publicKey,
+        new BN(8_022_536_812_037),
+        [folioTokenMint.publicKey]
+      );
+
+      const currentTime = new BN(
+        (await context.getClock()).unixTimestamp.toString()
+      );
+
+      await createAndSetRewardInfo(
+        context,
+        programRewards,
+        realmPDA,
+        new RewardInfo(
+          folioTokenMint.publicKey,
+          currentTime.sub(new BN(10)),
+          new BN(0),
+          new BN(0),
+          new BN(0),
+          new BN(0),
+          false
+        )
+      );
+
+      rewardTokensFeeRecipientATA = await getOrCreateAtaAddress(
+        context,
+        folioTokenMint.publicKey,
+        getRewardTokensPDA(realmPDA)
+      );
+
+      await createAndSetFolio(
+        context,
+        programFolio,
+        folioTokenMint.publicKey,
+        undefined,
+        undefined,
+        currentTime,
+        new BN(0),
+        new BN(0),
+        false,
+        undefined,
+        AMOUNT_TO_DISTRIBUTE
+      );
+
+      await createAndSetFeeDistribution(
+        context,
+        programFolio,
+        folioPDA,
+        cranker.publicKey,
+        FEE_DISTRIBUTION_INDEX,
+        AMOUNT_TO_DISTRIBUTE,
+        [
+          {
+            recipient: getRewardTokensPDA(realmPDA),
+            portion: TOTAL_PORTION_FEE_RECIPIENT,
+          },
+        ]
+      );
+
+      await travelFutureSlot(context);
+    });
+
+    it("should accrue the rewards with a cranker that has no token owner record in the realm", async () => {
+      // The cranker never deposited in the realm
+      assert.equal(
+        banksClient.getAccount(
+          getUserTokenRecordRealmsPDA(
+            realmPDA,
+            GOVERNANCE_MINT.publicKey,
+            userKeypair.publicKey
+          )
+        ),
+        null
+      );
+
+      await crankIx(
+        buildRemainingAccountsForRewardTokensRecipient(
+          folioTokenMint.publicKey,
+          realmPDA,
+          GOVERNANCE_MINT.publicKey
+        )
+      );
+
+      await travelFutureSlot(context);
+
+      TestHelper.assertAccountIsClosed(
+        banksClient.getAccount(
+          getFeeDistributionPDA(folioPDA, FEE_DISTRIBUTION_INDEX)
+        )
+      );
+
+      assert.equal(
+        await getTokenBalance(banksClient, rewardTokensFeeRecipientATA),
+        BigInt(8_000_000_000)
+      );
+
+      // The distributed fees are now known by the reward info
+      const rewardInfo = await programRewards.account.rewardInfo.fetch(
+        getRewardInfoPDA(realmPDA, folioTokenMint.publicKey)
+      );
+      assert.equal(rewardInfo.balanceLastKnown.eq(AMOUNT_TO_DISTRIBUTE), true);
+    });
+
+    it("should fail if the reward info isn't writable", async () => {
+      const remainingAccounts = buildRemainingAccountsForRewardTokensRecipient(
+        folioTokenMint.publicKey,
+        realmPDA,
+        GOVERNANCE_MINT.publicKey
+      );
+      remainingAccounts[remainingAccounts.length - 1].isWritable = false;
+
+      const txnResult = await crankIx(remainingAccounts);
+
+      assertError(txnResult, "InvalidRewardInfo");
+    });
+
+    it("should fail if the accounts to accrue the rewards are incomplete", async () => {
+      const txnResult = await crankIx(
+        buildRemainingAccountsForRewardTokensRecipient(
+          folioTokenMint.publicKey,
+          realmPDA,
+          GOVERNANCE_MINT.publicKey
+        ).slice(0, 4)
+      );
+
+      assertError(txnResult, "MissingRemainingAccount");
+    });
+  });
 });
@@ -0,0 +1,318 @@
+import { BN, Program, Provider } from "@coral-xyz/anchor";
+import { Keypair, PublicKey, TransactionInstruction } from "@solana/web3.js";
+import {
+  AuthorityType,
+  createApproveInstruction,
+  createFreezeAccountInstruction,
+  createSetAuthorityInstruction,
+  createTransferInstruction,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import {
+  airdrop,
+  assertError,
+  getConnectors,
+  travelFutureSlot,
+} from "../bankrun-program-helper";
+
+import { getFolioPDA } from "../../../utils/pda-helper";
+import {
+  executeFolioAction,
+  updateFolioActionRegistrar,
+} from "../bankrun-ix-helper";
+import {
+  createAndSetActor,
+  createAndSetFolio,
+  Role,
+} from "../bankrun-account-helper";
+import { Folio } from "../../../target/types/folio";
+import { FolioAdmin } from "../../../target/types/folio_admin";
+import { TransferHookMock } from "../../../target/types/transfer_hook_mock";
+import { DEFAULT_DECIMALS } from "../../../utils/constants";
+import {
+  getAtaAddress,
+  getTokenBalance,
+  initToken,
+  mintToken,
+} from "../bankrun-token-helper";
+import { LiteSVM } from "litesvm";
+import * as assert from "assert";
+
+/**
+ * Tests for executing actions on behalf of the folio, including:
+ * - Only programs of the folio action registrar can be called
+ * - The token, associated token and system programs can't be called directly
+ * - Neither the folio token mint nor a token account of it can be passed, so
+ *   the folio's mint and freeze authorities can't be used
+ * - The folio's token accounts can't lose tokens, get a delegate, or change
+ *   owner or close authority
+ */
+describe("Bankrun - Folio Action", () => {
+  let context: LiteSVM;
+  let provider: Provider;
+  let banksClient: LiteSVM;
+
+  let programFolioAdmin: Program<FolioAdmin>;
+  let programFolio: Program<Folio>;
+  let programTransferHookMock: Program<TransferHookMock>;
+  let keys: any;
+
+  let payerKeypair: Keypair;
+  let adminKeypair: Keypair;
+
+  let folioOwnerKeypair: Keypair;
+
+  let folioTokenMint: Keypair;
+  let basketMint: Keypair;
+
+  let folioPDA: PublicKey;
+  let folioATA: PublicKey;
+  let folioOwnerATA: PublicKey;
+
+  // Whole tokens, as minted by `mintToken`
+  const FOLIO_AMOUNT = 100;
+  const FOLIO_OWNER_AMOUNT = 10;
+
+  function toRaw(amount: number) {
+    return BigInt(amount) * BigInt(10 ** DEFAULT_DECIMALS);
+  }
+
+  // The folio signs through the CPI, so it can't be a signer of the transaction
+  const executeIx = (ix: TransactionInstruction, targetProgram: PublicKey) =>
+    executeFolioAction<true>(
+      banksClient,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      targetProgram,
+      ix.data,
+      ix.keys.map((key) =>
+        key.pubkey.equals(folioPDA) ? { ...key, isSigner: false } : key
+      )
+    );
+
+  // Routes the instruction through the registered mock program
+  const executeForwardedIx = async (ix: TransactionInstruction) =>
+    executeIx(
+      await programTransferHookMock.methods
+        .forward(ix.data)
+        .accountsPartial({ targetProgram: ix.programId })
+        .remainingAccounts(ix.keys)
+        .instruction(),
+      programTransferHookMock.programId
+    );
+
+  beforeEach(async () => {
+    ({
+      keys,
+      programFolioAdmin,
+      programFolio,
+      programTransferHookMock,
+      provider,
+      context,
+    } = await getConnectors());
+
+    banksClient = context;
+
+    payerKeypair = provider.wallet.payer;
+
+    adminKeypair = Keypair.fromSecretKey(Uint8Array.from(keys.admin));
+
+    folioOwnerKeypair = Keypair.generate();
+    folioTokenMint = Keypair.generate();
+    basketMint = Keypair.generate();
+
+    await airdrop(context, payerKeypair.publicKey, 1000);
+    await airdrop(context, adminKeypair.publicKey, 1000);
+    await airdrop(context, folioOwnerKeypair.publicKey, 1000);
+
+    folioPDA = getFolioPDA(folioTokenMint.publicKey);
+
+    await createAndSetFolio(context, programFolio, folioTokenMint.publicKey);
+
+    initToken(context, folioPDA, folioTokenMint, DEFAULT_DECIMALS);
+    initToken(context, adminKeypair.publicKey, basketMint, DEFAULT_DECIMALS);
+
+    mintToken(context, basketMint.publicKey, FOLIO_AMOUNT, folioPDA);
+    mintToken(
+      context,
+      basketMint.publicKey,
+      FOLIO_OWNER_AMOUNT,
+      folioOwnerKeypair.publicKey
+    );
+
+    folioATA = getAtaAddress(basketMint.publicKey, folioPDA);
+    folioOwnerATA = getAtaAddress(
+      basketMint.publicKey,
+      folioOwnerKeypair.publicKey
+    );
+
+    await createAndSetActor(
+      context,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      Role.Owner
+    );
+
+    await updateFolioActionRegistrar<true>(
+      banksClient,
+      programFolioAdmin,
+      adminKeypair,
+      [programTransferHookMock.programId],
+      false
+    );
+
+    await travelFutureSlot(context);
+  });
+
+  it("should execute an action that doesn't take tokens from the folio", async () => {
+    await executeForwardedIx(
+      createTransferInstruction(
+        folioOwnerATA,
+        folioATA,
+        folioOwnerKeypair.publicKey,
+        toRaw(FOLIO_OWNER_AMOUNT)
+      )
+    );
+
+    await travelFutureSlot(context);
+
+    assert.equal(
+      await getTokenBalance(banksClient, folioATA),
+      toRaw(FOLIO_AMOUNT + FOLIO_OWNER_AMOUNT)
+    );
+  });
+
+  it("should fail if the target program isn't in the folio action registrar", async () => {
+    await updateFolioActionRegistrar<true>(
+      banksClient,
+      programFolioAdmin,
+      adminKeypair,
+      [programTransferHookMock.programId],
+      true
+    );
+
+    await travelFutureSlot(context);
+
+    const txnResult = await executeForwardedIx(
+      createTransferInstruction(
+        folioOwnerATA,
+        folioATA,
+        folioOwnerKeypair.publicKey,
+        toRaw(FOLIO_OWNER_AMOUNT)
+      )
+    );
+
+    assertError(txnResult, "ProgramNotInRegistrar");
+  });
+
+  it("should fail if the target program is the token program", async () => {
+    await updateFolioActionRegistrar<true>(
+      banksClient,
+      programFolioAdmin,
+      adminKeypair,
+      [TOKEN_PROGRAM_ID],
+      false
+    );
+
+    await travelFutureSlot(context);
+
+    const txnResult = await executeIx(
+      createTransferInstruction(
+        folioOwnerATA,
+        folioATA,
+        folioOwnerKeypair.publicKey,
+        toRaw(FOLIO_OWNER_AMOUNT)
+      ),
+      TOKEN_PROGRAM_ID
+    );
+
+    assertError(txnResult, "InvalidProgram");
+  });
+
+  it("should fail to freeze the folio token account of a holder", async () => {
+    mintToken(
+      context,
+      folioTokenMint.publicKey,
+      FOLIO_OWNER_AMOUNT,
+      folioOwnerKeypair.publicKey
+    );
+
+    // The folio is the freeze authority of the folio token mint
+    const txnResult = await executeForwardedIx(
+      createFreezeAccountInstruction(
+        getAtaAddress(folioTokenMint.publicKey, folioOwnerKeypair.publicKey),
+        folioTokenMint.publicKey,
+        folioPDA
+      )
+    );
+
+    assertError(txnResult, "InvalidFolioTokenMint");
+  });
+
+  it("should fail if the folio token mint is passed", async () => {
+    const ix = createTransferInstruction(
+      folioOwnerATA,
+      folioATA,
+      folioOwnerKeypair.publicKey,
+      toRaw(FOLIO_OWNER_AMOUNT)
+    );
+    ix.keys.push({
+      pubkey: folioTokenMint.publicKey,
+      isSigner: false,
+      isWritable: true,
+    });
+
+    const txnResult = await executeForwardedIx(ix);
+
+    assertError(txnResult, "InvalidFolioTokenMint");
+  });
+
+  it("should fail if the balance of a folio token account decreases", async () => {
+    const txnResult = await executeForwardedIx(
+      createTransferInstruction(folioATA, folioOwnerATA, folioPDA, toRaw(1))
+    );
+
+    assertError(txnResult, "FolioActionInvariantViolated");
+  });
+
+  it("should fail if a delegate is set on a folio token account", async () => {
+    const txnResult = await executeForwardedIx(
+      createApproveInstruction(
+        folioATA,
+        folioOwnerKeypair.publicKey,
+        folioPDA,
+        toRaw(FOLIO_AMOUNT)
+      )
+    );
+
+    assertError(txnResult, "FolioActionInvariantViolated");
+  });
+
+  it("should fail if the owner of a folio token account changes", async () => {
+    const txnResult = await executeForwardedIx(
+      createSetAuthorityInstruction(
+        folioATA,
+        folioPDA,
+        AuthorityType.AccountOwner,
+        folioOwnerKeypair.publicKey
+      )
+    );
+
+    assertError(txnResult, "FolioActionInvariantViolated");
+  });
+
+  it("should fail if the close authority of a folio token account changes", async () => {
+    const txnResult = await executeForwardedIx(
+      createSetAuthorityInstruction(
+        folioATA,
+        folioPDA,
+        AuthorityType.CloseAccount,
+        folioOwnerKeypair.publicKey
+      )
+    );
+
+    assertError(txnResult, "FolioActionInvariantViolated");
+  });
+});
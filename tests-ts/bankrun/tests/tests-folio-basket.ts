@@ -238,7 +238,6 @@ describe("Bankrun - Folio basket", () => {
     },
 
     ...[
-      ExtensionType.TransferFeeConfig,
       ExtensionType.MintCloseAuthority,
       ExtensionType.NonTransferable,
     ].map((extension) => {
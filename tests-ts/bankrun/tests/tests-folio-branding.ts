@@ -0,0 +1,181 @@
+import { Program, Provider } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import {
+  airdrop,
+  assertError,
+  BanksTransactionResultWithMeta,
+  getConnectors,
+  travelFutureSlot,
+} from "../bankrun-program-helper";
+
+import { getFolioPDA } from "../../../utils/pda-helper";
+import { updateFolioBranding } from "../bankrun-ix-helper";
+import {
+  createAndSetFolio,
+  Role,
+  createAndSetActor,
+  createAndSetMetadataAccount,
+} from "../bankrun-account-helper";
+import { Folio } from "../../../target/types/folio";
+import { initToken } from "../bankrun-token-helper";
+import { TOKEN_METADATA_PROGRAM_ID } from "../../../utils/constants";
+import { LiteSVM } from "litesvm";
+import * as assert from "assert";
+
+/**
+ * Tests for the folio branding update, including:
+ * - Brand manager and owner can update the branding
+ * - Other roles are rejected
+ * - Legacy immutable metadata can't be rebranded, but the mandate can
+ */
+describe("Bankrun - Folio Branding", () => {
+  let context: LiteSVM;
+  let provider: Provider;
+  let banksClient: LiteSVM;
+
+  let programFolio: Program<Folio>;
+
+  let payerKeypair: Keypair;
+  let actorKeypair: Keypair;
+
+  let folioTokenMint: Keypair;
+
+  let folioPDA: PublicKey;
+
+  const NEW_MANDATE = "New mandate";
+
+  const TEST_CASES = [
+    {
+      desc: "(brand manager updates the mandate, success)",
+      role: Role.BrandManager,
+      name: null,
+      isMetadataMutable: true,
+      expectedError: null,
+    },
+    {
+      desc: "(owner updates the mandate, success)",
+      role: Role.Owner,
+      name: null,
+      isMetadataMutable: true,
+      expectedError: null,
+    },
+    {
+      desc: "(rebalance manager, invalid role)",
+      role: Role.RebalanceManager,
+      name: null,
+      isMetadataMutable: true,
+      expectedError: "InvalidRole",
+    },
+    {
+      desc: "(auction launcher, invalid role)",
+      role: Role.AuctionLauncher,
+      name: null,
+      isMetadataMutable: true,
+      expectedError: "InvalidRole",
+    },
+    {
+      desc: "(guardian, invalid role)",
+      role: Role.Guardian,
+      name: null,
+      isMetadataMutable: true,
+      expectedError: "InvalidRole",
+    },
+    {
+      desc: "(legacy immutable metadata, name can't be updated)",
+      role: Role.BrandManager,
+      name: "New name",
+      isMetadataMutable: false,
+      expectedError: "FolioMetadataImmutable",
+    },
+    {
+      desc: "(legacy immutable metadata, mandate can still be updated)",
+      role: Role.BrandManager,
+      name: null,
+      isMetadataMutable: false,
+      expectedError: null,
+    },
+  ];
+
+  beforeEach(async () => {
+    ({ programFolio, provider, context } = await getConnectors());
+
+    banksClient = context;
+
+    payerKeypair = provider.wallet.payer;
+
+    actorKeypair = Keypair.generate();
+    folioTokenMint = Keypair.generate();
+
+    await airdrop(context, payerKeypair.publicKey, 1000);
+    await airdrop(context, actorKeypair.publicKey, 1000);
+
+    folioPDA = getFolioPDA(folioTokenMint.publicKey);
+  });
+
+  describe("Specific Cases - Update Folio Branding", () => {
+    TEST_CASES.forEach(
+      ({ desc, role, name, isMetadataMutable, expectedError }) => {
+        describe(`When ${desc}`, () => {
+          let txnResult: BanksTransactionResultWithMeta;
+
+          beforeEach(async () => {
+            await createAndSetFolio(
+              context,
+              programFolio,
+              folioTokenMint.publicKey
+            );
+
+            initToken(context, folioPDA, folioTokenMint.publicKey);
+
+            await createAndSetMetadataAccount(
+              context,
+              folioPDA,
+              folioTokenMint.publicKey,
+              TOKEN_METADATA_PROGRAM_ID,
+              isMetadataMutable
+            );
+
+            await createAndSetActor(
+              context,
+              programFolio,
+              actorKeypair,
+              folioPDA,
+              role
+            );
+
+            await travelFutureSlot(context);
+
+            txnResult = await updateFolioBranding<true>(
+              banksClient,
+              programFolio,
+              actorKeypair,
+              folioPDA,
+              folioTokenMint.publicKey,
+              name,
+              null,
+              null,
+              NEW_MANDATE
+            );
+          });
+
+          if (expectedError) {
+            it("should fail with expected error", () => {
+              assertError(txnResult, expectedError);
+            });
+          } else {
+            it("should succeed", async () => {
+              await travelFutureSlot(context);
+
+              const folio = await programFolio.account.folio.fetch(folioPDA);
+
+              assert.equal(
+                Buffer.from(folio.mandate.value).toString().replace(/\0/g, ""),
+                NEW_MANDATE
+              );
+            });
+          }
+        });
+      }
+    );
+  });
+});
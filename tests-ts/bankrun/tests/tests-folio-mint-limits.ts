@@ -0,0 +1,293 @@
+import { BN, Program, Provider } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import {
+  airdrop,
+  assertError,
+  getConnectors,
+  travelFutureSlot,
+} from "../bankrun-program-helper";
+
+import {
+  getFolioFeeConfigPDA,
+  getFolioMintLimitsPDA,
+  getFolioPDA,
+  getUserPendingBasketPDA,
+} from "../../../utils/pda-helper";
+import {
+  addToPendingBasket,
+  mintFolioToken,
+  updateFolio,
+} from "../bankrun-ix-helper";
+import {
+  closeAccount,
+  createAndSetActor,
+  createAndSetDaoFeeConfig,
+  createAndSetFolio,
+  createAndSetFolioBasket,
+  FolioStatus,
+  FolioTokenAmount,
+  Role,
+} from "../bankrun-account-helper";
+import { Folio } from "../../../target/types/folio";
+import { FolioAdmin } from "../../../target/types/folio_admin";
+import { D9, DEFAULT_DECIMALS } from "../../../utils/constants";
+import {
+  getAtaAddress,
+  getTokenBalance,
+  initToken,
+  mintToken,
+} from "../bankrun-token-helper";
+import { LiteSVM } from "litesvm";
+import * as assert from "assert";
+
+/**
+ * Tests for the optional mint limits of a folio, including:
+ * - The folio mint limits account is only required when setting the mint limits
+ * - Removing every limit disables them on the folio
+ * - The account is only required to mint when the folio has mint limits enabled
+ */
+describe("Bankrun - Folio Mint Limits", () => {
+  let context: LiteSVM;
+  let provider: Provider;
+  let banksClient: LiteSVM;
+
+  let programFolioAdmin: Program<FolioAdmin>;
+  let programFolio: Program<Folio>;
+  let keys: any;
+
+  let payerKeypair: Keypair;
+  let adminKeypair: Keypair;
+
+  let folioOwnerKeypair: Keypair;
+  let userKeypair: Keypair;
+
+  let folioTokenMint: Keypair;
+  let basketMint: Keypair;
+
+  let folioPDA: PublicKey;
+
+  const feeRecipient: PublicKey = Keypair.generate().publicKey;
+
+  // Whole tokens, 1 basket token per folio token
+  const FOLIO_TOKEN_SUPPLY = 1_000;
+  const BASKET_AMOUNT = 1_000;
+  const USER_AMOUNT = 100;
+
+  const SHARES = new BN(USER_AMOUNT).mul(D9);
+
+  const setMintLimits = (
+    maxSupply: BN | null,
+    maxMintPerTx: BN | null,
+    useFolioMintLimits: boolean = true
+  ) =>
+    updateFolio<true>(
+      context,
+      banksClient,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      folioTokenMint.publicKey,
+      feeRecipient,
+      null,
+      null,
+      null,
+      null,
+      [],
+      [],
+      null,
+      true,
+      null,
+      maxSupply,
+      maxMintPerTx,
+      null,
+      useFolioMintLimits
+    );
+
+  const mintShares = (useFolioMintLimits: boolean) =>
+    mintFolioToken<true>(
+      context,
+      banksClient,
+      programFolio,
+      userKeypair,
+      folioPDA,
+      folioTokenMint.publicKey,
+      [{ mint: basketMint.publicKey, amount: new BN(0) }],
+      SHARES,
+      true,
+      null,
+      useFolioMintLimits
+    );
+
+  async function getMintLimitsEnabled() {
+    const folio = await programFolio.account.folio.fetch(folioPDA);
+
+    return folio.mintLimitsEnabled;
+  }
+
+  beforeEach(async () => {
+    ({ keys, programFolioAdmin, programFolio, provider, context } =
+      await getConnectors());
+
+    banksClient = context;
+
+    payerKeypair = provider.wallet.payer;
+
+    adminKeypair = Keypair.fromSecretKey(Uint8Array.from(keys.admin));
+
+    folioOwnerKeypair = Keypair.generate();
+    userKeypair = Keypair.generate();
+    folioTokenMint = Keypair.generate();
+    basketMint = Keypair.generate();
+
+    await airdrop(context, payerKeypair.publicKey, 1000);
+    await airdrop(context, adminKeypair.publicKey, 1000);
+    await airdrop(context, folioOwnerKeypair.publicKey, 1000);
+    await airdrop(context, userKeypair.publicKey, 1000);
+
+    folioPDA = getFolioPDA(folioTokenMint.publicKey);
+
+    // No fees, so the minted shares are exact
+    await createAndSetDaoFeeConfig(
+      context,
+      programFolioAdmin,
+      feeRecipient,
+      new BN(0),
+      new BN(0)
+    );
+    await closeAccount(context, getFolioFeeConfigPDA(folioPDA));
+    await closeAccount(context, getFolioMintLimitsPDA(folioPDA));
+
+    await createAndSetFolio(
+      context,
+      programFolio,
+      folioTokenMint.publicKey,
+      FolioStatus.Initialized,
+      new BN(0)
+    );
+
+    initToken(
+      context,
+      folioPDA,
+      folioTokenMint,
+      DEFAULT_DECIMALS,
+      new BN(FOLIO_TOKEN_SUPPLY).mul(D9)
+    );
+    initToken(context, adminKeypair.publicKey, basketMint, DEFAULT_DECIMALS);
+
+    mintToken(context, basketMint.publicKey, BASKET_AMOUNT, folioPDA);
+    mintToken(
+      context,
+      basketMint.publicKey,
+      USER_AMOUNT,
+      userKeypair.publicKey
+    );
+
+    await createAndSetFolioBasket(context, programFolio, folioPDA, [
+      new FolioTokenAmount(
+        basketMint.publicKey,
+        new BN(BASKET_AMOUNT).mul(D9)
+      ),
+    ]);
+
+    await createAndSetActor(
+      context,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      Role.Owner
+    );
+
+    await closeAccount(
+      context,
+      getUserPendingBasketPDA(folioPDA, userKeypair.publicKey)
+    );
+
+    await addToPendingBasket<true>(
+      context,
+      banksClient,
+      programFolio,
+      userKeypair,
+      folioPDA,
+      [{ mint: basketMint.publicKey, amount: SHARES }]
+    );
+
+    await travelFutureSlot(context);
+  });
+
+  it("should set the mint limits and enable them on the folio", async () => {
+    await setMintLimits(new BN(2_000).mul(D9), null);
+
+    await travelFutureSlot(context);
+
+    const folioMintLimits = await programFolio.account.folioMintLimits.fetch(
+      getFolioMintLimitsPDA(folioPDA)
+    );
+    assert.equal(folioMintLimits.maxSupply.eq(new BN(2_000).mul(D9)), true);
+    assert.equal(folioMintLimits.maxMintPerTx.isZero(), true);
+    assert.equal(await getMintLimitsEnabled(), 1);
+  });
+
+  it("should fail to set the mint limits without the folio mint limits account", async () => {
+    const txnResult = await setMintLimits(new BN(2_000).mul(D9), null, false);
+
+    assertError(txnResult, "MissingFolioMintLimits");
+  });
+
+  it("should disable the mint limits once every limit is removed", async () => {
+    await setMintLimits(new BN(2_000).mul(D9), new BN(10).mul(D9));
+
+    await travelFutureSlot(context);
+
+    await setMintLimits(new BN(0), new BN(0));
+
+    await travelFutureSlot(context);
+
+    assert.equal(await getMintLimitsEnabled(), 0);
+
+    await mintShares(false);
+
+    await travelFutureSlot(context);
+
+    assert.equal(
+      await getTokenBalance(
+        banksClient,
+        getAtaAddress(folioTokenMint.publicKey, userKeypair.publicKey)
+      ),
+      BigInt(SHARES.toString())
+    );
+  });
+
+  it("should mint without the folio mint limits account when there are no limits", async () => {
+    await mintShares(false);
+
+    await travelFutureSlot(context);
+
+    assert.equal(
+      await getTokenBalance(
+        banksClient,
+        getAtaAddress(folioTokenMint.publicKey, userKeypair.publicKey)
+      ),
+      BigInt(SHARES.toString())
+    );
+  });
+
+  it("should fail to mint without the folio mint limits account when there are limits", async () => {
+    await setMintLimits(null, new BN(10).mul(D9));
+
+    await travelFutureSlot(context);
+
+    const txnResult = await mintShares(false);
+
+    assertError(txnResult, "MissingFolioMintLimits");
+  });
+
+  it("should enforce the mint limits with the folio mint limits account", async () => {
+    await setMintLimits(null, new BN(10).mul(D9));
+
+    await travelFutureSlot(context);
+
+    const txnResult = await mintShares(true);
+
+    assertError(txnResult, "MaxMintPerTransactionExceeded");
+  });
+});
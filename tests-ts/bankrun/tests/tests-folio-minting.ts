@@ -13,6 +13,7 @@ import {
   getInvalidRemainingAccounts,
   createAndSetFolioFeeConfig,
   FolioTokenAmount,
+  PauseFlag,
 } from "../bankrun-account-helper";
 import {
   assertExpectedBalancesChanges,
@@ -52,6 +53,7 @@ import {
 } from "../bankrun-ix-helper";
 import {
   assertInvalidFolioStatusTestCase,
+  assertPausedTestCase,
   GeneralTestCases,
 } from "../bankrun-general-tests-helper";
 import * as assert from "assert";
@@ -706,6 +708,15 @@ describe("Bankrun - Folio Minting", () => {
         );
       });
 
+      it(`should run ${GeneralTestCases.Paused} for MINT`, async () => {
+        await assertPausedTestCase(
+          context,
+          folioPDA,
+          generalIxMintFolioToken,
+          PauseFlag.Mint
+        );
+      });
+
       it(`should run ${GeneralTestCases.InvalidFolioStatus} for both KILLED and INITIALIZING and MIGRATING`, async () => {
         await assertInvalidFolioStatusTestCase(
           context,
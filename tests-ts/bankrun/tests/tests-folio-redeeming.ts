@@ -13,6 +13,7 @@ import {
   createAndSetFolioFeeConfig,
   closeAccount,
   FolioTokenAmount,
+  PauseFlag,
 } from "../bankrun-account-helper";
 import {
   assertExpectedBalancesChanges,
@@ -51,6 +52,7 @@ import {
 } from "../bankrun-ix-helper";
 import {
   assertInvalidFolioStatusTestCase,
+  assertPausedTestCase,
   GeneralTestCases,
 } from "../bankrun-general-tests-helper";
 import * as assert from "assert";
@@ -514,6 +516,15 @@ describe("Bankrun - Folio redeeming", () => {
     });
 
     describe("should run general tests for burn folio token", () => {
+      it(`should run ${GeneralTestCases.Paused} for REDEEM`, async () => {
+        await assertPausedTestCase(
+          context,
+          folioPDA,
+          generalIxBurnFolioToken,
+          PauseFlag.Redeem
+        );
+      });
+
       it(`should run ${GeneralTestCases.InvalidFolioStatus} for INITIALIZING & MIGRATING`, async () => {
         await assertInvalidFolioStatusTestCase(
           context,
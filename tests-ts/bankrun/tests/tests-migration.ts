@@ -1,16 +1,33 @@
 import { BN, Program, Provider } from "@coral-xyz/anchor";
 import { AccountMeta, Keypair, PublicKey } from "@solana/web3.js";
+import { ExtensionType, TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
 
 import {
   airdrop,
   assertError,
   BanksTransactionResultWithMeta,
   getConnectors,
+  setClock,
   travelFutureSlot,
 } from "../bankrun-program-helper";
 
-import { getFolioBasketPDA, getFolioPDA } from "../../../utils/pda-helper";
-import { migrateFolioTokens, startFolioMigration } from "../bankrun-ix-helper";
+import {
+  getFeeDistributionPDA,
+  getFolioBasketPDA,
+  getFolioMintLimitsPDA,
+  getFolioPDA,
+  getMigrationRecordPDA,
+  getUserPendingBasketPDA,
+} from "../../../utils/pda-helper";
+import {
+  abortFolioMigration,
+  initializeTransferHookExtraAccountMetaList,
+  migrateFeeDistribution,
+  migrateFolioTokens,
+  migrateUserPendingBasket,
+  startFolioMigration,
+  updateFolio,
+} from "../bankrun-ix-helper";
 import {
   createAndSetFolio,
   Role,
@@ -23,6 +40,13 @@ import {
   createAndSetFeeDistribution,
   FolioTokenAmount,
   createAndSetMetadataAccount,
+  createAndSetMigrationRecord,
+  createAndSetFolioLegacyBasket,
+  createAndSetUserPendingBasket,
+  TokenAmount,
+  buildRemainingAccountsForMigrateFolioTokens,
+  buildRemainingAccountsForTransferHook,
+  getTransferCounterPDA,
 } from "../bankrun-account-helper";
 import { Folio } from "../../../target/types/folio";
 import {
@@ -39,21 +63,28 @@ import {
   DEFAULT_DECIMALS,
   FOLIO_PROGRAM_ID,
   MAX_MINT_FEE,
+  MIGRATION_ABORT_GRACE_PERIOD,
   TOTAL_PORTION_FEE_RECIPIENT,
 } from "../../../utils/constants";
 import {
   assertExpectedBalancesChanges,
+  getAtaAddress,
   getMintAuthorities,
   getOrCreateAtaAddress,
+  getTokenBalance,
   getTokenBalancesFromMints,
   initToken,
+  initToken2022Tx,
   mintToken,
+  mintToken2022Tx,
 } from "../bankrun-token-helper";
 import { FolioAdmin } from "../../../target/types/folio_admin";
+import { TransferHookMock } from "../../../target/types/transfer_hook_mock";
 import { Folio as FolioSecond } from "../../../target/types/second_folio";
 import { LiteSVM } from "litesvm";
 import path from "path";
 import { readFile } from "fs/promises";
+import { TestHelper } from "../../../utils/test-helper";
 
 /**
  * Tests for folio migration functionality, including:
@@ -62,6 +93,11 @@ import { readFile } from "fs/promises";
  * - Program validation
  * - Token transfer validation
  * - Account state updates during migration
+ * - Migrating user pending baskets and fee distributions once the abort grace
+ *   period has passed
+ * - Migrating basket tokens with an active transfer hook
+ * - Migrating basket tokens with a transfer fee, only the amount received is
+ *   credited to the new folio
  */
 
 describe("Bankrun - Folio migration", () => {
@@ -72,6 +108,7 @@ describe("Bankrun - Folio migration", () => {
   let programFolio: Program<Folio>;
   let programFolioSecond: Program<FolioSecond>;
   let programFolioAdmin: Program<FolioAdmin>;
+  let programTransferHookMock: Program<TransferHookMock>;
   let keys: any;
 
   let payerKeypair: Keypair;
@@ -103,6 +140,9 @@ describe("Bankrun - Folio migration", () => {
 
     initialFolioBasket: FolioTokenAmount[];
 
+    // Null when the folio never had a legacy basket
+    legacyTokenAmounts: FolioTokenAmount[] | null;
+
     isMigrating: boolean;
 
     includeSecondProgramInRegistrar: boolean;
@@ -112,7 +152,6 @@ describe("Bankrun - Folio migration", () => {
     // Expected changes
     expectedTokenBalanceChanges: BN[];
 
-    maxAllowedPendingFees: BN;
     folioConfig: {
       lastPoke: BN | null;
       daoPendingFeeShares: BN | null;
@@ -130,6 +169,8 @@ describe("Bankrun - Folio migration", () => {
 
     initialFolioBasket: [],
 
+    legacyTokenAmounts: null,
+
     isMigrating: false,
 
     includeSecondProgramInRegistrar: true,
@@ -139,8 +180,6 @@ describe("Bankrun - Folio migration", () => {
     // Expected changes
     expectedTokenBalanceChanges: Array(MINTS.length).fill(new BN(0)),
 
-    maxAllowedPendingFees: new BN(D9),
-
     folioConfig: null,
   };
 
@@ -161,10 +200,32 @@ describe("Bankrun - Folio migration", () => {
       secondFolioOwner: FOLIO_PROGRAM_ID,
       newFolioProgram: FOLIO_PROGRAM_ID,
     },
+    {
+      desc: "(legacy basket still holds tokens, errors out)",
+      expectedError: "LegacyBasketNotEmpty",
+      legacyTokenAmounts: [
+        new FolioTokenAmount(MINTS[0].publicKey, new BN(1_000_000)),
+      ],
+    },
     {
       desc: "(is valid, succeeds)",
       expectedError: null,
     },
+    {
+      desc: "(legacy basket fully redeemed, succeeds)",
+      expectedError: null,
+      legacyTokenAmounts: [],
+    },
+    {
+      desc: "(has pending fee shares, carries them over, succeeds)",
+      expectedError: null,
+      folioConfig: {
+        lastPoke: null,
+        daoPendingFeeShares: new BN(1000).mul(D9),
+        feeRecipientsPendingFeeShares: new BN(2000).mul(D9),
+        feeRecipientsPendingFeeSharesToBeMinted: new BN(3000).mul(D9),
+      },
+    },
   ];
 
   const TEST_CASES_MIGRATE_FOLIO_TOKENS = [
@@ -184,12 +245,12 @@ describe("Bankrun - Folio migration", () => {
       secondFolioOwner: Keypair.generate().publicKey,
     },
     {
-      desc: "(invalid number of remaining accounts, errors out)",
-      expectedError: "InvalidNumberOfRemainingAccounts",
+      desc: "(missing remaining accounts, errors out)",
+      expectedError: "MissingRemainingAccount",
       remainingAccounts: async () => [
         {
-          pubkey: Keypair.generate().publicKey,
-          isWritable: true,
+          pubkey: MINTS[0].publicKey,
+          isWritable: false,
           isSigner: false,
         },
       ],
@@ -341,6 +402,18 @@ describe("Bankrun - Folio migration", () => {
       folioTokenMint.publicKey
     );
 
+    if (isMigrating) {
+      // The abort grace period of the migration has already passed
+      await createAndSetMigrationRecord(
+        context,
+        programFolio,
+        oldFolioPDA,
+        newFolioPDA,
+        FolioStatus.Initialized,
+        new BN(0)
+      );
+    }
+
     if (isBaseCaseForMigrateFolioTokens) {
       // Folio in second program
       await createAndSetFolio(
@@ -433,6 +506,7 @@ describe("Bankrun - Folio migration", () => {
       programFolio,
       programFolioSecond,
       programFolioAdmin,
+      programTransferHookMock,
       provider,
       context,
     } = await getConnectors());
@@ -469,7 +543,6 @@ describe("Bankrun - Folio migration", () => {
         oldFolioPDA,
         newFolioPDA,
         programFolioSecond.programId,
-        new BN(1),
         true
       );
 
@@ -563,8 +636,8 @@ describe("Bankrun - Folio migration", () => {
           const {
             newFolioProgram,
             customFolioTokenMint,
-            maxAllowedPendingFees,
             folioConfig,
+            legacyTokenAmounts,
           } = {
             ...DEFAULT_PARAMS,
             ...restOfParams,
@@ -587,6 +660,16 @@ describe("Bankrun - Folio migration", () => {
             }
 
             await initBaseCase(false, folioConfigToUse, customFolioTokenMint);
+
+            if (legacyTokenAmounts !== null) {
+              await createAndSetFolioLegacyBasket(
+                context,
+                programFolio,
+                oldFolioPDA,
+                legacyTokenAmounts
+              );
+            }
+
             if (
               newFolioProgram &&
               !newFolioProgram.equals(programFolioSecond.programId) &&
@@ -622,7 +705,6 @@ describe("Bankrun - Folio migration", () => {
               oldFolioPDA,
               newFolioPDA,
               newFolioProgram || programFolioSecond.programId,
-              maxAllowedPendingFees,
               true
             );
           });
@@ -653,6 +735,43 @@ describe("Bankrun - Folio migration", () => {
                 mintAuthoritiesAfter.freezeAuthority,
                 newFolioPDA
               );
+
+              const oldFolio = await programFolio.account.folio.fetch(
+                oldFolioPDA
+              );
+              const newFolio = await programFolioSecond.account.folio.fetch(
+                newFolioPDA
+              );
+
+              assert.equal(
+                newFolio.daoPendingFeeShares.toString(),
+                (folioConfig?.daoPendingFeeShares ?? new BN(0)).toString()
+              );
+              assert.equal(
+                newFolio.feeRecipientsPendingFeeShares.toString(),
+                (
+                  folioConfig?.feeRecipientsPendingFeeShares ?? new BN(0)
+                ).toString()
+              );
+              assert.equal(
+                newFolio.feeRecipientsPendingFeeSharesToBeMinted.toString(),
+                (
+                  folioConfig?.feeRecipientsPendingFeeSharesToBeMinted ??
+                  new BN(0)
+                ).toString()
+              );
+              assert.equal(oldFolio.status, FolioStatus.Migrating);
+
+              const migrationRecord =
+                await programFolio.account.migrationRecord.fetch(
+                  getMigrationRecordPDA(oldFolioPDA)
+                );
+              assert.deepEqual(migrationRecord.folio, oldFolioPDA);
+              assert.deepEqual(migrationRecord.newFolio, newFolioPDA);
+              assert.equal(
+                migrationRecord.previousStatus,
+                FolioStatus.Initialized
+              );
             });
           }
         });
@@ -818,4 +937,754 @@ describe("Bankrun - Folio migration", () => {
       }
     );
   });
+
+  describe("Specific Cases - Abort Folio Migration", () => {
+    let startedAt: bigint;
+
+    beforeEach(async () => {
+      const currentTime = BigInt(
+        (await context.getClock()).unixTimestamp.toString()
+      );
+      const endOfDay =
+        (currentTime / BigInt(DAY_IN_SECONDS)) * BigInt(DAY_IN_SECONDS);
+
+      await initBaseCase(false, {
+        lastPoke: new BN(endOfDay.toString()),
+        daoPendingFeeShares: null,
+        feeRecipientsPendingFeeShares: null,
+        feeRecipientsPendingFeeSharesToBeMinted: null,
+      });
+
+      await travelFutureSlot(context);
+
+      await startFolioMigration<true>(
+        context,
+        banksClient,
+        programFolio,
+        folioOwnerKeypair,
+        folioTokenMint.publicKey,
+        oldFolioPDA,
+        newFolioPDA,
+        programFolioSecond.programId,
+        true
+      );
+
+      startedAt = BigInt((await context.getClock()).unixTimestamp.toString());
+    });
+
+    it("should abort the migration during the grace period", async () => {
+      await travelFutureSlot(context);
+
+      await abortFolioMigration<true>(
+        banksClient,
+        programFolio,
+        folioOwnerKeypair,
+        folioTokenMint.publicKey,
+        oldFolioPDA,
+        newFolioPDA,
+        programFolioSecond.programId,
+        true
+      );
+
+      const mintAuthoritiesAfter = await getMintAuthorities(
+        banksClient,
+        folioTokenMint.publicKey
+      );
+      assert.deepEqual(mintAuthoritiesAfter.mintAuthority, oldFolioPDA);
+      assert.deepEqual(mintAuthoritiesAfter.freezeAuthority, oldFolioPDA);
+
+      const oldFolio = await programFolio.account.folio.fetch(oldFolioPDA);
+      assert.equal(oldFolio.status, FolioStatus.Initialized);
+
+      TestHelper.assertAccountIsClosed(
+        banksClient.getAccount(getMigrationRecordPDA(oldFolioPDA))
+      );
+      TestHelper.assertAccountIsClosed(banksClient.getAccount(newFolioPDA));
+    });
+
+    it("should fail to abort after the grace period", async () => {
+      setClock(
+        context,
+        Number(startedAt) + MIGRATION_ABORT_GRACE_PERIOD.toNumber()
+      );
+
+      const txnResult = await abortFolioMigration<true>(
+        banksClient,
+        programFolio,
+        folioOwnerKeypair,
+        folioTokenMint.publicKey,
+        oldFolioPDA,
+        newFolioPDA,
+        programFolioSecond.programId,
+        true
+      );
+      assertError(txnResult, "MigrationAbortGracePeriodPassed");
+    });
+  });
+
+  describe("Specific Cases - Start Folio Migration With Mint Limits", () => {
+    const MAX_SUPPLY = new BN(2_000).mul(D9);
+    const MAX_MINT_PER_TX = new BN(10).mul(D9);
+
+    const setMintLimits = (maxSupply: BN, maxMintPerTx: BN) =>
+      updateFolio<true>(
+        context,
+        banksClient,
+        programFolio,
+        folioOwnerKeypair,
+        oldFolioPDA,
+        folioTokenMint.publicKey,
+        feeRecipient,
+        null,
+        null,
+        null,
+        null,
+        [],
+        [],
+        null,
+        true,
+        null,
+        maxSupply,
+        maxMintPerTx
+      );
+
+    const startMigration = () =>
+      startFolioMigration<true>(
+        context,
+        banksClient,
+        programFolio,
+        folioOwnerKeypair,
+        folioTokenMint.publicKey,
+        oldFolioPDA,
+        newFolioPDA,
+        programFolioSecond.programId,
+        true
+      );
+
+    beforeEach(async () => {
+      const currentTime = BigInt(
+        (await context.getClock()).unixTimestamp.toString()
+      );
+      const endOfDay =
+        (currentTime / BigInt(DAY_IN_SECONDS)) * BigInt(DAY_IN_SECONDS);
+
+      await initBaseCase(false, {
+        lastPoke: new BN(endOfDay.toString()),
+        daoPendingFeeShares: null,
+        feeRecipientsPendingFeeShares: null,
+        feeRecipientsPendingFeeSharesToBeMinted: null,
+      });
+
+      await travelFutureSlot(context);
+    });
+
+    it("should carry the mint limits over to the new folio", async () => {
+      await setMintLimits(MAX_SUPPLY, MAX_MINT_PER_TX);
+
+      await travelFutureSlot(context);
+
+      await startMigration();
+
+      const newFolio =
+        await programFolioSecond.account.folio.fetch(newFolioPDA);
+      assert.equal(newFolio.mintLimitsEnabled, 1);
+
+      const newFolioMintLimits =
+        await programFolioSecond.account.folioMintLimits.fetch(
+          getFolioMintLimitsPDA(newFolioPDA, programFolioSecond.programId)
+        );
+      assert.deepEqual(newFolioMintLimits.folio, newFolioPDA);
+      assert.equal(newFolioMintLimits.maxSupply.eq(MAX_SUPPLY), true);
+      assert.equal(newFolioMintLimits.maxMintPerTx.eq(MAX_MINT_PER_TX), true);
+    });
+
+    it("should not create the mint limits of the new folio when removed", async () => {
+      await setMintLimits(new BN(0), new BN(0));
+
+      await travelFutureSlot(context);
+
+      await startMigration();
+
+      const newFolio =
+        await programFolioSecond.account.folio.fetch(newFolioPDA);
+      assert.equal(newFolio.mintLimitsEnabled, 0);
+
+      TestHelper.assertAccountIsClosed(
+        banksClient.getAccount(
+          getFolioMintLimitsPDA(newFolioPDA, programFolioSecond.programId)
+        )
+      );
+    });
+  });
+
+  describe("Specific Cases - Migrate User Pending Basket", () => {
+    const AMOUNT_FOR_MINTING = new BN(100).mul(D9);
+    const AMOUNT_FOR_REDEEMING = new BN(50).mul(D9);
+
+    const migrateIx = () =>
+      migrateUserPendingBasket<true>(
+        context,
+        banksClient,
+        programFolio,
+        payerKeypair, // Can be anyone
+        oldFolioPDA,
+        newFolioPDA,
+        programFolioSecond.programId,
+        folioTokenMint.publicKey,
+        userKeypair.publicKey,
+        [MINTS[0].publicKey]
+      );
+
+    beforeEach(async () => {
+      await initBaseCase(true, null, null, null, [], true);
+
+      await createAndSetUserPendingBasket(
+        context,
+        programFolio,
+        oldFolioPDA,
+        userKeypair.publicKey,
+        [
+          new TokenAmount(
+            MINTS[0].publicKey,
+            AMOUNT_FOR_MINTING,
+            AMOUNT_FOR_REDEEMING
+          ),
+        ]
+      );
+
+      await getOrCreateAtaAddress(context, MINTS[0].publicKey, newFolioPDA);
+
+      await travelFutureSlot(context);
+    });
+
+    it("should move the pending amounts and their tokens to the new folio program", async () => {
+      const oldFolioBalanceBefore = await getTokenBalance(
+        banksClient,
+        getAtaAddress(MINTS[0].publicKey, oldFolioPDA)
+      );
+
+      await migrateIx();
+
+      await travelFutureSlot(context);
+
+      const newUserPendingBasket =
+        await programFolio.account.userPendingBasket.fetch(
+          getUserPendingBasketPDA(
+            newFolioPDA,
+            userKeypair.publicKey,
+            programFolioSecond.programId
+          )
+        );
+      const migratedTokenAmount =
+        newUserPendingBasket.basket.tokenAmounts.find((ta) =>
+          ta.mint.equals(MINTS[0].publicKey)
+        );
+      assert.equal(
+        migratedTokenAmount.amountForMinting.eq(AMOUNT_FOR_MINTING),
+        true
+      );
+      assert.equal(
+        migratedTokenAmount.amountForRedeeming.eq(AMOUNT_FOR_REDEEMING),
+        true
+      );
+
+      const oldUserPendingBasket =
+        await programFolio.account.userPendingBasket.fetch(
+          getUserPendingBasketPDA(oldFolioPDA, userKeypair.publicKey)
+        );
+      assert.equal(
+        oldUserPendingBasket.basket.tokenAmounts.some((ta) =>
+          ta.mint.equals(MINTS[0].publicKey)
+        ),
+        false
+      );
+
+      const migratedAmount = BigInt(
+        AMOUNT_FOR_MINTING.add(AMOUNT_FOR_REDEEMING).toString()
+      );
+      assert.equal(
+        await getTokenBalance(
+          banksClient,
+          getAtaAddress(MINTS[0].publicKey, oldFolioPDA)
+        ),
+        oldFolioBalanceBefore - migratedAmount
+      );
+      assert.equal(
+        await getTokenBalance(
+          banksClient,
+          getAtaAddress(MINTS[0].publicKey, newFolioPDA)
+        ),
+        migratedAmount
+      );
+    });
+
+    it("should fail before the abort grace period has passed", async () => {
+      await createAndSetMigrationRecord(
+        context,
+        programFolio,
+        oldFolioPDA,
+        newFolioPDA,
+        FolioStatus.Initialized,
+        new BN((await context.getClock()).unixTimestamp.toString())
+      );
+
+      const txnResult = await migrateIx();
+
+      assertError(txnResult, "MigrationAbortGracePeriodNotPassed");
+    });
+
+    it("should fail to migrate the same pending amounts twice", async () => {
+      await migrateIx();
+
+      await travelFutureSlot(context);
+
+      const txnResult = await migrateIx();
+
+      assertError(txnResult, "InvalidRemovedTokenMints");
+    });
+  });
+
+  describe("Specific Cases - Migrate Fee Distribution", () => {
+    const FEE_DISTRIBUTION_INDEX = new BN(0);
+
+    const migrateIx = () =>
+      migrateFeeDistribution<true>(
+        banksClient,
+        programFolio,
+        userKeypair,
+        adminKeypair.publicKey,
+        oldFolioPDA,
+        newFolioPDA,
+        programFolioSecond.programId,
+        folioTokenMint.publicKey,
+        FEE_DISTRIBUTION_INDEX
+      );
+
+    beforeEach(async () => {
+      await initBaseCase(true, null, null, null, [], true);
+
+      await travelFutureSlot(context);
+    });
+
+    it("should move the fee distribution to the new folio program", async () => {
+      const oldCrankerLamportsBefore = context.getBalance(
+        adminKeypair.publicKey
+      );
+
+      await migrateIx();
+
+      await travelFutureSlot(context);
+
+      TestHelper.assertAccountIsClosed(
+        banksClient.getAccount(
+          getFeeDistributionPDA(oldFolioPDA, FEE_DISTRIBUTION_INDEX)
+        )
+      );
+      assert.equal(
+        context.getBalance(adminKeypair.publicKey) > oldCrankerLamportsBefore,
+        true
+      );
+
+      const newFeeDistribution =
+        await programFolio.account.feeDistribution.fetch(
+          getFeeDistributionPDA(
+            newFolioPDA,
+            FEE_DISTRIBUTION_INDEX,
+            programFolioSecond.programId
+          )
+        );
+      assert.deepEqual(newFeeDistribution.folio, newFolioPDA);
+      assert.deepEqual(newFeeDistribution.cranker, userKeypair.publicKey);
+      assert.equal(
+        newFeeDistribution.amountToDistribute.eq(AMOUNT_TO_DISTRIBUTE),
+        true
+      );
+      assert.deepEqual(
+        newFeeDistribution.feeRecipientsState[0].recipient,
+        feeRecipients[0].publicKey
+      );
+      assert.equal(
+        newFeeDistribution.feeRecipientsState[0].portion.eq(
+          TOTAL_PORTION_FEE_RECIPIENT
+        ),
+        true
+      );
+    });
+
+    it("should fail before the abort grace period has passed", async () => {
+      await createAndSetMigrationRecord(
+        context,
+        programFolio,
+        oldFolioPDA,
+        newFolioPDA,
+        FolioStatus.Initialized,
+        new BN((await context.getClock()).unixTimestamp.toString())
+      );
+
+      const txnResult = await migrateIx();
+
+      assertError(txnResult, "MigrationAbortGracePeriodNotPassed");
+    });
+
+    it("should fail to migrate the same fee distribution twice", async () => {
+      await migrateIx();
+
+      await travelFutureSlot(context);
+
+      // The old fee distribution was closed by the first migration
+      const txnResult = await migrateIx();
+
+      assertError(txnResult, "AccountOwnedByWrongProgram");
+    });
+  });
+
+  describe("Specific Cases - Migrate Hooked Basket Token", () => {
+    const AMOUNT = new BN(1_000);
+
+    let hookedMint: Keypair;
+    let transferHookProgramId: PublicKey;
+
+    async function hookedRemainingAccounts(
+      transferHookAccounts: AccountMeta[] = buildRemainingAccountsForTransferHook(
+        hookedMint.publicKey,
+        transferHookProgramId
+      )
+    ): Promise<AccountMeta[]> {
+      return [
+        ...(await buildRemainingAccountsForMigrateFolioTokens(
+          context,
+          payerKeypair,
+          oldFolioPDA,
+          newFolioPDA,
+          [hookedMint.publicKey],
+          TOKEN_2022_PROGRAM_ID
+        )),
+        ...transferHookAccounts,
+      ];
+    }
+
+    async function getTransferCounter() {
+      return programTransferHookMock.account.transferCounter.fetch(
+        getTransferCounterPDA(hookedMint.publicKey, transferHookProgramId)
+      );
+    }
+
+    beforeEach(async () => {
+      hookedMint = Keypair.generate();
+      transferHookProgramId = programTransferHookMock.programId;
+
+      await initBaseCase(
+        true,
+        null,
+        null,
+        null,
+        [new FolioTokenAmount(hookedMint.publicKey, AMOUNT)],
+        true
+      );
+
+      await initToken2022Tx(
+        context,
+        adminKeypair,
+        hookedMint,
+        ExtensionType.TransferHook,
+        DEFAULT_DECIMALS,
+        transferHookProgramId
+      );
+
+      await initializeTransferHookExtraAccountMetaList(
+        banksClient,
+        programTransferHookMock,
+        adminKeypair,
+        hookedMint.publicKey
+      );
+
+      // The token accounts of a hooked mint need the transfer hook account
+      // extension, so they are created through the token program
+      await mintToken2022Tx(
+        context,
+        adminKeypair,
+        hookedMint.publicKey,
+        oldFolioPDA,
+        AMOUNT.muln(2)
+      );
+      await mintToken2022Tx(
+        context,
+        adminKeypair,
+        hookedMint.publicKey,
+        newFolioPDA,
+        new BN(0)
+      );
+
+      await travelFutureSlot(context);
+    });
+
+    it("should migrate the folio tokens through the transfer hook", async () => {
+      await migrateFolioTokens<true>(
+        context,
+        banksClient,
+        programFolio,
+        payerKeypair, // Can be anyone
+        oldFolioPDA,
+        newFolioPDA,
+        programFolioSecond.programId,
+        folioTokenMint.publicKey,
+        [hookedMint.publicKey],
+        true,
+        await hookedRemainingAccounts(),
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      await travelFutureSlot(context);
+
+      const transferCounter = await getTransferCounter();
+      assert.equal(transferCounter.transfers.toNumber(), 1);
+      assert.equal(transferCounter.amount.toString(), AMOUNT.toString());
+
+      assert.equal(
+        await getTokenBalance(
+          banksClient,
+          getAtaAddress(
+            hookedMint.publicKey,
+            newFolioPDA,
+            TOKEN_2022_PROGRAM_ID
+          )
+        ),
+        BigInt(AMOUNT.toString())
+      );
+
+      const newFolioBasket = await programFolio.account.folioBasket.fetch(
+        getFolioBasketPDA(newFolioPDA, programFolioSecond.programId)
+      );
+      const migratedTokenAmount = newFolioBasket.basket.tokenAmounts.find(
+        (ta) => ta.mint.equals(hookedMint.publicKey)
+      );
+      assert.equal(migratedTokenAmount.amount.eq(AMOUNT), true);
+    });
+
+    it("should fail to migrate the folio tokens without the transfer hook accounts", async () => {
+      const txnResult = await migrateFolioTokens<true>(
+        context,
+        banksClient,
+        programFolio,
+        payerKeypair,
+        oldFolioPDA,
+        newFolioPDA,
+        programFolioSecond.programId,
+        folioTokenMint.publicKey,
+        [hookedMint.publicKey],
+        true,
+        await hookedRemainingAccounts([]),
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      assertError(txnResult, "MissingRemainingAccount");
+    });
+
+    it("should migrate the user pending basket through the transfer hook", async () => {
+      await createAndSetUserPendingBasket(
+        context,
+        programFolio,
+        oldFolioPDA,
+        userKeypair.publicKey,
+        [new TokenAmount(hookedMint.publicKey, AMOUNT, new BN(0))]
+      );
+
+      await travelFutureSlot(context);
+
+      await migrateUserPendingBasket<true>(
+        context,
+        banksClient,
+        programFolio,
+        payerKeypair,
+        oldFolioPDA,
+        newFolioPDA,
+        programFolioSecond.programId,
+        folioTokenMint.publicKey,
+        userKeypair.publicKey,
+        [hookedMint.publicKey],
+        true,
+        await hookedRemainingAccounts(),
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      await travelFutureSlot(context);
+
+      const transferCounter = await getTransferCounter();
+      assert.equal(transferCounter.transfers.toNumber(), 1);
+
+      const newUserPendingBasket =
+        await programFolio.account.userPendingBasket.fetch(
+          getUserPendingBasketPDA(
+            newFolioPDA,
+            userKeypair.publicKey,
+            programFolioSecond.programId
+          )
+        );
+      const migratedTokenAmount =
+        newUserPendingBasket.basket.tokenAmounts.find((ta) =>
+          ta.mint.equals(hookedMint.publicKey)
+        );
+      assert.equal(migratedTokenAmount.amountForMinting.eq(AMOUNT), true);
+    });
+  });
+
+  describe("Specific Cases - Migrate Basket Token With Transfer Fee", () => {
+    // 1% transfer fee
+    const TRANSFER_FEE = { basisPoints: 100, maximumFee: new BN(1_000_000) };
+    const AMOUNT = new BN(100_000);
+    const AMOUNT_AFTER_FEE = new BN(99_000);
+
+    let feeMint: Keypair;
+
+    beforeEach(async () => {
+      feeMint = Keypair.generate();
+
+      await initBaseCase(
+        true,
+        null,
+        null,
+        null,
+        [new FolioTokenAmount(feeMint.publicKey, AMOUNT)],
+        true
+      );
+
+      await initToken2022Tx(
+        context,
+        adminKeypair,
+        feeMint,
+        ExtensionType.TransferFeeConfig,
+        DEFAULT_DECIMALS,
+        null,
+        TRANSFER_FEE
+      );
+
+      // The token accounts of a mint with a transfer fee need the transfer fee
+      // amount extension, so they are created through the token program
+      await mintToken2022Tx(
+        context,
+        adminKeypair,
+        feeMint.publicKey,
+        oldFolioPDA,
+        AMOUNT.muln(2)
+      );
+      await mintToken2022Tx(
+        context,
+        adminKeypair,
+        feeMint.publicKey,
+        newFolioPDA,
+        new BN(0)
+      );
+
+      await travelFutureSlot(context);
+    });
+
+    it("should only credit the amount received to the new folio basket", async () => {
+      await migrateFolioTokens<true>(
+        context,
+        banksClient,
+        programFolio,
+        payerKeypair, // Can be anyone
+        oldFolioPDA,
+        newFolioPDA,
+        programFolioSecond.programId,
+        folioTokenMint.publicKey,
+        [feeMint.publicKey],
+        true,
+        [],
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      await travelFutureSlot(context);
+
+      assert.equal(
+        await getTokenBalance(
+          banksClient,
+          getAtaAddress(feeMint.publicKey, newFolioPDA, TOKEN_2022_PROGRAM_ID)
+        ),
+        BigInt(AMOUNT_AFTER_FEE.toString())
+      );
+
+      const newFolioBasket = await programFolio.account.folioBasket.fetch(
+        getFolioBasketPDA(newFolioPDA, programFolioSecond.programId)
+      );
+      const migratedTokenAmount = newFolioBasket.basket.tokenAmounts.find(
+        (ta) => ta.mint.equals(feeMint.publicKey)
+      );
+      assert.equal(migratedTokenAmount.amount.eq(AMOUNT_AFTER_FEE), true);
+
+      const newFolio = await programFolio.account.folio.fetch(newFolioPDA);
+      assert.equal(newFolio.status, FolioStatus.Initialized);
+    });
+
+    it("should only credit the amount received to the new user pending basket", async () => {
+      // 60% for minting, 40% for redeeming
+      await createAndSetUserPendingBasket(
+        context,
+        programFolio,
+        oldFolioPDA,
+        userKeypair.publicKey,
+        [
+          new TokenAmount(
+            feeMint.publicKey,
+            AMOUNT.muln(60).divn(100),
+            AMOUNT.muln(40).divn(100)
+          ),
+        ]
+      );
+
+      await travelFutureSlot(context);
+
+      await migrateUserPendingBasket<true>(
+        context,
+        banksClient,
+        programFolio,
+        payerKeypair,
+        oldFolioPDA,
+        newFolioPDA,
+        programFolioSecond.programId,
+        folioTokenMint.publicKey,
+        userKeypair.publicKey,
+        [feeMint.publicKey],
+        true,
+        [],
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      await travelFutureSlot(context);
+
+      assert.equal(
+        await getTokenBalance(
+          banksClient,
+          getAtaAddress(feeMint.publicKey, newFolioPDA, TOKEN_2022_PROGRAM_ID)
+        ),
+        BigInt(AMOUNT_AFTER_FEE.toString())
+      );
+
+      const newUserPendingBasket =
+        await programFolio.account.userPendingBasket.fetch(
+          getUserPendingBasketPDA(
+            newFolioPDA,
+            userKeypair.publicKey,
+            programFolioSecond.programId
+          )
+        );
+      const migratedTokenAmount =
+        newUserPendingBasket.basket.tokenAmounts.find((ta) =>
+          ta.mint.equals(feeMint.publicKey)
+        );
+      // The fee is taken pro-rata from both amounts
+      assert.equal(
+        migratedTokenAmount.amountForMinting.eq(
+          AMOUNT_AFTER_FEE.muln(60).divn(100)
+        ),
+        true
+      );
+      assert.equal(
+        migratedTokenAmount.amountForRedeeming.eq(
+          AMOUNT_AFTER_FEE.muln(40).divn(100)
+        ),
+        true
+      );
+    });
+  });
 });
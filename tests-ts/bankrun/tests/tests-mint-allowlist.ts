@@ -0,0 +1,279 @@
+import { BN, Program, Provider } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import {
+  airdrop,
+  assertError,
+  getConnectors,
+  travelFutureSlot,
+} from "../bankrun-program-helper";
+
+import {
+  getFolioPDA,
+  getMintAllowlistEntryPDA,
+  getUserPendingBasketPDA,
+} from "../../../utils/pda-helper";
+import {
+  addToMintAllowlist,
+  addToPendingBasket,
+  removeFromMintAllowlist,
+} from "../bankrun-ix-helper";
+import {
+  closeAccount,
+  createAndSetActor,
+  createAndSetFolio,
+  createAndSetFolioBasket,
+  FolioTokenAmount,
+  Role,
+  setFolioMintAllowlistEnabled,
+} from "../bankrun-account-helper";
+import { Folio } from "../../../target/types/folio";
+import {
+  assertNotValidRoleTestCase,
+  GeneralTestCases,
+} from "../bankrun-general-tests-helper";
+import { D9, DEFAULT_DECIMALS } from "../../../utils/constants";
+import { initToken, mintToken } from "../bankrun-token-helper";
+import { LiteSVM } from "litesvm";
+import * as assert from "assert";
+import { TestHelper } from "../../../utils/test-helper";
+
+/**
+ * Tests for the mint allowlist of a folio, including:
+ * - Only the allowlist manager or the owner can add and remove users
+ * - Removing a user closes its mint allowlist entry
+ * - Once enabled, only the users on the mint allowlist can mint
+ */
+describe("Bankrun - Mint Allowlist", () => {
+  let context: LiteSVM;
+  let provider: Provider;
+  let banksClient: LiteSVM;
+
+  let programFolio: Program<Folio>;
+  let keys: any;
+
+  let payerKeypair: Keypair;
+  let adminKeypair: Keypair;
+
+  let folioOwnerKeypair: Keypair;
+  let allowlistManagerKeypair: Keypair;
+  let userKeypair: Keypair;
+
+  let folioTokenMint: Keypair;
+  let basketMint: Keypair;
+
+  let folioPDA: PublicKey;
+
+  const BASKET_AMOUNT = 1_000;
+  const USER_AMOUNT = 100;
+
+  const generalIxAddToMintAllowlist = () =>
+    addToMintAllowlist<true>(
+      banksClient,
+      programFolio,
+      allowlistManagerKeypair,
+      folioPDA,
+      userKeypair.publicKey
+    );
+
+  const generalIxRemoveFromMintAllowlist = () =>
+    removeFromMintAllowlist<true>(
+      banksClient,
+      programFolio,
+      allowlistManagerKeypair,
+      folioPDA,
+      userKeypair.publicKey
+    );
+
+  const addUserTokensToPendingBasket = () =>
+    addToPendingBasket<true>(
+      context,
+      banksClient,
+      programFolio,
+      userKeypair,
+      folioPDA,
+      [{ mint: basketMint.publicKey, amount: new BN(USER_AMOUNT).mul(D9) }]
+    );
+
+  beforeEach(async () => {
+    ({ keys, programFolio, provider, context } = await getConnectors());
+
+    banksClient = context;
+
+    payerKeypair = provider.wallet.payer;
+
+    adminKeypair = Keypair.fromSecretKey(Uint8Array.from(keys.admin));
+
+    folioOwnerKeypair = Keypair.generate();
+    allowlistManagerKeypair = Keypair.generate();
+    userKeypair = Keypair.generate();
+    folioTokenMint = Keypair.generate();
+    basketMint = Keypair.generate();
+
+    await airdrop(context, payerKeypair.publicKey, 1000);
+    await airdrop(context, adminKeypair.publicKey, 1000);
+    await airdrop(context, folioOwnerKeypair.publicKey, 1000);
+    await airdrop(context, allowlistManagerKeypair.publicKey, 1000);
+    await airdrop(context, userKeypair.publicKey, 1000);
+
+    folioPDA = getFolioPDA(folioTokenMint.publicKey);
+
+    await createAndSetFolio(context, programFolio, folioTokenMint.publicKey);
+
+    initToken(context, folioPDA, folioTokenMint, DEFAULT_DECIMALS);
+    initToken(context, adminKeypair.publicKey, basketMint, DEFAULT_DECIMALS);
+
+    mintToken(context, basketMint.publicKey, BASKET_AMOUNT, folioPDA);
+    mintToken(
+      context,
+      basketMint.publicKey,
+      USER_AMOUNT,
+      userKeypair.publicKey
+    );
+
+    await createAndSetFolioBasket(context, programFolio, folioPDA, [
+      new FolioTokenAmount(
+        basketMint.publicKey,
+        new BN(BASKET_AMOUNT).mul(D9)
+      ),
+    ]);
+
+    await createAndSetActor(
+      context,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      Role.Owner
+    );
+
+    await createAndSetActor(
+      context,
+      programFolio,
+      allowlistManagerKeypair,
+      folioPDA,
+      Role.AllowlistManager
+    );
+
+    await closeAccount(
+      context,
+      getUserPendingBasketPDA(folioPDA, userKeypair.publicKey)
+    );
+  });
+
+  describe("General Tests", () => {
+    it(`should run ${GeneralTestCases.NotRole} for add to mint allowlist`, async () => {
+      await assertNotValidRoleTestCase(
+        context,
+        programFolio,
+        allowlistManagerKeypair,
+        folioPDA,
+        generalIxAddToMintAllowlist
+      );
+    });
+
+    it(`should run ${GeneralTestCases.NotRole} for remove from mint allowlist`, async () => {
+      await generalIxAddToMintAllowlist();
+
+      await travelFutureSlot(context);
+
+      await assertNotValidRoleTestCase(
+        context,
+        programFolio,
+        allowlistManagerKeypair,
+        folioPDA,
+        generalIxRemoveFromMintAllowlist
+      );
+    });
+  });
+
+  it("should let the allowlist manager add a user", async () => {
+    await generalIxAddToMintAllowlist();
+
+    await travelFutureSlot(context);
+
+    const mintAllowlistEntry =
+      await programFolio.account.mintAllowlistEntry.fetch(
+        getMintAllowlistEntryPDA(folioPDA, userKeypair.publicKey)
+      );
+    assert.equal(mintAllowlistEntry.folio.toBase58(), folioPDA.toBase58());
+    assert.equal(
+      mintAllowlistEntry.user.toBase58(),
+      userKeypair.publicKey.toBase58()
+    );
+  });
+
+  it("should let the owner add a user", async () => {
+    await addToMintAllowlist<true>(
+      banksClient,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      userKeypair.publicKey
+    );
+
+    await travelFutureSlot(context);
+
+    const mintAllowlistEntry = banksClient.getAccount(
+      getMintAllowlistEntryPDA(folioPDA, userKeypair.publicKey)
+    );
+    assert.equal(
+      mintAllowlistEntry.owner.toString(),
+      programFolio.programId.toString()
+    );
+  });
+
+  it("should remove a user, closing its mint allowlist entry", async () => {
+    await generalIxAddToMintAllowlist();
+
+    await travelFutureSlot(context);
+
+    await generalIxRemoveFromMintAllowlist();
+
+    await travelFutureSlot(context);
+
+    TestHelper.assertAccountIsClosed(
+      banksClient.getAccount(
+        getMintAllowlistEntryPDA(folioPDA, userKeypair.publicKey)
+      )
+    );
+  });
+
+  it("should fail to remove a user that isn't on the mint allowlist", async () => {
+    const txnResult = await generalIxRemoveFromMintAllowlist();
+
+    assertError(txnResult, "AccountNotInitialized");
+  });
+
+  it("should not let a user that isn't on the enabled mint allowlist mint", async () => {
+    await setFolioMintAllowlistEnabled(context, folioPDA, true);
+
+    await travelFutureSlot(context);
+
+    const txnResult = await addUserTokensToPendingBasket();
+
+    assertError(txnResult, "UserNotOnMintAllowlist");
+  });
+
+  it("should let a user on the enabled mint allowlist mint", async () => {
+    await setFolioMintAllowlistEnabled(context, folioPDA, true);
+
+    await generalIxAddToMintAllowlist();
+
+    await travelFutureSlot(context);
+
+    await addUserTokensToPendingBasket();
+
+    await travelFutureSlot(context);
+
+    const userPendingBasket =
+      await programFolio.account.userPendingBasket.fetch(
+        getUserPendingBasketPDA(folioPDA, userKeypair.publicKey)
+      );
+    const tokenAmount = userPendingBasket.basket.tokenAmounts.find((ta) =>
+      ta.mint.equals(basketMint.publicKey)
+    );
+    assert.equal(
+      tokenAmount.amountForMinting.eq(new BN(USER_AMOUNT).mul(D9)),
+      true
+    );
+  });
+});
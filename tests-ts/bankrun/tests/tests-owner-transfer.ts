@@ -0,0 +1,245 @@
+import { Program, Provider } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import {
+  airdrop,
+  assertError,
+  getConnectors,
+  travelFutureSlot,
+} from "../bankrun-program-helper";
+
+import {
+  getActorPDA,
+  getFolioActorsPDA,
+  getFolioPDA,
+  getPendingOwnerPDA,
+} from "../../../utils/pda-helper";
+import {
+  acceptOwner,
+  cancelOwnerProposal,
+  proposeOwner,
+} from "../bankrun-ix-helper";
+import {
+  createAndSetActor,
+  createAndSetFolio,
+  Role,
+} from "../bankrun-account-helper";
+import { Folio } from "../../../target/types/folio";
+import {
+  assertNotValidRoleTestCase,
+  GeneralTestCases,
+} from "../bankrun-general-tests-helper";
+import { DEFAULT_DECIMALS } from "../../../utils/constants";
+import { initToken } from "../bankrun-token-helper";
+import { LiteSVM } from "litesvm";
+import * as assert from "assert";
+import { TestHelper } from "../../../utils/test-helper";
+
+/**
+ * Tests for the two step ownership transfer of a folio, including:
+ * - Only the owner can propose and cancel an ownership transfer
+ * - Only the proposed new owner can accept it
+ * - Accepting gives the owner role to the new owner and removes it from the previous owner
+ */
+describe("Bankrun - Owner Transfer", () => {
+  let context: LiteSVM;
+  let provider: Provider;
+  let banksClient: LiteSVM;
+
+  let programFolio: Program<Folio>;
+
+  let payerKeypair: Keypair;
+
+  let folioOwnerKeypair: Keypair;
+  let newOwnerKeypair: Keypair;
+
+  let folioTokenMint: Keypair;
+
+  let folioPDA: PublicKey;
+
+  const generalIxProposeOwner = () =>
+    proposeOwner<true>(
+      banksClient,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      newOwnerKeypair.publicKey
+    );
+
+  const generalIxCancelOwnerProposal = () =>
+    cancelOwnerProposal<true>(
+      banksClient,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      folioOwnerKeypair.publicKey
+    );
+
+  async function getRoles(authority: PublicKey) {
+    const actor = await programFolio.account.actor.fetch(
+      getActorPDA(authority, folioPDA)
+    );
+
+    return actor.roles;
+  }
+
+  beforeEach(async () => {
+    ({ programFolio, provider, context } = await getConnectors());
+
+    banksClient = context;
+
+    payerKeypair = provider.wallet.payer;
+
+    folioOwnerKeypair = Keypair.generate();
+    newOwnerKeypair = Keypair.generate();
+    folioTokenMint = Keypair.generate();
+
+    await airdrop(context, payerKeypair.publicKey, 1000);
+    await airdrop(context, folioOwnerKeypair.publicKey, 1000);
+    await airdrop(context, newOwnerKeypair.publicKey, 1000);
+
+    folioPDA = getFolioPDA(folioTokenMint.publicKey);
+
+    await createAndSetFolio(context, programFolio, folioTokenMint.publicKey);
+
+    initToken(context, folioPDA, folioTokenMint, DEFAULT_DECIMALS);
+
+    await createAndSetActor(
+      context,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      Role.Owner
+    );
+  });
+
+  describe("General Tests", () => {
+    it(`should run ${GeneralTestCases.NotRole} for propose owner`, async () => {
+      await assertNotValidRoleTestCase(
+        context,
+        programFolio,
+        folioOwnerKeypair,
+        folioPDA,
+        generalIxProposeOwner
+      );
+    });
+
+    it(`should run ${GeneralTestCases.NotRole} for cancel owner proposal`, async () => {
+      await generalIxProposeOwner();
+
+      await travelFutureSlot(context);
+
+      await assertNotValidRoleTestCase(
+        context,
+        programFolio,
+        folioOwnerKeypair,
+        folioPDA,
+        generalIxCancelOwnerProposal
+      );
+    });
+  });
+
+  it("should propose a new owner", async () => {
+    await generalIxProposeOwner();
+
+    await travelFutureSlot(context);
+
+    const pendingOwner = await programFolio.account.pendingOwner.fetch(
+      getPendingOwnerPDA(folioPDA)
+    );
+    assert.equal(pendingOwner.folio.toBase58(), folioPDA.toBase58());
+    assert.equal(
+      pendingOwner.proposedBy.toBase58(),
+      folioOwnerKeypair.publicKey.toBase58()
+    );
+    assert.equal(
+      pendingOwner.newOwner.toBase58(),
+      newOwnerKeypair.publicKey.toBase58()
+    );
+  });
+
+  it("should fail if the owner proposes itself", async () => {
+    const txnResult = await proposeOwner<true>(
+      banksClient,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      folioOwnerKeypair.publicKey
+    );
+
+    assertError(txnResult, "InvalidPendingOwner");
+  });
+
+  it("should transfer the owner role once the new owner accepts", async () => {
+    await generalIxProposeOwner();
+
+    await travelFutureSlot(context);
+
+    await acceptOwner<true>(
+      banksClient,
+      programFolio,
+      newOwnerKeypair,
+      folioPDA,
+      folioOwnerKeypair.publicKey
+    );
+
+    await travelFutureSlot(context);
+
+    assert.equal(await getRoles(newOwnerKeypair.publicKey), Role.Owner);
+    assert.equal(await getRoles(folioOwnerKeypair.publicKey), 0);
+
+    const folioActors = await programFolio.account.folioActors.fetch(
+      getFolioActorsPDA(folioPDA)
+    );
+    const newOwnerEntry = folioActors.actors.find((entry) =>
+      entry.authority.equals(newOwnerKeypair.publicKey)
+    );
+    assert.equal(newOwnerEntry.roles, Role.Owner);
+
+    TestHelper.assertAccountIsClosed(
+      banksClient.getAccount(getPendingOwnerPDA(folioPDA))
+    );
+  });
+
+  it("should fail if someone else than the proposed new owner accepts", async () => {
+    await generalIxProposeOwner();
+
+    await travelFutureSlot(context);
+
+    const otherKeypair = Keypair.generate();
+    await airdrop(context, otherKeypair.publicKey, 1000);
+
+    const txnResult = await acceptOwner<true>(
+      banksClient,
+      programFolio,
+      otherKeypair,
+      folioPDA,
+      folioOwnerKeypair.publicKey
+    );
+
+    assertError(txnResult, "InvalidPendingOwner");
+  });
+
+  it("should cancel the ownership transfer", async () => {
+    await generalIxProposeOwner();
+
+    await travelFutureSlot(context);
+
+    await generalIxCancelOwnerProposal();
+
+    await travelFutureSlot(context);
+
+    TestHelper.assertAccountIsClosed(
+      banksClient.getAccount(getPendingOwnerPDA(folioPDA))
+    );
+
+    const txnResult = await acceptOwner<true>(
+      banksClient,
+      programFolio,
+      newOwnerKeypair,
+      folioPDA,
+      folioOwnerKeypair.publicKey
+    );
+
+    assertError(txnResult, "AccountNotInitialized");
+  });
+});
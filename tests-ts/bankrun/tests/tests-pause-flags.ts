@@ -0,0 +1,281 @@
+import { Program, Provider } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import {
+  airdrop,
+  assertError,
+  getConnectors,
+  travelFutureSlot,
+} from "../bankrun-program-helper";
+
+import { getFolioPDA } from "../../../utils/pda-helper";
+import {
+  pauseFolio,
+  removeActor,
+  unpauseFolio,
+} from "../bankrun-ix-helper";
+import {
+  createAndSetActor,
+  createAndSetFolio,
+  FolioStatus,
+  PauseFlag,
+  Role,
+  setFolioPauseFlags,
+} from "../bankrun-account-helper";
+import { Folio } from "../../../target/types/folio";
+import {
+  assertInvalidFolioStatusTestCase,
+  assertNotValidRoleTestCase,
+  GeneralTestCases,
+} from "../bankrun-general-tests-helper";
+import { DEFAULT_DECIMALS } from "../../../utils/constants";
+import { initToken } from "../bankrun-token-helper";
+import { LiteSVM } from "litesvm";
+import * as assert from "assert";
+
+/**
+ * Tests for pausing and unpausing actions of a folio, including:
+ * - The guardian and the owner can pause and unpause
+ * - The guardian can't do anything else the owner can
+ * - Only known pause flags are accepted
+ *
+ * Each flag blocking its instructions is tested with the general tests of those instructions.
+ */
+describe("Bankrun - Pause Flags", () => {
+  let context: LiteSVM;
+  let provider: Provider;
+  let banksClient: LiteSVM;
+
+  let programFolio: Program<Folio>;
+
+  let payerKeypair: Keypair;
+
+  let folioOwnerKeypair: Keypair;
+  let guardianKeypair: Keypair;
+
+  let folioTokenMint: Keypair;
+
+  let folioPDA: PublicKey;
+
+  const generalIxPauseFolio = () =>
+    pauseFolio<true>(
+      banksClient,
+      programFolio,
+      guardianKeypair,
+      folioPDA,
+      PauseFlag.Mint
+    );
+
+  const generalIxUnpauseFolio = () =>
+    unpauseFolio<true>(
+      banksClient,
+      programFolio,
+      guardianKeypair,
+      folioPDA,
+      PauseFlag.Mint
+    );
+
+  async function getPauseFlags() {
+    const folio = await programFolio.account.folio.fetch(folioPDA);
+
+    return folio.pauseFlags;
+  }
+
+  beforeEach(async () => {
+    ({ programFolio, provider, context } = await getConnectors());
+
+    banksClient = context;
+
+    payerKeypair = provider.wallet.payer;
+
+    folioOwnerKeypair = Keypair.generate();
+    guardianKeypair = Keypair.generate();
+    folioTokenMint = Keypair.generate();
+
+    await airdrop(context, payerKeypair.publicKey, 1000);
+    await airdrop(context, folioOwnerKeypair.publicKey, 1000);
+    await airdrop(context, guardianKeypair.publicKey, 1000);
+
+    folioPDA = getFolioPDA(folioTokenMint.publicKey);
+
+    await createAndSetFolio(context, programFolio, folioTokenMint.publicKey);
+
+    initToken(context, folioPDA, folioTokenMint, DEFAULT_DECIMALS);
+
+    await createAndSetActor(
+      context,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      Role.Owner
+    );
+
+    await createAndSetActor(
+      context,
+      programFolio,
+      guardianKeypair,
+      folioPDA,
+      Role.Guardian
+    );
+  });
+
+  describe("General Tests", () => {
+    describe("should run general tests for pause folio", () => {
+      it(`should run ${GeneralTestCases.NotRole}`, async () => {
+        await assertNotValidRoleTestCase(
+          context,
+          programFolio,
+          guardianKeypair,
+          folioPDA,
+          generalIxPauseFolio
+        );
+      });
+
+      it(`should run ${GeneralTestCases.InvalidFolioStatus} for MIGRATING`, async () => {
+        await assertInvalidFolioStatusTestCase(
+          context,
+          programFolio,
+          folioTokenMint.publicKey,
+          generalIxPauseFolio,
+          FolioStatus.Migrating
+        );
+      });
+    });
+
+    describe("should run general tests for unpause folio", () => {
+      it(`should run ${GeneralTestCases.NotRole}`, async () => {
+        await assertNotValidRoleTestCase(
+          context,
+          programFolio,
+          guardianKeypair,
+          folioPDA,
+          generalIxUnpauseFolio
+        );
+      });
+
+      it(`should run ${GeneralTestCases.InvalidFolioStatus} for MIGRATING`, async () => {
+        await assertInvalidFolioStatusTestCase(
+          context,
+          programFolio,
+          folioTokenMint.publicKey,
+          generalIxUnpauseFolio,
+          FolioStatus.Migrating
+        );
+      });
+    });
+  });
+
+  it("should let the guardian pause and unpause", async () => {
+    await pauseFolio<true>(
+      banksClient,
+      programFolio,
+      guardianKeypair,
+      folioPDA,
+      PauseFlag.Mint | PauseFlag.Auctions
+    );
+
+    await travelFutureSlot(context);
+
+    assert.equal(await getPauseFlags(), PauseFlag.Mint | PauseFlag.Auctions);
+
+    await unpauseFolio<true>(
+      banksClient,
+      programFolio,
+      guardianKeypair,
+      folioPDA,
+      PauseFlag.Mint
+    );
+
+    await travelFutureSlot(context);
+
+    assert.equal(await getPauseFlags(), PauseFlag.Auctions);
+  });
+
+  it("should let the owner unpause what the guardian paused", async () => {
+    await setFolioPauseFlags(
+      context,
+      folioPDA,
+      PauseFlag.Redeem | PauseFlag.FeeDistribution
+    );
+
+    await travelFutureSlot(context);
+
+    await unpauseFolio<true>(
+      banksClient,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      PauseFlag.Redeem | PauseFlag.FeeDistribution
+    );
+
+    await travelFutureSlot(context);
+
+    assert.equal(await getPauseFlags(), 0);
+  });
+
+  it("should let the guardian pause a killed folio", async () => {
+    await createAndSetFolio(
+      context,
+      programFolio,
+      folioTokenMint.publicKey,
+      FolioStatus.Killed
+    );
+
+    await travelFutureSlot(context);
+
+    await generalIxPauseFolio();
+
+    await travelFutureSlot(context);
+
+    assert.equal(await getPauseFlags(), PauseFlag.Mint);
+  });
+
+  it("should fail if no pause flag is given", async () => {
+    const txnResult = await pauseFolio<true>(
+      banksClient,
+      programFolio,
+      guardianKeypair,
+      folioPDA,
+      0
+    );
+
+    assertError(txnResult, "InvalidPauseFlags");
+  });
+
+  it("should fail if an unknown pause flag is given", async () => {
+    const txnResult = await pauseFolio<true>(
+      banksClient,
+      programFolio,
+      guardianKeypair,
+      folioPDA,
+      PauseFlag.Mint | 0b10000000
+    );
+
+    assertError(txnResult, "InvalidPauseFlags");
+  });
+
+  it("should fail to unpause if an unknown pause flag is given", async () => {
+    const txnResult = await unpauseFolio<true>(
+      banksClient,
+      programFolio,
+      guardianKeypair,
+      folioPDA,
+      0b10000000
+    );
+
+    assertError(txnResult, "InvalidPauseFlags");
+  });
+
+  it("should not let the guardian act as the owner", async () => {
+    const txnResult = await removeActor<true>(
+      banksClient,
+      programFolio,
+      guardianKeypair,
+      folioPDA,
+      folioOwnerKeypair.publicKey,
+      Role.Owner,
+      true
+    );
+
+    assertError(txnResult, "InvalidRole");
+  });
+});
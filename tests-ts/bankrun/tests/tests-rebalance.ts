@@ -587,7 +587,6 @@ describe("Bankrun - Rebalance", () => {
     },
 
     ...[
-      ExtensionType.TransferFeeConfig,
       ExtensionType.MintCloseAuthority,
       ExtensionType.NonTransferable,
     ].map((extension) => {
@@ -1097,7 +1096,6 @@ describe("Bankrun - Rebalance", () => {
     },
 
     ...[
-      ExtensionType.TransferFeeConfig,
       ExtensionType.MintCloseAuthority,
       ExtensionType.NonTransferable,
     ].map((extension) => {
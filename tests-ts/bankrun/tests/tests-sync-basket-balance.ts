@@ -0,0 +1,441 @@
+import { BN, Program, Provider } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import {
+  airdrop,
+  assertError,
+  BanksTransactionResultWithMeta,
+  getConnectors,
+  travelFutureSlot,
+} from "../bankrun-program-helper";
+
+import {
+  getFolioBasketPDA,
+  getFolioPDA,
+  getFolioPendingBasketPDA,
+  getUserPendingBasketPDA,
+} from "../../../utils/pda-helper";
+import {
+  setPendingBasketsTracked,
+  syncBasketBalance,
+  trackUserPendingBasket,
+} from "../bankrun-ix-helper";
+import {
+  closeAccount,
+  createAndSetActor,
+  createAndSetFolio,
+  createAndSetFolioBasket,
+  createAndSetUserPendingBasket,
+  FolioStatus,
+  FolioTokenAmount,
+  Role,
+  TokenAmount,
+} from "../bankrun-account-helper";
+import { Folio } from "../../../target/types/folio";
+import { DEFAULT_DECIMALS } from "../../../utils/constants";
+import {
+  assertInvalidFolioStatusTestCase,
+  assertNotValidRoleTestCase,
+  GeneralTestCases,
+} from "../bankrun-general-tests-helper";
+import {
+  getAtaAddress,
+  getTokenBalance,
+  initToken,
+  mintToken,
+} from "../bankrun-token-helper";
+import { LiteSVM } from "litesvm";
+import * as assert from "assert";
+
+/**
+ * Tests for syncing the folio's token balances with the basket, including:
+ * - Crediting the surplus of a basket token to the basket
+ * - Sweeping the tokens that aren't in the basket to a recipient
+ * - Pending amounts of the users are never counted as surplus
+ * - Pending baskets created before the folio pending basket need to be
+ *   tracked, and the folio marked as tracked by the admin, before syncing
+ */
+describe("Bankrun - Sync Basket Balance", () => {
+  let context: LiteSVM;
+  let provider: Provider;
+  let banksClient: LiteSVM;
+
+  let programFolio: Program<Folio>;
+  let keys: any;
+
+  let payerKeypair: Keypair;
+  let adminKeypair: Keypair;
+
+  let folioOwnerKeypair: Keypair;
+  let userKeypair: Keypair;
+
+  let folioTokenMint: Keypair;
+  let basketMint: Keypair;
+  let nonBasketMint: Keypair;
+
+  let folioPDA: PublicKey;
+
+  // Whole tokens, as minted by `mintToken`
+  const BASKET_AMOUNT = 100;
+  const PENDING_AMOUNT = 20;
+  const SURPLUS_AMOUNT = 5;
+  const NON_BASKET_AMOUNT = 10;
+
+  function toRaw(amount: number) {
+    return new BN(amount).mul(new BN(10 ** DEFAULT_DECIMALS));
+  }
+
+  async function initFolio(pendingBasketsTracked: boolean) {
+    await createAndSetFolio(
+      context,
+      programFolio,
+      folioTokenMint.publicKey,
+      FolioStatus.Initialized,
+      null,
+      new BN(0),
+      new BN(0),
+      new BN(0),
+      false,
+      "",
+      new BN(0),
+      pendingBasketsTracked
+    );
+  }
+
+  async function getBasketAmount(mint: PublicKey) {
+    const folioBasket = await programFolio.account.folioBasket.fetch(
+      getFolioBasketPDA(folioPDA)
+    );
+
+    return folioBasket.basket.tokenAmounts.find((ta) => ta.mint.equals(mint))
+      .amount;
+  }
+
+  async function getFolioBalance(mint: PublicKey) {
+    return getTokenBalance(banksClient, getAtaAddress(mint, folioPDA));
+  }
+
+  const generalIxSyncBasketBalance = () =>
+    syncBasketBalance<true>(
+      context,
+      banksClient,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      basketMint.publicKey,
+      folioOwnerKeypair.publicKey
+    );
+
+  beforeEach(async () => {
+    ({ keys, programFolio, provider, context } = await getConnectors());
+
+    banksClient = context;
+
+    payerKeypair = provider.wallet.payer;
+
+    adminKeypair = Keypair.fromSecretKey(Uint8Array.from(keys.admin));
+
+    folioOwnerKeypair = Keypair.generate();
+    userKeypair = Keypair.generate();
+    folioTokenMint = Keypair.generate();
+    basketMint = Keypair.generate();
+    nonBasketMint = Keypair.generate();
+
+    await airdrop(context, payerKeypair.publicKey, 1000);
+    await airdrop(context, adminKeypair.publicKey, 1000);
+    await airdrop(context, folioOwnerKeypair.publicKey, 1000);
+    await airdrop(context, userKeypair.publicKey, 1000);
+
+    folioPDA = getFolioPDA(folioTokenMint.publicKey);
+
+    await initFolio(true);
+
+    initToken(context, folioPDA, folioTokenMint, DEFAULT_DECIMALS);
+    initToken(context, adminKeypair.publicKey, basketMint, DEFAULT_DECIMALS);
+    initToken(context, adminKeypair.publicKey, nonBasketMint, DEFAULT_DECIMALS);
+
+    // The folio holds the basket, the pending amount of the user (only in the
+    // cases with a pending basket) and a surplus
+    mintToken(
+      context,
+      basketMint.publicKey,
+      BASKET_AMOUNT + PENDING_AMOUNT + SURPLUS_AMOUNT,
+      folioPDA
+    );
+    mintToken(context, nonBasketMint.publicKey, NON_BASKET_AMOUNT, folioPDA);
+    mintToken(context, basketMint.publicKey, 0, folioOwnerKeypair.publicKey);
+    mintToken(context, nonBasketMint.publicKey, 0, folioOwnerKeypair.publicKey);
+
+    await createAndSetFolioBasket(context, programFolio, folioPDA, [
+      new FolioTokenAmount(basketMint.publicKey, toRaw(BASKET_AMOUNT)),
+    ]);
+
+    await createAndSetActor(
+      context,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      Role.Owner
+    );
+
+    await closeAccount(context, getFolioPendingBasketPDA(folioPDA));
+    await closeAccount(
+      context,
+      getUserPendingBasketPDA(folioPDA, userKeypair.publicKey)
+    );
+  });
+
+  describe("General Tests", () => {
+    it(`should run ${GeneralTestCases.NotRole}`, async () => {
+      await assertNotValidRoleTestCase(
+        context,
+        programFolio,
+        folioOwnerKeypair,
+        folioPDA,
+        generalIxSyncBasketBalance
+      );
+    });
+
+    it(`should run ${GeneralTestCases.InvalidFolioStatus}`, async () => {
+      await assertInvalidFolioStatusTestCase(
+        context,
+        programFolio,
+        folioTokenMint.publicKey,
+        generalIxSyncBasketBalance,
+        FolioStatus.Migrating
+      );
+    });
+  });
+
+  describe("Specific Cases - Sync Basket Balance", () => {
+    it("should credit the surplus of a basket token to the basket", async () => {
+      await generalIxSyncBasketBalance();
+
+      await travelFutureSlot(context);
+
+      // No user has a pending basket here, so everything above the basket is surplus
+      assert.equal(
+        (await getBasketAmount(basketMint.publicKey)).toString(),
+        toRaw(BASKET_AMOUNT + PENDING_AMOUNT + SURPLUS_AMOUNT).toString()
+      );
+      assert.equal(
+        await getFolioBalance(basketMint.publicKey),
+        BigInt(
+          toRaw(BASKET_AMOUNT + PENDING_AMOUNT + SURPLUS_AMOUNT).toString()
+        )
+      );
+    });
+
+    it("should sweep the tokens that aren't in the basket to the recipient", async () => {
+      await syncBasketBalance<true>(
+        context,
+        banksClient,
+        programFolio,
+        folioOwnerKeypair,
+        folioPDA,
+        nonBasketMint.publicKey,
+        folioOwnerKeypair.publicKey
+      );
+
+      await travelFutureSlot(context);
+
+      assert.equal(await getFolioBalance(nonBasketMint.publicKey), BigInt(0));
+      assert.equal(
+        await getTokenBalance(
+          banksClient,
+          getAtaAddress(nonBasketMint.publicKey, folioOwnerKeypair.publicKey)
+        ),
+        BigInt(toRaw(NON_BASKET_AMOUNT).toString())
+      );
+    });
+
+    it("should fail if there is no surplus", async () => {
+      mintToken(context, basketMint.publicKey, BASKET_AMOUNT, folioPDA);
+
+      await travelFutureSlot(context);
+
+      const txnResult = await generalIxSyncBasketBalance();
+
+      assertError(txnResult, "NoSurplusBalanceToSync");
+    });
+
+    it("should fail if the recipient is the folio's token account", async () => {
+      const txnResult = await syncBasketBalance<true>(
+        context,
+        banksClient,
+        programFolio,
+        folioOwnerKeypair,
+        folioPDA,
+        nonBasketMint.publicKey,
+        folioPDA
+      );
+
+      assertError(txnResult, "InvalidRecipientTokenAccount");
+    });
+  });
+
+  describe("Specific Cases - Pending basket created before the folio pending basket", () => {
+    let txnResult: BanksTransactionResultWithMeta;
+
+    beforeEach(async () => {
+      // A folio created before the folio pending basket, with a pending
+      // basket of a user that was never tracked
+      await initFolio(false);
+
+      await createAndSetUserPendingBasket(
+        context,
+        programFolio,
+        folioPDA,
+        userKeypair.publicKey,
+        [
+          new TokenAmount(
+            basketMint.publicKey,
+            toRaw(PENDING_AMOUNT),
+            new BN(0)
+          ),
+        ]
+      );
+
+      await travelFutureSlot(context);
+    });
+
+    it("should fail to sync while the pending baskets aren't tracked", async () => {
+      txnResult = await generalIxSyncBasketBalance();
+
+      assertError(txnResult, "PendingBasketsNotTracked");
+
+      // The sweep of a non basket token is blocked as well
+      txnResult = await syncBasketBalance<true>(
+        context,
+        banksClient,
+        programFolio,
+        folioOwnerKeypair,
+        folioPDA,
+        nonBasketMint.publicKey,
+        folioOwnerKeypair.publicKey
+      );
+
+      assertError(txnResult, "PendingBasketsNotTracked");
+    });
+
+    it("should track the pending basket permissionlessly", async () => {
+      txnResult = await trackUserPendingBasket<true>(
+        banksClient,
+        programFolio,
+        payerKeypair,
+        folioPDA,
+        userKeypair.publicKey
+      );
+
+      await travelFutureSlot(context);
+
+      const userPendingBasket =
+        await programFolio.account.userPendingBasket.fetch(
+          getUserPendingBasketPDA(folioPDA, userKeypair.publicKey)
+        );
+      assert.equal(userPendingBasket.isTracked, 1);
+
+      const folioPendingBasket =
+        await programFolio.account.folioPendingBasket.fetch(
+          getFolioPendingBasketPDA(folioPDA)
+        );
+      const trackedAmount = folioPendingBasket.basket.tokenAmounts.find((ta) =>
+        ta.mint.equals(basketMint.publicKey)
+      );
+      assert.equal(
+        trackedAmount.amountForMinting.toString(),
+        toRaw(PENDING_AMOUNT).toString()
+      );
+      assert.equal(trackedAmount.amountForRedeeming.toString(), "0");
+    });
+
+    it("should fail to track the same pending basket twice", async () => {
+      await trackUserPendingBasket<true>(
+        banksClient,
+        programFolio,
+        payerKeypair,
+        folioPDA,
+        userKeypair.publicKey
+      );
+
+      await travelFutureSlot(context);
+
+      txnResult = await trackUserPendingBasket<true>(
+        banksClient,
+        programFolio,
+        payerKeypair,
+        folioPDA,
+        userKeypair.publicKey
+      );
+
+      assertError(txnResult, "UserPendingBasketAlreadyTracked");
+    });
+
+    it("should fail to mark the pending baskets as tracked if not admin", async () => {
+      txnResult = await setPendingBasketsTracked<true>(
+        banksClient,
+        programFolio,
+        folioOwnerKeypair,
+        folioPDA
+      );
+
+      assertError(txnResult, "Unauthorized");
+    });
+
+    it("should fail to mark the pending baskets as tracked twice", async () => {
+      await setPendingBasketsTracked<true>(
+        banksClient,
+        programFolio,
+        adminKeypair,
+        folioPDA
+      );
+
+      await travelFutureSlot(context);
+
+      txnResult = await setPendingBasketsTracked<true>(
+        banksClient,
+        programFolio,
+        adminKeypair,
+        folioPDA
+      );
+
+      assertError(txnResult, "PendingBasketsAlreadyTracked");
+    });
+
+    it("should only credit the surplus once the pending basket is tracked", async () => {
+      await trackUserPendingBasket<true>(
+        banksClient,
+        programFolio,
+        payerKeypair,
+        folioPDA,
+        userKeypair.publicKey
+      );
+      await setPendingBasketsTracked<true>(
+        banksClient,
+        programFolio,
+        adminKeypair,
+        folioPDA
+      );
+
+      await travelFutureSlot(context);
+
+      const folio = await programFolio.account.folio.fetch(folioPDA);
+      assert.equal(folio.pendingBasketsTracked, 1);
+
+      txnResult = await generalIxSyncBasketBalance();
+
+      await travelFutureSlot(context);
+
+      // The pending amount of the user stays out of the basket
+      assert.equal(
+        (await getBasketAmount(basketMint.publicKey)).toString(),
+        toRaw(BASKET_AMOUNT + SURPLUS_AMOUNT).toString()
+      );
+      assert.equal(
+        await getFolioBalance(basketMint.publicKey),
+        BigInt(
+          toRaw(BASKET_AMOUNT + PENDING_AMOUNT + SURPLUS_AMOUNT).toString()
+        )
+      );
+    });
+  });
+});
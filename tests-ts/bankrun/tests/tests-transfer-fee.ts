@@ -0,0 +1,488 @@
+import { BN, Program, Provider } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import {
+  ExtensionType,
+  getTransferFeeAmount,
+  getTransferFeeConfig,
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  unpackAccount,
+  unpackMint,
+} from "@solana/spl-token";
+import {
+  airdrop,
+  getConnectors,
+  travelFutureSlot,
+} from "../bankrun-program-helper";
+
+import {
+  getAuctionPDA,
+  getFolioBasketPDA,
+  getFolioPDA,
+  getUserPendingBasketPDA,
+} from "../../../utils/pda-helper";
+import {
+  addToBasket,
+  addToPendingBasket,
+  bid,
+  harvestWithheldFees,
+} from "../bankrun-ix-helper";
+import {
+  Auction,
+  buildRemainingAccounts,
+  closeAccount,
+  createAndSetActor,
+  createAndSetAuction,
+  createAndSetAuctionEndsAccount,
+  createAndSetDaoFeeConfig,
+  createAndSetFolio,
+  createAndSetFolioBasket,
+  createAndSetRebalanceAccount,
+  FolioStatus,
+  FolioTokenAmount,
+  Role,
+} from "../bankrun-account-helper";
+import { Folio } from "../../../target/types/folio";
+import { FolioAdmin } from "../../../target/types/folio_admin";
+import { D18, DEFAULT_DECIMALS } from "../../../utils/constants";
+import {
+  getAtaAddress,
+  getTokenBalance,
+  initToken,
+  initToken2022Tx,
+  mintToken,
+  mintToken2022Tx,
+} from "../bankrun-token-helper";
+import { LiteSVM } from "litesvm";
+import * as assert from "assert";
+
+/**
+ * Tests for the basket tokens with a transfer fee, including:
+ * - The basket only accounts for the amount received by the folio, net of
+ *   the transfer fee, when adding to the pending basket or the basket
+ * - Bidders pay the transfer fee of the buy token, so the folio receives the
+ *   full bought amount
+ * - The withheld fees can be harvested, without changing the basket
+ */
+describe("Bankrun - Transfer Fee", () => {
+  let context: LiteSVM;
+  let provider: Provider;
+  let banksClient: LiteSVM;
+
+  let programFolio: Program<Folio>;
+  let programFolioAdmin: Program<FolioAdmin>;
+  let keys: any;
+
+  let payerKeypair: Keypair;
+  let adminKeypair: Keypair;
+
+  let folioOwnerKeypair: Keypair;
+  let userKeypair: Keypair;
+  let bidderKeypair: Keypair;
+
+  let folioTokenMint: Keypair;
+  let feeMint: Keypair;
+  let sellMint: Keypair;
+
+  let folioPDA: PublicKey;
+
+  // 1%, with a maximum fee that is never reached
+  const TRANSFER_FEE = { basisPoints: 100, maximumFee: new BN(1_000_000) };
+
+  const AMOUNT = new BN(10_000);
+  const AMOUNT_AFTER_FEE = new BN(9_900);
+
+  const REBALANCE_NONCE = new BN(1);
+  const AUCTION_ID = new BN(1);
+  const BUY_LIMIT_SPOT = new BN(10000000).mul(D18).div(new BN(10_000));
+
+  async function getWithheldAmounts(): Promise<{
+    folioTokenAccount: bigint;
+    mint: bigint;
+  }> {
+    const folioTokenAccountAddress = getAtaAddress(
+      feeMint.publicKey,
+      folioPDA,
+      TOKEN_2022_PROGRAM_ID
+    );
+    const folioTokenAccount = await context.getAccount(
+      folioTokenAccountAddress
+    );
+    const mint = await context.getAccount(feeMint.publicKey);
+
+    return {
+      folioTokenAccount: getTransferFeeAmount(
+        unpackAccount(
+          folioTokenAccountAddress,
+          { ...folioTokenAccount, data: Buffer.from(folioTokenAccount.data) },
+          TOKEN_2022_PROGRAM_ID
+        )
+      ).withheldAmount,
+      mint: getTransferFeeConfig(
+        unpackMint(
+          feeMint.publicKey,
+          { ...mint, data: Buffer.from(mint.data) },
+          TOKEN_2022_PROGRAM_ID
+        )
+      ).withheldAmount,
+    };
+  }
+
+  async function getFolioFeeMintBalance() {
+    return getTokenBalance(
+      banksClient,
+      getAtaAddress(feeMint.publicKey, folioPDA, TOKEN_2022_PROGRAM_ID)
+    );
+  }
+
+  async function addFeeMintToPendingBasket() {
+    return addToPendingBasket<true>(
+      context,
+      banksClient,
+      programFolio,
+      userKeypair,
+      folioPDA,
+      [{ mint: feeMint.publicKey, amount: AMOUNT }],
+      true,
+      await buildRemainingAccounts(
+        context,
+        [{ mint: feeMint.publicKey, amount: AMOUNT }],
+        userKeypair.publicKey,
+        folioPDA,
+        true,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      ),
+      false,
+      TOKEN_2022_PROGRAM_ID
+    );
+  }
+
+  beforeEach(async () => {
+    ({ keys, programFolio, programFolioAdmin, provider, context } =
+      await getConnectors());
+
+    banksClient = context;
+
+    payerKeypair = provider.wallet.payer;
+
+    adminKeypair = Keypair.fromSecretKey(Uint8Array.from(keys.admin));
+
+    folioOwnerKeypair = Keypair.generate();
+    userKeypair = Keypair.generate();
+    bidderKeypair = Keypair.generate();
+    folioTokenMint = Keypair.generate();
+    feeMint = Keypair.generate();
+    sellMint = Keypair.generate();
+
+    await airdrop(context, payerKeypair.publicKey, 1000);
+    await airdrop(context, adminKeypair.publicKey, 1000);
+    await airdrop(context, folioOwnerKeypair.publicKey, 1000);
+    await airdrop(context, userKeypair.publicKey, 1000);
+    await airdrop(context, bidderKeypair.publicKey, 1000);
+
+    folioPDA = getFolioPDA(folioTokenMint.publicKey);
+
+    await createAndSetFolio(
+      context,
+      programFolio,
+      folioTokenMint.publicKey,
+      FolioStatus.Initialized
+    );
+
+    initToken(
+      context,
+      folioPDA,
+      folioTokenMint,
+      DEFAULT_DECIMALS,
+      new BN(10_000)
+    );
+
+    await initToken2022Tx(
+      context,
+      adminKeypair,
+      feeMint,
+      ExtensionType.TransferFeeConfig,
+      DEFAULT_DECIMALS,
+      null,
+      TRANSFER_FEE
+    );
+
+    // The token accounts of a mint with a transfer fee need the transfer fee
+    // amount extension, so they are created through the token program
+    for (const owner of [
+      folioPDA,
+      userKeypair.publicKey,
+      folioOwnerKeypair.publicKey,
+      bidderKeypair.publicKey,
+    ]) {
+      await mintToken2022Tx(
+        context,
+        adminKeypair,
+        feeMint.publicKey,
+        owner,
+        owner.equals(folioPDA) ? new BN(0) : AMOUNT
+      );
+    }
+
+    initToken(context, adminKeypair.publicKey, sellMint, DEFAULT_DECIMALS);
+    mintToken(context, sellMint.publicKey, 1_000, folioPDA);
+
+    await createAndSetFolioBasket(context, programFolio, folioPDA, [
+      new FolioTokenAmount(sellMint.publicKey, new BN(1_000)),
+    ]);
+
+    await createAndSetActor(
+      context,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      Role.Owner
+    );
+
+    await closeAccount(
+      context,
+      getUserPendingBasketPDA(folioPDA, userKeypair.publicKey)
+    );
+  });
+
+  describe("Specific Cases - Add to Pending Basket", () => {
+    beforeEach(async () => {
+      await travelFutureSlot(context);
+
+      await addFeeMintToPendingBasket();
+    });
+
+    it("should only account for the amount received by the folio", async () => {
+      await travelFutureSlot(context);
+
+      const userPendingBasket =
+        await programFolio.account.userPendingBasket.fetch(
+          getUserPendingBasketPDA(folioPDA, userKeypair.publicKey)
+        );
+      const pendingToken = userPendingBasket.basket.tokenAmounts.find(
+        (token) => token.mint.equals(feeMint.publicKey)
+      );
+
+      assert.equal(
+        pendingToken.amountForMinting.toString(),
+        AMOUNT_AFTER_FEE.toString()
+      );
+      assert.equal(
+        (await getFolioFeeMintBalance()).toString(),
+        AMOUNT_AFTER_FEE.toString()
+      );
+      assert.equal(
+        (await getWithheldAmounts()).folioTokenAccount.toString(),
+        AMOUNT.sub(AMOUNT_AFTER_FEE).toString()
+      );
+    });
+  });
+
+  describe("Specific Cases - Add to Basket", () => {
+    beforeEach(async () => {
+      await travelFutureSlot(context);
+
+      await addToBasket<true>(
+        context,
+        banksClient,
+        programFolio,
+        folioOwnerKeypair,
+        folioPDA,
+        [{ mint: feeMint.publicKey, amount: AMOUNT }],
+        null,
+        folioTokenMint.publicKey,
+
+        true,
+        false,
+        await buildRemainingAccounts(
+          context,
+          [{ mint: feeMint.publicKey, amount: AMOUNT }],
+          folioOwnerKeypair.publicKey,
+          folioPDA,
+          true,
+          true,
+          TOKEN_2022_PROGRAM_ID
+        ),
+        TOKEN_2022_PROGRAM_ID
+      );
+    });
+
+    it("should only account for the amount received by the folio", async () => {
+      await travelFutureSlot(context);
+
+      const folioBasket = await programFolio.account.folioBasket.fetch(
+        getFolioBasketPDA(folioPDA)
+      );
+      const basketToken = folioBasket.basket.tokenAmounts.find((token) =>
+        token.mint.equals(feeMint.publicKey)
+      );
+
+      assert.equal(basketToken.amount.toString(), AMOUNT_AFTER_FEE.toString());
+      assert.equal(
+        (await getFolioFeeMintBalance()).toString(),
+        AMOUNT_AFTER_FEE.toString()
+      );
+    });
+  });
+
+  describe("Specific Cases - Bid", () => {
+    const SELL_AMOUNT = new BN(1_000);
+    // The bidder pays the transfer fee on top of the bought amount
+    const BOUGHT_AMOUNT_WITH_FEE = new BN(1_011);
+
+    let bidderBalanceBefore: bigint;
+
+    beforeEach(async () => {
+      const currentTime = new BN(
+        (await context.getClock()).unixTimestamp.toString()
+      );
+
+      await createAndSetDaoFeeConfig(
+        context,
+        programFolioAdmin,
+        adminKeypair.publicKey,
+        new BN(0)
+      );
+
+      await createAndSetRebalanceAccount(
+        context,
+        programFolio,
+        folioPDA,
+        undefined,
+        undefined,
+        REBALANCE_NONCE
+      );
+      await createAndSetAuctionEndsAccount(
+        context,
+        programFolio,
+        folioPDA,
+        REBALANCE_NONCE,
+        sellMint.publicKey,
+        feeMint.publicKey,
+        new BN(1)
+      );
+
+      const auction = Auction.default(
+        folioPDA,
+        feeMint.publicKey,
+        sellMint.publicKey
+      );
+      auction.id = AUCTION_ID;
+      auction.nonce = REBALANCE_NONCE;
+      auction.start = currentTime;
+      auction.end = currentTime.add(new BN(1000000000));
+      auction.sellLimitSpot = new BN(0);
+      auction.buyLimitSpot = BUY_LIMIT_SPOT;
+      auction.prices.start = new BN(1).mul(D18);
+      auction.prices.end = new BN(1).mul(D18);
+      await createAndSetAuction(context, programFolio, auction, folioPDA);
+
+      await travelFutureSlot(context);
+
+      bidderBalanceBefore = await getTokenBalance(
+        banksClient,
+        getAtaAddress(
+          feeMint.publicKey,
+          bidderKeypair.publicKey,
+          TOKEN_2022_PROGRAM_ID
+        )
+      );
+
+      await bid<true>(
+        context,
+        banksClient,
+        programFolio,
+        bidderKeypair,
+        folioPDA,
+        folioTokenMint.publicKey,
+        getAuctionPDA(folioPDA, REBALANCE_NONCE, AUCTION_ID),
+        REBALANCE_NONCE,
+        SELL_AMOUNT,
+        AMOUNT,
+        false,
+        sellMint.publicKey,
+        feeMint.publicKey,
+        Buffer.from([]),
+        true,
+        [],
+        TOKEN_2022_PROGRAM_ID,
+        TOKEN_PROGRAM_ID
+      );
+    });
+
+    it("should make the folio receive the full bought amount", async () => {
+      await travelFutureSlot(context);
+
+      const folioBasket = await programFolio.account.folioBasket.fetch(
+        getFolioBasketPDA(folioPDA)
+      );
+      const basketToken = folioBasket.basket.tokenAmounts.find((token) =>
+        token.mint.equals(feeMint.publicKey)
+      );
+
+      assert.equal(basketToken.amount.toString(), SELL_AMOUNT.toString());
+      assert.equal(
+        (await getFolioFeeMintBalance()).toString(),
+        SELL_AMOUNT.toString()
+      );
+      assert.equal(
+        (
+          bidderBalanceBefore -
+          (await getTokenBalance(
+            banksClient,
+            getAtaAddress(
+              feeMint.publicKey,
+              bidderKeypair.publicKey,
+              TOKEN_2022_PROGRAM_ID
+            )
+          ))
+        ).toString(),
+        BOUGHT_AMOUNT_WITH_FEE.toString()
+      );
+    });
+  });
+
+  describe("Specific Cases - Harvest Withheld Fees", () => {
+    beforeEach(async () => {
+      await travelFutureSlot(context);
+
+      await addFeeMintToPendingBasket();
+
+      await travelFutureSlot(context);
+
+      await harvestWithheldFees<true>(
+        banksClient,
+        programFolio,
+        userKeypair,
+        folioPDA,
+        feeMint.publicKey
+      );
+    });
+
+    it("should move the withheld fees to the mint, without changing the basket", async () => {
+      await travelFutureSlot(context);
+
+      const withheldAmounts = await getWithheldAmounts();
+      assert.equal(withheldAmounts.folioTokenAccount.toString(), "0");
+      assert.equal(
+        withheldAmounts.mint.toString(),
+        AMOUNT.sub(AMOUNT_AFTER_FEE).toString()
+      );
+
+      // The folio balance still matches the amount in the pending basket
+      const userPendingBasket =
+        await programFolio.account.userPendingBasket.fetch(
+          getUserPendingBasketPDA(folioPDA, userKeypair.publicKey)
+        );
+      const pendingToken = userPendingBasket.basket.tokenAmounts.find(
+        (token) => token.mint.equals(feeMint.publicKey)
+      );
+
+      assert.equal(
+        (await getFolioFeeMintBalance()).toString(),
+        pendingToken.amountForMinting.toString()
+      );
+    });
+  });
+});
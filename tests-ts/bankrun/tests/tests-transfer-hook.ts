@@ -0,0 +1,734 @@
+import { BN, Program, Provider } from "@coral-xyz/anchor";
+import { AccountMeta, Keypair, PublicKey } from "@solana/web3.js";
+import {
+  ExtensionType,
+  getAssociatedTokenAddressSync,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+import {
+  airdrop,
+  assertError,
+  BanksTransactionResultWithMeta,
+  getConnectors,
+  travelFutureSlot,
+} from "../bankrun-program-helper";
+
+import {
+  getAuctionPDA,
+  getFolioBasketPDA,
+  getFolioPDA,
+  getTransferHookRegistrarPDA,
+  getUserPendingBasketPDA,
+} from "../../../utils/pda-helper";
+import {
+  addToBasket,
+  addToPendingBasket,
+  bid,
+  initializeTransferHookExtraAccountMetaList,
+  redeemFromPendingBasket,
+  removeFromPendingBasket,
+} from "../bankrun-ix-helper";
+import {
+  Auction,
+  buildRemainingAccounts,
+  buildRemainingAccountsForTransferHook,
+  closeAccount,
+  createAndSetActor,
+  createAndSetAuction,
+  createAndSetAuctionEndsAccount,
+  createAndSetDaoFeeConfig,
+  createAndSetFolio,
+  createAndSetFolioBasket,
+  createAndSetRebalanceAccount,
+  createAndSetTransferHookRegistrar,
+  createAndSetUserPendingBasket,
+  FolioStatus,
+  FolioTokenAmount,
+  getExtraAccountMetaListPDA,
+  getTransferCounterPDA,
+  Role,
+  TokenAmount,
+} from "../bankrun-account-helper";
+import { Folio } from "../../../target/types/folio";
+import { FolioAdmin } from "../../../target/types/folio_admin";
+import { TransferHookMock } from "../../../target/types/transfer_hook_mock";
+import { D18, DEFAULT_DECIMALS } from "../../../utils/constants";
+import {
+  getTokenBalance,
+  initToken,
+  initToken2022Tx,
+  mintToken2022Tx,
+} from "../bankrun-token-helper";
+import { LiteSVM } from "litesvm";
+import * as assert from "assert";
+
+/**
+ * Tests for the basket tokens with an active transfer hook, including:
+ * - Transfer hook programs need to be in the transfer hook registrar of the
+ *   folio admin program for the tokens to be added to the folio
+ * - The transfer hook is invoked on every transfer path (pending baskets,
+ *   add to basket, bids)
+ * - The accounts required by the transfer hook are validated
+ */
+describe("Bankrun - Transfer Hook", () => {
+  let context: LiteSVM;
+  let provider: Provider;
+  let banksClient: LiteSVM;
+
+  let programFolio: Program<Folio>;
+  let programFolioAdmin: Program<FolioAdmin>;
+  let programTransferHookMock: Program<TransferHookMock>;
+  let keys: any;
+
+  let payerKeypair: Keypair;
+  let adminKeypair: Keypair;
+
+  let folioOwnerKeypair: Keypair;
+  let userKeypair: Keypair;
+  let bidderKeypair: Keypair;
+
+  let folioTokenMint: Keypair;
+  let hookedMint: Keypair;
+  let otherHookedMint: Keypair;
+
+  let folioPDA: PublicKey;
+
+  let transferHookProgramId: PublicKey;
+
+  const AMOUNT = new BN(1_000);
+  const REBALANCE_NONCE = new BN(1);
+  const AUCTION_ID = new BN(1);
+  const BUY_LIMIT_SPOT = new BN(10000000).mul(D18).div(new BN(10_000));
+
+  function getAta(mint: PublicKey, owner: PublicKey) {
+    return getAssociatedTokenAddressSync(
+      mint,
+      owner,
+      true,
+      TOKEN_2022_PROGRAM_ID
+    );
+  }
+
+  async function getTransferCounter(mint: PublicKey) {
+    return programTransferHookMock.account.transferCounter.fetch(
+      getTransferCounterPDA(mint, transferHookProgramId)
+    );
+  }
+
+  async function initHookedMint(mint: Keypair) {
+    await initToken2022Tx(
+      context,
+      adminKeypair,
+      mint,
+      ExtensionType.TransferHook,
+      DEFAULT_DECIMALS,
+      transferHookProgramId
+    );
+
+    await initializeTransferHookExtraAccountMetaList(
+      banksClient,
+      programTransferHookMock,
+      adminKeypair,
+      mint.publicKey
+    );
+
+    // The token accounts of a hooked mint need the transfer hook account
+    // extension, so they are created through the token program
+    await mintToken2022Tx(
+      context,
+      adminKeypair,
+      mint.publicKey,
+      folioPDA,
+      AMOUNT
+    );
+    await mintToken2022Tx(
+      context,
+      adminKeypair,
+      mint.publicKey,
+      userKeypair.publicKey,
+      AMOUNT
+    );
+    await mintToken2022Tx(
+      context,
+      adminKeypair,
+      mint.publicKey,
+      folioOwnerKeypair.publicKey,
+      AMOUNT
+    );
+    await mintToken2022Tx(
+      context,
+      adminKeypair,
+      mint.publicKey,
+      bidderKeypair.publicKey,
+      AMOUNT
+    );
+  }
+
+  async function pendingBasketRemainingAccounts(
+    sender: PublicKey,
+    recipient: PublicKey,
+    transferHookAccounts: AccountMeta[] = buildRemainingAccountsForTransferHook(
+      hookedMint.publicKey,
+      transferHookProgramId
+    )
+  ): Promise<AccountMeta[]> {
+    return [
+      ...(await buildRemainingAccounts(
+        context,
+        [{ mint: hookedMint.publicKey, amount: AMOUNT }],
+        sender,
+        recipient,
+        true,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      )),
+      ...transferHookAccounts,
+    ];
+  }
+
+  beforeEach(async () => {
+    ({
+      keys,
+      programFolio,
+      programFolioAdmin,
+      programTransferHookMock,
+      provider,
+      context,
+    } = await getConnectors());
+
+    banksClient = context;
+
+    payerKeypair = provider.wallet.payer;
+
+    adminKeypair = Keypair.fromSecretKey(Uint8Array.from(keys.admin));
+
+    folioOwnerKeypair = Keypair.generate();
+    userKeypair = Keypair.generate();
+    bidderKeypair = Keypair.generate();
+    folioTokenMint = Keypair.generate();
+    hookedMint = Keypair.generate();
+    otherHookedMint = Keypair.generate();
+
+    transferHookProgramId = programTransferHookMock.programId;
+
+    await airdrop(context, payerKeypair.publicKey, 1000);
+    await airdrop(context, adminKeypair.publicKey, 1000);
+    await airdrop(context, folioOwnerKeypair.publicKey, 1000);
+    await airdrop(context, userKeypair.publicKey, 1000);
+    await airdrop(context, bidderKeypair.publicKey, 1000);
+
+    folioPDA = getFolioPDA(folioTokenMint.publicKey);
+
+    await createAndSetFolio(
+      context,
+      programFolio,
+      folioTokenMint.publicKey,
+      FolioStatus.Initialized
+    );
+
+    initToken(
+      context,
+      folioPDA,
+      folioTokenMint,
+      DEFAULT_DECIMALS,
+      new BN(10_000)
+    );
+
+    await initHookedMint(hookedMint);
+    await initHookedMint(otherHookedMint);
+
+    await createAndSetFolioBasket(context, programFolio, folioPDA, [
+      new FolioTokenAmount(hookedMint.publicKey, AMOUNT),
+    ]);
+
+    await createAndSetActor(
+      context,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      Role.Owner
+    );
+
+    await createAndSetTransferHookRegistrar(context, programFolioAdmin, [
+      transferHookProgramId,
+    ]);
+
+    await closeAccount(
+      context,
+      getUserPendingBasketPDA(folioPDA, userKeypair.publicKey)
+    );
+  });
+
+  describe("Specific Cases - Add to Pending Basket", () => {
+    const TEST_CASES = [
+      {
+        desc: "(transfer hook program allowed, success)",
+        allowedPrograms: () => [transferHookProgramId],
+        transferHookAccounts: () =>
+          buildRemainingAccountsForTransferHook(
+            hookedMint.publicKey,
+            transferHookProgramId
+          ),
+        expectedError: null,
+      },
+      {
+        desc: "(transfer hook program not allowed, errors out)",
+        allowedPrograms: () => [],
+        transferHookAccounts: () =>
+          buildRemainingAccountsForTransferHook(
+            hookedMint.publicKey,
+            transferHookProgramId
+          ),
+        expectedError: "TransferHookProgramNotAllowed",
+      },
+      {
+        desc: "(missing transfer hook accounts, errors out)",
+        allowedPrograms: () => [transferHookProgramId],
+        transferHookAccounts: () => [],
+        expectedError: "MissingRemainingAccount",
+      },
+      {
+        desc: "(invalid transfer hook program, errors out)",
+        allowedPrograms: () => [transferHookProgramId],
+        transferHookAccounts: () => {
+          const accounts = buildRemainingAccountsForTransferHook(
+            hookedMint.publicKey,
+            transferHookProgramId
+          );
+          accounts[0].pubkey = programFolio.programId;
+          return accounts;
+        },
+        expectedError: "InvalidTransferHookProgram",
+      },
+      {
+        desc: "(invalid extra account meta list, errors out)",
+        allowedPrograms: () => [transferHookProgramId],
+        transferHookAccounts: () => {
+          const accounts = buildRemainingAccountsForTransferHook(
+            hookedMint.publicKey,
+            transferHookProgramId
+          );
+          accounts[1].pubkey = getExtraAccountMetaListPDA(
+            otherHookedMint.publicKey,
+            transferHookProgramId
+          );
+          return accounts;
+        },
+        expectedError: "InvalidExtraAccountMetaList",
+      },
+    ];
+
+    TEST_CASES.forEach(
+      ({ desc, allowedPrograms, transferHookAccounts, expectedError }) => {
+        describe(`When ${desc}`, () => {
+          let txnResult: BanksTransactionResultWithMeta;
+          let folioBalanceBefore: bigint;
+
+          beforeEach(async () => {
+            await createAndSetTransferHookRegistrar(
+              context,
+              programFolioAdmin,
+              allowedPrograms()
+            );
+
+            await travelFutureSlot(context);
+
+            folioBalanceBefore = await getTokenBalance(
+              banksClient,
+              getAta(hookedMint.publicKey, folioPDA)
+            );
+
+            txnResult = await addToPendingBasket<true>(
+              context,
+              banksClient,
+              programFolio,
+              userKeypair,
+              folioPDA,
+              [{ mint: hookedMint.publicKey, amount: AMOUNT }],
+              true,
+              await pendingBasketRemainingAccounts(
+                userKeypair.publicKey,
+                folioPDA,
+                transferHookAccounts()
+              ),
+              false,
+              TOKEN_2022_PROGRAM_ID
+            );
+          });
+
+          if (expectedError) {
+            it("should fail with expected error", () => {
+              assertError(txnResult, expectedError);
+            });
+          } else {
+            it("should succeed", async () => {
+              await travelFutureSlot(context);
+
+              const transferCounter = await getTransferCounter(
+                hookedMint.publicKey
+              );
+              assert.equal(transferCounter.transfers.toNumber(), 1);
+              assert.equal(
+                transferCounter.amount.toString(),
+                AMOUNT.toString()
+              );
+
+              assert.equal(
+                await getTokenBalance(
+                  banksClient,
+                  getAta(hookedMint.publicKey, folioPDA)
+                ),
+                folioBalanceBefore + BigInt(AMOUNT.toString())
+              );
+
+              const userPendingBasket =
+                await programFolio.account.userPendingBasket.fetch(
+                  getUserPendingBasketPDA(folioPDA, userKeypair.publicKey)
+                );
+              assert.equal(
+                userPendingBasket.basket.tokenAmounts[0].amountForMinting.toString(),
+                AMOUNT.toString()
+              );
+            });
+          }
+        });
+      }
+    );
+  });
+
+  describe("Specific Cases - Remove and Redeem from Pending Basket", () => {
+    const TEST_CASES = [
+      {
+        desc: "(remove from pending basket, success)",
+        isRedeem: false,
+        allowedPrograms: () => [transferHookProgramId],
+      },
+      {
+        desc: "(remove from pending basket, transfer hook program not allowed anymore, success)",
+        isRedeem: false,
+        allowedPrograms: () => [],
+      },
+      {
+        desc: "(redeem from pending basket, success)",
+        isRedeem: true,
+        allowedPrograms: () => [transferHookProgramId],
+      },
+      {
+        desc: "(redeem from pending basket, transfer hook program not allowed anymore, success)",
+        isRedeem: true,
+        allowedPrograms: () => [],
+      },
+    ];
+
+    TEST_CASES.forEach(({ desc, isRedeem, allowedPrograms }) => {
+      describe(`When ${desc}`, () => {
+        let txnResult: BanksTransactionResultWithMeta;
+        let userBalanceBefore: bigint;
+
+        beforeEach(async () => {
+          // Outbound transfers are never restricted, so users can always
+          // withdraw their tokens
+          await createAndSetTransferHookRegistrar(
+            context,
+            programFolioAdmin,
+            allowedPrograms()
+          );
+
+          await createAndSetUserPendingBasket(
+            context,
+            programFolio,
+            folioPDA,
+            userKeypair.publicKey,
+            [
+              new TokenAmount(
+                hookedMint.publicKey,
+                isRedeem ? new BN(0) : AMOUNT,
+                isRedeem ? AMOUNT : new BN(0)
+              ),
+            ]
+          );
+
+          await travelFutureSlot(context);
+
+          userBalanceBefore = await getTokenBalance(
+            banksClient,
+            getAta(hookedMint.publicKey, userKeypair.publicKey)
+          );
+
+          const tokens = [{ mint: hookedMint.publicKey, amount: AMOUNT }];
+          const remainingAccounts = await pendingBasketRemainingAccounts(
+            folioPDA,
+            userKeypair.publicKey
+          );
+
+          if (isRedeem) {
+            txnResult = await redeemFromPendingBasket<true>(
+              context,
+              banksClient,
+              programFolio,
+              userKeypair,
+              folioPDA,
+              tokens,
+              true,
+              remainingAccounts,
+              TOKEN_2022_PROGRAM_ID
+            );
+          } else {
+            txnResult = await removeFromPendingBasket<true>(
+              context,
+              banksClient,
+              programFolio,
+              userKeypair,
+              folioPDA,
+              tokens,
+              true,
+              remainingAccounts,
+              TOKEN_2022_PROGRAM_ID
+            );
+          }
+        });
+
+        it("should succeed", async () => {
+          await travelFutureSlot(context);
+
+          const transferCounter = await getTransferCounter(
+            hookedMint.publicKey
+          );
+          assert.equal(transferCounter.transfers.toNumber(), 1);
+
+          assert.equal(
+            await getTokenBalance(
+              banksClient,
+              getAta(hookedMint.publicKey, userKeypair.publicKey)
+            ),
+            userBalanceBefore + BigInt(AMOUNT.toString())
+          );
+        });
+      });
+    });
+  });
+
+  describe("Specific Cases - Add to Basket", () => {
+    const TEST_CASES = [
+      {
+        desc: "(transfer hook program allowed, success)",
+        setRegistrar: true,
+        expectedError: null,
+      },
+      {
+        desc: "(transfer hook registrar not set, errors out)",
+        setRegistrar: false,
+        expectedError: "UnsupportedSPLToken",
+      },
+    ];
+
+    TEST_CASES.forEach(({ desc, setRegistrar, expectedError }) => {
+      describe(`When ${desc}`, () => {
+        let txnResult: BanksTransactionResultWithMeta;
+
+        beforeEach(async () => {
+          if (!setRegistrar) {
+            await closeAccount(context, getTransferHookRegistrarPDA());
+          }
+
+          await travelFutureSlot(context);
+
+          txnResult = await addToBasket<true>(
+            context,
+            banksClient,
+            programFolio,
+            folioOwnerKeypair,
+            folioPDA,
+            [{ mint: otherHookedMint.publicKey, amount: AMOUNT }],
+            null,
+            folioTokenMint.publicKey,
+
+            true,
+            false,
+            [
+              ...(await buildRemainingAccounts(
+                context,
+                [{ mint: otherHookedMint.publicKey, amount: AMOUNT }],
+                folioOwnerKeypair.publicKey,
+                folioPDA,
+                true,
+                true,
+                TOKEN_2022_PROGRAM_ID
+              )),
+              ...buildRemainingAccountsForTransferHook(
+                otherHookedMint.publicKey,
+                transferHookProgramId
+              ),
+            ],
+            TOKEN_2022_PROGRAM_ID
+          );
+        });
+
+        if (expectedError) {
+          it("should fail with expected error", () => {
+            assertError(txnResult, expectedError);
+          });
+        } else {
+          it("should succeed", async () => {
+            await travelFutureSlot(context);
+
+            const transferCounter = await getTransferCounter(
+              otherHookedMint.publicKey
+            );
+            assert.equal(transferCounter.transfers.toNumber(), 1);
+
+            const folioBasket = await programFolio.account.folioBasket.fetch(
+              getFolioBasketPDA(folioPDA)
+            );
+            const basketToken = folioBasket.basket.tokenAmounts.find((token) =>
+              token.mint.equals(otherHookedMint.publicKey)
+            );
+            assert.equal(basketToken.amount.toString(), AMOUNT.toString());
+          });
+        }
+      });
+    });
+  });
+
+  describe("Specific Cases - Bid", () => {
+    const TEST_CASES = [
+      {
+        desc: "(hooked sell and buy mints, transfer hook program allowed, success)",
+        allowedPrograms: () => [transferHookProgramId],
+        expectedError: null,
+      },
+      {
+        desc: "(buy mint transfer hook program not allowed, errors out)",
+        allowedPrograms: () => [],
+        expectedError: "TransferHookProgramNotAllowed",
+      },
+    ];
+
+    TEST_CASES.forEach(({ desc, allowedPrograms, expectedError }) => {
+      describe(`When ${desc}`, () => {
+        let txnResult: BanksTransactionResultWithMeta;
+
+        beforeEach(async () => {
+          const currentTime = new BN(
+            (await context.getClock()).unixTimestamp.toString()
+          );
+
+          await createAndSetTransferHookRegistrar(
+            context,
+            programFolioAdmin,
+            allowedPrograms()
+          );
+
+          await createAndSetDaoFeeConfig(
+            context,
+            programFolioAdmin,
+            adminKeypair.publicKey,
+            new BN(0)
+          );
+
+          await createAndSetRebalanceAccount(
+            context,
+            programFolio,
+            folioPDA,
+            undefined,
+            undefined,
+            REBALANCE_NONCE
+          );
+          await createAndSetAuctionEndsAccount(
+            context,
+            programFolio,
+            folioPDA,
+            REBALANCE_NONCE,
+            hookedMint.publicKey,
+            otherHookedMint.publicKey,
+            new BN(1)
+          );
+
+          const auction = Auction.default(
+            folioPDA,
+            otherHookedMint.publicKey,
+            hookedMint.publicKey
+          );
+          auction.id = AUCTION_ID;
+          auction.nonce = REBALANCE_NONCE;
+          auction.start = currentTime;
+          auction.end = currentTime.add(new BN(1000000000));
+          auction.sellLimitSpot = new BN(0);
+          auction.buyLimitSpot = BUY_LIMIT_SPOT;
+          auction.prices.start = new BN(1).mul(D18);
+          auction.prices.end = new BN(1).mul(D18);
+          await createAndSetAuction(context, programFolio, auction, folioPDA);
+
+          await travelFutureSlot(context);
+
+          txnResult = await bid<true>(
+            context,
+            banksClient,
+            programFolio,
+            bidderKeypair,
+            folioPDA,
+            folioTokenMint.publicKey,
+            getAuctionPDA(folioPDA, REBALANCE_NONCE, AUCTION_ID),
+            REBALANCE_NONCE,
+            AMOUNT,
+            AMOUNT,
+            false,
+            hookedMint.publicKey,
+            otherHookedMint.publicKey,
+            Buffer.from([]),
+            true,
+            [
+              ...buildRemainingAccountsForTransferHook(
+                hookedMint.publicKey,
+                transferHookProgramId
+              ),
+              ...buildRemainingAccountsForTransferHook(
+                otherHookedMint.publicKey,
+                transferHookProgramId
+              ),
+            ],
+            TOKEN_2022_PROGRAM_ID,
+            TOKEN_2022_PROGRAM_ID
+          );
+        });
+
+        if (expectedError) {
+          it("should fail with expected error", () => {
+            assertError(txnResult, expectedError);
+          });
+        } else {
+          it("should succeed", async () => {
+            await travelFutureSlot(context);
+
+            // Both the sell and buy transfers invoked the transfer hook
+            const sellTransferCounter = await getTransferCounter(
+              hookedMint.publicKey
+            );
+            assert.equal(sellTransferCounter.transfers.toNumber(), 1);
+            assert.equal(
+              sellTransferCounter.amount.toString(),
+              AMOUNT.toString()
+            );
+
+            const buyTransferCounter = await getTransferCounter(
+              otherHookedMint.publicKey
+            );
+            assert.equal(buyTransferCounter.transfers.toNumber(), 1);
+            assert.equal(
+              buyTransferCounter.amount.toString(),
+              AMOUNT.toString()
+            );
+
+            assert.equal(
+              await getTokenBalance(
+                banksClient,
+                getAta(otherHookedMint.publicKey, folioPDA)
+              ),
+              BigInt(AMOUNT.mul(new BN(2)).toString())
+            );
+          });
+        }
+      });
+    });
+  });
+});
@@ -0,0 +1,325 @@
+import { BN, Program, Provider } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import {
+  airdrop,
+  assertError,
+  getConnectors,
+  setClock,
+  travelFutureSlot,
+} from "../bankrun-program-helper";
+
+import {
+  getFolioPDA,
+  getKillRecordPDA,
+  getUnkillConfigPDA,
+} from "../../../utils/pda-helper";
+import { killFolio, setUnkillConfig, unkillFolio } from "../bankrun-ix-helper";
+import {
+  closeAccount,
+  createAndSetActor,
+  createAndSetFolio,
+  FolioStatus,
+  Role,
+} from "../bankrun-account-helper";
+import { Folio } from "../../../target/types/folio";
+import { FolioAdmin } from "../../../target/types/folio_admin";
+import {
+  assertInvalidFolioStatusTestCase,
+  assertNonAdminTestCase,
+  assertNotValidRoleTestCase,
+  GeneralTestCases,
+} from "../bankrun-general-tests-helper";
+import {
+  DEFAULT_DECIMALS,
+  MAX_UNKILL_FOLIO_TIMELOCK,
+  UNKILL_FOLIO_TIMELOCK,
+} from "../../../utils/constants";
+import { initToken } from "../bankrun-token-helper";
+import { LiteSVM } from "litesvm";
+import * as assert from "assert";
+import { TestHelper } from "../../../utils/test-helper";
+
+/**
+ * Tests for killing and unkilling a folio, including:
+ * - The kill records the time of the kill and the unkill timelock of the unkill config
+ * - The folio can only be unkilled by its owner once the timelock has passed
+ * - A folio killed before kill records existed gets one on its first unkill
+ * - A folio whose migration started can't be unkilled
+ */
+describe("Bankrun - Unkill Folio", () => {
+  let context: LiteSVM;
+  let provider: Provider;
+  let banksClient: LiteSVM;
+
+  let programFolioAdmin: Program<FolioAdmin>;
+  let programFolio: Program<Folio>;
+  let keys: any;
+
+  let payerKeypair: Keypair;
+  let adminKeypair: Keypair;
+
+  let folioOwnerKeypair: Keypair;
+
+  let folioTokenMint: Keypair;
+
+  let folioPDA: PublicKey;
+
+  const generalIxKillFolio = () =>
+    killFolio<true>(banksClient, programFolio, folioOwnerKeypair, folioPDA);
+
+  const generalIxUnkillFolio = () =>
+    unkillFolio<true>(
+      banksClient,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      folioTokenMint.publicKey
+    );
+
+  function getCurrentTime() {
+    return Number(context.getClock().unixTimestamp);
+  }
+
+  async function getFolioStatus() {
+    const folio = await programFolio.account.folio.fetch(folioPDA);
+
+    return folio.status;
+  }
+
+  async function getKillRecord() {
+    return programFolio.account.killRecord.fetch(getKillRecordPDA(folioPDA));
+  }
+
+  beforeEach(async () => {
+    ({ keys, programFolioAdmin, programFolio, provider, context } =
+      await getConnectors());
+
+    banksClient = context;
+
+    payerKeypair = provider.wallet.payer;
+
+    adminKeypair = Keypair.fromSecretKey(Uint8Array.from(keys.admin));
+
+    folioOwnerKeypair = Keypair.generate();
+    folioTokenMint = Keypair.generate();
+
+    await airdrop(context, payerKeypair.publicKey, 1000);
+    await airdrop(context, adminKeypair.publicKey, 1000);
+    await airdrop(context, folioOwnerKeypair.publicKey, 1000);
+
+    folioPDA = getFolioPDA(folioTokenMint.publicKey);
+
+    // The default unkill timelock is used until the admin sets the unkill config
+    await closeAccount(context, getUnkillConfigPDA());
+    await closeAccount(context, getKillRecordPDA(folioPDA));
+
+    await createAndSetFolio(context, programFolio, folioTokenMint.publicKey);
+
+    initToken(context, folioPDA, folioTokenMint, DEFAULT_DECIMALS);
+
+    await createAndSetActor(
+      context,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      Role.Owner
+    );
+  });
+
+  describe("General Tests", () => {
+    it(`should run ${GeneralTestCases.NotAdmin} for set unkill config`, async () => {
+      await assertNonAdminTestCase(context, () =>
+        setUnkillConfig<false>(
+          banksClient,
+          programFolioAdmin,
+          adminKeypair,
+          new BN(3600),
+          false
+        )
+      );
+    });
+
+    it(`should run ${GeneralTestCases.NotRole} for kill folio`, async () => {
+      await assertNotValidRoleTestCase(
+        context,
+        programFolio,
+        folioOwnerKeypair,
+        folioPDA,
+        generalIxKillFolio
+      );
+    });
+
+    it(`should run ${GeneralTestCases.NotRole} for unkill folio`, async () => {
+      await generalIxKillFolio();
+
+      await travelFutureSlot(context);
+
+      await assertNotValidRoleTestCase(
+        context,
+        programFolio,
+        folioOwnerKeypair,
+        folioPDA,
+        generalIxUnkillFolio
+      );
+    });
+
+    it(`should run ${GeneralTestCases.InvalidFolioStatus} for unkill folio for INITIALIZED & MIGRATING`, async () => {
+      await assertInvalidFolioStatusTestCase(
+        context,
+        programFolio,
+        folioTokenMint.publicKey,
+        generalIxUnkillFolio,
+        FolioStatus.Initialized
+      );
+
+      await assertInvalidFolioStatusTestCase(
+        context,
+        programFolio,
+        folioTokenMint.publicKey,
+        generalIxUnkillFolio,
+        FolioStatus.Migrating
+      );
+    });
+  });
+
+  it("should fail to set an unkill timelock over the max", async () => {
+    const txnResult = await setUnkillConfig<true>(
+      banksClient,
+      programFolioAdmin,
+      adminKeypair,
+      MAX_UNKILL_FOLIO_TIMELOCK.addn(1)
+    );
+
+    assertError(txnResult, "InvalidUnkillTimelock");
+  });
+
+  it("should record the kill with the default unkill timelock", async () => {
+    const killedAt = getCurrentTime();
+
+    await generalIxKillFolio();
+
+    await travelFutureSlot(context);
+
+    assert.equal(await getFolioStatus(), FolioStatus.Killed);
+
+    const killRecord = await getKillRecord();
+    assert.equal(killRecord.folio.toBase58(), folioPDA.toBase58());
+    assert.equal(killRecord.killedAt.toNumber() >= killedAt, true);
+    assert.equal(killRecord.unkillTimelock.eq(UNKILL_FOLIO_TIMELOCK), true);
+  });
+
+  it("should keep the unkill timelock of the kill when the unkill config changes", async () => {
+    await setUnkillConfig<true>(
+      banksClient,
+      programFolioAdmin,
+      adminKeypair,
+      new BN(3600)
+    );
+
+    await travelFutureSlot(context);
+
+    await generalIxKillFolio();
+
+    await travelFutureSlot(context);
+
+    await setUnkillConfig<true>(
+      banksClient,
+      programFolioAdmin,
+      adminKeypair,
+      MAX_UNKILL_FOLIO_TIMELOCK
+    );
+
+    await travelFutureSlot(context);
+
+    const killRecord = await getKillRecord();
+    assert.equal(killRecord.unkillTimelock.toNumber(), 3600);
+
+    setClock(context, killRecord.killedAt.toNumber() + 3600);
+
+    await generalIxUnkillFolio();
+
+    await travelFutureSlot(context);
+
+    assert.equal(await getFolioStatus(), FolioStatus.Initialized);
+  });
+
+  it("should fail to unkill before the unkill timelock has passed", async () => {
+    await generalIxKillFolio();
+
+    await travelFutureSlot(context);
+
+    const txnResult = await generalIxUnkillFolio();
+
+    assertError(txnResult, "UnkillTimelockNotPassed");
+  });
+
+  it("should unkill once the unkill timelock has passed", async () => {
+    await generalIxKillFolio();
+
+    await travelFutureSlot(context);
+
+    const killRecord = await getKillRecord();
+    setClock(
+      context,
+      killRecord.killedAt.add(killRecord.unkillTimelock).toNumber()
+    );
+
+    await generalIxUnkillFolio();
+
+    await travelFutureSlot(context);
+
+    assert.equal(await getFolioStatus(), FolioStatus.Initialized);
+    TestHelper.assertAccountIsClosed(
+      banksClient.getAccount(getKillRecordPDA(folioPDA))
+    );
+  });
+
+  it("should start the unkill timelock of a folio killed without a kill record", async () => {
+    await createAndSetFolio(
+      context,
+      programFolio,
+      folioTokenMint.publicKey,
+      FolioStatus.Killed
+    );
+
+    await travelFutureSlot(context);
+
+    const firstUnkillAt = getCurrentTime();
+
+    await generalIxUnkillFolio();
+
+    await travelFutureSlot(context);
+
+    // Only the kill record is created, the folio stays killed
+    assert.equal(await getFolioStatus(), FolioStatus.Killed);
+
+    const killRecord = await getKillRecord();
+    assert.equal(killRecord.killedAt.toNumber() >= firstUnkillAt, true);
+
+    assertError(await generalIxUnkillFolio(), "UnkillTimelockNotPassed");
+  });
+
+  it("should fail to unkill once the migration started", async () => {
+    await generalIxKillFolio();
+
+    await travelFutureSlot(context);
+
+    // The mint authority is moved to the new folio when the migration starts
+    initToken(
+      context,
+      Keypair.generate().publicKey,
+      folioTokenMint,
+      DEFAULT_DECIMALS
+    );
+
+    const killRecord = await getKillRecord();
+    setClock(
+      context,
+      killRecord.killedAt.add(killRecord.unkillTimelock).toNumber()
+    );
+
+    const txnResult = await generalIxUnkillFolio();
+
+    assertError(txnResult, "InvalidFolioStatus");
+  });
+});
@@ -0,0 +1,137 @@
+import { Program, Provider } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import {
+  airdrop,
+  assertError,
+  getConnectors,
+  travelFutureSlot,
+} from "../bankrun-program-helper";
+
+import { getActorPDA, getFolioPDA } from "../../../utils/pda-helper";
+import { upgradeAccount } from "../bankrun-ix-helper";
+import {
+  createAndSetActor,
+  createAndSetFolio,
+  Role,
+} from "../bankrun-account-helper";
+import { Folio } from "../../../target/types/folio";
+import { DEFAULT_DECIMALS } from "../../../utils/constants";
+import { initToken } from "../bankrun-token-helper";
+import { LiteSVM } from "litesvm";
+import * as assert from "assert";
+
+/**
+ * Tests for upgrading versioned accounts to their current layout, including:
+ * - An account with an older layout is upgraded to the current version
+ * - An account already on the current version can't be upgraded again
+ * - Only versioned accounts of the folio program can be upgraded
+ *
+ * Upgrading a legacy fee distribution is tested with the close fee distribution tests.
+ */
+describe("Bankrun - Upgrade Account", () => {
+  let context: LiteSVM;
+  let provider: Provider;
+  let banksClient: LiteSVM;
+
+  let programFolio: Program<Folio>;
+
+  let payerKeypair: Keypair;
+
+  let folioOwnerKeypair: Keypair;
+
+  let folioTokenMint: Keypair;
+
+  let folioPDA: PublicKey;
+
+  async function getFolioVersion() {
+    const folio = await programFolio.account.folio.fetch(folioPDA);
+
+    return folio.version;
+  }
+
+  beforeEach(async () => {
+    ({ programFolio, provider, context } = await getConnectors());
+
+    banksClient = context;
+
+    payerKeypair = provider.wallet.payer;
+
+    folioOwnerKeypair = Keypair.generate();
+    folioTokenMint = Keypair.generate();
+
+    await airdrop(context, payerKeypair.publicKey, 1000);
+    await airdrop(context, folioOwnerKeypair.publicKey, 1000);
+
+    folioPDA = getFolioPDA(folioTokenMint.publicKey);
+
+    // Created with the layout before the version header was added (version 0)
+    await createAndSetFolio(context, programFolio, folioTokenMint.publicKey);
+
+    initToken(context, folioPDA, folioTokenMint, DEFAULT_DECIMALS);
+
+    await createAndSetActor(
+      context,
+      programFolio,
+      folioOwnerKeypair,
+      folioPDA,
+      Role.Owner
+    );
+  });
+
+  it("should upgrade a folio to the current version", async () => {
+    assert.equal(await getFolioVersion(), 0);
+
+    await upgradeAccount<true>(
+      banksClient,
+      programFolio,
+      payerKeypair,
+      folioPDA
+    );
+
+    await travelFutureSlot(context);
+
+    assert.equal(await getFolioVersion(), 1);
+  });
+
+  it("should fail if the account is already upgraded", async () => {
+    await upgradeAccount<true>(
+      banksClient,
+      programFolio,
+      payerKeypair,
+      folioPDA
+    );
+
+    await travelFutureSlot(context);
+
+    const txnResult = await upgradeAccount<true>(
+      banksClient,
+      programFolio,
+      payerKeypair,
+      folioPDA
+    );
+
+    assertError(txnResult, "AccountAlreadyUpgraded");
+  });
+
+  it("should fail if the account isn't owned by the folio program", async () => {
+    const txnResult = await upgradeAccount<true>(
+      banksClient,
+      programFolio,
+      payerKeypair,
+      folioOwnerKeypair.publicKey
+    );
+
+    assertError(txnResult, "InvalidAccountOwner");
+  });
+
+  it("should fail if the account isn't versioned", async () => {
+    const txnResult = await upgradeAccount<true>(
+      banksClient,
+      programFolio,
+      payerKeypair,
+      getActorPDA(folioOwnerKeypair.publicKey, folioPDA)
+    );
+
+    assertError(txnResult, "AccountNotUpgradable");
+  });
+});
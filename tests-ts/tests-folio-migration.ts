@@ -1,4 +1,4 @@
-import { airdrop, getConnectors } from "../utils/program-helper";
+import { airdrop, getConnectors, wait } from "../utils/program-helper";
 import { Folio } from "../target/types/folio";
 import { Folio as SecondFolio } from "../target/types/second_folio";
 import { BN, Program } from "@coral-xyz/anchor";
@@ -15,12 +15,12 @@ import * as assert from "assert";
 
 import {
   MAX_AUCTION_LENGTH,
+  MIGRATION_ABORT_GRACE_PERIOD,
   MAX_TVL_FEE,
   MAX_MINT_FEE,
   DEFAULT_DECIMALS,
   FEE_NUMERATOR,
   MAX_FEE_FLOOR,
-  D9,
 } from "../utils/constants";
 import { getMint } from "@solana/spl-token";
 import {
@@ -198,8 +198,7 @@ describe("Folio Migration Tests", () => {
       folioTokenMint.publicKey,
       folioPDA,
       newFolioPDA,
-      programSecondFolio.programId,
-      new BN(D9)
+      programSecondFolio.programId
     );
 
     const oldFolioAccountAfter = await programFolio.account.folio.fetch(
@@ -266,6 +265,9 @@ describe("Folio Migration Tests", () => {
       });
     }
 
+    // Tokens can only be migrated once the abort grace period has passed
+    await wait(MIGRATION_ABORT_GRACE_PERIOD.toNumber() + 1);
+
     await migrateFolioTokens(
       connection,
       payerKeypair, // Can be anyone
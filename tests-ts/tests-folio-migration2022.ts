@@ -28,7 +28,6 @@ import {
   DEFAULT_DECIMALS,
   FEE_NUMERATOR,
   MAX_FEE_FLOOR,
-  D9,
 } from "../utils/constants";
 import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -261,7 +260,6 @@ describe("Folio Migration Tests 2022", () => {
       folioPDA,
       newFolioPDA,
       programSecondFolio.programId,
-      new BN(D9),
       TOKEN_2022_PROGRAM_ID
     );
 
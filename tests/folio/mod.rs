@@ -1,11 +1,24 @@
 pub mod serde;
 pub mod test_actor;
 pub mod test_auction;
+pub mod test_fee_discount;
 pub mod test_fee_distribution;
 pub mod test_fee_recipients;
 pub mod test_fixed_size_string;
 pub mod test_folio;
+pub mod test_folio_actors;
 pub mod test_folio_basket;
+pub mod test_folio_legacy_basket;
+pub mod test_folio_mint_limits;
+pub mod test_folio_pending_basket;
 pub mod test_folio_status;
+pub mod test_folio_token_account_snapshot;
+pub mod test_kill_record;
+pub mod test_migration_record;
+pub mod test_migration_verifier;
+pub mod test_pause_flags;
+pub mod test_pending_owner;
+pub mod test_rebalance;
 pub mod test_roles;
 pub mod test_user_pending_basket;
+pub mod test_versioned_account;
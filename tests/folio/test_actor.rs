@@ -3,7 +3,10 @@
 mod tests {
 
     use anchor_lang::prelude::Pubkey;
+    use anchor_lang::Discriminator;
     use folio::state::Actor;
+    use folio::utils::structs::Role;
+    use shared::constants::ACTOR_VERSION;
     use shared::errors::ErrorCode;
 
     #[test]
@@ -19,6 +22,7 @@ mod tests {
         assert_eq!(actor.authority, authority);
         assert_eq!(actor.folio, folio);
         assert_eq!(actor.roles, 0);
+        assert_eq!(actor.version, ACTOR_VERSION);
     }
 
     #[test]
@@ -51,13 +55,71 @@ mod tests {
             authority: Pubkey::new_unique(),
             folio: Pubkey::new_unique(),
             roles: 123,
+            version: ACTOR_VERSION,
+            role_expirations: [100; 8],
         };
 
         actor.reset();
 
         assert_eq!(actor.roles, 0);
+        assert_eq!(actor.role_expirations, [0; 8]);
         assert_eq!(actor.authority, Pubkey::default());
         assert_eq!(actor.folio, Pubkey::default());
         assert_eq!(actor.bump, 255);
     }
+
+    #[test]
+    fn test_add_role_with_expiration() {
+        let mut actor = Actor::default();
+
+        actor
+            .add_role(Role::AuctionLauncher, Some(2_000), 1_000)
+            .unwrap();
+        actor.add_role(Role::Owner, None, 1_000).unwrap();
+
+        assert!(actor.has_active_role(Role::AuctionLauncher, 1_999));
+        assert!(!actor.has_active_role(Role::AuctionLauncher, 2_000));
+        assert!(actor.has_active_role(Role::Owner, u64::MAX));
+        assert!(!actor.has_active_role(Role::BrandManager, 1_000));
+
+        // Re-granting without an expiration makes the role permanent
+        actor.add_role(Role::AuctionLauncher, None, 3_000).unwrap();
+        assert!(actor.has_active_role(Role::AuctionLauncher, 3_000));
+
+        let result = actor.add_role(Role::BrandManager, Some(1_000), 1_000);
+        assert_eq!(result.unwrap_err(), ErrorCode::InvalidRoleExpiration.into());
+    }
+
+    #[test]
+    fn test_remove_role_clears_expiration() {
+        let mut actor = Actor::default();
+
+        actor.add_role(Role::Guardian, Some(2_000), 1_000).unwrap();
+        actor.remove_role(Role::Guardian);
+
+        assert_eq!(actor.roles, 0);
+        assert_eq!(actor.role_expirations[Role::Guardian.index()], 0);
+        assert!(!actor.has_active_role(Role::Guardian, 1_000));
+    }
+
+    #[test]
+    fn test_try_deserialize_with_legacy_support_legacy_layout() {
+        let authority = Pubkey::new_unique();
+        let folio = Pubkey::new_unique();
+
+        let mut data = Actor::DISCRIMINATOR.to_vec();
+        data.push(254);
+        data.extend_from_slice(authority.as_ref());
+        data.extend_from_slice(folio.as_ref());
+        data.push(Role::Owner as u8 | Role::AuctionLauncher as u8);
+
+        let actor = Actor::try_deserialize_with_legacy_support(&data).unwrap();
+
+        assert_eq!(actor.bump, 254);
+        assert_eq!(actor.authority, authority);
+        assert_eq!(actor.folio, folio);
+        assert_eq!(actor.version, 0);
+        assert!(actor.has_active_role(Role::Owner, u64::MAX));
+        assert!(actor.has_active_role(Role::AuctionLauncher, u64::MAX));
+    }
 }
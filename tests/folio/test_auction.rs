@@ -6,8 +6,9 @@ mod tests {
     use folio::state::Auction;
     use folio::utils::structs::AuctionStatus;
     use folio::utils::{BasketRange, PricesInAuction};
-    use shared::constants::{MAX_RATE, MAX_TTL};
+    use shared::constants::{D18_U128, MAX_RATE, MAX_TTL};
     use shared::errors::ErrorCode::*;
+    use shared::utils::Rounding;
 
     fn setup_auction() -> Auction {
         let mut auction = Auction::default();
@@ -598,4 +599,79 @@ mod tests {
         let expected = 1048808848170151500u128;
         assert!(price >= expected - 1000000000 && price <= expected + 1000000000);
     }
+
+    #[test]
+    fn test_get_raw_limit() {
+        // No multiplier, limit is already in raw amounts
+        assert_eq!(
+            Auction::get_raw_limit(5 * D18_U128, None, Rounding::Ceiling).unwrap(),
+            5 * D18_U128
+        );
+
+        // 2 UI tokens per raw token, so 5 UI tokens per share are 2.5 raw tokens per share
+        assert_eq!(
+            Auction::get_raw_limit(5 * D18_U128, Some(2 * D18_U128), Rounding::Floor).unwrap(),
+            5 * D18_U128 / 2
+        );
+
+        // Rounding
+        assert_eq!(
+            Auction::get_raw_limit(10, Some(3 * D18_U128), Rounding::Floor).unwrap(),
+            3
+        );
+        assert_eq!(
+            Auction::get_raw_limit(10, Some(3 * D18_U128), Rounding::Ceiling).unwrap(),
+            4
+        );
+
+        // Zero multiplier
+        assert_eq!(
+            Auction::get_raw_limit(10, Some(0), Rounding::Floor).err(),
+            Some(InvalidUiAmountMultiplier.into())
+        );
+    }
+
+    #[test]
+    fn test_get_raw_price() {
+        // No multipliers
+        assert_eq!(
+            Auction::get_raw_price(3 * D18_U128, None, None).unwrap(),
+            3 * D18_U128
+        );
+
+        // 1 UI sell token = 3 UI buy tokens, 1 raw sell token = 2 UI sell tokens, so 1 raw sell token = 6 raw buy tokens
+        assert_eq!(
+            Auction::get_raw_price(3 * D18_U128, Some(2 * D18_U128), None).unwrap(),
+            6 * D18_U128
+        );
+
+        // 1 raw buy token = 4 UI buy tokens, so 1 raw sell token = 1.5 raw buy tokens
+        assert_eq!(
+            Auction::get_raw_price(3 * D18_U128, Some(2 * D18_U128), Some(4 * D18_U128)).unwrap(),
+            3 * D18_U128 / 2
+        );
+
+        // Rounded up
+        assert_eq!(
+            Auction::get_raw_price(10, None, Some(3 * D18_U128)).unwrap(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_get_raw_limits() {
+        let auction = Auction {
+            sell_limit: 10,
+            buy_limit: 10,
+            ..Auction::default()
+        };
+
+        assert_eq!(auction.get_raw_limits(None, None).unwrap(), (10, 10));
+        assert_eq!(
+            auction
+                .get_raw_limits(Some(3 * D18_U128), Some(3 * D18_U128))
+                .unwrap(),
+            (4, 3)
+        );
+    }
 }
@@ -0,0 +1,51 @@
+//! Tests for the FeeDiscount state
+#[cfg(test)]
+mod tests {
+
+    use anchor_lang::prelude::Pubkey;
+    use folio::state::FeeDiscount;
+    use shared::constants::MAX_FEE_DISCOUNT;
+    use shared::errors::ErrorCode;
+
+    #[test]
+    fn test_process_init_if_needed_new_account() {
+        let mut fee_discount = FeeDiscount::default();
+        let folio = Pubkey::new_unique();
+        let minter = Pubkey::new_unique();
+
+        let result = fee_discount.process_init_if_needed(0, 255, &folio, &minter);
+
+        assert!(result.is_ok());
+        assert_eq!(fee_discount.bump, 255);
+        assert_eq!(fee_discount.folio, folio);
+        assert_eq!(fee_discount.minter, minter);
+        assert_eq!(fee_discount.scaled_discount, 0);
+    }
+
+    #[test]
+    fn test_process_init_if_needed_mismatched_bumps() {
+        let mut fee_discount = FeeDiscount::default();
+        let folio = Pubkey::new_unique();
+        let minter = Pubkey::new_unique();
+
+        let result = fee_discount.process_init_if_needed(254, 255, &folio, &minter);
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), ErrorCode::InvalidBump.into());
+    }
+
+    #[test]
+    fn test_set_discount() {
+        let mut fee_discount = FeeDiscount::default();
+
+        assert!(fee_discount.set_discount(MAX_FEE_DISCOUNT / 2).is_ok());
+        assert_eq!(fee_discount.scaled_discount, MAX_FEE_DISCOUNT / 2);
+
+        assert!(fee_discount.set_discount(MAX_FEE_DISCOUNT).is_ok());
+        assert_eq!(fee_discount.scaled_discount, MAX_FEE_DISCOUNT);
+
+        let result = fee_discount.set_discount(MAX_FEE_DISCOUNT + 1);
+        assert_eq!(result.unwrap_err(), ErrorCode::InvalidFeeDiscount.into());
+        assert_eq!(fee_discount.scaled_discount, MAX_FEE_DISCOUNT);
+    }
+}
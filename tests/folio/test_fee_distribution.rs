@@ -5,7 +5,9 @@ mod tests {
     use anchor_lang::prelude::Pubkey;
     use folio::state::FeeDistribution;
     use folio::utils::structs::FeeRecipient;
-    use shared::constants::MAX_FEE_RECIPIENTS;
+    use shared::constants::{
+        FEE_DISTRIBUTION_CLOSE_GRACE_PERIOD, MAX_FEE_RECIPIENTS, MAX_FEE_RECIPIENTS_PORTION,
+    };
 
     #[test]
     fn test_is_fully_distributed() {
@@ -22,4 +24,49 @@ mod tests {
         fee_distribution.fee_recipients_state[1].recipient = Pubkey::new_unique();
         assert!(!fee_distribution.is_fully_distributed());
     }
+
+    #[test]
+    fn test_is_closable() {
+        let mut fee_distribution = FeeDistribution {
+            created_at: 1000,
+            ..Default::default()
+        };
+
+        assert!(fee_distribution.is_closable(1000));
+
+        fee_distribution.fee_recipients_state[0].recipient = Pubkey::new_unique();
+        assert!(!fee_distribution.is_closable(1000));
+        assert!(!fee_distribution.is_closable(1000 + FEE_DISTRIBUTION_CLOSE_GRACE_PERIOD - 1));
+        assert!(fee_distribution.is_closable(1000 + FEE_DISTRIBUTION_CLOSE_GRACE_PERIOD));
+    }
+
+    #[test]
+    fn test_get_raw_undistributed_amount() {
+        let mut fee_distribution = FeeDistribution {
+            // 100 tokens in D18
+            amount_to_distribute: 100_000_000_000_000_000_000,
+            ..Default::default()
+        };
+
+        assert_eq!(fee_distribution.get_raw_undistributed_amount().unwrap(), 0);
+
+        fee_distribution.fee_recipients_state[0] = FeeRecipient {
+            recipient: Pubkey::new_unique(),
+            portion: MAX_FEE_RECIPIENTS_PORTION / 4,
+        };
+        fee_distribution.fee_recipients_state[1] = FeeRecipient {
+            recipient: Pubkey::new_unique(),
+            portion: MAX_FEE_RECIPIENTS_PORTION / 2,
+        };
+        // Already distributed
+        fee_distribution.fee_recipients_state[2] = FeeRecipient {
+            recipient: Pubkey::default(),
+            portion: MAX_FEE_RECIPIENTS_PORTION / 4,
+        };
+
+        assert_eq!(
+            fee_distribution.get_raw_undistributed_amount().unwrap(),
+            75_000_000_000
+        );
+    }
 }
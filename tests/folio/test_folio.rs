@@ -3,6 +3,7 @@
 #[cfg(test)]
 mod tests {
     use folio::state::Folio;
+    use folio::utils::structs::{FeeDistributionFallback, PauseFlag};
     use shared::constants::MAX_TVL_FEE;
     use shared::errors::ErrorCode;
     use shared::utils::{Decimal, Rounding};
@@ -123,6 +124,7 @@ mod tests {
                 200_000_000_000_000_000,   // 20% dao fee (0.2 * D18)
                 1_000_000_000_000_000_000, // denominator 1.0 * D18
                 1_000_000_000_000_000,     // 0.1% floor (0.001 * D18)
+                0,                         // no mint fee discount
             )
             .unwrap();
 
@@ -149,6 +151,7 @@ mod tests {
                 200_000_000_000_000_000,   // 20% dao fee
                 1_000_000_000_000_000_000, // denominator
                 10_000_000_000_000_000,    // 1% floor (higher than calculated dao fee)
+                0,                         // no mint fee discount
             )
             .unwrap();
 
@@ -171,6 +174,7 @@ mod tests {
                 200_000_000_000_000_000,   // 20% dao fee
                 1_000_000_000_000_000_000, // denominator
                 1_000_000_000_000_000,     // 0.1% floor
+                0,                         // no mint fee discount
             )
             .unwrap();
 
@@ -197,6 +201,7 @@ mod tests {
                 200_000_000_000_000_000,   // 20% dao fee
                 1_000_000_000_000_000_000, // denominator
                 1_000_000_000_000_000,     // 0.1% floor
+                0,                         // no mint fee discount
             )
             .unwrap();
 
@@ -219,6 +224,7 @@ mod tests {
                 200_000_000_000_000_000,   // 20% dao fee
                 1_000_000_000_000_000_000, // denominator
                 1_000_000_000_000_000,     // 0.1% floor
+                0,                         // no mint fee discount
             )
             .unwrap();
 
@@ -236,6 +242,57 @@ mod tests {
         assert_eq!(folio.fee_recipients_pending_fee_shares, 40_000_000_000); // 0.00000004 * D18
     }
 
+    #[test]
+    fn test_calculate_fees_for_minting_with_discount() {
+        let mut folio = Folio {
+            mint_fee: 50_000_000_000_000_000, // 5% mint fee
+            ..Folio::default()
+        };
+
+        let result = folio
+            .calculate_fees_for_minting(
+                1_000_000_000,             // 1 token
+                200_000_000_000_000_000,   // 20% dao fee
+                1_000_000_000_000_000_000, // denominator
+                1_000_000_000_000_000,     // 0.1% floor
+                500_000_000_000_000_000,   // 50% mint fee discount
+            )
+            .unwrap();
+
+        // Discounted mint fee: 5% * 50% = 2.5%
+        // Expected total fee: 1 * 0.025 = 0.025 tokens
+        // Expected dao fee: 0.025 * 0.2 = 0.005 tokens
+        assert_eq!(result.0, 25_000_000);
+        assert_eq!(folio.dao_pending_fee_shares, 5_000_000_000_000_000);
+        assert_eq!(
+            folio.fee_recipients_pending_fee_shares,
+            20_000_000_000_000_000
+        );
+    }
+
+    #[test]
+    fn test_calculate_fees_for_minting_full_discount_keeps_floor() {
+        let mut folio = Folio {
+            mint_fee: 50_000_000_000_000_000, // 5% mint fee
+            ..Folio::default()
+        };
+
+        let result = folio
+            .calculate_fees_for_minting(
+                1_000_000_000,             // 1 token
+                200_000_000_000_000_000,   // 20% dao fee
+                1_000_000_000_000_000_000, // denominator
+                1_000_000_000_000_000,     // 0.1% floor
+                1_000_000_000_000_000_000, // 100% mint fee discount
+            )
+            .unwrap();
+
+        // Only the DAO floor applies
+        assert_eq!(result.0, 1_000_000); // 0.001 tokens in D9 (floor)
+        assert_eq!(folio.dao_pending_fee_shares, 1_000_000_000_000_000);
+        assert_eq!(folio.fee_recipients_pending_fee_shares, 0);
+    }
+
     #[test]
     fn test_calculate_fees_for_minting_accumulation() {
         let mut folio = Folio {
@@ -250,6 +307,7 @@ mod tests {
                 200_000_000_000_000_000,   // 20% dao fee
                 1_000_000_000_000_000_000, // denominator
                 1_000_000_000_000_000,     // 0.1% floor
+                0,                         // no mint fee discount
             )
             .unwrap();
 
@@ -263,6 +321,7 @@ mod tests {
                 200_000_000_000_000_000,   // 20% dao fee
                 1_000_000_000_000_000_000, // denominator
                 1_000_000_000_000_000,     // 0.1% floor
+                0,                         // no mint fee discount
             )
             .unwrap();
 
@@ -585,4 +644,113 @@ mod tests {
             max_expected
         );
     }
+
+    #[test]
+    fn test_pause_and_unpause() {
+        let mut folio = Folio::default();
+
+        assert!(folio.validate_not_paused(PauseFlag::Mint).is_ok());
+
+        folio
+            .pause(PauseFlag::Mint as u8 | PauseFlag::Auctions as u8)
+            .unwrap();
+
+        assert_eq!(
+            folio.validate_not_paused(PauseFlag::Mint).unwrap_err(),
+            ErrorCode::FolioPaused.into()
+        );
+        assert_eq!(
+            folio.validate_not_paused(PauseFlag::Auctions).unwrap_err(),
+            ErrorCode::FolioPaused.into()
+        );
+        assert!(folio.validate_not_paused(PauseFlag::Redeem).is_ok());
+        assert!(folio
+            .validate_not_paused(PauseFlag::FeeDistribution)
+            .is_ok());
+
+        folio.unpause(PauseFlag::Mint as u8).unwrap();
+
+        assert!(folio.validate_not_paused(PauseFlag::Mint).is_ok());
+        assert_eq!(folio.pause_flags, PauseFlag::Auctions as u8);
+    }
+
+    #[test]
+    fn test_set_mint_allowlist_enabled() {
+        let mut folio = Folio::default();
+
+        assert!(!folio.is_mint_allowlist_enabled());
+
+        folio.set_mint_allowlist_enabled(true);
+        assert!(folio.is_mint_allowlist_enabled());
+        assert_eq!(folio.mint_allowlist_enabled, 1);
+
+        folio.set_mint_allowlist_enabled(false);
+        assert!(!folio.is_mint_allowlist_enabled());
+    }
+
+    #[test]
+    fn test_set_mint_limits_enabled() {
+        let mut folio = Folio::default();
+
+        assert!(!folio.is_mint_limits_enabled());
+
+        folio.set_mint_limits_enabled(true);
+        assert!(folio.is_mint_limits_enabled());
+        assert_eq!(folio.mint_limits_enabled, 1);
+
+        folio.set_mint_limits_enabled(false);
+        assert!(!folio.is_mint_limits_enabled());
+    }
+
+    #[test]
+    fn test_set_fee_distribution_fallback() {
+        let mut folio = Folio::default();
+
+        // Folios that never set it keep sending the undelivered shares to the DAO
+        assert_eq!(
+            folio.get_fee_distribution_fallback().unwrap(),
+            FeeDistributionFallback::Dao
+        );
+
+        folio.set_fee_distribution_fallback(FeeDistributionFallback::FeeRecipients);
+        assert_eq!(folio.fee_distribution_fallback, 1);
+        assert_eq!(
+            folio.get_fee_distribution_fallback().unwrap(),
+            FeeDistributionFallback::FeeRecipients
+        );
+
+        folio.set_fee_distribution_fallback(FeeDistributionFallback::Dao);
+        assert_eq!(
+            folio.get_fee_distribution_fallback().unwrap(),
+            FeeDistributionFallback::Dao
+        );
+    }
+
+    #[test]
+    fn test_get_fee_distribution_fallback_invalid() {
+        let folio = Folio {
+            fee_distribution_fallback: 2,
+            ..Folio::default()
+        };
+
+        assert_eq!(
+            folio.get_fee_distribution_fallback().unwrap_err(),
+            ErrorCode::InvalidFeeDistributionFallback.into()
+        );
+    }
+
+    #[test]
+    fn test_pause_invalid_flags() {
+        let mut folio = Folio::default();
+
+        assert_eq!(
+            folio.pause(0).unwrap_err(),
+            ErrorCode::InvalidPauseFlags.into()
+        );
+        assert_eq!(
+            folio.unpause(0b1000_0000).unwrap_err(),
+            ErrorCode::InvalidPauseFlags.into()
+        );
+        assert_eq!(folio.pause_flags, 0);
+    }
 }
@@ -0,0 +1,97 @@
+//! Tests for the FolioActors state
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+    use folio::state::FolioActors;
+    use folio::utils::structs::{ActorEntry, Role};
+    use shared::constants::MAX_FOLIO_ACTORS;
+
+    fn indexed_actors(folio_actors: &FolioActors) -> Vec<ActorEntry> {
+        folio_actors
+            .actors
+            .iter()
+            .filter(|entry| entry.authority != Pubkey::default())
+            .copied()
+            .collect()
+    }
+
+    #[test]
+    fn test_set_actor_roles() {
+        let mut folio_actors = FolioActors::default();
+        let owner = Pubkey::new_unique();
+        let auction_launcher = Pubkey::new_unique();
+
+        folio_actors.set_actor_roles(&owner, Role::Owner as u8);
+        folio_actors.set_actor_roles(&auction_launcher, Role::AuctionLauncher as u8);
+
+        // Updating an existing actor doesn't add a new entry
+        folio_actors.set_actor_roles(&owner, Role::Owner as u8 | Role::Guardian as u8);
+
+        assert_eq!(
+            indexed_actors(&folio_actors),
+            vec![
+                ActorEntry {
+                    authority: owner,
+                    roles: Role::Owner as u8 | Role::Guardian as u8,
+                },
+                ActorEntry {
+                    authority: auction_launcher,
+                    roles: Role::AuctionLauncher as u8,
+                },
+            ]
+        );
+
+        // No roles left means the actor is removed from the index
+        folio_actors.set_actor_roles(&owner, 0);
+
+        assert_eq!(
+            indexed_actors(&folio_actors),
+            vec![ActorEntry {
+                authority: auction_launcher,
+                roles: Role::AuctionLauncher as u8,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_set_actor_roles_reuses_empty_slots() {
+        let mut folio_actors = FolioActors::default();
+
+        for _ in 0..MAX_FOLIO_ACTORS {
+            folio_actors.set_actor_roles(&Pubkey::new_unique(), Role::BrandManager as u8);
+        }
+
+        assert!(!folio_actors.is_incomplete);
+
+        // The index is full, the actor isn't indexed but it doesn't fail
+        let not_indexed = Pubkey::new_unique();
+        folio_actors.set_actor_roles(&not_indexed, Role::BrandManager as u8);
+
+        assert!(folio_actors.is_incomplete);
+        assert_eq!(indexed_actors(&folio_actors).len(), MAX_FOLIO_ACTORS);
+        assert!(!folio_actors
+            .actors
+            .iter()
+            .any(|entry| entry.authority == not_indexed));
+
+        let removed = folio_actors.actors[3].authority;
+        folio_actors.remove_actor(&removed);
+
+        let new_actor = Pubkey::new_unique();
+        folio_actors.set_actor_roles(&new_actor, Role::RebalanceManager as u8);
+
+        assert_eq!(folio_actors.actors[3].authority, new_actor);
+    }
+
+    #[test]
+    fn test_remove_actor_not_indexed() {
+        let mut folio_actors = FolioActors::default();
+        let owner = Pubkey::new_unique();
+
+        folio_actors.set_actor_roles(&owner, Role::Owner as u8);
+        folio_actors.remove_actor(&Pubkey::new_unique());
+
+        assert_eq!(indexed_actors(&folio_actors).len(), 1);
+    }
+}
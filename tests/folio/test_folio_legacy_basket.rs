@@ -0,0 +1,235 @@
+//! Tests for the FolioLegacyBasket state
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+    use folio::state::FolioLegacyBasket;
+    use folio::utils::FolioTokenAmount;
+    use shared::constants::MAX_FOLIO_LEGACY_TOKEN_AMOUNTS;
+    use shared::errors::ErrorCode;
+    use shared::utils::Decimal;
+
+    // 1000 folio tokens
+    const RAW_FOLIO_TOKEN_SUPPLY: u64 = 1_000_000_000_000;
+
+    fn scaled_total_supply(raw_folio_token_supply: u64) -> Decimal {
+        Decimal::from_token_amount(raw_folio_token_supply).unwrap()
+    }
+
+    fn add_token_amount(
+        legacy: &mut FolioLegacyBasket,
+        mint: &Pubkey,
+        raw_amount: u64,
+    ) -> anchor_lang::Result<()> {
+        legacy.add_token_amount(
+            mint,
+            raw_amount,
+            RAW_FOLIO_TOKEN_SUPPLY,
+            &scaled_total_supply(RAW_FOLIO_TOKEN_SUPPLY),
+        )
+    }
+
+    #[test]
+    fn test_add_token_amount_new_and_existing_mint() {
+        let mut legacy = FolioLegacyBasket::default();
+        let mint = Pubkey::new_unique();
+
+        add_token_amount(&mut legacy, &mint, 100).unwrap();
+        add_token_amount(&mut legacy, &mint, 50).unwrap();
+
+        assert_eq!(legacy.token_amounts[0].mint, mint);
+        assert_eq!(legacy.token_amounts[0].amount, 150);
+        assert_eq!(legacy.token_amounts[1].mint, Pubkey::default());
+        assert_eq!(legacy.get_token_amount_or_zero(&mint), 150);
+    }
+
+    #[test]
+    fn test_add_token_amount_skips_zero_amount() {
+        let mut legacy = FolioLegacyBasket::default();
+        let mint = Pubkey::new_unique();
+
+        add_token_amount(&mut legacy, &mint, 0).unwrap();
+
+        assert!(legacy
+            .token_amounts
+            .iter()
+            .all(|ta| ta.mint == Pubkey::default()));
+        assert_eq!(legacy.get_token_amount_or_zero(&mint), 0);
+    }
+
+    #[test]
+    fn test_add_token_amount_invalid_mint() {
+        let mut legacy = FolioLegacyBasket::default();
+
+        let result = add_token_amount(&mut legacy, &Pubkey::default(), 100);
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            ErrorCode::InvalidAddedTokenMints.into()
+        );
+    }
+
+    #[test]
+    fn test_add_token_amount_full() {
+        let mut legacy = FolioLegacyBasket::default();
+
+        // Even a single raw unit can still be redeemed by the whole supply
+        for i in 0..MAX_FOLIO_LEGACY_TOKEN_AMOUNTS {
+            legacy.token_amounts[i] = FolioTokenAmount {
+                mint: Pubkey::new_unique(),
+                amount: 1,
+            };
+        }
+
+        let result = add_token_amount(&mut legacy, &Pubkey::new_unique(), 100);
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            ErrorCode::MaxNumberOfLegacyTokensReached.into()
+        );
+    }
+
+    #[test]
+    fn test_add_token_amount_full_frees_redeemed_slots() {
+        let mut legacy = FolioLegacyBasket::default();
+
+        for i in 0..MAX_FOLIO_LEGACY_TOKEN_AMOUNTS {
+            legacy.token_amounts[i] = FolioTokenAmount {
+                mint: Pubkey::new_unique(),
+                amount: 1,
+            };
+        }
+        legacy.token_amounts[3].amount = 0;
+
+        let new_mint = Pubkey::new_unique();
+        add_token_amount(&mut legacy, &new_mint, 100).unwrap();
+
+        assert_eq!(legacy.token_amounts[3].mint, new_mint);
+        assert_eq!(legacy.token_amounts[3].amount, 100);
+    }
+
+    #[test]
+    fn test_clear_empty_token_amounts() {
+        let mut legacy = FolioLegacyBasket::default();
+        let redeemed_mint = Pubkey::new_unique();
+        let other_mint = Pubkey::new_unique();
+
+        add_token_amount(&mut legacy, &redeemed_mint, 100_000).unwrap();
+        add_token_amount(&mut legacy, &other_mint, 200_000).unwrap();
+        legacy.token_amounts[0].amount = 0;
+
+        legacy
+            .clear_empty_token_amounts(
+                RAW_FOLIO_TOKEN_SUPPLY,
+                &scaled_total_supply(RAW_FOLIO_TOKEN_SUPPLY),
+            )
+            .unwrap();
+
+        assert_eq!(legacy.token_amounts[0].mint, Pubkey::default());
+        assert_eq!(legacy.token_amounts[1].mint, other_mint);
+        assert_eq!(legacy.get_token_amount_or_zero(&other_mint), 200_000);
+
+        // The freed slot can be reused
+        let new_mint = Pubkey::new_unique();
+        add_token_amount(&mut legacy, &new_mint, 300_000).unwrap();
+        assert_eq!(legacy.token_amounts[0].mint, new_mint);
+    }
+
+    #[test]
+    fn test_clear_empty_token_amounts_keeps_zero_decimals_mint() {
+        let mut legacy = FolioLegacyBasket::default();
+        // 5 whole tokens of a mint with 0 decimals
+        let zero_decimals_mint = Pubkey::new_unique();
+
+        add_token_amount(&mut legacy, &zero_decimals_mint, 5).unwrap();
+
+        legacy
+            .clear_empty_token_amounts(
+                RAW_FOLIO_TOKEN_SUPPLY,
+                &scaled_total_supply(RAW_FOLIO_TOKEN_SUPPLY),
+            )
+            .unwrap();
+
+        assert_eq!(legacy.token_amounts[0].mint, zero_decimals_mint);
+        assert_eq!(legacy.get_token_amount_or_zero(&zero_decimals_mint), 5);
+        assert!(!legacy.is_empty());
+
+        // Once the last whole token is redeemed, the slot is freed
+        legacy.token_amounts[0].amount = 0;
+
+        legacy
+            .clear_empty_token_amounts(
+                RAW_FOLIO_TOKEN_SUPPLY,
+                &scaled_total_supply(RAW_FOLIO_TOKEN_SUPPLY),
+            )
+            .unwrap();
+
+        assert!(legacy.is_empty());
+    }
+
+    #[test]
+    fn test_clear_empty_token_amounts_frees_unredeemable_amounts() {
+        let mut legacy = FolioLegacyBasket::default();
+        let dust_mint = Pubkey::new_unique();
+        let other_mint = Pubkey::new_unique();
+
+        legacy.token_amounts[0] = FolioTokenAmount {
+            mint: dust_mint,
+            amount: 999,
+        };
+        legacy.token_amounts[1] = FolioTokenAmount {
+            mint: other_mint,
+            amount: 1_000,
+        };
+
+        // A single raw folio token is left, the rest of the supply is pending fee shares
+        let scaled_total_supply_folio_token = scaled_total_supply(1_000);
+
+        legacy
+            .clear_empty_token_amounts(1, &scaled_total_supply_folio_token)
+            .unwrap();
+
+        // 999 * 1 / 1000 rounds down to zero
+        assert_eq!(legacy.token_amounts[0].mint, Pubkey::default());
+        assert_eq!(legacy.token_amounts[0].amount, 0);
+        assert_eq!(legacy.get_token_amount_or_zero(&dust_mint), 0);
+        // 1000 * 1 / 1000 can still be redeemed
+        assert_eq!(legacy.get_token_amount_or_zero(&other_mint), 1_000);
+    }
+
+    #[test]
+    fn test_clear_empty_token_amounts_no_supply_left() {
+        let mut legacy = FolioLegacyBasket::default();
+        let mint = Pubkey::new_unique();
+
+        add_token_amount(&mut legacy, &mint, 1_000_000).unwrap();
+
+        legacy
+            .clear_empty_token_amounts(0, &scaled_total_supply(0))
+            .unwrap();
+
+        assert!(legacy.is_empty());
+    }
+
+    #[test]
+    fn test_is_empty() {
+        let mut legacy = FolioLegacyBasket::default();
+        assert!(legacy.is_empty());
+
+        let mint = Pubkey::new_unique();
+        add_token_amount(&mut legacy, &mint, 100).unwrap();
+        assert!(!legacy.is_empty());
+
+        // Fully redeemed
+        legacy.token_amounts[0].amount = 0;
+        legacy
+            .clear_empty_token_amounts(
+                RAW_FOLIO_TOKEN_SUPPLY,
+                &scaled_total_supply(RAW_FOLIO_TOKEN_SUPPLY),
+            )
+            .unwrap();
+        assert!(legacy.is_empty());
+    }
+}
@@ -0,0 +1,112 @@
+//! Tests for the FolioMintLimits state
+
+#[cfg(test)]
+mod tests {
+    use folio::state::{Folio, FolioMintLimits};
+    use shared::constants::{D18_U128, D9_U128};
+    use shared::errors::ErrorCode;
+
+    #[test]
+    fn test_set_limits() {
+        let mut folio_mint_limits = FolioMintLimits::default();
+
+        folio_mint_limits.set_limits(Some(1_000), Some(100));
+        assert_eq!(folio_mint_limits.max_supply, 1_000);
+        assert_eq!(folio_mint_limits.max_mint_per_tx, 100);
+
+        // Only the provided limits are updated
+        folio_mint_limits.set_limits(None, Some(0));
+        assert_eq!(folio_mint_limits.max_supply, 1_000);
+        assert_eq!(folio_mint_limits.max_mint_per_tx, 0);
+    }
+
+    #[test]
+    fn test_has_limits() {
+        let mut folio_mint_limits = FolioMintLimits::default();
+        assert!(!folio_mint_limits.has_limits());
+
+        folio_mint_limits.set_limits(Some(1_000), None);
+        assert!(folio_mint_limits.has_limits());
+
+        folio_mint_limits.set_limits(Some(0), Some(100));
+        assert!(folio_mint_limits.has_limits());
+
+        // Removing every limit disables them
+        folio_mint_limits.set_limits(None, Some(0));
+        assert!(!folio_mint_limits.has_limits());
+    }
+
+    #[test]
+    fn test_get_folio_mint_limits_not_enabled() {
+        let folio = Folio::default();
+
+        // The account isn't needed when the folio has no mint limits
+        let folio_mint_limits = FolioMintLimits::get_folio_mint_limits(&folio, None).unwrap();
+        assert!(!folio_mint_limits.has_limits());
+    }
+
+    #[test]
+    fn test_get_folio_mint_limits_enabled_missing_account() {
+        let mut folio = Folio::default();
+        folio.set_mint_limits_enabled(true);
+
+        let result = FolioMintLimits::get_folio_mint_limits(&folio, None);
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.err().unwrap(),
+            ErrorCode::MissingFolioMintLimits.into()
+        );
+    }
+
+    #[test]
+    fn test_validate_mint_no_limits() {
+        let folio = Folio::default();
+        let folio_mint_limits = FolioMintLimits::default();
+
+        assert!(folio_mint_limits
+            .validate_mint(&folio, u64::MAX / 2, u64::MAX / 2)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_validate_mint_max_mint_per_tx() {
+        let folio = Folio::default();
+        let folio_mint_limits = FolioMintLimits {
+            max_mint_per_tx: 100,
+            ..FolioMintLimits::default()
+        };
+
+        assert!(folio_mint_limits.validate_mint(&folio, 0, 100).is_ok());
+        assert_eq!(
+            folio_mint_limits.validate_mint(&folio, 0, 101).unwrap_err(),
+            ErrorCode::MaxMintPerTransactionExceeded.into()
+        );
+    }
+
+    #[test]
+    fn test_validate_mint_max_supply_includes_pending_fee_shares() {
+        // 10 shares of pending fees, in D18
+        let folio = Folio {
+            dao_pending_fee_shares: 4 * D18_U128,
+            fee_recipients_pending_fee_shares: 6 * D18_U128,
+            ..Folio::default()
+        };
+        let folio_mint_limits = FolioMintLimits {
+            max_supply: 100 * D9_U128 as u64,
+            ..FolioMintLimits::default()
+        };
+
+        // 80 + 10 + 10 = 100
+        assert!(folio_mint_limits
+            .validate_mint(&folio, 80 * D9_U128 as u64, 10 * D9_U128 as u64)
+            .is_ok());
+
+        assert_eq!(
+            folio_mint_limits
+                .validate_mint(&folio, 80 * D9_U128 as u64, 10 * D9_U128 as u64 + 1)
+                .unwrap_err(),
+            ErrorCode::MaxSupplyExceeded.into()
+        );
+    }
+}
@@ -0,0 +1,131 @@
+//! Tests for the FolioPendingBasket state
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+    use folio::state::FolioPendingBasket;
+    use folio::utils::structs::TokenAmount;
+    use shared::constants::MAX_USER_PENDING_BASKET_TOKEN_AMOUNTS;
+    use shared::errors::ErrorCode;
+
+    fn token_amount(mint: Pubkey, minting: u64, redeeming: u64) -> TokenAmount {
+        TokenAmount {
+            mint,
+            amount_for_minting: minting,
+            amount_for_redeeming: redeeming,
+        }
+    }
+
+    #[test]
+    fn test_add_token_amounts_new_and_existing_mint() {
+        let mut pending = FolioPendingBasket::default();
+        let mint = Pubkey::new_unique();
+
+        pending
+            .add_token_amounts(&[token_amount(mint, 100, 0)])
+            .unwrap();
+        pending
+            .add_token_amounts(&[token_amount(mint, 50, 25)])
+            .unwrap();
+
+        assert_eq!(pending.basket.token_amounts[0], token_amount(mint, 150, 25));
+        assert_eq!(pending.basket.token_amounts[1], TokenAmount::default());
+        assert_eq!(pending.get_total_pending_amount(&mint).unwrap(), 175);
+    }
+
+    #[test]
+    fn test_add_token_amounts_skips_empty_amounts() {
+        let mut pending = FolioPendingBasket::default();
+
+        pending
+            .add_token_amounts(&[
+                token_amount(Pubkey::new_unique(), 0, 0),
+                TokenAmount::default(),
+            ])
+            .unwrap();
+
+        assert!(pending
+            .basket
+            .token_amounts
+            .iter()
+            .all(|ta| *ta == TokenAmount::default()));
+    }
+
+    #[test]
+    fn test_add_token_amounts_full() {
+        let mut pending = FolioPendingBasket::default();
+
+        for i in 0..MAX_USER_PENDING_BASKET_TOKEN_AMOUNTS {
+            pending.basket.token_amounts[i] = token_amount(Pubkey::new_unique(), 1, 0);
+        }
+
+        let result = pending.add_token_amounts(&[token_amount(Pubkey::new_unique(), 1, 0)]);
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            ErrorCode::InvalidAddedTokenMints.into()
+        );
+    }
+
+    #[test]
+    fn test_remove_token_amounts_saturates_and_clears_slot() {
+        let mut pending = FolioPendingBasket::default();
+        let mint = Pubkey::new_unique();
+        let other_mint = Pubkey::new_unique();
+
+        pending
+            .add_token_amounts(&[token_amount(mint, 100, 50), token_amount(other_mint, 10, 0)])
+            .unwrap();
+
+        pending.remove_token_amounts(&[token_amount(mint, 40, 0)]);
+        assert_eq!(pending.basket.token_amounts[0], token_amount(mint, 60, 50));
+
+        // Removing more than tracked saturates at 0 and frees the slot
+        pending.remove_token_amounts(&[token_amount(mint, 100, 100)]);
+        assert_eq!(pending.basket.token_amounts[0], TokenAmount::default());
+        assert_eq!(pending.get_total_pending_amount(&mint).unwrap(), 0);
+
+        // Unknown mints are ignored
+        pending.remove_token_amounts(&[token_amount(Pubkey::new_unique(), 10, 10)]);
+        assert_eq!(pending.get_total_pending_amount(&other_mint).unwrap(), 10);
+    }
+
+    #[test]
+    fn test_update_from_user_pending_basket() {
+        let mut pending = FolioPendingBasket::default();
+        let mint_a = Pubkey::new_unique();
+        let mint_b = Pubkey::new_unique();
+
+        // Other users
+        pending
+            .add_token_amounts(&[token_amount(mint_a, 1_000, 0)])
+            .unwrap();
+
+        // Untracked user pending basket, registered fully
+        pending
+            .update_from_user_pending_basket(&[], &[token_amount(mint_a, 200, 0)])
+            .unwrap();
+        assert_eq!(pending.get_total_pending_amount(&mint_a).unwrap(), 1_200);
+
+        // The user mints, then burns for mint b
+        pending
+            .update_from_user_pending_basket(
+                &[token_amount(mint_a, 200, 0)],
+                &[token_amount(mint_a, 20, 0), token_amount(mint_b, 0, 300)],
+            )
+            .unwrap();
+        assert_eq!(pending.get_total_pending_amount(&mint_a).unwrap(), 1_020);
+        assert_eq!(pending.get_total_pending_amount(&mint_b).unwrap(), 300);
+
+        // The user redeems everything
+        pending
+            .update_from_user_pending_basket(
+                &[token_amount(mint_a, 20, 0), token_amount(mint_b, 0, 300)],
+                &[],
+            )
+            .unwrap();
+        assert_eq!(pending.get_total_pending_amount(&mint_a).unwrap(), 1_000);
+        assert_eq!(pending.get_total_pending_amount(&mint_b).unwrap(), 0);
+    }
+}
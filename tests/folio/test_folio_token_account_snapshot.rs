@@ -0,0 +1,66 @@
+//! Tests for the FolioTokenAccountSnapshot struct
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+    use folio::utils::FolioTokenAccountSnapshot;
+    use shared::errors::ErrorCode;
+
+    fn snapshot() -> FolioTokenAccountSnapshot {
+        FolioTokenAccountSnapshot {
+            key: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            amount: 1_000,
+            delegate: None,
+            close_authority: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_after_folio_action_balance_increased_or_unchanged() {
+        let before = snapshot();
+
+        assert!(before.validate_after_folio_action(Some(&before)).is_ok());
+
+        let after = FolioTokenAccountSnapshot {
+            amount: 1_500,
+            ..before
+        };
+        assert!(before.validate_after_folio_action(Some(&after)).is_ok());
+    }
+
+    #[test]
+    fn test_validate_after_folio_action_violations() {
+        let before = snapshot();
+
+        let violations = [
+            None,
+            Some(FolioTokenAccountSnapshot {
+                amount: 999,
+                ..before
+            }),
+            Some(FolioTokenAccountSnapshot {
+                owner: Pubkey::new_unique(),
+                ..before
+            }),
+            Some(FolioTokenAccountSnapshot {
+                delegate: Some(Pubkey::new_unique()),
+                ..before
+            }),
+            Some(FolioTokenAccountSnapshot {
+                close_authority: Some(Pubkey::new_unique()),
+                ..before
+            }),
+        ];
+
+        for after in violations.iter() {
+            let result = before.validate_after_folio_action(after.as_ref());
+
+            assert!(result.is_err());
+            assert_eq!(
+                result.unwrap_err(),
+                error!(ErrorCode::FolioActionInvariantViolated)
+            );
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//! Tests for the KillRecord state
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+    use folio::state::KillRecord;
+    use shared::constants::UNKILL_FOLIO_TIMELOCK;
+    use shared::errors::ErrorCode;
+
+    #[test]
+    fn test_validate_unkill_timelock() {
+        let kill_record = KillRecord {
+            killed_at: 1_000,
+            unkill_timelock: UNKILL_FOLIO_TIMELOCK,
+            ..KillRecord::default()
+        };
+
+        assert_eq!(
+            kill_record.validate_unkill_timelock(1_000).unwrap_err(),
+            ErrorCode::UnkillTimelockNotPassed.into()
+        );
+        assert_eq!(
+            kill_record
+                .validate_unkill_timelock(1_000 + UNKILL_FOLIO_TIMELOCK - 1)
+                .unwrap_err(),
+            ErrorCode::UnkillTimelockNotPassed.into()
+        );
+        assert!(kill_record
+            .validate_unkill_timelock(1_000 + UNKILL_FOLIO_TIMELOCK)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_validate_unkill_timelock_uses_recorded_timelock() {
+        let kill_record = KillRecord {
+            killed_at: 1_000,
+            unkill_timelock: 60,
+            ..KillRecord::default()
+        };
+
+        assert_eq!(
+            kill_record.validate_unkill_timelock(1_059).unwrap_err(),
+            ErrorCode::UnkillTimelockNotPassed.into()
+        );
+        assert!(kill_record.validate_unkill_timelock(1_060).is_ok());
+    }
+
+    #[test]
+    fn test_validate_unkill_timelock_no_timelock() {
+        let kill_record = KillRecord {
+            killed_at: 1_000,
+            unkill_timelock: 0,
+            ..KillRecord::default()
+        };
+
+        assert!(kill_record.validate_unkill_timelock(1_000).is_ok());
+    }
+
+    #[test]
+    fn test_validate_unkill_timelock_overflow() {
+        let kill_record = KillRecord {
+            killed_at: u64::MAX,
+            unkill_timelock: UNKILL_FOLIO_TIMELOCK,
+            ..KillRecord::default()
+        };
+
+        assert_eq!(
+            kill_record.validate_unkill_timelock(u64::MAX).unwrap_err(),
+            ErrorCode::MathOverflow.into()
+        );
+    }
+
+    #[test]
+    fn test_init() {
+        let folio = Pubkey::new_unique();
+        let mut kill_record = KillRecord::default();
+
+        assert!(kill_record.is_uninitialized());
+
+        kill_record.init(254, folio, 1_000, UNKILL_FOLIO_TIMELOCK);
+
+        assert!(!kill_record.is_uninitialized());
+        assert_eq!(kill_record.bump, 254);
+        assert_eq!(kill_record.folio, folio);
+        assert_eq!(kill_record.killed_at, 1_000);
+        assert_eq!(kill_record.unkill_timelock, UNKILL_FOLIO_TIMELOCK);
+        assert_eq!(
+            kill_record.get_unkillable_at().unwrap(),
+            1_000 + UNKILL_FOLIO_TIMELOCK
+        );
+    }
+}
@@ -0,0 +1,85 @@
+//! Tests for the MigrationRecord state
+
+#[cfg(test)]
+mod tests {
+    use folio::state::MigrationRecord;
+    use shared::constants::MIGRATION_ABORT_GRACE_PERIOD;
+    use shared::errors::ErrorCode;
+
+    #[test]
+    fn test_get_abortable_until() {
+        let migration_record = MigrationRecord {
+            started_at: 1_000,
+            ..MigrationRecord::default()
+        };
+
+        assert_eq!(
+            migration_record.get_abortable_until().unwrap(),
+            1_000 + MIGRATION_ABORT_GRACE_PERIOD
+        );
+    }
+
+    #[test]
+    fn test_validate_abort_grace_period() {
+        let migration_record = MigrationRecord {
+            started_at: 1_000,
+            ..MigrationRecord::default()
+        };
+
+        assert!(migration_record.validate_abort_grace_period(1_000).is_ok());
+        assert!(migration_record
+            .validate_abort_grace_period(1_000 + MIGRATION_ABORT_GRACE_PERIOD - 1)
+            .is_ok());
+        assert_eq!(
+            migration_record
+                .validate_abort_grace_period(1_000 + MIGRATION_ABORT_GRACE_PERIOD)
+                .unwrap_err(),
+            ErrorCode::MigrationAbortGracePeriodPassed.into()
+        );
+    }
+
+    #[test]
+    fn test_validate_abort_grace_period_passed() {
+        let migration_record = MigrationRecord {
+            started_at: 1_000,
+            ..MigrationRecord::default()
+        };
+
+        assert_eq!(
+            migration_record
+                .validate_abort_grace_period_passed(1_000)
+                .unwrap_err(),
+            ErrorCode::MigrationAbortGracePeriodNotPassed.into()
+        );
+        assert_eq!(
+            migration_record
+                .validate_abort_grace_period_passed(1_000 + MIGRATION_ABORT_GRACE_PERIOD - 1)
+                .unwrap_err(),
+            ErrorCode::MigrationAbortGracePeriodNotPassed.into()
+        );
+        assert!(migration_record
+            .validate_abort_grace_period_passed(1_000 + MIGRATION_ABORT_GRACE_PERIOD)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_validate_abort_grace_period_overflow() {
+        let migration_record = MigrationRecord {
+            started_at: u64::MAX,
+            ..MigrationRecord::default()
+        };
+
+        assert_eq!(
+            migration_record
+                .validate_abort_grace_period(u64::MAX)
+                .unwrap_err(),
+            ErrorCode::MathOverflow.into()
+        );
+        assert_eq!(
+            migration_record
+                .validate_abort_grace_period_passed(u64::MAX)
+                .unwrap_err(),
+            ErrorCode::MathOverflow.into()
+        );
+    }
+}
@@ -0,0 +1,586 @@
+//! Dry-run verifier for the migration of the folio accounts to a new folio program.
+//!
+//! The accounts of the old folio program are written to raw account blobs and read back the same way the new folio
+//! program reads them, then the conversion used by `create_folio_from_old_program`,
+//! `update_basket_in_new_folio_program`, `update_user_pending_basket_in_new_folio_program` and
+//! `create_fee_distribution_from_old_program` is run and every field of the resulting accounts is checked.
+//!
+//! The new accounts are destructured without `..`, so adding a field to one of the layouts doesn't compile until its
+//! migration is covered here.
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::__private::bytemuck;
+    use anchor_lang::prelude::Pubkey;
+    use anchor_lang::{AccountDeserialize, AccountSerialize, Discriminator, ZeroCopy};
+    use folio::state::{
+        FeeDistribution, FeeRecipients, Folio, FolioBasket, FolioMintLimits, UserPendingBasket,
+    };
+    use folio::utils::structs::{
+        FeeRecipient, FolioStatus, FolioTokenAmount, TokenAmount, UserTokenBasket,
+    };
+    use folio::utils::FixedSizeString;
+    use shared::constants::{
+        FEE_DISTRIBUTION_VERSION, FOLIO_BASKET_VERSION, FOLIO_VERSION, MAX_FEE_RECIPIENTS,
+    };
+    use shared::errors::ErrorCode;
+    use shared::utils::TokenUtil;
+    use spl_token_2022::extension::transfer_fee::{TransferFee, TransferFeeConfig};
+
+    const NEW_BUMP: u8 = 254;
+
+    /// Write an account of the old folio program to a raw account blob, discriminator included.
+    fn to_old_account_blob<T: ZeroCopy + Discriminator>(account: &T) -> Vec<u8> {
+        [
+            T::DISCRIMINATOR.to_vec(),
+            bytemuck::bytes_of(account).to_vec(),
+        ]
+        .concat()
+    }
+
+    /// Read an account of the old folio program from a raw account blob, skipping the discriminator.
+    fn load_old_account<T: ZeroCopy + Discriminator>(blob: &[u8]) -> T {
+        assert_eq!(blob[..8], T::DISCRIMINATOR[..]);
+        assert_eq!(blob.len(), 8 + std::mem::size_of::<T>());
+
+        bytemuck::pod_read_unaligned(&blob[8..])
+    }
+
+    fn old_folio(version: u8) -> Folio {
+        Folio {
+            bump: 251,
+            status: FolioStatus::Migrating as u8,
+            pause_flags: 0b101,
+            mint_allowlist_enabled: 1,
+            version,
+            fee_distribution_fallback: 1,
+            pending_baskets_tracked: 0,
+            mint_limits_enabled: 1,
+            initialized_at: 1_700_000_000,
+            folio_token_mint: Pubkey::new_unique(),
+            tvl_fee: 3_340_960_028,
+            mint_fee: 50_000_000_000_000_000,
+            dao_pending_fee_shares: 1_000_000_000_001,
+            fee_recipients_pending_fee_shares: 2_000_000_000_002,
+            auction_length: 3_600,
+            last_poke: 1_700_086_400,
+            mandate: FixedSizeString::new("Migrated folio mandate"),
+            fee_recipients_pending_fee_shares_to_be_minted: 3_000_000_000_003,
+        }
+    }
+
+    fn old_fee_recipients() -> FeeRecipients {
+        let mut fee_recipients = [FeeRecipient::default(); MAX_FEE_RECIPIENTS];
+        fee_recipients[0] = FeeRecipient {
+            recipient: Pubkey::new_unique(),
+            portion: 600_000_000_000_000_000,
+        };
+        fee_recipients[1] = FeeRecipient {
+            recipient: Pubkey::new_unique(),
+            portion: 400_000_000_000_000_000,
+        };
+
+        FeeRecipients {
+            bump: 252,
+            _padding: [0; 7],
+            distribution_index: 42,
+            folio: Pubkey::new_unique(),
+            fee_recipients,
+        }
+    }
+
+    fn old_folio_basket(token_amounts: &[FolioTokenAmount]) -> FolioBasket {
+        let mut old_folio_basket = FolioBasket {
+            bump: 253,
+            folio: Pubkey::new_unique(),
+            ..FolioBasket::default()
+        };
+        old_folio_basket.basket.token_amounts[..token_amounts.len()].copy_from_slice(token_amounts);
+
+        old_folio_basket
+    }
+
+    fn verify_folio_migration(old_folio_version: u8) {
+        let old_folio = old_folio(old_folio_version);
+        let blob = to_old_account_blob(&old_folio);
+        let loaded_old_folio: Folio = load_old_account(&blob);
+
+        let mut new_folio = Folio::default();
+        new_folio.init_from_old_folio(&loaded_old_folio, NEW_BUMP);
+
+        let Folio {
+            bump,
+            status,
+            pause_flags,
+            mint_allowlist_enabled,
+            version,
+            fee_distribution_fallback,
+            pending_baskets_tracked,
+            mint_limits_enabled,
+            initialized_at,
+            folio_token_mint,
+            tvl_fee,
+            mint_fee,
+            dao_pending_fee_shares,
+            fee_recipients_pending_fee_shares,
+            auction_length,
+            last_poke,
+            mandate,
+            fee_recipients_pending_fee_shares_to_be_minted,
+        } = new_folio;
+
+        // Fields specific to the new folio
+        assert_eq!(bump, NEW_BUMP);
+        assert_eq!(version, FOLIO_VERSION);
+        assert_eq!(status, FolioStatus::Migrating as u8);
+        assert_eq!(pending_baskets_tracked, 1);
+
+        // Fields carried over from the old folio
+        assert_eq!(pause_flags, old_folio.pause_flags);
+        assert_eq!(mint_allowlist_enabled, old_folio.mint_allowlist_enabled);
+        // The FolioMintLimits account is carried over with the flag, see `test_verify_folio_mint_limits_migration`
+        assert_eq!(mint_limits_enabled, old_folio.mint_limits_enabled);
+        assert_eq!(
+            fee_distribution_fallback,
+            old_folio.fee_distribution_fallback
+        );
+        assert_eq!(initialized_at, old_folio.initialized_at);
+        assert_eq!(folio_token_mint, old_folio.folio_token_mint);
+        assert_eq!(tvl_fee, old_folio.tvl_fee);
+        assert_eq!(mint_fee, old_folio.mint_fee);
+        assert_eq!(dao_pending_fee_shares, old_folio.dao_pending_fee_shares);
+        assert_eq!(
+            fee_recipients_pending_fee_shares,
+            old_folio.fee_recipients_pending_fee_shares
+        );
+        assert_eq!(auction_length, old_folio.auction_length);
+        assert_eq!(last_poke, old_folio.last_poke);
+        assert_eq!(mandate.value, old_folio.mandate.value);
+        assert_eq!(
+            fee_recipients_pending_fee_shares_to_be_minted,
+            old_folio.fee_recipients_pending_fee_shares_to_be_minted
+        );
+    }
+
+    #[test]
+    fn test_verify_folio_migration() {
+        verify_folio_migration(FOLIO_VERSION);
+    }
+
+    #[test]
+    fn test_verify_folio_migration_from_legacy_layout() {
+        // Folios created before the version header have the same layout, with a zeroed version
+        verify_folio_migration(0);
+    }
+
+    #[test]
+    fn test_verify_folio_mint_limits_migration() {
+        let old_folio_mint_limits = FolioMintLimits {
+            bump: 250,
+            folio: Pubkey::new_unique(),
+            max_supply: 1_000_000_000_000_000,
+            max_mint_per_tx: 10_000_000_000_000,
+        };
+
+        let mut blob = vec![];
+        old_folio_mint_limits.try_serialize(&mut blob).unwrap();
+        assert_eq!(blob.len(), FolioMintLimits::SIZE);
+
+        let loaded_old_folio_mint_limits =
+            FolioMintLimits::try_deserialize(&mut &blob[..]).unwrap();
+
+        // Same as `init_or_update_folio_mint_limits` on an account that isn't initialized yet
+        let new_folio = Pubkey::new_unique();
+        let mut new_folio_mint_limits = FolioMintLimits {
+            bump: NEW_BUMP,
+            folio: new_folio,
+            max_supply: 0,
+            max_mint_per_tx: 0,
+        };
+        new_folio_mint_limits.set_limits(
+            Some(loaded_old_folio_mint_limits.max_supply),
+            Some(loaded_old_folio_mint_limits.max_mint_per_tx),
+        );
+
+        let FolioMintLimits {
+            bump,
+            folio,
+            max_supply,
+            max_mint_per_tx,
+        } = new_folio_mint_limits;
+
+        // Fields specific to the new folio mint limits
+        assert_eq!(bump, NEW_BUMP);
+        assert_eq!(folio, new_folio);
+
+        // Fields carried over from the old folio mint limits
+        assert_eq!(max_supply, old_folio_mint_limits.max_supply);
+        assert_eq!(max_mint_per_tx, old_folio_mint_limits.max_mint_per_tx);
+    }
+
+    #[test]
+    fn test_verify_fee_recipients_migration() {
+        let old_fee_recipients = old_fee_recipients();
+        let blob = to_old_account_blob(&old_fee_recipients);
+        let loaded_old_fee_recipients: FeeRecipients = load_old_account(&blob);
+
+        let new_folio = Pubkey::new_unique();
+        let mut new_fee_recipients = FeeRecipients {
+            bump: NEW_BUMP,
+            folio: new_folio,
+            ..FeeRecipients::default()
+        };
+        new_fee_recipients.init_from_old_fee_recipients(&loaded_old_fee_recipients);
+
+        let FeeRecipients {
+            bump,
+            _padding,
+            distribution_index,
+            folio,
+            fee_recipients,
+        } = new_fee_recipients;
+
+        // Fields specific to the new fee recipients
+        assert_eq!(bump, NEW_BUMP);
+        assert_eq!(folio, new_folio);
+        assert_eq!(_padding, [0; 7]);
+
+        // Fields carried over from the old fee recipients
+        assert_eq!(distribution_index, old_fee_recipients.distribution_index);
+        assert_eq!(fee_recipients, old_fee_recipients.fee_recipients);
+        assert!(new_fee_recipients
+            .validate_fee_recipient_total_portions_and_check_for_duplicates()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_verify_folio_basket_migration() {
+        let token_amounts = [
+            FolioTokenAmount {
+                mint: Pubkey::new_unique(),
+                amount: 1_000,
+            },
+            FolioTokenAmount {
+                mint: Pubkey::new_unique(),
+                amount: 2_000_000,
+            },
+            FolioTokenAmount {
+                mint: Pubkey::new_unique(),
+                amount: u64::MAX,
+            },
+        ];
+        let mut old_folio_basket = old_folio_basket(&token_amounts);
+        let original_old_folio_basket = old_folio_basket;
+
+        let new_folio = Pubkey::new_unique();
+        let mut new_folio_basket = FolioBasket {
+            bump: NEW_BUMP,
+            version: FOLIO_BASKET_VERSION,
+            folio: new_folio,
+            ..FolioBasket::default()
+        };
+
+        // Tokens are migrated one by one, and removed from the old basket after each migration
+        for (i, token_amount) in token_amounts.iter().enumerate() {
+            let blob = to_old_account_blob(&old_folio_basket);
+            let loaded_old_folio_basket: FolioBasket = load_old_account(&blob);
+
+            let has_tokens_left = new_folio_basket
+                .migrate_token_from_old_folio_basket(
+                    &loaded_old_folio_basket,
+                    &token_amount.mint,
+                    token_amount.amount,
+                )
+                .unwrap();
+
+            assert_eq!(has_tokens_left, i < token_amounts.len() - 1);
+
+            old_folio_basket
+                .remove_token_mint_from_basket(token_amount.mint)
+                .unwrap();
+        }
+
+        let FolioBasket {
+            bump,
+            version,
+            _padding,
+            folio,
+            basket,
+        } = new_folio_basket;
+
+        // Fields specific to the new folio basket
+        assert_eq!(bump, NEW_BUMP);
+        assert_eq!(version, FOLIO_BASKET_VERSION);
+        assert_eq!(folio, new_folio);
+        assert_eq!(_padding, [0; 6]);
+
+        // Tokens carried over from the old folio basket
+        assert_eq!(
+            basket.token_amounts,
+            original_old_folio_basket.basket.token_amounts
+        );
+        assert_eq!(old_folio_basket.get_total_number_of_mints(), 0);
+    }
+
+    #[test]
+    fn test_verify_folio_basket_migration_with_transfer_fee() {
+        // 1% transfer fee
+        let transfer_fee = TransferFee {
+            epoch: 0.into(),
+            maximum_fee: u64::MAX.into(),
+            transfer_fee_basis_points: 100.into(),
+        };
+        let transfer_fee_config = TransferFeeConfig {
+            older_transfer_fee: transfer_fee,
+            newer_transfer_fee: transfer_fee,
+            ..TransferFeeConfig::default()
+        };
+
+        let token_amount = FolioTokenAmount {
+            mint: Pubkey::new_unique(),
+            amount: 1_000_000,
+        };
+        let old_folio_basket = old_folio_basket(&[token_amount]);
+        let blob = to_old_account_blob(&old_folio_basket);
+        let loaded_old_folio_basket: FolioBasket = load_old_account(&blob);
+
+        let raw_received_amount = TokenUtil::get_amount_after_transfer_fee_in_epoch(
+            Some(&transfer_fee_config),
+            0,
+            token_amount.amount,
+        )
+        .unwrap();
+        assert_eq!(raw_received_amount, 990_000);
+
+        let mut new_folio_basket = FolioBasket::default();
+
+        let has_tokens_left = new_folio_basket
+            .migrate_token_from_old_folio_basket(
+                &loaded_old_folio_basket,
+                &token_amount.mint,
+                raw_received_amount,
+            )
+            .unwrap();
+
+        assert!(!has_tokens_left);
+        // Only the net amount is credited to the new basket
+        assert_eq!(
+            new_folio_basket
+                .get_token_amount_in_folio_basket(&token_amount.mint)
+                .unwrap(),
+            raw_received_amount
+        );
+    }
+
+    #[test]
+    fn test_verify_folio_basket_migration_received_more_than_sent() {
+        let token_amount = FolioTokenAmount {
+            mint: Pubkey::new_unique(),
+            amount: 1_000,
+        };
+        let old_folio_basket = old_folio_basket(&[token_amount]);
+        let blob = to_old_account_blob(&old_folio_basket);
+        let loaded_old_folio_basket: FolioBasket = load_old_account(&blob);
+
+        let mut new_folio_basket = FolioBasket::default();
+
+        assert_eq!(
+            new_folio_basket
+                .migrate_token_from_old_folio_basket(
+                    &loaded_old_folio_basket,
+                    &token_amount.mint,
+                    1_001
+                )
+                .unwrap_err(),
+            ErrorCode::InvalidTokenBalance.into()
+        );
+        assert_eq!(new_folio_basket.get_total_number_of_mints(), 0);
+    }
+
+    #[test]
+    fn test_verify_folio_basket_migration_token_not_in_old_basket() {
+        let old_folio_basket = old_folio_basket(&[FolioTokenAmount {
+            mint: Pubkey::new_unique(),
+            amount: 1_000,
+        }]);
+        let blob = to_old_account_blob(&old_folio_basket);
+        let loaded_old_folio_basket: FolioBasket = load_old_account(&blob);
+
+        let mut new_folio_basket = FolioBasket::default();
+
+        assert_eq!(
+            new_folio_basket
+                .migrate_token_from_old_folio_basket(
+                    &loaded_old_folio_basket,
+                    &Pubkey::new_unique(),
+                    0
+                )
+                .unwrap_err(),
+            ErrorCode::TokenMintNotInOldFolioBasket.into()
+        );
+        assert_eq!(new_folio_basket.get_total_number_of_mints(), 0);
+    }
+
+    #[test]
+    fn test_verify_user_pending_basket_migration() {
+        let token_amounts = [
+            TokenAmount {
+                mint: Pubkey::new_unique(),
+                amount_for_minting: 1_000,
+                amount_for_redeeming: 0,
+            },
+            TokenAmount {
+                mint: Pubkey::new_unique(),
+                amount_for_minting: 0,
+                amount_for_redeeming: 2_000_000,
+            },
+            TokenAmount {
+                mint: Pubkey::new_unique(),
+                amount_for_minting: u64::MAX / 2,
+                amount_for_redeeming: u64::MAX / 2,
+            },
+        ];
+
+        let mut basket = UserTokenBasket::default();
+        basket.token_amounts[..token_amounts.len()].copy_from_slice(&token_amounts);
+
+        let mut old_user_pending_basket = UserPendingBasket {
+            bump: 249,
+            is_tracked: 1,
+            _padding: [0; 6],
+            owner: Pubkey::new_unique(),
+            folio: Pubkey::new_unique(),
+            basket,
+        };
+        let original_old_user_pending_basket = old_user_pending_basket;
+
+        // Same as `process_init_if_needed` on an account that isn't initialized yet
+        let new_folio = Pubkey::new_unique();
+        let mut new_user_pending_basket = UserPendingBasket {
+            bump: NEW_BUMP,
+            owner: old_user_pending_basket.owner,
+            folio: new_folio,
+            ..UserPendingBasket::default()
+        };
+
+        // Tokens are migrated one by one, and taken from the old pending basket after each migration
+        for token_amount in token_amounts.iter() {
+            let blob = to_old_account_blob(&old_user_pending_basket);
+            let loaded_old_user_pending_basket: UserPendingBasket = load_old_account(&blob);
+
+            let migrated_token_amount = *loaded_old_user_pending_basket
+                .basket
+                .token_amounts
+                .iter()
+                .find(|ta| ta.mint == token_amount.mint)
+                .unwrap();
+
+            new_user_pending_basket
+                .add_migrated_token_amount(
+                    &migrated_token_amount,
+                    migrated_token_amount.amount_for_minting
+                        + migrated_token_amount.amount_for_redeeming,
+                )
+                .unwrap();
+            // Done by `process_user_pending_basket_change` once the new pending basket is updated
+            new_user_pending_basket.track_token_amounts();
+
+            old_user_pending_basket
+                .take_token_amount(&token_amount.mint)
+                .unwrap();
+        }
+
+        let UserPendingBasket {
+            bump,
+            is_tracked,
+            _padding,
+            owner,
+            folio,
+            basket,
+        } = new_user_pending_basket;
+
+        // Fields specific to the new user pending basket
+        assert_eq!(bump, NEW_BUMP);
+        assert_eq!(folio, new_folio);
+        assert_eq!(is_tracked, 1);
+        assert_eq!(_padding, [0; 6]);
+
+        // Fields carried over from the old user pending basket
+        assert_eq!(owner, original_old_user_pending_basket.owner);
+        assert_eq!(
+            basket.token_amounts,
+            original_old_user_pending_basket.basket.token_amounts
+        );
+        assert!(old_user_pending_basket
+            .basket
+            .token_amounts
+            .iter()
+            .all(|ta| *ta == TokenAmount::default()));
+    }
+
+    #[test]
+    fn test_verify_fee_distribution_migration() {
+        let old_fee_recipients = old_fee_recipients();
+
+        let old_fee_distribution = FeeDistribution {
+            bump: 248,
+            version: FEE_DISTRIBUTION_VERSION,
+            _padding: [0; 6],
+            index: 7,
+            folio: Pubkey::new_unique(),
+            cranker: Pubkey::new_unique(),
+            amount_to_distribute: 4_000_000_000_004,
+            fee_recipients_state: old_fee_recipients.fee_recipients,
+            created_at: 1_700_043_200,
+            _padding_2: [0; 8],
+        };
+        let blob = to_old_account_blob(&old_fee_distribution);
+        let loaded_old_fee_distribution: FeeDistribution = load_old_account(&blob);
+
+        // Same as `create_fee_distribution_from_old_program`, the index is kept and the caller becomes the cranker
+        let new_folio = Pubkey::new_unique();
+        let new_cranker = Pubkey::new_unique();
+        let mut new_fee_distribution = FeeDistribution {
+            bump: NEW_BUMP,
+            version: FEE_DISTRIBUTION_VERSION,
+            index: old_fee_distribution.index,
+            folio: new_folio,
+            cranker: new_cranker,
+            ..FeeDistribution::default()
+        };
+        new_fee_distribution.init_from_old_fee_distribution(&loaded_old_fee_distribution);
+
+        let FeeDistribution {
+            bump,
+            version,
+            _padding,
+            index,
+            folio,
+            cranker,
+            amount_to_distribute,
+            fee_recipients_state,
+            created_at,
+            _padding_2,
+        } = new_fee_distribution;
+
+        // Fields specific to the new fee distribution
+        assert_eq!(bump, NEW_BUMP);
+        assert_eq!(version, FEE_DISTRIBUTION_VERSION);
+        assert_eq!(folio, new_folio);
+        assert_eq!(cranker, new_cranker);
+        assert_eq!(_padding, [0; 6]);
+        assert_eq!(_padding_2, [0; 8]);
+
+        // Fields carried over from the old fee distribution
+        assert_eq!(index, old_fee_distribution.index);
+        assert_eq!(
+            amount_to_distribute,
+            old_fee_distribution.amount_to_distribute
+        );
+        assert_eq!(
+            fee_recipients_state,
+            old_fee_distribution.fee_recipients_state
+        );
+        assert_eq!(created_at, old_fee_distribution.created_at);
+    }
+}
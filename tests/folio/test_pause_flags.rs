@@ -0,0 +1,38 @@
+//! Tests for the PauseFlag state
+
+#[cfg(test)]
+mod tests {
+
+    use folio::utils::structs::PauseFlag;
+
+    #[test]
+    fn test_pause_flag_bits() {
+        assert_eq!(PauseFlag::Mint as u8, 0b0000_0001);
+        assert_eq!(PauseFlag::Redeem as u8, 0b0000_0010);
+        assert_eq!(PauseFlag::Auctions as u8, 0b0000_0100);
+        assert_eq!(PauseFlag::FeeDistribution as u8, 0b0000_1000);
+        assert_eq!(PauseFlag::ALL, 0b0000_1111);
+    }
+
+    #[test]
+    fn test_is_paused() {
+        let pause_flags = PauseFlag::Mint as u8 | PauseFlag::FeeDistribution as u8;
+
+        assert!(PauseFlag::is_paused(pause_flags, PauseFlag::Mint));
+        assert!(!PauseFlag::is_paused(pause_flags, PauseFlag::Redeem));
+        assert!(!PauseFlag::is_paused(pause_flags, PauseFlag::Auctions));
+        assert!(PauseFlag::is_paused(
+            pause_flags,
+            PauseFlag::FeeDistribution
+        ));
+    }
+
+    #[test]
+    fn test_is_valid() {
+        assert!(PauseFlag::is_valid(PauseFlag::Redeem as u8));
+        assert!(PauseFlag::is_valid(PauseFlag::ALL));
+        assert!(!PauseFlag::is_valid(0));
+        assert!(!PauseFlag::is_valid(0b0001_0000));
+        assert!(!PauseFlag::is_valid(PauseFlag::Mint as u8 | 0b1000_0000));
+    }
+}
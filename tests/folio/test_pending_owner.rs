@@ -0,0 +1,99 @@
+//! Tests for the PendingOwner state
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+    use folio::state::PendingOwner;
+    use shared::errors::ErrorCode;
+
+    #[test]
+    fn test_process_init_if_needed() {
+        let mut pending_owner = PendingOwner::default();
+        let folio = Pubkey::new_unique();
+
+        pending_owner
+            .process_init_if_needed(0, 255, &folio)
+            .unwrap();
+
+        assert_eq!(pending_owner.bump, 255);
+        assert_eq!(pending_owner.folio, folio);
+
+        assert_eq!(
+            pending_owner
+                .process_init_if_needed(255, 254, &folio)
+                .unwrap_err(),
+            ErrorCode::InvalidBump.into()
+        );
+    }
+
+    #[test]
+    fn test_propose() {
+        let mut pending_owner = PendingOwner::default();
+        let proposed_by = Pubkey::new_unique();
+        let new_owner = Pubkey::new_unique();
+
+        pending_owner
+            .propose(&proposed_by, &new_owner, 1_000)
+            .unwrap();
+
+        assert_eq!(pending_owner.proposed_by, proposed_by);
+        assert_eq!(pending_owner.new_owner, new_owner);
+        assert_eq!(pending_owner.proposed_at, 1_000);
+
+        // A new proposal overrides the previous one
+        let other_new_owner = Pubkey::new_unique();
+        pending_owner
+            .propose(&proposed_by, &other_new_owner, 2_000)
+            .unwrap();
+
+        assert_eq!(pending_owner.new_owner, other_new_owner);
+        assert_eq!(pending_owner.proposed_at, 2_000);
+    }
+
+    #[test]
+    fn test_propose_invalid_new_owner() {
+        let mut pending_owner = PendingOwner::default();
+        let proposed_by = Pubkey::new_unique();
+
+        assert_eq!(
+            pending_owner
+                .propose(&proposed_by, &proposed_by, 1_000)
+                .unwrap_err(),
+            ErrorCode::InvalidPendingOwner.into()
+        );
+        assert_eq!(
+            pending_owner
+                .propose(&proposed_by, &Pubkey::default(), 1_000)
+                .unwrap_err(),
+            ErrorCode::InvalidPendingOwner.into()
+        );
+    }
+
+    #[test]
+    fn test_validate_acceptance() {
+        let proposed_by = Pubkey::new_unique();
+        let new_owner = Pubkey::new_unique();
+
+        let pending_owner = PendingOwner {
+            proposed_by,
+            new_owner,
+            ..PendingOwner::default()
+        };
+
+        assert!(pending_owner
+            .validate_acceptance(&new_owner, &proposed_by)
+            .is_ok());
+        assert_eq!(
+            pending_owner
+                .validate_acceptance(&Pubkey::new_unique(), &proposed_by)
+                .unwrap_err(),
+            ErrorCode::InvalidPendingOwner.into()
+        );
+        assert_eq!(
+            pending_owner
+                .validate_acceptance(&new_owner, &Pubkey::new_unique())
+                .unwrap_err(),
+            ErrorCode::InvalidPendingOwner.into()
+        );
+    }
+}
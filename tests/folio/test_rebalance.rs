@@ -0,0 +1,43 @@
+//! Tests for the Rebalance state
+
+#[cfg(test)]
+mod tests {
+
+    use folio::state::Rebalance;
+
+    #[test]
+    fn test_start_rebalance_sets_use_ui_amounts() {
+        let mut rebalance = Rebalance::default();
+
+        rebalance
+            .start_rebalance(100, 10, 20, &[], vec![], false, true)
+            .unwrap();
+        assert_eq!(rebalance.use_ui_amounts, 1);
+        assert_eq!(rebalance.nonce, 1);
+
+        // Adding details doesn't change it
+        rebalance.add_rebalance_details(&[], vec![], true).unwrap();
+        assert_eq!(rebalance.use_ui_amounts, 1);
+
+        // A new rebalance sets it again
+        rebalance
+            .start_rebalance(200, 10, 20, &[], vec![], true, false)
+            .unwrap();
+        assert_eq!(rebalance.use_ui_amounts, 0);
+        assert_eq!(rebalance.nonce, 2);
+    }
+
+    #[test]
+    fn test_clear_keeps_use_ui_amounts() {
+        let mut rebalance = Rebalance {
+            use_ui_amounts: 1,
+            current_auction_id: 3,
+            ..Rebalance::default()
+        };
+
+        rebalance.clear();
+
+        assert_eq!(rebalance.use_ui_amounts, 1);
+        assert_eq!(rebalance.current_auction_id, 0);
+    }
+}
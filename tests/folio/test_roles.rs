@@ -10,6 +10,19 @@ mod tests {
         assert_eq!(Role::Owner as u8, 0b0000_0001);
         assert_eq!(Role::RebalanceManager as u8, 0b0000_0010);
         assert_eq!(Role::AuctionLauncher as u8, 0b0000_0100);
+        assert_eq!(Role::BrandManager as u8, 0b0000_1000);
+        assert_eq!(Role::Guardian as u8, 0b0001_0000);
+        assert_eq!(Role::AllowlistManager as u8, 0b0010_0000);
+    }
+
+    #[test]
+    fn test_role_index() {
+        assert_eq!(Role::Owner.index(), 0);
+        assert_eq!(Role::RebalanceManager.index(), 1);
+        assert_eq!(Role::AuctionLauncher.index(), 2);
+        assert_eq!(Role::BrandManager.index(), 3);
+        assert_eq!(Role::Guardian.index(), 4);
+        assert_eq!(Role::AllowlistManager.index(), 5);
     }
 
     #[test]
@@ -397,6 +397,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_get_tracked_token_amounts() {
+        let mut user_basket = UserPendingBasket::default();
+
+        let mint = Pubkey::new_unique();
+        user_basket.basket.token_amounts[1] = TokenAmount {
+            mint,
+            amount_for_minting: 100,
+            amount_for_redeeming: 200,
+        };
+
+        // Not tracked yet, nothing is accounted for
+        assert!(user_basket.get_tracked_token_amounts().is_empty());
+
+        let token_amounts = user_basket.track_token_amounts();
+        assert_eq!(user_basket.is_tracked, 1);
+        assert_eq!(token_amounts, vec![user_basket.basket.token_amounts[1]]);
+        assert_eq!(user_basket.get_tracked_token_amounts(), token_amounts);
+    }
+
     #[test]
     fn test_to_assets_for_minting() {
         let mut user_amount = TokenAmount {
@@ -741,4 +761,102 @@ mod tests {
         assert_eq!(user_amount.amount_for_minting, 500_000); // Is reduced to 1/2
         assert_eq!(related_mint.amount, 1_500_000);
     }
+
+    #[test]
+    fn test_take_token_amount() {
+        let mut pending = UserPendingBasket::default();
+        let token = TokenAmount {
+            mint: Pubkey::new_unique(),
+            amount_for_minting: 100,
+            amount_for_redeeming: 50,
+        };
+        let other_token = TokenAmount {
+            mint: Pubkey::new_unique(),
+            amount_for_minting: 200,
+            amount_for_redeeming: 0,
+        };
+        pending.basket.token_amounts[0] = token;
+        pending.basket.token_amounts[1] = other_token;
+
+        let taken = pending.take_token_amount(&token.mint).unwrap();
+
+        assert_eq!(taken, token);
+        assert_eq!(pending.basket.token_amounts[0], TokenAmount::default());
+        assert_eq!(pending.basket.token_amounts[1], other_token);
+    }
+
+    #[test]
+    fn test_take_token_amount_missing_mint() {
+        let mut pending = UserPendingBasket::default();
+
+        let result = pending.take_token_amount(&Pubkey::new_unique());
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            ErrorCode::InvalidRemovedTokenMints.into()
+        );
+
+        let result = pending.take_token_amount(&Pubkey::default());
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            ErrorCode::InvalidRemovedTokenMints.into()
+        );
+    }
+
+    #[test]
+    fn test_add_migrated_token_amount() {
+        let mut pending = UserPendingBasket::default();
+        let token = TokenAmount {
+            mint: Pubkey::new_unique(),
+            amount_for_minting: 100,
+            amount_for_redeeming: 50,
+        };
+
+        pending.add_migrated_token_amount(&token, 150).unwrap();
+
+        assert_eq!(pending.basket.token_amounts[0], token);
+        assert_eq!(pending.basket.token_amounts[1], TokenAmount::default());
+
+        // Adding again accumulates both amounts in the same slot
+        pending.add_migrated_token_amount(&token, 150).unwrap();
+
+        assert_eq!(pending.basket.token_amounts[0].amount_for_minting, 200);
+        assert_eq!(pending.basket.token_amounts[0].amount_for_redeeming, 100);
+        assert_eq!(pending.basket.token_amounts[1], TokenAmount::default());
+    }
+
+    #[test]
+    fn test_add_migrated_token_amount_with_transfer_fee() {
+        let mut pending = UserPendingBasket::default();
+        let token = TokenAmount {
+            mint: Pubkey::new_unique(),
+            amount_for_minting: 1_000,
+            amount_for_redeeming: 333,
+        };
+
+        // 1_333 sent, 1_320 received
+        pending.add_migrated_token_amount(&token, 1_320).unwrap();
+
+        // The fee is taken pro-rata, the rounding goes to the amount for minting
+        assert_eq!(pending.basket.token_amounts[0].amount_for_redeeming, 329);
+        assert_eq!(pending.basket.token_amounts[0].amount_for_minting, 991);
+    }
+
+    #[test]
+    fn test_add_migrated_token_amount_received_more_than_sent() {
+        let mut pending = UserPendingBasket::default();
+        let token = TokenAmount {
+            mint: Pubkey::new_unique(),
+            amount_for_minting: 100,
+            amount_for_redeeming: 50,
+        };
+
+        let result = pending.add_migrated_token_amount(&token, 151);
+
+        assert_eq!(result.unwrap_err(), ErrorCode::InvalidTokenBalance.into());
+        assert_eq!(pending.basket.token_amounts[0], TokenAmount::default());
+    }
 }
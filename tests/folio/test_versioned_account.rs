@@ -0,0 +1,160 @@
+//! Tests for the layout versioning of the zero copy accounts
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::Discriminator;
+    use anchor_lang::__private::bytemuck;
+    use folio::state::{Auction, FeeDistribution, Folio, FolioBasket, Rebalance};
+    use folio::utils::versioned_account::VersionedAccount;
+    use shared::constants::{
+        AUCTION_VERSION, FEE_DISTRIBUTION_CLOSE_GRACE_PERIOD, FEE_DISTRIBUTION_VERSION,
+        FOLIO_BASKET_VERSION, FOLIO_VERSION, REBALANCE_VERSION,
+    };
+    use shared::errors::ErrorCode;
+
+    const CURRENT_TIME: u64 = 1_700_000_000;
+
+    fn legacy_account_data<T: VersionedAccount>() -> Vec<u8> {
+        let mut data = vec![0u8; T::CURRENT_SIZE];
+        data[..8].copy_from_slice(T::DISCRIMINATOR);
+        data
+    }
+
+    #[test]
+    fn test_version_offsets() {
+        // The version byte took over a padding byte, so the layout of the existing accounts is unchanged
+        assert_eq!(Folio::VERSION_OFFSET, 12);
+        assert_eq!(FolioBasket::VERSION_OFFSET, 9);
+        assert_eq!(Rebalance::VERSION_OFFSET, 10);
+        assert_eq!(Auction::VERSION_OFFSET, 9);
+        assert_eq!(FeeDistribution::VERSION_OFFSET, 9);
+
+        assert_eq!(Folio::CURRENT_SIZE, 280);
+        assert_eq!(FolioBasket::CURRENT_SIZE, 4048);
+        assert_eq!(Rebalance::CURRENT_SIZE, 3448);
+        assert_eq!(Auction::CURRENT_SIZE, 216);
+        assert_eq!(FeeDistribution::CURRENT_SIZE, 3192);
+    }
+
+    #[test]
+    fn test_get_version() {
+        let mut data = legacy_account_data::<Folio>();
+        assert_eq!(Folio::get_version(&data).unwrap(), 0);
+
+        data[Folio::VERSION_OFFSET] = FOLIO_VERSION;
+        assert_eq!(Folio::get_version(&data).unwrap(), FOLIO_VERSION);
+    }
+
+    #[test]
+    fn test_get_version_invalid_discriminator() {
+        let data = legacy_account_data::<Folio>();
+
+        assert_eq!(
+            FolioBasket::get_version(&data).unwrap_err(),
+            ErrorCode::AccountNotUpgradable.into()
+        );
+    }
+
+    #[test]
+    fn test_get_version_data_too_short() {
+        let data = Folio::DISCRIMINATOR.to_vec();
+
+        assert_eq!(
+            Folio::get_version(&data).unwrap_err(),
+            ErrorCode::AccountNotUpgradable.into()
+        );
+    }
+
+    #[test]
+    fn test_upgrade_data() {
+        let mut data = legacy_account_data::<Folio>();
+        data[Folio::VERSION_OFFSET - 1] = 1;
+        data[Folio::VERSION_OFFSET + 1] = 2;
+
+        Folio::upgrade_data(&mut data, CURRENT_TIME).unwrap();
+
+        assert_eq!(data[Folio::VERSION_OFFSET], FOLIO_VERSION);
+        // The other fields are left untouched
+        assert_eq!(data[..8], Folio::DISCRIMINATOR[..]);
+        assert_eq!(data[Folio::VERSION_OFFSET - 1], 1);
+        assert_eq!(data[Folio::VERSION_OFFSET + 1], 2);
+    }
+
+    #[test]
+    fn test_upgrade_data_all_accounts() {
+        let mut data = legacy_account_data::<FolioBasket>();
+        FolioBasket::upgrade_data(&mut data, CURRENT_TIME).unwrap();
+        assert_eq!(
+            FolioBasket::get_version(&data).unwrap(),
+            FOLIO_BASKET_VERSION
+        );
+
+        let mut data = legacy_account_data::<Rebalance>();
+        Rebalance::upgrade_data(&mut data, CURRENT_TIME).unwrap();
+        assert_eq!(Rebalance::get_version(&data).unwrap(), REBALANCE_VERSION);
+
+        let mut data = legacy_account_data::<Auction>();
+        Auction::upgrade_data(&mut data, CURRENT_TIME).unwrap();
+        assert_eq!(Auction::get_version(&data).unwrap(), AUCTION_VERSION);
+    }
+
+    #[test]
+    fn test_upgrade_data_other_accounts_ignore_current_time() {
+        let mut data = legacy_account_data::<Folio>();
+
+        Folio::upgrade_data(&mut data, CURRENT_TIME).unwrap();
+
+        let mut expected_data = legacy_account_data::<Folio>();
+        expected_data[Folio::VERSION_OFFSET] = FOLIO_VERSION;
+        assert_eq!(data, expected_data);
+    }
+
+    #[test]
+    fn test_upgrade_data_already_upgraded() {
+        let mut data = legacy_account_data::<Rebalance>();
+        Rebalance::upgrade_data(&mut data, CURRENT_TIME).unwrap();
+
+        assert_eq!(
+            Rebalance::upgrade_data(&mut data, CURRENT_TIME).unwrap_err(),
+            ErrorCode::AccountAlreadyUpgraded.into()
+        );
+    }
+
+    #[test]
+    fn test_upgrade_data_not_resized() {
+        let mut data = legacy_account_data::<Auction>();
+        data.truncate(Auction::CURRENT_SIZE - 8);
+
+        assert_eq!(
+            Auction::upgrade_data(&mut data, CURRENT_TIME).unwrap_err(),
+            ErrorCode::InvalidAccountData.into()
+        );
+    }
+
+    #[test]
+    fn test_upgrade_data_legacy_fee_distribution() {
+        // Fee distributions created before `created_at` was added are 16 bytes shorter, they are resized with the
+        // new bytes zeroed before being upgraded
+        let mut data = legacy_account_data::<FeeDistribution>();
+        data[FeeDistribution::VERSION_OFFSET + 1..FeeDistribution::CURRENT_SIZE - 16].fill(1);
+        data[FeeDistribution::VERSION_OFFSET + 1..FeeDistribution::VERSION_OFFSET + 7].fill(0);
+
+        FeeDistribution::upgrade_data(&mut data, CURRENT_TIME).unwrap();
+
+        assert_eq!(
+            FeeDistribution::get_version(&data).unwrap(),
+            FEE_DISTRIBUTION_VERSION
+        );
+
+        let fee_distribution: FeeDistribution = bytemuck::pod_read_unaligned(&data[8..]);
+
+        // A missing `created_at` is set to the time of the upgrade, so the fee distribution can't be closed right away
+        assert_eq!(fee_distribution.created_at, CURRENT_TIME);
+        assert!(!fee_distribution.is_closable(CURRENT_TIME));
+        assert!(!fee_distribution.is_closable(
+            CURRENT_TIME + FEE_DISTRIBUTION_CLOSE_GRACE_PERIOD - 1
+        ));
+        assert!(fee_distribution.is_closable(CURRENT_TIME + FEE_DISTRIBUTION_CLOSE_GRACE_PERIOD));
+        assert_eq!(fee_distribution.index, u64::from_le_bytes([1; 8]));
+    }
+}
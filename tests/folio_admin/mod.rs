@@ -1 +1,6 @@
+pub mod test_dao_fee_config;
+pub mod test_folio_action_registrar;
+pub mod test_folio_fee_config;
 pub mod test_program_registrar;
+pub mod test_transfer_hook_registrar;
+pub mod test_unkill_config;
@@ -0,0 +1,138 @@
+//! Test module for the DAOFeeConfig struct.
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+    use anchor_lang::Discriminator;
+    use folio_admin::state::DAOFeeConfig;
+    use folio_admin::utils::dao_fee_config::FeeDetails;
+    use folio_admin::utils::ProtocolFeeRecipient;
+    use shared::constants::{FEE_DENOMINATOR, MAX_FEE_RECIPIENTS_PORTION};
+    use shared::errors::ErrorCode;
+
+    fn protocol_fee_recipient(portion: u128) -> ProtocolFeeRecipient {
+        ProtocolFeeRecipient {
+            recipient: Pubkey::new_unique(),
+            portion,
+        }
+    }
+
+    #[test]
+    fn test_try_deserialize_with_legacy_support_legacy_layout() {
+        let fee_recipient = Pubkey::new_unique();
+
+        let mut data = DAOFeeConfig::DISCRIMINATOR.to_vec();
+        data.push(255);
+        data.extend_from_slice(fee_recipient.as_ref());
+        data.extend_from_slice(&100u128.to_le_bytes());
+        data.extend_from_slice(&10u128.to_le_bytes());
+
+        let deserialized = DAOFeeConfig::try_deserialize_with_legacy_support(&data).unwrap();
+
+        assert_eq!(deserialized.bump, 255);
+        assert_eq!(deserialized.fee_recipient, fee_recipient);
+        assert_eq!(deserialized.default_fee_numerator, 100);
+        assert_eq!(deserialized.default_fee_floor, 10);
+        assert!(deserialized
+            .protocol_fee_recipients
+            .iter()
+            .all(|r| *r == ProtocolFeeRecipient::default()));
+
+        let protocol_fee_recipients = deserialized.get_protocol_fee_recipients();
+        assert_eq!(protocol_fee_recipients.len(), 1);
+        assert_eq!(protocol_fee_recipients[0].recipient, fee_recipient);
+        assert_eq!(
+            protocol_fee_recipients[0].portion,
+            MAX_FEE_RECIPIENTS_PORTION
+        );
+    }
+
+    #[test]
+    fn test_set_protocol_fee_recipients() {
+        let mut dao_fee_config = DAOFeeConfig::default();
+
+        let recipients = vec![
+            protocol_fee_recipient(600_000_000_000_000_000),
+            protocol_fee_recipient(400_000_000_000_000_000),
+        ];
+
+        dao_fee_config
+            .set_protocol_fee_recipients(recipients.clone())
+            .unwrap();
+
+        assert_eq!(dao_fee_config.get_protocol_fee_recipients(), recipients);
+
+        // Clearing falls back to the fee recipient
+        dao_fee_config.set_protocol_fee_recipients(vec![]).unwrap();
+        assert_eq!(dao_fee_config.get_protocol_fee_recipients().len(), 1);
+    }
+
+    #[test]
+    fn test_set_protocol_fee_recipients_invalid() {
+        let mut dao_fee_config = DAOFeeConfig::default();
+
+        let result = dao_fee_config.set_protocol_fee_recipients(vec![
+            protocol_fee_recipient(500_000_000_000_000_000),
+            protocol_fee_recipient(400_000_000_000_000_000),
+        ]);
+        assert_eq!(
+            result.unwrap_err(),
+            error!(ErrorCode::InvalidFeeRecipientPortion)
+        );
+
+        let duplicate = protocol_fee_recipient(500_000_000_000_000_000);
+        let result = dao_fee_config.set_protocol_fee_recipients(vec![duplicate, duplicate]);
+        assert_eq!(
+            result.unwrap_err(),
+            error!(ErrorCode::InvalidFeeRecipientContainsDuplicates)
+        );
+
+        let result = dao_fee_config.set_protocol_fee_recipients(vec![
+            protocol_fee_recipient(200_000_000_000_000_000),
+            protocol_fee_recipient(200_000_000_000_000_000),
+            protocol_fee_recipient(200_000_000_000_000_000),
+            protocol_fee_recipient(200_000_000_000_000_000),
+            protocol_fee_recipient(200_000_000_000_000_000),
+        ]);
+        assert_eq!(
+            result.unwrap_err(),
+            error!(ErrorCode::InvalidFeeRecipientCount)
+        );
+    }
+
+    #[test]
+    fn test_validate_protocol_fee_recipient_total_portions_overflow() {
+        let mut dao_fee_config = DAOFeeConfig::default();
+        dao_fee_config.protocol_fee_recipients[0] = protocol_fee_recipient(u128::MAX);
+        dao_fee_config.protocol_fee_recipients[1] = protocol_fee_recipient(2);
+
+        assert_eq!(
+            dao_fee_config
+                .validate_protocol_fee_recipient_total_portions_and_check_for_duplicates()
+                .unwrap_err(),
+            error!(ErrorCode::MathOverflow)
+        );
+    }
+
+    #[test]
+    fn test_split_protocol_fee_amount() {
+        let protocol_fee_recipients = vec![
+            protocol_fee_recipient(333_333_333_333_333_334),
+            protocol_fee_recipient(333_333_333_333_333_333),
+            protocol_fee_recipient(333_333_333_333_333_333),
+        ];
+
+        let fee_details = FeeDetails {
+            fee_recipient: protocol_fee_recipients[0].recipient,
+            protocol_fee_recipients,
+            scaled_fee_denominator: FEE_DENOMINATOR,
+            scaled_fee_numerator: 0,
+            scaled_fee_floor: 0,
+        };
+
+        let amounts = fee_details.split_protocol_fee_amount(1_000).unwrap();
+
+        // The first recipient receives the rounding dust
+        assert_eq!(amounts, vec![334, 333, 333]);
+        assert_eq!(amounts.iter().sum::<u64>(), 1_000);
+    }
+}
@@ -0,0 +1,61 @@
+//! Test module for the FolioFeeConfig struct.
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+    use anchor_lang::Discriminator;
+    use folio_admin::state::FolioFeeConfig;
+
+    fn serialize_legacy_folio_fee_config(
+        bump: u8,
+        fee_numerator: u128,
+        fee_floor: u128,
+    ) -> Vec<u8> {
+        let mut data = FolioFeeConfig::DISCRIMINATOR.to_vec();
+        data.push(bump);
+        data.extend_from_slice(&fee_numerator.to_le_bytes());
+        data.extend_from_slice(&fee_floor.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn test_try_deserialize_with_legacy_support_current_layout() {
+        let fee_recipient = Pubkey::new_unique();
+        let folio_fee_config = FolioFeeConfig {
+            bump: 254,
+            fee_numerator: 100,
+            fee_floor: 10,
+            fee_recipient,
+        };
+
+        let mut data = Vec::new();
+        folio_fee_config.try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), FolioFeeConfig::SIZE);
+
+        let deserialized = FolioFeeConfig::try_deserialize_with_legacy_support(&data).unwrap();
+
+        assert_eq!(deserialized.bump, 254);
+        assert_eq!(deserialized.fee_numerator, 100);
+        assert_eq!(deserialized.fee_floor, 10);
+        assert_eq!(deserialized.fee_recipient, fee_recipient);
+    }
+
+    #[test]
+    fn test_try_deserialize_with_legacy_support_legacy_layout() {
+        let data = serialize_legacy_folio_fee_config(253, 200, 20);
+        assert!(data.len() < FolioFeeConfig::SIZE);
+
+        let deserialized = FolioFeeConfig::try_deserialize_with_legacy_support(&data).unwrap();
+
+        assert_eq!(deserialized.bump, 253);
+        assert_eq!(deserialized.fee_numerator, 200);
+        assert_eq!(deserialized.fee_floor, 20);
+        assert_eq!(deserialized.fee_recipient, Pubkey::default());
+    }
+
+    #[test]
+    fn test_try_deserialize_with_legacy_support_invalid_discriminator() {
+        let data = vec![0u8; FolioFeeConfig::SIZE];
+
+        assert!(FolioFeeConfig::try_deserialize_with_legacy_support(&data).is_err());
+    }
+}
@@ -0,0 +1,79 @@
+//! Test module for the TransferHookRegistrar struct.
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+    use folio_admin::state::TransferHookRegistrar;
+    use shared::errors::ErrorCode;
+
+    #[test]
+    fn test_add_to_registrar_success() {
+        let mut registrar = TransferHookRegistrar::default();
+
+        let program1 = Pubkey::new_unique();
+        let program2 = Pubkey::new_unique();
+
+        assert!(registrar.add_to_registrar(&[program1, program2]).is_ok());
+        assert!(registrar.is_in_registrar(program1));
+        assert!(registrar.is_in_registrar(program2));
+    }
+
+    #[test]
+    fn test_add_to_registrar_skips_registered_programs() {
+        let mut registrar = TransferHookRegistrar::default();
+        let program = Pubkey::new_unique();
+
+        registrar.add_to_registrar(&[program, program]).unwrap();
+        registrar.add_to_registrar(&[program]).unwrap();
+
+        assert_eq!(
+            registrar
+                .allowed_programs
+                .iter()
+                .filter(|p| **p == program)
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_add_to_registrar_too_many_programs() {
+        let mut registrar = TransferHookRegistrar::default();
+        let programs: Vec<Pubkey> = (0..=TransferHookRegistrar::MAX_ALLOWED_PROGRAMS)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+
+        let result = registrar.add_to_registrar(&programs);
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), error!(ErrorCode::InvalidProgramCount));
+    }
+
+    #[test]
+    fn test_add_to_registrar_default_pubkey() {
+        let mut registrar = TransferHookRegistrar::default();
+
+        let result = registrar.add_to_registrar(&[Pubkey::default()]);
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), error!(ErrorCode::InvalidProgram));
+        assert!(!registrar.is_in_registrar(Pubkey::default()));
+    }
+
+    #[test]
+    fn test_remove_from_registrar() {
+        let mut registrar = TransferHookRegistrar::default();
+
+        let program1 = Pubkey::new_unique();
+        let program2 = Pubkey::new_unique();
+
+        registrar.add_to_registrar(&[program1, program2]).unwrap();
+        assert!(registrar.remove_from_registrar(&[program1]).is_ok());
+        assert!(!registrar.is_in_registrar(program1));
+        assert!(registrar.is_in_registrar(program2));
+
+        let result = registrar.remove_from_registrar(&[program1]);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            error!(ErrorCode::ProgramNotInRegistrar)
+        );
+    }
+}
@@ -0,0 +1,22 @@
+//! Test module for the UnkillConfig struct.
+#[cfg(test)]
+mod tests {
+    use folio_admin::state::UnkillConfig;
+    use shared::constants::{MAX_UNKILL_FOLIO_TIMELOCK, UNKILL_FOLIO_TIMELOCK};
+    use shared::errors::ErrorCode;
+
+    #[test]
+    fn test_validate_unkill_timelock() {
+        assert!(UnkillConfig::validate_unkill_timelock(0).is_ok());
+        assert!(UnkillConfig::validate_unkill_timelock(UNKILL_FOLIO_TIMELOCK).is_ok());
+        assert!(UnkillConfig::validate_unkill_timelock(MAX_UNKILL_FOLIO_TIMELOCK).is_ok());
+    }
+
+    #[test]
+    fn test_validate_unkill_timelock_too_long() {
+        assert_eq!(
+            UnkillConfig::validate_unkill_timelock(MAX_UNKILL_FOLIO_TIMELOCK + 1).unwrap_err(),
+            ErrorCode::InvalidUnkillTimelock.into()
+        );
+    }
+}
@@ -1,2 +1,3 @@
 pub mod logger;
 pub mod test_math_util;
+pub mod test_token_util;
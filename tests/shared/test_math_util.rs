@@ -29,6 +29,52 @@ mod tests {
         assert_eq!(result.0, U256::from(1000) * D9_U256);
     }
 
+    #[test]
+    fn test_decimal_from_f64() {
+        assert_eq!(Decimal::from_f64(0.0).unwrap().0, U256::from(0));
+        assert_eq!(Decimal::from_f64(1.0).unwrap().0, D18_U256);
+        assert_eq!(
+            Decimal::from_f64(1.5).unwrap().0,
+            U256::from(1_500_000_000_000_000_000u128)
+        );
+        assert_eq!(
+            Decimal::from_f64(1000.25).unwrap().0,
+            U256::from(1_000_250_000_000_000_000_000u128)
+        );
+
+        // 0.1 is 0.1000000000000000055511151231257827... in binary, rounded down
+        assert_eq!(
+            Decimal::from_f64(0.1).unwrap().0,
+            U256::from(100_000_000_000_000_005u128)
+        );
+        // 1/3 is 0.333333333333333314829616256247390992... in binary, rounded down
+        assert_eq!(
+            Decimal::from_f64(1.0 / 3.0).unwrap().0,
+            U256::from(333_333_333_333_333_314u128)
+        );
+
+        // Below 1e-18 and subnormals round down to 0
+        assert_eq!(Decimal::from_f64(1e-19).unwrap().0, U256::from(0));
+        assert_eq!(
+            Decimal::from_f64(f64::MIN_POSITIVE).unwrap().0,
+            U256::from(0)
+        );
+        assert_eq!(Decimal::from_f64(5e-324).unwrap().0, U256::from(0));
+
+        // Largest power of 2 that fits
+        assert_eq!(
+            Decimal::from_f64(2f64.powi(196)).unwrap().0,
+            D18_U256 << 196
+        );
+
+        assert!(Decimal::from_f64(2f64.powi(197)).is_err());
+        assert!(Decimal::from_f64(f64::MAX).is_err());
+        assert!(Decimal::from_f64(f64::INFINITY).is_err());
+        assert!(Decimal::from_f64(f64::NAN).is_err());
+        assert!(Decimal::from_f64(-1.0).is_err());
+        assert!(Decimal::from_f64(-0.0).is_err());
+    }
+
     #[test]
     fn test_decimal_to_token_amount() {
         let decimal = Decimal::from_token_amount(100u64).unwrap();
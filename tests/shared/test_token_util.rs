@@ -0,0 +1,255 @@
+//! Tests for the token utilities
+#[cfg(test)]
+mod tests {
+    use shared::errors::ErrorCode;
+    use shared::utils::TokenUtil;
+    use spl_token_2022::extension::transfer_fee::{TransferFee, TransferFeeConfig};
+
+    const NEWER_TRANSFER_FEE_EPOCH: u64 = 10;
+
+    fn transfer_fee(epoch: u64, maximum_fee: u64, transfer_fee_basis_points: u16) -> TransferFee {
+        TransferFee {
+            epoch: epoch.into(),
+            maximum_fee: maximum_fee.into(),
+            transfer_fee_basis_points: transfer_fee_basis_points.into(),
+        }
+    }
+
+    /// 1% until `NEWER_TRANSFER_FEE_EPOCH`, then 5%.
+    fn transfer_fee_config_with_fee_update() -> TransferFeeConfig {
+        TransferFeeConfig {
+            older_transfer_fee: transfer_fee(0, u64::MAX, 100),
+            newer_transfer_fee: transfer_fee(NEWER_TRANSFER_FEE_EPOCH, u64::MAX, 500),
+            ..TransferFeeConfig::default()
+        }
+    }
+
+    fn transfer_fee_config(maximum_fee: u64, transfer_fee_basis_points: u16) -> TransferFeeConfig {
+        TransferFeeConfig {
+            older_transfer_fee: transfer_fee(0, maximum_fee, transfer_fee_basis_points),
+            newer_transfer_fee: transfer_fee(0, maximum_fee, transfer_fee_basis_points),
+            ..TransferFeeConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_amounts_without_transfer_fee() {
+        assert_eq!(
+            TokenUtil::get_amount_after_transfer_fee_in_epoch(None, 0, 10_000).unwrap(),
+            10_000
+        );
+        assert_eq!(
+            TokenUtil::get_amount_before_transfer_fee_in_epoch(None, 0, u64::MAX).unwrap(),
+            u64::MAX
+        );
+
+        let zero_fee_config = transfer_fee_config(u64::MAX, 0);
+        assert_eq!(
+            TokenUtil::get_amount_after_transfer_fee_in_epoch(Some(&zero_fee_config), 0, 10_000)
+                .unwrap(),
+            10_000
+        );
+        assert_eq!(
+            TokenUtil::get_amount_before_transfer_fee_in_epoch(Some(&zero_fee_config), 0, 10_000)
+                .unwrap(),
+            10_000
+        );
+    }
+
+    #[test]
+    fn test_amounts_at_epoch_boundary() {
+        let config = transfer_fee_config_with_fee_update();
+
+        // Older fee until the epoch before the update
+        assert_eq!(
+            TokenUtil::get_amount_after_transfer_fee_in_epoch(
+                Some(&config),
+                NEWER_TRANSFER_FEE_EPOCH - 1,
+                10_000
+            )
+            .unwrap(),
+            9_900
+        );
+        assert_eq!(
+            TokenUtil::get_amount_before_transfer_fee_in_epoch(
+                Some(&config),
+                NEWER_TRANSFER_FEE_EPOCH - 1,
+                9_900
+            )
+            .unwrap(),
+            10_000
+        );
+
+        // Newer fee from the epoch of the update
+        assert_eq!(
+            TokenUtil::get_amount_after_transfer_fee_in_epoch(
+                Some(&config),
+                NEWER_TRANSFER_FEE_EPOCH,
+                10_000
+            )
+            .unwrap(),
+            9_500
+        );
+        assert_eq!(
+            TokenUtil::get_amount_before_transfer_fee_in_epoch(
+                Some(&config),
+                NEWER_TRANSFER_FEE_EPOCH,
+                9_500
+            )
+            .unwrap(),
+            10_000
+        );
+        assert_eq!(
+            TokenUtil::get_amount_after_transfer_fee_in_epoch(
+                Some(&config),
+                NEWER_TRANSFER_FEE_EPOCH + 1,
+                10_000
+            )
+            .unwrap(),
+            9_500
+        );
+    }
+
+    #[test]
+    fn test_amounts_at_max_fee() {
+        // 10%, capped at 50
+        let config = transfer_fee_config(50, 1_000);
+
+        // Below the cap
+        assert_eq!(
+            TokenUtil::get_amount_after_transfer_fee_in_epoch(Some(&config), 0, 400).unwrap(),
+            360
+        );
+        assert_eq!(
+            TokenUtil::get_amount_before_transfer_fee_in_epoch(Some(&config), 0, 360).unwrap(),
+            400
+        );
+
+        // At and above the cap
+        assert_eq!(
+            TokenUtil::get_amount_after_transfer_fee_in_epoch(Some(&config), 0, 500).unwrap(),
+            450
+        );
+        assert_eq!(
+            TokenUtil::get_amount_after_transfer_fee_in_epoch(Some(&config), 0, 10_000).unwrap(),
+            9_950
+        );
+        assert_eq!(
+            TokenUtil::get_amount_before_transfer_fee_in_epoch(Some(&config), 0, 9_950).unwrap(),
+            10_000
+        );
+        assert_eq!(
+            TokenUtil::get_amount_after_transfer_fee_in_epoch(Some(&config), 0, u64::MAX).unwrap(),
+            u64::MAX - 50
+        );
+        assert_eq!(
+            TokenUtil::get_amount_before_transfer_fee_in_epoch(Some(&config), 0, u64::MAX - 50)
+                .unwrap(),
+            u64::MAX
+        );
+    }
+
+    #[test]
+    fn test_amounts_at_max_basis_points() {
+        // 100%, capped at 50
+        let config = transfer_fee_config(50, 10_000);
+
+        assert_eq!(
+            TokenUtil::get_amount_after_transfer_fee_in_epoch(Some(&config), 0, 30).unwrap(),
+            0
+        );
+        assert_eq!(
+            TokenUtil::get_amount_after_transfer_fee_in_epoch(Some(&config), 0, 150).unwrap(),
+            100
+        );
+        assert_eq!(
+            TokenUtil::get_amount_before_transfer_fee_in_epoch(Some(&config), 0, 100).unwrap(),
+            150
+        );
+    }
+
+    #[test]
+    fn test_amount_before_transfer_fee_overflow() {
+        let config = transfer_fee_config(u64::MAX, 100);
+
+        assert_eq!(
+            TokenUtil::get_amount_before_transfer_fee_in_epoch(Some(&config), 0, u64::MAX)
+                .unwrap_err(),
+            ErrorCode::MathOverflow.into()
+        );
+    }
+
+    #[test]
+    fn test_amount_before_transfer_fee_round_trip() {
+        // The amount sent with `get_amount_before_transfer_fee` is always received in full
+        let configs = [
+            transfer_fee_config_with_fee_update(),
+            transfer_fee_config(50, 1_000),
+            transfer_fee_config(u64::MAX, 1),
+            transfer_fee_config(u64::MAX, 9_999),
+            transfer_fee_config(7, 10_000),
+        ];
+
+        for config in configs.iter() {
+            for epoch in [NEWER_TRANSFER_FEE_EPOCH - 1, NEWER_TRANSFER_FEE_EPOCH] {
+                for raw_amount_received in (0..2_000).chain([999_999_999, 1_000_000_007]) {
+                    let raw_amount_sent = TokenUtil::get_amount_before_transfer_fee_in_epoch(
+                        Some(config),
+                        epoch,
+                        raw_amount_received,
+                    )
+                    .unwrap();
+
+                    assert_eq!(
+                        TokenUtil::get_amount_after_transfer_fee_in_epoch(
+                            Some(config),
+                            epoch,
+                            raw_amount_sent
+                        )
+                        .unwrap(),
+                        raw_amount_received
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_scaled_ui_amount_multiplier_from_f64() {
+        assert_eq!(
+            TokenUtil::get_scaled_ui_amount_multiplier_from_f64(1.0).unwrap(),
+            1_000_000_000_000_000_000
+        );
+        assert_eq!(
+            TokenUtil::get_scaled_ui_amount_multiplier_from_f64(2.5).unwrap(),
+            2_500_000_000_000_000_000
+        );
+        // Rounded down from 1.100000000000000088817841970012523...
+        assert_eq!(
+            TokenUtil::get_scaled_ui_amount_multiplier_from_f64(1.1).unwrap(),
+            1_100_000_000_000_000_088
+        );
+        assert_eq!(
+            TokenUtil::get_scaled_ui_amount_multiplier_from_f64(1e-18).unwrap(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_scaled_ui_amount_multiplier_from_f64_invalid() {
+        for multiplier in [0.0, -0.0, -1.0, 1e-19, f64::NAN, f64::INFINITY] {
+            assert_eq!(
+                TokenUtil::get_scaled_ui_amount_multiplier_from_f64(multiplier).unwrap_err(),
+                ErrorCode::InvalidUiAmountMultiplier.into()
+            );
+        }
+
+        // Doesn't fit in a u128
+        for multiplier in [1e21, f64::MAX] {
+            assert_eq!(
+                TokenUtil::get_scaled_ui_amount_multiplier_from_f64(multiplier).unwrap_err(),
+                ErrorCode::MathOverflow.into()
+            );
+        }
+    }
+}
@@ -51,12 +51,18 @@ export const MAX_MINT_FEE = new BN("50000000000000000");
 export const MIN_AUCTION_LENGTH = new BN(60);
 export const MAX_AUCTION_LENGTH = new BN(604800);
 export const MAX_TTL = new BN(604800 * 4);
+// Value of the test build of the program, 1 week otherwise
+export const MIGRATION_ABORT_GRACE_PERIOD = new BN(5);
+export const FEE_DISTRIBUTION_CLOSE_GRACE_PERIOD = new BN(604800 * 4);
+export const UNKILL_FOLIO_TIMELOCK = new BN(604800);
+export const MAX_UNKILL_FOLIO_TIMELOCK = new BN(604800 * 4);
 // 1e27 = 1000000000000000000000000000
 export const MAX_RATE = new BN("1000000000000000000000000000");
 
 export const MAX_FEE_RECIPIENTS = 64;
 export const MAX_FOLIO_TOKEN_AMOUNTS = 100;
 export const MAX_USER_PENDING_BASKET_TOKEN_AMOUNTS = 110;
+export const MAX_FOLIO_LEGACY_TOKEN_AMOUNTS = 10;
 export const TOTAL_PORTION_FEE_RECIPIENT = new BN("1000000000000000000");
 export const MAX_REWARD_TOKENS = 4;
 export const MAX_CONCURRENT_AUCTIONS = 16;
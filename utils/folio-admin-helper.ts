@@ -11,10 +11,13 @@ import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
 import { pSendAndConfirmTxn } from "./program-helper";
 import {
   getProgramRegistrarPDA,
+  getFolioActionRegistrarPDA,
   getDAOFeeConfigPDA,
   getFolioFeeConfigPDA,
   getFeeDistributionPDA,
   getTVLFeeRecipientsPDA,
+  getUnkillConfigPDA,
+  getTransferHookRegistrarPDA,
 } from "./pda-helper";
 import { FolioAdmin } from "../target/types/folio_admin";
 import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
@@ -57,12 +60,13 @@ export async function setDaoFeeConfig(
   adminKeypair: Keypair,
   feeRecipient: PublicKey,
   feeNumerator: BN,
-  feeFloor: BN
+  feeFloor: BN,
+  protocolFeeRecipients: { recipient: PublicKey; portion: BN }[] | null = null
 ) {
   const folioAdminProgram = getFolioAdminProgram(connection, adminKeypair);
 
   const setDaoFeeConfig = await folioAdminProgram.methods
-    .setDaoFeeConfig(feeRecipient, feeNumerator, feeFloor)
+    .setDaoFeeConfig(feeRecipient, feeNumerator, feeFloor, protocolFeeRecipients)
     .accountsPartial({
       systemProgram: SystemProgram.programId,
       rent: SYSVAR_RENT_PUBKEY,
@@ -83,12 +87,13 @@ export async function setFolioFeeConfig(
   folioTokenMint: PublicKey,
   feeNumerator: BN,
   feeFloor: BN,
-  daoFeeRecipient: PublicKey
+  daoFeeRecipient: PublicKey,
+  folioFeeRecipient: PublicKey | null = null
 ) {
   const folioAdminProgram = getFolioAdminProgram(connection, adminKeypair);
 
   const setFolioFeeConfig = await folioAdminProgram.methods
-    .setFolioFeeConfig(feeNumerator, feeFloor)
+    .setFolioFeeConfig(feeNumerator, feeFloor, folioFeeRecipient)
     .accountsPartial({
       systemProgram: SystemProgram.programId,
       rent: SYSVAR_RENT_PUBKEY,
@@ -166,3 +171,78 @@ export async function updateProgramRegistrar(
     skipPreflight: SKIP_PREFLIGHT,
   });
 }
+
+export async function updateFolioActionRegistrar(
+  connection: Connection,
+  adminKeypair: Keypair,
+  programIds: PublicKey[],
+  toRemove: boolean
+) {
+  const folioAdminProgram = getFolioAdminProgram(connection, adminKeypair);
+
+  const updateFolioActionRegistrar = await folioAdminProgram.methods
+    .updateFolioActionRegistrar(programIds, toRemove)
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      admin: adminKeypair.publicKey,
+      folioActionRegistrar: getFolioActionRegistrarPDA(),
+    })
+    .instruction();
+
+  await pSendAndConfirmTxn(
+    folioAdminProgram,
+    [updateFolioActionRegistrar],
+    [],
+    {
+      skipPreflight: SKIP_PREFLIGHT,
+    }
+  );
+}
+
+export async function setUnkillConfig(
+  connection: Connection,
+  adminKeypair: Keypair,
+  unkillTimelock: BN
+) {
+  const folioAdminProgram = getFolioAdminProgram(connection, adminKeypair);
+
+  const setUnkillConfig = await folioAdminProgram.methods
+    .setUnkillConfig(unkillTimelock)
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      admin: adminKeypair.publicKey,
+      unkillConfig: getUnkillConfigPDA(),
+    })
+    .instruction();
+
+  await pSendAndConfirmTxn(folioAdminProgram, [setUnkillConfig], [], {
+    skipPreflight: SKIP_PREFLIGHT,
+  });
+}
+
+export async function updateTransferHookRegistrar(
+  connection: Connection,
+  adminKeypair: Keypair,
+  programIds: PublicKey[],
+  toRemove: boolean
+) {
+  const folioAdminProgram = getFolioAdminProgram(connection, adminKeypair);
+
+  const updateTransferHookRegistrar = await folioAdminProgram.methods
+    .updateTransferHookRegistrar(programIds, toRemove)
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      admin: adminKeypair.publicKey,
+      transferHookRegistrar: getTransferHookRegistrarPDA(),
+    })
+    .instruction();
+
+  await pSendAndConfirmTxn(
+    folioAdminProgram,
+    [updateTransferHookRegistrar],
+    [],
+    {
+      skipPreflight: SKIP_PREFLIGHT,
+    }
+  );
+}
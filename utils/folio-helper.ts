@@ -1,10 +1,12 @@
 import { AnchorProvider, BN, Program } from "@coral-xyz/anchor";
 import { Folio } from "../target/types/folio";
 import {
+  AccountMeta,
   Connection,
   Keypair,
   PublicKey,
   SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
   SYSVAR_RENT_PUBKEY,
 } from "@solana/web3.js";
 import idlFolio from "../target/idl/folio.json";
@@ -21,11 +23,22 @@ import {
   getTVLFeeRecipientsPDA,
   getFolioPDA,
   getMetadataPDA,
+  getMigrationRecordPDA,
   getProgramRegistrarPDA,
   getUserPendingBasketPDA,
+  getFolioLegacyBasketPDA,
+  getFolioPendingBasketPDA,
   getFolioFeeConfigPDA,
   getRebalancePDA,
   getAuctionEndsPDA,
+  getFeeDiscountPDA,
+  getMintAllowlistEntryPDA,
+  getFolioMintLimitsPDA,
+  getKillRecordPDA,
+  getUnkillConfigPDA,
+  getPendingOwnerPDA,
+  getFolioActorsPDA,
+  getFolioActionRegistrarPDA,
 } from "./pda-helper";
 import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -186,7 +199,12 @@ export async function updateFolio(
   feeRecipientsToAdd: { recipient: PublicKey; portion: BN }[],
   feeRecipientsToRemove: PublicKey[],
   mandate: string | null,
-  tokenProgramForAta: PublicKey = TOKEN_PROGRAM_ID
+  tokenProgramForAta: PublicKey = TOKEN_PROGRAM_ID,
+  mintAllowlistEnabled: boolean | null = null,
+  maxSupply: BN | null = null,
+  maxMintPerTx: BN | null = null,
+  // { dao: {} } or { feeRecipients: {} }
+  feeDistributionFallback: object | null = null
 ) {
   const folioProgram = getFolioProgram(connection, folioOwnerKeypair);
 
@@ -198,7 +216,11 @@ export async function updateFolio(
       auctionLength,
       feeRecipientsToAdd,
       feeRecipientsToRemove,
-      mandate
+      mandate,
+      mintAllowlistEnabled,
+      maxSupply,
+      maxMintPerTx,
+      feeDistributionFallback as any
     )
     .accountsPartial({
       systemProgram: SystemProgram.programId,
@@ -207,6 +229,10 @@ export async function updateFolio(
       actor: getActorPDA(folioOwnerKeypair.publicKey, folio),
       folio: folio,
       feeRecipients: getTVLFeeRecipientsPDA(folio),
+      folioMintLimits:
+        maxSupply !== null || maxMintPerTx !== null
+          ? getFolioMintLimitsPDA(folio)
+          : null,
     })
     .remainingAccounts([
       {
@@ -258,17 +284,52 @@ export async function updateFolio(
   );
 }
 
+export async function updateFolioBranding(
+  connection: Connection,
+  brandManagerKeypair: Keypair,
+  folio: PublicKey,
+  folioTokenMint: PublicKey,
+  name: string | null,
+  symbol: string | null,
+  uri: string | null,
+  mandate: string | null,
+  tokenProgram: PublicKey = TOKEN_PROGRAM_ID
+) {
+  const folioProgram = getFolioProgram(connection, brandManagerKeypair);
+
+  const updateFolioBranding = await folioProgram.methods
+    .updateFolioBranding(name, symbol, uri, mandate)
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
+      instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+      tokenProgram: tokenProgram,
+      brandManager: brandManagerKeypair.publicKey,
+      actor: getActorPDA(brandManagerKeypair.publicKey, folio),
+      folio: folio,
+      folioTokenMint: folioTokenMint,
+      tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+      metadata: getMetadataPDA(folioTokenMint),
+    })
+    .instruction();
+
+  await pSendAndConfirmTxn(folioProgram, [updateFolioBranding], [], {
+    skipPreflight: SKIP_PREFLIGHT,
+  });
+}
+
 export async function addOrUpdateActor(
   connection: Connection,
   folioOwnerKeypair: Keypair,
   folio: PublicKey,
   newActorAuthority: PublicKey,
-  role: any = { priceCurator: {} }
+  role: any = { priceCurator: {} },
+  expiresAt: BN | null = null
 ) {
   const folioProgram = getFolioProgram(connection, folioOwnerKeypair);
 
   const addOrUpdateActor = await folioProgram.methods
-    .initOrUpdateActor(role)
+    .initOrUpdateActor(role, expiresAt)
     .accountsPartial({
       systemProgram: SystemProgram.programId,
       rent: SYSVAR_RENT_PUBKEY,
@@ -277,6 +338,7 @@ export async function addOrUpdateActor(
       folioOwnerActor: getActorPDA(folioOwnerKeypair.publicKey, folio),
       newActor: getActorPDA(newActorAuthority, folio),
       folio: folio,
+      folioActors: getFolioActorsPDA(folio),
     })
     .instruction();
 
@@ -285,6 +347,202 @@ export async function addOrUpdateActor(
   });
 }
 
+export async function proposeOwner(
+  connection: Connection,
+  folioOwnerKeypair: Keypair,
+  folio: PublicKey,
+  newOwner: PublicKey
+) {
+  const folioProgram = getFolioProgram(connection, folioOwnerKeypair);
+
+  const proposeOwner = await folioProgram.methods
+    .proposeOwner()
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
+      folioOwner: folioOwnerKeypair.publicKey,
+      folioOwnerActor: getActorPDA(folioOwnerKeypair.publicKey, folio),
+      newOwner: newOwner,
+      pendingOwner: getPendingOwnerPDA(folio),
+      folio: folio,
+    })
+    .instruction();
+
+  await pSendAndConfirmTxn(folioProgram, [proposeOwner], [], {
+    skipPreflight: SKIP_PREFLIGHT,
+  });
+}
+
+export async function acceptOwner(
+  connection: Connection,
+  newOwnerKeypair: Keypair,
+  folio: PublicKey,
+  previousOwner: PublicKey
+) {
+  const folioProgram = getFolioProgram(connection, newOwnerKeypair);
+
+  const acceptOwner = await folioProgram.methods
+    .acceptOwner()
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
+      newOwner: newOwnerKeypair.publicKey,
+      newOwnerActor: getActorPDA(newOwnerKeypair.publicKey, folio),
+      previousOwner: previousOwner,
+      previousOwnerActor: getActorPDA(previousOwner, folio),
+      pendingOwner: getPendingOwnerPDA(folio),
+      folio: folio,
+      folioActors: getFolioActorsPDA(folio),
+    })
+    .instruction();
+
+  await pSendAndConfirmTxn(folioProgram, [acceptOwner], [], {
+    skipPreflight: SKIP_PREFLIGHT,
+  });
+}
+
+export async function cancelOwnerProposal(
+  connection: Connection,
+  folioOwnerKeypair: Keypair,
+  folio: PublicKey,
+  proposedBy: PublicKey
+) {
+  const folioProgram = getFolioProgram(connection, folioOwnerKeypair);
+
+  const cancelOwnerProposal = await folioProgram.methods
+    .cancelOwnerProposal()
+    .accountsPartial({
+      folioOwner: folioOwnerKeypair.publicKey,
+      folioOwnerActor: getActorPDA(folioOwnerKeypair.publicKey, folio),
+      proposedBy: proposedBy,
+      pendingOwner: getPendingOwnerPDA(folio),
+      folio: folio,
+    })
+    .instruction();
+
+  await pSendAndConfirmTxn(folioProgram, [cancelOwnerProposal], [], {
+    skipPreflight: SKIP_PREFLIGHT,
+  });
+}
+
+export async function addToMintAllowlist(
+  connection: Connection,
+  allowlistManagerKeypair: Keypair,
+  folio: PublicKey,
+  user: PublicKey
+) {
+  const folioProgram = getFolioProgram(connection, allowlistManagerKeypair);
+
+  const addToMintAllowlist = await folioProgram.methods
+    .addToMintAllowlist()
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
+      allowlistManager: allowlistManagerKeypair.publicKey,
+      actor: getActorPDA(allowlistManagerKeypair.publicKey, folio),
+      folio: folio,
+      user: user,
+      mintAllowlistEntry: getMintAllowlistEntryPDA(folio, user),
+    })
+    .instruction();
+
+  await pSendAndConfirmTxn(folioProgram, [addToMintAllowlist], [], {
+    skipPreflight: SKIP_PREFLIGHT,
+  });
+}
+
+export async function removeFromMintAllowlist(
+  connection: Connection,
+  allowlistManagerKeypair: Keypair,
+  folio: PublicKey,
+  user: PublicKey
+) {
+  const folioProgram = getFolioProgram(connection, allowlistManagerKeypair);
+
+  const removeFromMintAllowlist = await folioProgram.methods
+    .removeFromMintAllowlist()
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      allowlistManager: allowlistManagerKeypair.publicKey,
+      actor: getActorPDA(allowlistManagerKeypair.publicKey, folio),
+      folio: folio,
+      user: user,
+      mintAllowlistEntry: getMintAllowlistEntryPDA(folio, user),
+    })
+    .instruction();
+
+  await pSendAndConfirmTxn(folioProgram, [removeFromMintAllowlist], [], {
+    skipPreflight: SKIP_PREFLIGHT,
+  });
+}
+
+export async function migrateActor(
+  connection: Connection,
+  payerKeypair: Keypair,
+  actorAuthority: PublicKey,
+  folio: PublicKey
+) {
+  const folioProgram = getFolioProgram(connection, payerKeypair);
+
+  const migrateActor = await folioProgram.methods
+    .migrateActor()
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      payer: payerKeypair.publicKey,
+      actor: getActorPDA(actorAuthority, folio),
+    })
+    .instruction();
+
+  await pSendAndConfirmTxn(folioProgram, [migrateActor], [], {
+    skipPreflight: SKIP_PREFLIGHT,
+  });
+}
+
+export async function indexActor(
+  connection: Connection,
+  payerKeypair: Keypair,
+  actorAuthority: PublicKey,
+  folio: PublicKey
+) {
+  const folioProgram = getFolioProgram(connection, payerKeypair);
+
+  const indexActor = await folioProgram.methods
+    .indexActor()
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
+      payer: payerKeypair.publicKey,
+      actor: getActorPDA(actorAuthority, folio),
+      folioActors: getFolioActorsPDA(folio),
+    })
+    .instruction();
+
+  await pSendAndConfirmTxn(folioProgram, [indexActor], [], {
+    skipPreflight: SKIP_PREFLIGHT,
+  });
+}
+
+export async function upgradeAccount(
+  connection: Connection,
+  payerKeypair: Keypair,
+  account: PublicKey
+) {
+  const folioProgram = getFolioProgram(connection, payerKeypair);
+
+  const upgradeAccount = await folioProgram.methods
+    .upgradeAccount()
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      payer: payerKeypair.publicKey,
+      account,
+    })
+    .instruction();
+
+  await pSendAndConfirmTxn(folioProgram, [upgradeAccount], [], {
+    skipPreflight: SKIP_PREFLIGHT,
+  });
+}
+
 export async function removeActor(
   connection: Connection,
   folioOwnerKeypair: Keypair,
@@ -305,6 +563,7 @@ export async function removeActor(
       folioOwnerActor: getActorPDA(folioOwnerKeypair.publicKey, folio),
       actorToRemove: getActorPDA(actorAuthority, folio),
       folio: folio,
+      folioActors: getFolioActorsPDA(folio),
     })
     .instruction();
 
@@ -365,6 +624,30 @@ export async function addToBasket(
   });
 }
 
+export async function addZeroBalanceToBasket(
+  connection: Connection,
+  folioOwnerKeypair: Keypair,
+  folio: PublicKey,
+  tokenToAdd: PublicKey
+) {
+  const folioProgram = getFolioProgram(connection, folioOwnerKeypair);
+
+  const addZeroBalanceToBasket = await folioProgram.methods
+    .addZeroBalanceToBasket()
+    .accountsPartial({
+      folioOwner: folioOwnerKeypair.publicKey,
+      actor: getActorPDA(folioOwnerKeypair.publicKey, folio),
+      folio,
+      folioBasket: getFolioBasketPDA(folio),
+      tokenMint: tokenToAdd,
+    })
+    .instruction();
+
+  await pSendAndConfirmTxn(folioProgram, [addZeroBalanceToBasket], [], {
+    skipPreflight: SKIP_PREFLIGHT,
+  });
+}
+
 export async function removeFromBasket(
   connection: Connection,
   folioOwnerKeypair: Keypair,
@@ -381,6 +664,7 @@ export async function removeFromBasket(
       actor: getActorPDA(folioOwnerKeypair.publicKey, folio),
       folio: folio,
       folioBasket: getFolioBasketPDA(folio),
+      folioLegacyBasket: getFolioLegacyBasketPDA(folio),
       tokenMint: tokenToRemove,
     })
     .instruction();
@@ -390,6 +674,77 @@ export async function removeFromBasket(
   });
 }
 
+export async function syncBasketBalance(
+  connection: Connection,
+  folioOwnerKeypair: Keypair,
+  folio: PublicKey,
+  mint: PublicKey,
+  recipient: PublicKey,
+  tokenProgram: PublicKey = TOKEN_PROGRAM_ID
+) {
+  const folioProgram = getFolioProgram(connection, folioOwnerKeypair);
+
+  const syncBasketBalance = await folioProgram.methods
+    .syncBasketBalance()
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      tokenProgram,
+      folioOwner: folioOwnerKeypair.publicKey,
+      actor: getActorPDA(folioOwnerKeypair.publicKey, folio),
+      folio,
+      folioBasket: getFolioBasketPDA(folio),
+      folioPendingBasket: getFolioPendingBasketPDA(folio),
+      folioLegacyBasket: getFolioLegacyBasketPDA(folio),
+      mint,
+      folioTokenAccount: await getOrCreateAtaAddress(
+        connection,
+        mint,
+        folioOwnerKeypair,
+        folio,
+        tokenProgram
+      ),
+      recipientTokenAccount: await getOrCreateAtaAddress(
+        connection,
+        mint,
+        folioOwnerKeypair,
+        recipient,
+        tokenProgram
+      ),
+    })
+    .instruction();
+
+  await pSendAndConfirmTxn(folioProgram, [syncBasketBalance], [], {
+    skipPreflight: SKIP_PREFLIGHT,
+  });
+}
+
+export async function executeFolioAction(
+  connection: Connection,
+  folioOwnerKeypair: Keypair,
+  folio: PublicKey,
+  targetProgram: PublicKey,
+  data: Buffer,
+  accounts: AccountMeta[]
+) {
+  const folioProgram = getFolioProgram(connection, folioOwnerKeypair);
+
+  const executeFolioAction = await folioProgram.methods
+    .executeFolioAction(data)
+    .accountsPartial({
+      folioOwner: folioOwnerKeypair.publicKey,
+      actor: getActorPDA(folioOwnerKeypair.publicKey, folio),
+      folio,
+      folioActionRegistrar: getFolioActionRegistrarPDA(),
+      targetProgram,
+    })
+    .remainingAccounts(accounts)
+    .instruction();
+
+  await pSendAndConfirmTxn(folioProgram, [executeFolioAction], [], {
+    skipPreflight: SKIP_PREFLIGHT,
+  });
+}
+
 export async function killFolio(
   connection: Connection,
   folioOwnerKeypair: Keypair,
@@ -404,6 +759,8 @@ export async function killFolio(
       folioOwner: folioOwnerKeypair.publicKey,
       actor: getActorPDA(folioOwnerKeypair.publicKey, folio),
       folio: folio,
+      killRecord: getKillRecordPDA(folio),
+      unkillConfig: getUnkillConfigPDA(),
     })
     .instruction();
 
@@ -412,6 +769,32 @@ export async function killFolio(
   });
 }
 
+export async function unkillFolio(
+  connection: Connection,
+  folioOwnerKeypair: Keypair,
+  folio: PublicKey,
+  folioTokenMint: PublicKey
+) {
+  const folioProgram = getFolioProgram(connection, folioOwnerKeypair);
+
+  const unkillFolio = await folioProgram.methods
+    .unkillFolio()
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      folioOwner: folioOwnerKeypair.publicKey,
+      actor: getActorPDA(folioOwnerKeypair.publicKey, folio),
+      folio: folio,
+      folioTokenMint: folioTokenMint,
+      killRecord: getKillRecordPDA(folio),
+      unkillConfig: getUnkillConfigPDA(),
+    })
+    .instruction();
+
+  await pSendAndConfirmTxn(folioProgram, [unkillFolio], [], {
+    skipPreflight: SKIP_PREFLIGHT,
+  });
+}
+
 export async function addToPendingBasket(
   connection: Connection,
   userKeypair: Keypair,
@@ -432,6 +815,11 @@ export async function addToPendingBasket(
       folio,
       folioBasket: getFolioBasketPDA(folio),
       userPendingBasket: getUserPendingBasketPDA(folio, userKeypair.publicKey),
+      folioPendingBasket: getFolioPendingBasketPDA(folio),
+      mintAllowlistEntry: getMintAllowlistEntryPDA(
+        folio,
+        userKeypair.publicKey
+      ),
     })
     .remainingAccounts(
       await buildRemainingAccounts(
@@ -471,6 +859,7 @@ export async function removeFromPendingBasket(
       folio,
       folioBasket: getFolioBasketPDA(folio),
       userPendingBasket: getUserPendingBasketPDA(folio, userKeypair.publicKey),
+      folioPendingBasket: getFolioPendingBasketPDA(folio),
     })
     .remainingAccounts(
       await buildRemainingAccounts(
@@ -510,6 +899,7 @@ export async function mintFolioToken(
       folioTokenMint,
       folioBasket: getFolioBasketPDA(folio),
       userPendingBasket: getUserPendingBasketPDA(folio, userKeypair.publicKey),
+      folioPendingBasket: getFolioPendingBasketPDA(folio),
       userFolioTokenAccount: await getOrCreateAtaAddress(
         connection,
         folioTokenMint,
@@ -517,6 +907,12 @@ export async function mintFolioToken(
         userKeypair.publicKey,
         tokenProgram
       ),
+      feeDiscount: getFeeDiscountPDA(folio, userKeypair.publicKey),
+      mintAllowlistEntry: getMintAllowlistEntryPDA(
+        folio,
+        userKeypair.publicKey
+      ),
+      folioMintLimits: getFolioMintLimitsPDA(folio),
     })
     .instruction();
 
@@ -545,6 +941,7 @@ export async function burnFolioToken(
   const burnFolioTokenIx = await folioProgram.methods
     .burnFolioToken(amountToBurn, minimumOutForTokenAmounts)
     .accountsPartial({
+      systemProgram: SystemProgram.programId,
       tokenProgram,
       associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       user: userKeypair.publicKey,
@@ -554,6 +951,8 @@ export async function burnFolioToken(
       folioTokenMint,
       folioBasket: getFolioBasketPDA(folio),
       userPendingBasket: getUserPendingBasketPDA(folio, userKeypair.publicKey),
+      folioPendingBasket: getFolioPendingBasketPDA(folio),
+      folioLegacyBasket: getFolioLegacyBasketPDA(folio),
       userFolioTokenAccount: await getOrCreateAtaAddress(
         connection,
         folioTokenMint,
@@ -593,6 +992,7 @@ export async function redeemFromPendingBasket(
       folio,
       folioBasket: getFolioBasketPDA(folio),
       userPendingBasket: getUserPendingBasketPDA(folio, userKeypair.publicKey),
+      folioPendingBasket: getFolioPendingBasketPDA(folio),
     })
     .remainingAccounts(
       await buildRemainingAccounts(
@@ -638,6 +1038,36 @@ export async function pokeFolio(
   });
 }
 
+export async function harvestWithheldFees(
+  connection: Connection,
+  userKeypair: Keypair,
+  folioPDA: PublicKey,
+  mint: PublicKey
+) {
+  const folioProgram = getFolioProgram(connection, userKeypair);
+
+  const harvestWithheldFees = await folioProgram.methods
+    .harvestWithheldFees()
+    .accountsPartial({
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      user: userKeypair.publicKey,
+      folio: folioPDA,
+      mint,
+      folioTokenAccount: await getOrCreateAtaAddress(
+        connection,
+        mint,
+        userKeypair,
+        folioPDA,
+        TOKEN_2022_PROGRAM_ID
+      ),
+    })
+    .instruction();
+
+  await pSendAndConfirmTxn(folioProgram, [harvestWithheldFees], [], {
+    skipPreflight: SKIP_PREFLIGHT,
+  });
+}
+
 export async function distributeFees(
   connection: Connection,
   userKeypair: Keypair,
@@ -724,7 +1154,8 @@ export async function startRebalance(
     limits: { spot: BN; low: BN; high: BN };
   }[],
   allRebalanceDetailsAdded: boolean,
-  mints: PublicKey[]
+  mints: PublicKey[],
+  useUiAmounts: boolean = false
 ) {
   const folioProgram = getFolioProgram(connection, rebalanceManagerKeypair);
 
@@ -740,7 +1171,8 @@ export async function startRebalance(
       new BN(auctionLauncherWindow),
       new BN(ttl),
       pricesAndLimits,
-      allRebalanceDetailsAdded
+      allRebalanceDetailsAdded,
+      useUiAmounts
     )
     .accountsPartial({
       systemProgram: SystemProgram.programId,
@@ -967,13 +1399,12 @@ export async function startFolioMigration(
   oldFolio: PublicKey,
   newFolio: PublicKey,
   newFolioProgram: PublicKey,
-  maxAllowedPendingFees: BN,
   folioMintTokenProgram: PublicKey = TOKEN_PROGRAM_ID
 ) {
   const folioProgram = getFolioProgram(connection, folioOwnerKeypair);
 
   const startFolioMigration = await folioProgram.methods
-    .startFolioMigration(maxAllowedPendingFees)
+    .startFolioMigration()
     .accountsPartial({
       systemProgram: SystemProgram.programId,
       tokenProgram: folioMintTokenProgram,
@@ -986,6 +1417,12 @@ export async function startFolioMigration(
       folioTokenMint,
       newFolioBasket: getFolioBasketPDA(newFolio, newFolioProgram),
       newActor: getActorPDA(folioOwnerKeypair.publicKey, newFolio, true),
+      oldFeeRecipients: getTVLFeeRecipientsPDA(oldFolio),
+      newFeeRecipients: getTVLFeeRecipientsPDA(newFolio, newFolioProgram),
+      oldFolioMintLimits: getFolioMintLimitsPDA(oldFolio),
+      newFolioMintLimits: getFolioMintLimitsPDA(newFolio, newFolioProgram),
+      oldFolioLegacyBasket: getFolioLegacyBasketPDA(oldFolio),
+      migrationRecord: getMigrationRecordPDA(oldFolio),
     })
     .instruction();
 
@@ -994,6 +1431,43 @@ export async function startFolioMigration(
   });
 }
 
+export async function abortFolioMigration(
+  connection: Connection,
+  folioOwnerKeypair: Keypair,
+  folioTokenMint: PublicKey,
+  oldFolio: PublicKey,
+  newFolio: PublicKey,
+  newFolioProgram: PublicKey,
+  folioMintTokenProgram: PublicKey = TOKEN_PROGRAM_ID
+) {
+  const folioProgram = getFolioProgram(connection, folioOwnerKeypair);
+
+  const abortFolioMigration = await folioProgram.methods
+    .abortFolioMigration()
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      tokenProgram: folioMintTokenProgram,
+      folioOwner: folioOwnerKeypair.publicKey,
+      actor: getActorPDA(folioOwnerKeypair.publicKey, oldFolio),
+      programRegistrar: getProgramRegistrarPDA(),
+      newFolioProgram,
+      oldFolio,
+      newFolio,
+      folioTokenMint,
+      newActor: getActorPDA(folioOwnerKeypair.publicKey, newFolio, true),
+      newFolioBasket: getFolioBasketPDA(newFolio, newFolioProgram),
+      newFeeRecipients: getTVLFeeRecipientsPDA(newFolio, newFolioProgram),
+      migrationRecord: getMigrationRecordPDA(oldFolio),
+      tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+      metadata: getMetadataPDA(folioTokenMint),
+    })
+    .instruction();
+
+  await pSendAndConfirmTxn(folioProgram, [abortFolioMigration], [], {
+    skipPreflight: SKIP_PREFLIGHT,
+  });
+}
+
 export async function migrateFolioTokens(
   connection: Connection,
   userKeypair: Keypair,
@@ -1017,6 +1491,7 @@ export async function migrateFolioTokens(
       newFolio,
       folioTokenMint,
       newFolioBasket: getFolioBasketPDA(newFolio, newFolioProgram),
+      migrationRecord: getMigrationRecordPDA(oldFolio),
     })
     .remainingAccounts(
       await buildRemainingAccountsForMigrateFolioTokens(
@@ -1033,3 +1508,102 @@ export async function migrateFolioTokens(
     skipPreflight: SKIP_PREFLIGHT,
   });
 }
+
+export async function migrateUserPendingBasket(
+  connection: Connection,
+  userKeypair: Keypair,
+  oldFolio: PublicKey,
+  newFolio: PublicKey,
+  newFolioProgram: PublicKey,
+  folioTokenMint: PublicKey,
+  pendingBasketOwner: PublicKey,
+  tokenMints: PublicKey[]
+) {
+  const folioProgram = getFolioProgram(connection, userKeypair);
+
+  const migrateUserPendingBasket = await folioProgram.methods
+    .migrateUserPendingBasket()
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      user: userKeypair.publicKey,
+      programRegistrar: getProgramRegistrarPDA(),
+      newFolioProgram,
+      oldFolio,
+      newFolio,
+      folioTokenMint,
+      pendingBasketOwner,
+      oldUserPendingBasket: getUserPendingBasketPDA(
+        oldFolio,
+        pendingBasketOwner
+      ),
+      oldFolioPendingBasket: getFolioPendingBasketPDA(oldFolio),
+      newUserPendingBasket: getUserPendingBasketPDA(
+        newFolio,
+        pendingBasketOwner,
+        newFolioProgram
+      ),
+      newFolioPendingBasket: getFolioPendingBasketPDA(
+        newFolio,
+        newFolioProgram
+      ),
+      migrationRecord: getMigrationRecordPDA(oldFolio),
+    })
+    .remainingAccounts(
+      await buildRemainingAccountsForMigrateFolioTokens(
+        connection,
+        userKeypair,
+        oldFolio,
+        newFolio,
+        tokenMints
+      )
+    )
+    .instruction();
+
+  await pSendAndConfirmTxn(
+    folioProgram,
+    [...getComputeLimitInstruction(1_400_000), migrateUserPendingBasket],
+    [],
+    {
+      skipPreflight: SKIP_PREFLIGHT,
+    }
+  );
+}
+
+export async function migrateFeeDistribution(
+  connection: Connection,
+  userKeypair: Keypair,
+  cranker: PublicKey,
+  oldFolio: PublicKey,
+  newFolio: PublicKey,
+  newFolioProgram: PublicKey,
+  folioTokenMint: PublicKey,
+  index: BN
+) {
+  const folioProgram = getFolioProgram(connection, userKeypair);
+
+  const migrateFeeDistribution = await folioProgram.methods
+    .migrateFeeDistribution()
+    .accountsPartial({
+      systemProgram: SystemProgram.programId,
+      user: userKeypair.publicKey,
+      cranker,
+      programRegistrar: getProgramRegistrarPDA(),
+      newFolioProgram,
+      oldFolio,
+      newFolio,
+      folioTokenMint,
+      oldFeeDistribution: getFeeDistributionPDA(oldFolio, index),
+      newFeeDistribution: getFeeDistributionPDA(
+        newFolio,
+        index,
+        newFolioProgram
+      ),
+      migrationRecord: getMigrationRecordPDA(oldFolio),
+    })
+    .instruction();
+
+  await pSendAndConfirmTxn(folioProgram, [migrateFeeDistribution], [], {
+    skipPreflight: SKIP_PREFLIGHT,
+  });
+}
@@ -26,6 +26,39 @@ export function getProgramRegistrarPDAWithBump() {
   );
 }
 
+export function getFolioActionRegistrarPDA() {
+  return getFolioActionRegistrarPDAWithBump()[0];
+}
+
+export function getFolioActionRegistrarPDAWithBump() {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("folio_action_registrar")],
+    FOLIO_ADMIN_PROGRAM_ID
+  );
+}
+
+export function getUnkillConfigPDA() {
+  return getUnkillConfigPDAWithBump()[0];
+}
+
+export function getUnkillConfigPDAWithBump() {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("unkill_config")],
+    FOLIO_ADMIN_PROGRAM_ID
+  );
+}
+
+export function getTransferHookRegistrarPDA() {
+  return getTransferHookRegistrarPDAWithBump()[0];
+}
+
+export function getTransferHookRegistrarPDAWithBump() {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("transfer_hook_registrar")],
+    FOLIO_ADMIN_PROGRAM_ID
+  );
+}
+
 export function getFolioPDA(
   folioTokenMint: PublicKey,
   useSecondFolioProgram: boolean = false
@@ -117,14 +150,20 @@ export function getFolioFeeConfigPDAWithBump(folio: PublicKey) {
   );
 }
 
-export function getTVLFeeRecipientsPDA(folio: PublicKey) {
-  return getTVLFeeRecipientsPDAWithBump(folio)[0];
+export function getTVLFeeRecipientsPDA(
+  folio: PublicKey,
+  programId?: PublicKey
+) {
+  return getTVLFeeRecipientsPDAWithBump(folio, programId)[0];
 }
 
-export function getTVLFeeRecipientsPDAWithBump(folio: PublicKey) {
+export function getTVLFeeRecipientsPDAWithBump(
+  folio: PublicKey,
+  programId: PublicKey = FOLIO_PROGRAM_ID
+) {
   return PublicKey.findProgramAddressSync(
     [Buffer.from("fee_recipients"), folio.toBuffer()],
-    FOLIO_PROGRAM_ID
+    programId
   );
 }
 
@@ -142,20 +181,139 @@ export function getFolioBasketPDAWithBump(
   );
 }
 
-export function getUserPendingBasketPDA(folio: PublicKey, user: PublicKey) {
-  return getUserPendingBasketPDAWithBump(folio, user)[0];
+export function getUserPendingBasketPDA(
+  folio: PublicKey,
+  user: PublicKey,
+  programId?: PublicKey
+) {
+  return getUserPendingBasketPDAWithBump(folio, user, programId)[0];
 }
 
 export function getUserPendingBasketPDAWithBump(
   folio: PublicKey,
-  user: PublicKey
+  user: PublicKey,
+  programId: PublicKey = FOLIO_PROGRAM_ID
 ) {
   return PublicKey.findProgramAddressSync(
     [Buffer.from("user_pending_basket"), folio.toBuffer(), user.toBuffer()],
+    programId
+  );
+}
+
+export function getFolioLegacyBasketPDA(folio: PublicKey) {
+  return getFolioLegacyBasketPDAWithBump(folio)[0];
+}
+
+export function getFolioLegacyBasketPDAWithBump(folio: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("folio_legacy_basket"), folio.toBuffer()],
+    FOLIO_PROGRAM_ID
+  );
+}
+
+export function getFolioPendingBasketPDA(
+  folio: PublicKey,
+  programId?: PublicKey
+) {
+  return getFolioPendingBasketPDAWithBump(folio, programId)[0];
+}
+
+export function getFolioPendingBasketPDAWithBump(
+  folio: PublicKey,
+  programId: PublicKey = FOLIO_PROGRAM_ID
+) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("folio_pending_basket"), folio.toBuffer()],
+    programId
+  );
+}
+
+export function getFeeDiscountPDA(folio: PublicKey, minter: PublicKey) {
+  return getFeeDiscountPDAWithBump(folio, minter)[0];
+}
+
+export function getFeeDiscountPDAWithBump(folio: PublicKey, minter: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("fee_discount"), folio.toBuffer(), minter.toBuffer()],
     FOLIO_PROGRAM_ID
   );
 }
 
+export function getKillRecordPDA(folio: PublicKey) {
+  return getKillRecordPDAWithBump(folio)[0];
+}
+
+export function getKillRecordPDAWithBump(folio: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("kill_record"), folio.toBuffer()],
+    FOLIO_PROGRAM_ID
+  );
+}
+
+export function getMigrationRecordPDA(folio: PublicKey) {
+  return getMigrationRecordPDAWithBump(folio)[0];
+}
+
+export function getMigrationRecordPDAWithBump(folio: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("migration_record"), folio.toBuffer()],
+    FOLIO_PROGRAM_ID
+  );
+}
+
+export function getPendingOwnerPDA(folio: PublicKey) {
+  return getPendingOwnerPDAWithBump(folio)[0];
+}
+
+export function getPendingOwnerPDAWithBump(folio: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("pending_owner"), folio.toBuffer()],
+    FOLIO_PROGRAM_ID
+  );
+}
+
+export function getFolioActorsPDA(folio: PublicKey) {
+  return getFolioActorsPDAWithBump(folio)[0];
+}
+
+export function getFolioActorsPDAWithBump(folio: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("folio_actors"), folio.toBuffer()],
+    FOLIO_PROGRAM_ID
+  );
+}
+
+export function getMintAllowlistEntryPDA(folio: PublicKey, user: PublicKey) {
+  return getMintAllowlistEntryPDAWithBump(folio, user)[0];
+}
+
+export function getMintAllowlistEntryPDAWithBump(
+  folio: PublicKey,
+  user: PublicKey
+) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("mint_allowlist_entry"), folio.toBuffer(), user.toBuffer()],
+    FOLIO_PROGRAM_ID
+  );
+}
+
+export function getFolioMintLimitsPDA(
+  folio: PublicKey,
+  programId?: PublicKey
+) {
+  return getFolioMintLimitsPDAWithBump(folio, programId)[0];
+}
+
+export function getFolioMintLimitsPDAWithBump(
+  folio: PublicKey,
+  programId: PublicKey = FOLIO_PROGRAM_ID
+) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("folio_mint_limits"), folio.toBuffer()],
+    programId
+  );
+}
+
 export function getMetadataPDA(mint: PublicKey) {
   return PublicKey.findProgramAddressSync(
     [
@@ -167,8 +325,12 @@ export function getMetadataPDA(mint: PublicKey) {
   )[0];
 }
 
-export function getFeeDistributionPDA(folio: PublicKey, index: BN) {
-  return getFeeDistributionPDAWithBump(folio, index)[0];
+export function getFeeDistributionPDA(
+  folio: PublicKey,
+  index: BN,
+  programId?: PublicKey
+) {
+  return getFeeDistributionPDAWithBump(folio, index, programId)[0];
 }
 
 export function getRebalancePDA(folio: PublicKey) {
@@ -181,14 +343,18 @@ export function getRebalancePDAWithBump(folio: PublicKey) {
     FOLIO_PROGRAM_ID
   );
 }
-export function getFeeDistributionPDAWithBump(folio: PublicKey, index: BN) {
+export function getFeeDistributionPDAWithBump(
+  folio: PublicKey,
+  index: BN,
+  programId: PublicKey = FOLIO_PROGRAM_ID
+) {
   return PublicKey.findProgramAddressSync(
     [
       Buffer.from("fee_distribution"),
       folio.toBuffer(),
       index.toBuffer("le", 8),
     ],
-    FOLIO_PROGRAM_ID
+    programId
   );
 }
 